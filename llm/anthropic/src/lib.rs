@@ -11,10 +11,13 @@ use crate::conversions::{
 use golem_llm::chat_stream::{LlmChatStream, LlmChatStreamState};
 use golem_llm::config::with_config_key;
 use golem_llm::durability::{DurableLLM, ExtendedGuest};
+use golem_llm::error::unsupported;
 use golem_llm::event_source::EventSource;
+use golem_llm::golem::llm::images;
+use golem_llm::golem::llm::images::{ImageOptions, ImageSize};
 use golem_llm::golem::llm::llm::{
-    ChatEvent, ChatStream, Config, ContentPart, Error, ErrorCode, Guest, Message, ResponseMetadata,
-    Role, StreamDelta, StreamEvent, ToolCall, ToolResult,
+    ChatEvent, ChatStream, Config, ContentPart, Error, ErrorCode, Guest, ImageReference, Message,
+    ModerationResult, ResponseMetadata, Role, StreamDelta, StreamEvent, ToolCall, ToolResult,
 };
 use golem_rust::wasm_rpc::Pollable;
 use log::trace;
@@ -292,6 +295,30 @@ impl Guest for AnthropicComponent {
     fn stream(messages: Vec<Message>, config: Config) -> ChatStream {
         ChatStream::new(Self::unwrapped_stream(messages, config))
     }
+
+    fn moderate(_content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+        Err(unsupported("content moderation"))
+    }
+}
+
+impl images::Guest for AnthropicComponent {
+    fn generate(
+        _prompt: String,
+        _size: Option<ImageSize>,
+        _count: u32,
+        _options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        Err(unsupported("image generation"))
+    }
+
+    fn edit(
+        _image: ImageReference,
+        _mask: Option<ImageReference>,
+        _prompt: String,
+        _options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        Err(unsupported("image editing"))
+    }
 }
 
 impl ExtendedGuest for AnthropicComponent {
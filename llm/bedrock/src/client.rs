@@ -1,6 +1,10 @@
 use crate::{
     async_utils::UnsafeFuture,
-    conversions::{self, from_converse_sdk_error, from_converse_stream_sdk_error, BedrockInput},
+    conversions::{
+        self, from_apply_guardrail_sdk_error, from_converse_sdk_error,
+        from_converse_stream_sdk_error, from_invoke_model_sdk_error, BedrockInput, BedrockModelId,
+        ImageModelInput,
+    },
     stream::BedrockChatStream,
     wasi_client::WasiClient,
 };
@@ -12,6 +16,8 @@ use aws_sdk_bedrockruntime::{
         converse::builders::ConverseFluentBuilder,
         converse_stream::builders::ConverseStreamFluentBuilder,
     },
+    primitives::Blob,
+    types::GuardrailContentSource,
 };
 use aws_types::region;
 use golem_llm::{
@@ -28,8 +34,15 @@ pub struct Bedrock {
 }
 
 impl Bedrock {
-    pub async fn new() -> Result<Self, llm::Error> {
-        let environment = BedrockEnvironment::load_from_env()?;
+    /// Creates a client for the configured `AWS_REGION`, unless the model is given as an ARN
+    /// (for example a cross-region inference profile ARN), in which case the ARN's region is used.
+    pub async fn new(model: Option<&str>) -> Result<Self, llm::Error> {
+        let mut environment = BedrockEnvironment::load_from_env()?;
+        if let Some(model) = model {
+            if let Some(region) = BedrockModelId::parse(model)?.region() {
+                environment.region = region.to_owned();
+            }
+        }
 
         let sdk_config = aws_config::defaults(BehaviorVersion::latest())
             .region(environment.aws_region())
@@ -107,6 +120,61 @@ impl Bedrock {
         }
     }
 
+    pub async fn apply_guardrail(
+        &self,
+        content: Vec<llm::ContentPart>,
+    ) -> Result<llm::ModerationResult, llm::Error> {
+        let guardrail = GuardrailEnvironment::load_from_env()?;
+        let content = conversions::content_parts_to_guardrail_content_blocks(content).await?;
+
+        trace!("Sending guardrail request to AWS Bedrock: {content:?}");
+        let guardrail_identifier = guardrail.identifier.clone();
+        let response = self
+            .client
+            .apply_guardrail()
+            .guardrail_identifier(guardrail.identifier)
+            .guardrail_version(guardrail.version)
+            .source(GuardrailContentSource::Input)
+            .set_content(Some(content))
+            .send()
+            .await
+            .map_err(|e| from_apply_guardrail_sdk_error(guardrail_identifier, e))?;
+
+        Ok(conversions::apply_guardrail_output_to_moderation_result(
+            response,
+        ))
+    }
+
+    pub async fn invoke_image_model(
+        &self,
+        input: ImageModelInput,
+    ) -> Result<Vec<llm::ImageReference>, llm::Error> {
+        let mut images = Vec::new();
+        for body in input.bodies {
+            trace!(
+                "Sending image generation request to AWS Bedrock model {}",
+                input.model_id
+            );
+            let response = self
+                .client
+                .invoke_model()
+                .model_id(&input.model_id)
+                .content_type("application/json")
+                .accept("application/json")
+                .body(Blob::new(body.to_string()))
+                .send()
+                .await
+                .map_err(|e| from_invoke_model_sdk_error(input.model_id.clone(), e))?;
+
+            images.extend(conversions::invoke_model_output_to_images(
+                input.family,
+                response.body().as_ref(),
+                &input.output_mime_type,
+            )?);
+        }
+        Ok(images)
+    }
+
     fn init_converse(&self, input: conversions::BedrockInput) -> ConverseFluentBuilder {
         self.client
             .converse()
@@ -115,6 +183,11 @@ impl Bedrock {
             .set_messages(Some(input.messages))
             .inference_config(input.inference_configuration)
             .set_tool_config(input.tools)
+            .set_guardrail_config(
+                input
+                    .guardrail
+                    .map(|guardrail| guardrail.to_guardrail_configuration()),
+            )
             .additional_model_request_fields(input.additional_fields)
     }
 
@@ -129,6 +202,11 @@ impl Bedrock {
             .set_messages(Some(input.messages))
             .inference_config(input.inference_configuration)
             .set_tool_config(input.tools)
+            .set_guardrail_config(
+                input
+                    .guardrail
+                    .map(|guardrail| guardrail.to_guardrail_stream_configuration()),
+            )
             .additional_model_request_fields(input.additional_fields)
     }
 }
@@ -166,6 +244,22 @@ impl BedrockEnvironment {
     }
 }
 
+#[derive(Debug)]
+pub struct GuardrailEnvironment {
+    identifier: String,
+    version: String,
+}
+
+impl GuardrailEnvironment {
+    pub fn load_from_env() -> Result<Self, llm::Error> {
+        Ok(Self {
+            identifier: get_config_key("BEDROCK_GUARDRAIL_ID")?,
+            version: get_config_key_or_none("BEDROCK_GUARDRAIL_VERSION")
+                .unwrap_or_else(|| "DRAFT".to_owned()),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 struct WasiSleep;
 
@@ -4,15 +4,23 @@ use std::collections::HashMap;
 use aws_sdk_bedrockruntime::{
     self as bedrock,
     error::SdkError,
-    operation::{converse, converse_stream},
+    operation::{apply_guardrail, converse, converse_stream, invoke_model},
     types::{
         ContentBlockDeltaEvent, ContentBlockStartEvent, ConversationRole,
-        ConverseStreamMetadataEvent, ConverseStreamOutput, ImageBlock, ImageFormat,
-        InferenceConfiguration, MessageStopEvent, SystemContentBlock, Tool, ToolConfiguration,
-        ToolInputSchema, ToolSpecification, ToolUseBlock,
+        ConverseStreamMetadataEvent, ConverseStreamOutput, GuardrailAction, GuardrailAssessment,
+        GuardrailConfiguration, GuardrailContentBlock, GuardrailContentFilterConfidence,
+        GuardrailContentPolicyAction, GuardrailImageBlock, GuardrailImageFormat,
+        GuardrailImageSource, GuardrailSensitiveInformationPolicyAction,
+        GuardrailStreamConfiguration, GuardrailStreamProcessingMode, GuardrailTextBlock,
+        GuardrailTopicPolicyAction, GuardrailTrace, GuardrailTraceAssessment,
+        GuardrailWordPolicyAction, ImageBlock, ImageFormat, InferenceConfiguration,
+        MessageStopEvent, SystemContentBlock, Tool, ToolConfiguration, ToolInputSchema,
+        ToolSpecification, ToolUseBlock,
     },
 };
-use golem_llm::golem::llm::llm;
+use base64::{engine::general_purpose, Engine as _};
+use golem_llm::golem::llm::{images, llm};
+use serde::Deserialize;
 use wstd::http;
 
 #[derive(Debug)]
@@ -22,6 +30,7 @@ pub struct BedrockInput {
     pub messages: Vec<bedrock::types::Message>,
     pub inference_configuration: InferenceConfiguration,
     pub tools: Option<ToolConfiguration>,
+    pub guardrail: Option<GuardrailOptions>,
     pub additional_fields: aws_smithy_types::Document,
 }
 
@@ -31,6 +40,8 @@ impl BedrockInput {
         config: llm::Config,
         tool_results: Option<Vec<(llm::ToolCall, llm::ToolResult)>>,
     ) -> Result<Self, llm::Error> {
+        let model_id = BedrockModelId::parse(&config.model)?;
+
         let (mut user_messages, system_instructions) =
             messages_to_bedrock_message_groups(messages).await?;
 
@@ -38,31 +49,225 @@ impl BedrockInput {
             user_messages.extend(tool_call_results_to_bedrock_tools(tool_results)?);
         }
 
-        let options = config
+        let mut options = config
             .provider_options
             .into_iter()
-            .map(|kv| (kv.key, Document::String(kv.value)))
+            .map(|kv| (kv.key, kv.value))
             .collect::<HashMap<_, _>>();
 
+        let top_p = options
+            .remove(TOP_P_KEY)
+            .and_then(|top_p| top_p.parse::<f32>().ok());
+        let guardrail = GuardrailOptions::from_provider_options(&mut options)?;
+        let additional_fields = provider_options_to_additional_fields(options)?;
+
         Ok(BedrockInput {
-            model_id: config.model,
+            model_id: model_id.into_string(),
             inference_configuration: InferenceConfiguration::builder()
                 .set_max_tokens(config.max_tokens.map(|x| x as i32))
                 .set_temperature(config.temperature)
                 .set_stop_sequences(config.stop_sequences)
-                .set_top_p(options.get("top_p").and_then(|v| match v {
-                    Document::String(v) => v.parse::<f32>().ok(),
-                    _ => None,
-                }))
+                .set_top_p(top_p)
                 .build(),
             messages: user_messages,
             system_instructions,
             tools: tool_defs_to_bedrock_tool_config(config.tools)?,
-            additional_fields: Document::Object(options),
+            guardrail,
+            additional_fields,
         })
     }
 }
 
+const TOP_P_KEY: &str = "top_p";
+const ADDITIONAL_MODEL_REQUEST_FIELDS_KEY: &str = "additional_model_request_fields";
+const GUARDRAIL_CONFIG_KEY: &str = "guardrail_config";
+
+/// The model to invoke, which can be a foundation model id, a cross-region inference profile id
+/// (e.g. `us.anthropic.claude-3-5-sonnet-20240620-v1:0`) or the ARN of a foundation model,
+/// inference profile, provisioned or custom model.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BedrockModelId {
+    Id(String),
+    Arn { arn: String, region: String },
+}
+
+impl BedrockModelId {
+    pub fn parse(model: &str) -> Result<Self, llm::Error> {
+        if !model.starts_with("arn:") {
+            return if model.is_empty() {
+                Err(custom_error(
+                    llm::ErrorCode::InvalidRequest,
+                    "Model id must not be empty".to_owned(),
+                ))
+            } else {
+                Ok(BedrockModelId::Id(model.to_owned()))
+            };
+        }
+
+        // arn:<partition>:bedrock:<region>:<account-id>:<resource-type>/<resource-id>
+        let parts = model.splitn(6, ':').collect::<Vec<_>>();
+        match parts.as_slice() {
+            ["arn", _partition, "bedrock", region, _account_id, resource]
+                if !region.is_empty() && resource.contains('/') =>
+            {
+                Ok(BedrockModelId::Arn {
+                    arn: model.to_owned(),
+                    region: region.to_string(),
+                })
+            }
+            _ => Err(custom_error(
+                llm::ErrorCode::InvalidRequest,
+                format!("Invalid Bedrock model ARN: {model}"),
+            )),
+        }
+    }
+
+    /// The region encoded in the model ARN, if any. Requests for ARNs have to be sent to this region.
+    pub fn region(&self) -> Option<&str> {
+        match self {
+            BedrockModelId::Id(_) => None,
+            BedrockModelId::Arn { region, .. } => Some(region),
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        match self {
+            BedrockModelId::Id(id) => id,
+            BedrockModelId::Arn { arn, .. } => arn,
+        }
+    }
+}
+
+/// Guardrail to apply on `Converse` and `ConverseStream` calls, configured by the JSON object of
+/// the `guardrail_config` provider option, e.g. `{"identifier": "gr-123", "trace": "enabled"}`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuardrailOptions {
+    pub identifier: String,
+    #[serde(default = "default_guardrail_version")]
+    pub version: String,
+    #[serde(default)]
+    pub trace: Option<GuardrailTraceSetting>,
+    /// Only used when streaming
+    #[serde(default)]
+    pub stream_processing_mode: Option<GuardrailStreamMode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailTraceSetting {
+    Enabled,
+    EnabledFull,
+    Disabled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailStreamMode {
+    Sync,
+    Async,
+}
+
+fn default_guardrail_version() -> String {
+    "DRAFT".to_owned()
+}
+
+impl From<GuardrailTraceSetting> for GuardrailTrace {
+    fn from(trace: GuardrailTraceSetting) -> Self {
+        match trace {
+            GuardrailTraceSetting::Enabled => GuardrailTrace::Enabled,
+            GuardrailTraceSetting::EnabledFull => GuardrailTrace::EnabledFull,
+            GuardrailTraceSetting::Disabled => GuardrailTrace::Disabled,
+        }
+    }
+}
+
+impl From<GuardrailStreamMode> for GuardrailStreamProcessingMode {
+    fn from(mode: GuardrailStreamMode) -> Self {
+        match mode {
+            GuardrailStreamMode::Sync => GuardrailStreamProcessingMode::Sync,
+            GuardrailStreamMode::Async => GuardrailStreamProcessingMode::Async,
+        }
+    }
+}
+
+impl GuardrailOptions {
+    fn from_provider_options(
+        options: &mut HashMap<String, String>,
+    ) -> Result<Option<Self>, llm::Error> {
+        options
+            .remove(GUARDRAIL_CONFIG_KEY)
+            .map(|raw| {
+                serde_json::from_str::<GuardrailOptions>(&raw).map_err(|err| {
+                    custom_error(
+                        llm::ErrorCode::InvalidRequest,
+                        format!("Invalid {GUARDRAIL_CONFIG_KEY}: {err}"),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    pub fn to_guardrail_configuration(&self) -> GuardrailConfiguration {
+        GuardrailConfiguration::builder()
+            .guardrail_identifier(self.identifier.clone())
+            .guardrail_version(self.version.clone())
+            .set_trace(self.trace.map(GuardrailTrace::from))
+            .build()
+            .unwrap()
+    }
+
+    pub fn to_guardrail_stream_configuration(&self) -> GuardrailStreamConfiguration {
+        GuardrailStreamConfiguration::builder()
+            .guardrail_identifier(self.identifier.clone())
+            .guardrail_version(self.version.clone())
+            .set_trace(self.trace.map(GuardrailTrace::from))
+            .set_stream_processing_mode(
+                self.stream_processing_mode
+                    .map(GuardrailStreamProcessingMode::from),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+/// Builds the `additionalModelRequestFields` document for model specific parameters (such as
+/// Claude's `top_k`). The `additional_model_request_fields` provider option can hold a JSON object
+/// which is passed through as-is, and every other unrecognized provider option is added to it as a
+/// top level string field.
+fn provider_options_to_additional_fields(
+    mut options: HashMap<String, String>,
+) -> Result<Document, llm::Error> {
+    let mut fields = match options.remove(ADDITIONAL_MODEL_REQUEST_FIELDS_KEY) {
+        Some(raw) => match serde_json::from_str::<serde_json::Value>(&raw) {
+            Ok(serde_json::Value::Object(map)) => map,
+            Ok(_) => {
+                return Err(custom_error(
+                    llm::ErrorCode::InvalidRequest,
+                    format!("{ADDITIONAL_MODEL_REQUEST_FIELDS_KEY} must be a JSON object"),
+                ))
+            }
+            Err(err) => {
+                return Err(custom_error(
+                    llm::ErrorCode::InvalidRequest,
+                    format!("Invalid {ADDITIONAL_MODEL_REQUEST_FIELDS_KEY}: {err}"),
+                ))
+            }
+        },
+        None => serde_json::Map::new(),
+    };
+
+    for (key, value) in options {
+        fields
+            .entry(key)
+            .or_insert(serde_json::Value::String(value));
+    }
+
+    Ok(serde_json_to_smithy_document(serde_json::Value::Object(
+        fields,
+    )))
+}
+
 fn tool_call_results_to_bedrock_tools(
     results: Vec<(llm::ToolCall, llm::ToolResult)>,
 ) -> Result<Vec<bedrock::types::Message>, llm::Error> {
@@ -274,6 +479,241 @@ fn str_to_bedrock_mime_type(mime_type: &str) -> Result<ImageFormat, llm::Error>
     }
 }
 
+pub async fn content_parts_to_guardrail_content_blocks(
+    content_parts: Vec<llm::ContentPart>,
+) -> Result<Vec<GuardrailContentBlock>, llm::Error> {
+    let mut guardrail_content_blocks: Vec<GuardrailContentBlock> = vec![];
+    for part in content_parts {
+        match part {
+            llm::ContentPart::Text(text) => {
+                guardrail_content_blocks.push(GuardrailContentBlock::Text(
+                    GuardrailTextBlock::builder().text(text).build().unwrap(),
+                ));
+            }
+            llm::ContentPart::Image(image) => {
+                let (mime_type, bytes) = match image {
+                    llm::ImageReference::Inline(image) => (image.mime_type, image.data),
+                    llm::ImageReference::Url(url) => {
+                        let bytes = get_bytes_from_url(&url.url).await?;
+                        let mime_type = infer::get(&bytes)
+                            .map(|kind| kind.mime_type().to_owned())
+                            .ok_or_else(|| {
+                                custom_error(
+                                    llm::ErrorCode::InvalidRequest,
+                                    format!(
+                                        "Could not infer the mime type of the image downloaded from url: {}",
+                                        url.url
+                                    ),
+                                )
+                            })?;
+                        (mime_type, bytes)
+                    }
+                };
+
+                guardrail_content_blocks.push(GuardrailContentBlock::Image(
+                    GuardrailImageBlock::builder()
+                        .format(str_to_guardrail_image_format(&mime_type)?)
+                        .source(GuardrailImageSource::Bytes(bytes.into()))
+                        .build()
+                        .unwrap(),
+                ));
+            }
+        }
+    }
+
+    Ok(guardrail_content_blocks)
+}
+
+fn str_to_guardrail_image_format(mime_type: &str) -> Result<GuardrailImageFormat, llm::Error> {
+    match mime_type {
+        "image/png" => Ok(GuardrailImageFormat::Png),
+        "image/jpeg" => Ok(GuardrailImageFormat::Jpeg),
+        other => Err(llm::Error {
+            code: llm::ErrorCode::Unsupported,
+            message: format!("Unsupported image type for guardrails: {other}"),
+            provider_error_json: None,
+        }),
+    }
+}
+
+pub fn apply_guardrail_output_to_moderation_result(
+    output: apply_guardrail::ApplyGuardrailOutput,
+) -> llm::ModerationResult {
+    let categories = output
+        .assessments()
+        .iter()
+        .flat_map(guardrail_assessment_to_moderation_categories)
+        .collect();
+
+    let provider_metadata = serde_json::json!({
+        "action": output.action().as_str(),
+    });
+
+    llm::ModerationResult {
+        flagged: output.action() == &GuardrailAction::GuardrailIntervened,
+        categories,
+        provider_metadata_json: Some(provider_metadata.to_string()),
+    }
+}
+
+/// Flattens a guardrail assessment into moderation categories. Content filters are reported
+/// with their lowercase filter type (e.g. `hate`), the other policies are prefixed with the policy
+/// name (e.g. `topic/<name>`, `pii/<type>`).
+fn guardrail_assessment_to_moderation_categories(
+    assessment: &GuardrailAssessment,
+) -> Vec<llm::ModerationCategory> {
+    let mut categories: Vec<llm::ModerationCategory> = vec![];
+
+    if let Some(policy) = assessment.content_policy() {
+        for filter in policy.filters() {
+            categories.push(llm::ModerationCategory {
+                name: filter.r#type().as_str().to_lowercase(),
+                flagged: filter.action() == &GuardrailContentPolicyAction::Blocked,
+                score: Some(guardrail_confidence_to_score(filter.confidence())),
+            });
+        }
+    }
+    if let Some(policy) = assessment.topic_policy() {
+        for topic in policy.topics() {
+            categories.push(llm::ModerationCategory {
+                name: format!("topic/{}", topic.name()),
+                flagged: topic.action() == &GuardrailTopicPolicyAction::Blocked,
+                score: None,
+            });
+        }
+    }
+    if let Some(policy) = assessment.word_policy() {
+        for word in policy.custom_words() {
+            categories.push(llm::ModerationCategory {
+                name: "word/custom".to_owned(),
+                flagged: word.action() == &GuardrailWordPolicyAction::Blocked,
+                score: None,
+            });
+        }
+        for word in policy.managed_word_lists() {
+            categories.push(llm::ModerationCategory {
+                name: format!("word/{}", word.r#type().as_str().to_lowercase()),
+                flagged: word.action() == &GuardrailWordPolicyAction::Blocked,
+                score: None,
+            });
+        }
+    }
+    if let Some(policy) = assessment.sensitive_information_policy() {
+        for entity in policy.pii_entities() {
+            categories.push(llm::ModerationCategory {
+                name: format!("pii/{}", entity.r#type().as_str().to_lowercase()),
+                flagged: matches!(
+                    entity.action(),
+                    GuardrailSensitiveInformationPolicyAction::Blocked
+                        | GuardrailSensitiveInformationPolicyAction::Anonymized
+                ),
+                score: None,
+            });
+        }
+        for regex in policy.regexes() {
+            categories.push(llm::ModerationCategory {
+                name: format!("regex/{}", regex.name().unwrap_or_default()),
+                flagged: matches!(
+                    regex.action(),
+                    GuardrailSensitiveInformationPolicyAction::Blocked
+                        | GuardrailSensitiveInformationPolicyAction::Anonymized
+                ),
+                score: None,
+            });
+        }
+    }
+
+    categories
+}
+
+fn guardrail_assessments_to_json<'a>(
+    assessments: impl IntoIterator<Item = &'a GuardrailAssessment>,
+) -> serde_json::Value {
+    serde_json::Value::Array(
+        assessments
+            .into_iter()
+            .flat_map(guardrail_assessment_to_moderation_categories)
+            .map(|category| {
+                serde_json::json!({
+                    "name": category.name,
+                    "flagged": category.flagged,
+                    "score": category.score,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Converts the guardrail trace into JSON, with the assessments of each guardrail flattened the
+/// same way as the results of `moderate`.
+fn guardrail_trace_to_json(trace: &GuardrailTraceAssessment) -> serde_json::Value {
+    let input_assessment = trace.input_assessment().map(|assessments| {
+        assessments
+            .iter()
+            .map(|(guardrail, assessment)| {
+                (
+                    guardrail.clone(),
+                    guardrail_assessments_to_json([assessment]),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>()
+    });
+    let output_assessments = trace.output_assessments().map(|assessments| {
+        assessments
+            .iter()
+            .map(|(guardrail, assessments)| {
+                (
+                    guardrail.clone(),
+                    guardrail_assessments_to_json(assessments),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>()
+    });
+
+    serde_json::json!({
+        "input_assessment": input_assessment,
+        "output_assessments": output_assessments,
+        "model_output": trace.model_output(),
+    })
+}
+
+/// Builds the `provider-metadata-json` field from the model specific response fields, extended
+/// with a `guardrail_trace` field when a guardrail trace was returned.
+fn response_metadata_json(
+    additional_model_response_fields: Option<Document>,
+    guardrail_trace: Option<&GuardrailTraceAssessment>,
+) -> Option<String> {
+    let additional_fields = additional_model_response_fields.map(smithy_document_to_json_value);
+    let metadata = match (additional_fields, guardrail_trace) {
+        (additional_fields, None) => additional_fields,
+        (Some(serde_json::Value::Object(mut fields)), Some(trace)) => {
+            fields.insert("guardrail_trace".to_owned(), guardrail_trace_to_json(trace));
+            Some(serde_json::Value::Object(fields))
+        }
+        (Some(additional_fields), Some(trace)) => Some(serde_json::json!({
+            "additional_model_response_fields": additional_fields,
+            "guardrail_trace": guardrail_trace_to_json(trace),
+        })),
+        (None, Some(trace)) => Some(serde_json::json!({
+            "guardrail_trace": guardrail_trace_to_json(trace),
+        })),
+    };
+
+    metadata.and_then(|metadata| serde_json::to_string(&metadata).ok())
+}
+
+/// Guardrails only report a confidence bucket for content filters, so it is mapped to the upper
+/// bound of the bucket to be comparable with score based providers.
+fn guardrail_confidence_to_score(confidence: &GuardrailContentFilterConfidence) -> f32 {
+    match confidence {
+        GuardrailContentFilterConfidence::None => 0.0,
+        GuardrailContentFilterConfidence::Low => 0.33,
+        GuardrailContentFilterConfidence::Medium => 0.66,
+        GuardrailContentFilterConfidence::High => 1.0,
+        _ => 0.0,
+    }
+}
+
 pub fn converse_output_to_tool_calls(
     response: converse::ConverseOutput,
 ) -> Result<Vec<llm::ToolCall>, llm::Error> {
@@ -370,18 +810,14 @@ fn converse_output_to_response_metadata(
         finish_reason: Some(bedrock_stop_reason_to_finish_reason(response.stop_reason())),
         usage: response.usage().map(bedrock_usage_to_llm_usage),
         provider_id: Some("bedrock".to_owned()),
-        provider_metadata_json: response
-            .additional_model_response_fields
-            .clone()
-            .and_then(smithy_document_to_metadata_json),
+        provider_metadata_json: response_metadata_json(
+            response.additional_model_response_fields.clone(),
+            response.trace().and_then(|trace| trace.guardrail()),
+        ),
         timestamp: None,
     }
 }
 
-fn smithy_document_to_metadata_json(doc: Document) -> Option<String> {
-    serde_json::to_string(&smithy_document_to_json_value(doc)).ok()
-}
-
 fn bedrock_usage_to_llm_usage(usage: &bedrock::types::TokenUsage) -> llm::Usage {
     llm::Usage {
         input_tokens: Some(usage.input_tokens() as u32),
@@ -485,7 +921,10 @@ fn process_metadata_event(metadata: ConverseStreamMetadataEvent) -> Option<llm::
         timestamp: None,
         usage: metadata.usage().map(bedrock_usage_to_llm_usage),
         provider_id: Some("bedrock".to_owned()),
-        provider_metadata_json: None,
+        provider_metadata_json: response_metadata_json(
+            None,
+            metadata.trace().and_then(|trace| trace.guardrail()),
+        ),
     }))
 }
 
@@ -495,10 +934,10 @@ fn process_message_stop_event(event: MessageStopEvent) -> Option<llm::StreamEven
         timestamp: None,
         usage: None,
         provider_id: None,
-        provider_metadata_json: event
-            .additional_model_response_fields
-            .clone()
-            .and_then(smithy_document_to_metadata_json),
+        provider_metadata_json: response_metadata_json(
+            event.additional_model_response_fields.clone(),
+            None,
+        ),
     }))
 }
 
@@ -572,6 +1011,349 @@ fn serde_json_to_smithy_document(value: serde_json::Value) -> Document {
     }
 }
 
+/// The request body schemas understood by the Bedrock image generation models
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageModelFamily {
+    /// Amazon Titan Image Generator and Amazon Nova Canvas
+    Titan,
+    /// Stability AI's Stable Diffusion 3.5, Stable Image Core / Ultra and their edit models
+    Stability,
+}
+
+impl ImageModelFamily {
+    pub fn from_model_id(model_id: &str) -> Self {
+        if model_id.contains("stability.") {
+            Self::Stability
+        } else {
+            Self::Titan
+        }
+    }
+}
+
+pub const DEFAULT_IMAGE_MODEL_ID: &str = "amazon.titan-image-generator-v2:0";
+
+#[derive(Debug)]
+pub struct ImageModelInput {
+    pub model_id: String,
+    pub family: ImageModelFamily,
+    /// One request body per model invocation. Stability models only return a single image per
+    /// invocation, so multiple images are requested by invoking the model multiple times.
+    pub bodies: Vec<serde_json::Value>,
+    pub output_mime_type: String,
+}
+
+impl ImageModelInput {
+    pub fn generate(
+        prompt: String,
+        size: Option<images::ImageSize>,
+        count: u32,
+        options: images::ImageOptions,
+    ) -> Self {
+        let model_id = options
+            .model
+            .clone()
+            .unwrap_or_else(|| DEFAULT_IMAGE_MODEL_ID.to_string());
+        let family = ImageModelFamily::from_model_id(&model_id);
+        let count = count.max(1);
+        let provider_options = image_provider_options_to_json(&options.provider_options);
+
+        let bodies = match family {
+            ImageModelFamily::Titan => {
+                let mut text_to_image_params = serde_json::json!({ "text": prompt });
+                if let Some(negative_prompt) = &options.negative_prompt {
+                    text_to_image_params["negativeText"] = negative_prompt.clone().into();
+                }
+                vec![serde_json::json!({
+                    "taskType": "TEXT_IMAGE",
+                    "textToImageParams": text_to_image_params,
+                    "imageGenerationConfig": titan_image_generation_config(
+                        size,
+                        Some(count),
+                        &options,
+                        provider_options.clone(),
+                    ),
+                })]
+            }
+            ImageModelFamily::Stability => (0..count)
+                .map(|index| {
+                    let mut body = serde_json::json!({
+                        "prompt": prompt,
+                        "mode": "text-to-image",
+                    });
+                    if let Some(size) = size {
+                        body["aspect_ratio"] = image_size_to_aspect_ratio(size).into();
+                    }
+                    stability_common_fields(&mut body, index, &options, &provider_options);
+                    body
+                })
+                .collect(),
+        };
+
+        Self {
+            output_mime_type: output_mime_type(family, &provider_options),
+            model_id,
+            family,
+            bodies,
+        }
+    }
+
+    /// Creates an inpainting request when a mask is given, and an image variation (Titan) or
+    /// image-to-image (Stability) request otherwise.
+    pub async fn edit(
+        image: llm::ImageReference,
+        mask: Option<llm::ImageReference>,
+        prompt: String,
+        options: images::ImageOptions,
+    ) -> Result<Self, llm::Error> {
+        let model_id = options
+            .model
+            .clone()
+            .unwrap_or_else(|| DEFAULT_IMAGE_MODEL_ID.to_string());
+        let family = ImageModelFamily::from_model_id(&model_id);
+        let provider_options = image_provider_options_to_json(&options.provider_options);
+
+        let image = image_reference_to_base64(image).await?;
+        let mask = match mask {
+            Some(mask) => Some(image_reference_to_base64(mask).await?),
+            None => None,
+        };
+
+        let body = match family {
+            ImageModelFamily::Titan => {
+                let mut params = serde_json::json!({ "text": prompt });
+                if let Some(negative_prompt) = &options.negative_prompt {
+                    params["negativeText"] = negative_prompt.clone().into();
+                }
+                let generation_config =
+                    titan_image_generation_config(None, None, &options, provider_options.clone());
+                match mask {
+                    Some(mask) => {
+                        params["image"] = image.into();
+                        params["maskImage"] = mask.into();
+                        serde_json::json!({
+                            "taskType": "INPAINTING",
+                            "inPaintingParams": params,
+                            "imageGenerationConfig": generation_config,
+                        })
+                    }
+                    None => {
+                        params["images"] = serde_json::json!([image]);
+                        serde_json::json!({
+                            "taskType": "IMAGE_VARIATION",
+                            "imageVariationParams": params,
+                            "imageGenerationConfig": generation_config,
+                        })
+                    }
+                }
+            }
+            ImageModelFamily::Stability => {
+                let mut body = serde_json::json!({
+                    "prompt": prompt,
+                    "image": image,
+                });
+                match mask {
+                    Some(mask) => {
+                        body["mask"] = mask.into();
+                    }
+                    None => {
+                        body["mode"] = "image-to-image".into();
+                        body["strength"] = DEFAULT_IMAGE_TO_IMAGE_STRENGTH.into();
+                    }
+                }
+                stability_common_fields(&mut body, 0, &options, &provider_options);
+                body
+            }
+        };
+
+        Ok(Self {
+            output_mime_type: output_mime_type(family, &provider_options),
+            model_id,
+            family,
+            bodies: vec![body],
+        })
+    }
+}
+
+const DEFAULT_IMAGE_TO_IMAGE_STRENGTH: f64 = 0.7;
+
+fn titan_image_generation_config(
+    size: Option<images::ImageSize>,
+    count: Option<u32>,
+    options: &images::ImageOptions,
+    provider_options: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Value {
+    let mut config = serde_json::Map::new();
+    if let Some(count) = count {
+        config.insert("numberOfImages".to_string(), count.into());
+    }
+    if let Some(size) = size {
+        config.insert("width".to_string(), size.width.into());
+        config.insert("height".to_string(), size.height.into());
+    }
+    if let Some(quality) = &options.quality {
+        config.insert("quality".to_string(), quality.clone().into());
+    }
+    if let Some(seed) = options.seed {
+        config.insert("seed".to_string(), seed.into());
+    }
+    config.extend(provider_options);
+    serde_json::Value::Object(config)
+}
+
+/// Sets the fields shared by all Stability request bodies. When a seed is given, it is
+/// incremented for each invocation so that multiple requested images are not identical.
+fn stability_common_fields(
+    body: &mut serde_json::Value,
+    index: u32,
+    options: &images::ImageOptions,
+    provider_options: &serde_json::Map<String, serde_json::Value>,
+) {
+    if let Some(negative_prompt) = &options.negative_prompt {
+        body["negative_prompt"] = negative_prompt.clone().into();
+    }
+    if let Some(seed) = options.seed {
+        body["seed"] = seed.wrapping_add(index as u64).into();
+    }
+    if let Some(style) = &options.style {
+        body["style_preset"] = style.clone().into();
+    }
+    if let Some(fields) = body.as_object_mut() {
+        fields.extend(provider_options.clone());
+    }
+}
+
+fn image_provider_options_to_json(
+    provider_options: &[llm::Kv],
+) -> serde_json::Map<String, serde_json::Value> {
+    provider_options
+        .iter()
+        .map(|kv| {
+            let value = serde_json::from_str(&kv.value)
+                .unwrap_or_else(|_| serde_json::Value::String(kv.value.clone()));
+            (kv.key.clone(), value)
+        })
+        .collect()
+}
+
+fn output_mime_type(
+    family: ImageModelFamily,
+    provider_options: &serde_json::Map<String, serde_json::Value>,
+) -> String {
+    match family {
+        ImageModelFamily::Titan => "image/png".to_string(),
+        ImageModelFamily::Stability => {
+            let format = provider_options
+                .get("output_format")
+                .and_then(|format| format.as_str())
+                .unwrap_or("png");
+            format!("image/{format}")
+        }
+    }
+}
+
+fn image_size_to_aspect_ratio(size: images::ImageSize) -> String {
+    fn gcd(a: u32, b: u32) -> u32 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    let divisor = gcd(size.width, size.height).max(1);
+    format!("{}:{}", size.width / divisor, size.height / divisor)
+}
+
+async fn image_reference_to_base64(image: llm::ImageReference) -> Result<String, llm::Error> {
+    let bytes = match image {
+        llm::ImageReference::Inline(source) => source.data,
+        llm::ImageReference::Url(url) => get_bytes_from_url(&url.url).await?,
+    };
+    Ok(general_purpose::STANDARD.encode(bytes))
+}
+
+/// Extracts the generated images from an `InvokeModel` response body. Both the Titan and the
+/// Stability models return the base64 encoded images in an `images` array.
+pub fn invoke_model_output_to_images(
+    family: ImageModelFamily,
+    body: &[u8],
+    mime_type: &str,
+) -> Result<Vec<llm::ImageReference>, llm::Error> {
+    let response: serde_json::Value = serde_json::from_slice(body).map_err(|err| {
+        custom_error(
+            llm::ErrorCode::InternalError,
+            format!("Failed to decode image model response: {err}"),
+        )
+    })?;
+
+    if let Some(error) = response.get("error").and_then(|error| error.as_str()) {
+        return Err(llm::Error {
+            code: llm::ErrorCode::InvalidRequest,
+            message: format!("Image generation failed: {error}"),
+            provider_error_json: Some(response.to_string()),
+        });
+    }
+
+    if family == ImageModelFamily::Stability {
+        let finish_reasons = response
+            .get("finish_reasons")
+            .and_then(|reasons| reasons.as_array())
+            .map(|reasons| {
+                reasons
+                    .iter()
+                    .filter_map(|reason| reason.as_str())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if !finish_reasons.is_empty() {
+            return Err(llm::Error {
+                code: llm::ErrorCode::InvalidRequest,
+                message: format!("Image generation failed: {}", finish_reasons.join(", ")),
+                provider_error_json: Some(response.to_string()),
+            });
+        }
+    }
+
+    response
+        .get("images")
+        .and_then(|images| images.as_array())
+        .ok_or_else(|| {
+            custom_error(
+                llm::ErrorCode::InternalError,
+                "Image model response did not contain any images".to_string(),
+            )
+        })?
+        .iter()
+        .map(|image| {
+            let data = image
+                .as_str()
+                .and_then(|image| general_purpose::STANDARD.decode(image).ok())
+                .ok_or_else(|| {
+                    custom_error(
+                        llm::ErrorCode::InternalError,
+                        "Failed to decode generated image".to_string(),
+                    )
+                })?;
+            Ok(llm::ImageReference::Inline(llm::ImageSource {
+                data,
+                mime_type: mime_type.to_string(),
+                detail: None,
+            }))
+        })
+        .collect()
+}
+
+pub fn from_invoke_model_sdk_error(
+    model_id: String,
+    sdk_error: SdkError<invoke_model::InvokeModelError>,
+) -> llm::Error {
+    llm::Error {
+        code: llm::ErrorCode::InternalError,
+        message: format!("Error calling Bedrock model {model_id}: {sdk_error:?}",),
+        provider_error_json: None,
+    }
+}
+
 pub fn from_converse_sdk_error(
     model_id: String,
     sdk_error: SdkError<converse::ConverseError>,
@@ -594,6 +1376,17 @@ pub fn from_converse_stream_sdk_error(
     }
 }
 
+pub fn from_apply_guardrail_sdk_error(
+    guardrail_identifier: String,
+    sdk_error: SdkError<apply_guardrail::ApplyGuardrailError>,
+) -> llm::Error {
+    llm::Error {
+        code: llm::ErrorCode::InternalError,
+        message: format!("Error calling Bedrock guardrail {guardrail_identifier}: {sdk_error:?}",),
+        provider_error_json: None,
+    }
+}
+
 pub fn custom_error(code: llm::ErrorCode, message: String) -> llm::Error {
     llm::Error {
         code,
@@ -610,9 +1403,351 @@ pub fn merge_metadata(
     metadata1.timestamp = metadata1.timestamp.or(metadata2.timestamp);
     metadata1.provider_id = metadata1.provider_id.or(metadata2.provider_id);
     metadata1.finish_reason = metadata1.finish_reason.or(metadata2.finish_reason);
-    metadata1.provider_metadata_json = metadata1
-        .provider_metadata_json
-        .or(metadata2.provider_metadata_json);
+    metadata1.provider_metadata_json = merge_metadata_json(
+        metadata1.provider_metadata_json,
+        metadata2.provider_metadata_json,
+    );
 
     metadata1
 }
+
+/// Merges two provider metadata JSON objects, preferring the fields of the first one. As the
+/// stream's stop and metadata events carry different details, both are kept when possible.
+fn merge_metadata_json(json1: Option<String>, json2: Option<String>) -> Option<String> {
+    match (json1, json2) {
+        (Some(json1), Some(json2)) => {
+            match (
+                serde_json::from_str::<serde_json::Value>(&json1),
+                serde_json::from_str::<serde_json::Value>(&json2),
+            ) {
+                (
+                    Ok(serde_json::Value::Object(mut fields1)),
+                    Ok(serde_json::Value::Object(fields2)),
+                ) => {
+                    for (key, value) in fields2 {
+                        fields1.entry(key).or_insert(value);
+                    }
+                    serde_json::to_string(&serde_json::Value::Object(fields1)).ok()
+                }
+                _ => Some(json1),
+            }
+        }
+        (json1, json2) => json1.or(json2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_model_id() {
+        assert_eq!(
+            BedrockModelId::parse("anthropic.claude-3-haiku-20240307-v1:0").unwrap(),
+            BedrockModelId::Id("anthropic.claude-3-haiku-20240307-v1:0".to_owned())
+        );
+
+        let arn = "arn:aws:bedrock:eu-west-1:123456789012:inference-profile/eu.anthropic.claude-3-haiku-20240307-v1:0";
+        let model_id = BedrockModelId::parse(arn).unwrap();
+        assert_eq!(model_id.region(), Some("eu-west-1"));
+        assert_eq!(model_id.into_string(), arn);
+    }
+
+    #[test]
+    fn test_parse_invalid_model_id() {
+        assert!(matches!(
+            BedrockModelId::parse(""),
+            Err(llm::Error {
+                code: llm::ErrorCode::InvalidRequest,
+                ..
+            })
+        ));
+        assert!(matches!(
+            BedrockModelId::parse("arn:aws:s3:::bucket/key"),
+            Err(llm::Error {
+                code: llm::ErrorCode::InvalidRequest,
+                ..
+            })
+        ));
+        assert!(matches!(
+            BedrockModelId::parse("arn:aws:bedrock::123456789012:foundation-model/model"),
+            Err(llm::Error {
+                code: llm::ErrorCode::InvalidRequest,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_guardrail_options() {
+        let mut options = HashMap::from([(
+            GUARDRAIL_CONFIG_KEY.to_owned(),
+            r#"{"identifier": "gr-123", "version": "2", "trace": "enabled_full", "stream_processing_mode": "async"}"#.to_owned(),
+        )]);
+
+        let guardrail = GuardrailOptions::from_provider_options(&mut options).unwrap();
+
+        assert_eq!(
+            guardrail,
+            Some(GuardrailOptions {
+                identifier: "gr-123".to_owned(),
+                version: "2".to_owned(),
+                trace: Some(GuardrailTraceSetting::EnabledFull),
+                stream_processing_mode: Some(GuardrailStreamMode::Async),
+            })
+        );
+        assert!(options.is_empty());
+
+        let configuration = guardrail.unwrap().to_guardrail_stream_configuration();
+        assert_eq!(configuration.trace(), &GuardrailTrace::EnabledFull);
+        assert_eq!(
+            configuration.stream_processing_mode(),
+            &GuardrailStreamProcessingMode::Async
+        );
+    }
+
+    #[test]
+    fn test_guardrail_options_defaults() {
+        let mut options = HashMap::from([(
+            GUARDRAIL_CONFIG_KEY.to_owned(),
+            r#"{"identifier": "gr-123"}"#.to_owned(),
+        )]);
+
+        let guardrail = GuardrailOptions::from_provider_options(&mut options)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(guardrail.version, "DRAFT");
+        assert_eq!(guardrail.trace, None);
+        assert_eq!(guardrail.stream_processing_mode, None);
+        assert_eq!(
+            GuardrailOptions::from_provider_options(&mut HashMap::new()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_guardrail_options() {
+        for config in [
+            r#"{"version": "1"}"#,
+            r#"{"identifier": "gr-123", "trace": "verbose"}"#,
+            r#"{"identifier": "gr-123", "guardrail_version": "1"}"#,
+            "gr-123",
+        ] {
+            let mut options = HashMap::from([(GUARDRAIL_CONFIG_KEY.to_owned(), config.to_owned())]);
+            assert!(matches!(
+                GuardrailOptions::from_provider_options(&mut options),
+                Err(llm::Error {
+                    code: llm::ErrorCode::InvalidRequest,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_additional_fields_keep_string_options() {
+        let options = HashMap::from([
+            (
+                ADDITIONAL_MODEL_REQUEST_FIELDS_KEY.to_owned(),
+                r#"{"top_k": 50, "stop": ["END"]}"#.to_owned(),
+            ),
+            ("top_k".to_owned(), "10".to_owned()),
+            ("user_id".to_owned(), "12345".to_owned()),
+        ]);
+
+        let fields =
+            smithy_document_to_json_value(provider_options_to_additional_fields(options).unwrap());
+
+        assert_eq!(
+            fields,
+            serde_json::json!({"top_k": 50, "stop": ["END"], "user_id": "12345"})
+        );
+    }
+
+    #[test]
+    fn test_invalid_additional_fields() {
+        let options = HashMap::from([(
+            ADDITIONAL_MODEL_REQUEST_FIELDS_KEY.to_owned(),
+            "[1, 2]".to_owned(),
+        )]);
+
+        assert!(matches!(
+            provider_options_to_additional_fields(options),
+            Err(llm::Error {
+                code: llm::ErrorCode::InvalidRequest,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_merge_metadata() {
+        let metadata1 = llm::ResponseMetadata {
+            finish_reason: Some(llm::FinishReason::Stop),
+            usage: None,
+            provider_id: None,
+            timestamp: None,
+            provider_metadata_json: Some(r#"{"stop_sequence": "END", "latency_ms": 1}"#.to_owned()),
+        };
+        let metadata2 = llm::ResponseMetadata {
+            finish_reason: Some(llm::FinishReason::Length),
+            usage: Some(llm::Usage {
+                input_tokens: Some(10),
+                output_tokens: Some(20),
+                total_tokens: Some(30),
+            }),
+            provider_id: None,
+            timestamp: None,
+            provider_metadata_json: Some(r#"{"latency_ms": 2, "guardrail_trace": {}}"#.to_owned()),
+        };
+
+        let metadata = merge_metadata(metadata1, metadata2);
+
+        assert_eq!(metadata.finish_reason, Some(llm::FinishReason::Stop));
+        assert_eq!(
+            metadata.usage.and_then(|usage| usage.total_tokens),
+            Some(30)
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&metadata.provider_metadata_json.unwrap())
+                .unwrap(),
+            serde_json::json!({"stop_sequence": "END", "latency_ms": 1, "guardrail_trace": {}})
+        );
+    }
+
+    #[test]
+    fn test_merge_non_object_metadata() {
+        assert_eq!(
+            merge_metadata_json(Some("[1]".to_owned()), Some(r#"{"a": 1}"#.to_owned())),
+            Some("[1]".to_owned())
+        );
+        assert_eq!(
+            merge_metadata_json(None, Some(r#"{"a": 1}"#.to_owned())),
+            Some(r#"{"a": 1}"#.to_owned())
+        );
+    }
+
+    fn content_filter(
+        filter_type: bedrock::types::GuardrailContentFilterType,
+        confidence: GuardrailContentFilterConfidence,
+        action: GuardrailContentPolicyAction,
+    ) -> bedrock::types::GuardrailContentFilter {
+        bedrock::types::GuardrailContentFilter::builder()
+            .r#type(filter_type)
+            .confidence(confidence)
+            .action(action)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_apply_guardrail_output_intervened() {
+        let assessment = GuardrailAssessment::builder()
+            .content_policy(
+                bedrock::types::GuardrailContentPolicyAssessment::builder()
+                    .filters(content_filter(
+                        bedrock::types::GuardrailContentFilterType::Violence,
+                        GuardrailContentFilterConfidence::High,
+                        GuardrailContentPolicyAction::Blocked,
+                    ))
+                    .filters(content_filter(
+                        bedrock::types::GuardrailContentFilterType::Insults,
+                        GuardrailContentFilterConfidence::Low,
+                        GuardrailContentPolicyAction::None,
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+            .topic_policy(
+                bedrock::types::GuardrailTopicPolicyAssessment::builder()
+                    .topics(
+                        bedrock::types::GuardrailTopic::builder()
+                            .name("investment-advice")
+                            .r#type(bedrock::types::GuardrailTopicType::Deny)
+                            .action(GuardrailTopicPolicyAction::Blocked)
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .sensitive_information_policy(
+                bedrock::types::GuardrailSensitiveInformationPolicyAssessment::builder()
+                    .pii_entities(
+                        bedrock::types::GuardrailPiiEntityFilter::builder()
+                            .r#match("jane@example.com")
+                            .r#type(bedrock::types::GuardrailPiiEntityType::Email)
+                            .action(GuardrailSensitiveInformationPolicyAction::Anonymized)
+                            .build()
+                            .unwrap(),
+                    )
+                    .set_regexes(Some(vec![]))
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let output = apply_guardrail::ApplyGuardrailOutput::builder()
+            .action(GuardrailAction::GuardrailIntervened)
+            .outputs(
+                bedrock::types::GuardrailOutputContent::builder()
+                    .text("Sorry, I cannot help with that.")
+                    .build(),
+            )
+            .assessments(assessment)
+            .build()
+            .unwrap();
+
+        let result = apply_guardrail_output_to_moderation_result(output);
+
+        assert!(result.flagged);
+        assert_eq!(
+            result.categories,
+            vec![
+                llm::ModerationCategory {
+                    name: "violence".to_owned(),
+                    flagged: true,
+                    score: Some(1.0),
+                },
+                llm::ModerationCategory {
+                    name: "insults".to_owned(),
+                    flagged: false,
+                    score: Some(0.33),
+                },
+                llm::ModerationCategory {
+                    name: "topic/investment-advice".to_owned(),
+                    flagged: true,
+                    score: None,
+                },
+                llm::ModerationCategory {
+                    name: "pii/email".to_owned(),
+                    flagged: true,
+                    score: None,
+                },
+            ]
+        );
+        assert_eq!(
+            result.provider_metadata_json.as_deref(),
+            Some(r#"{"action":"GUARDRAIL_INTERVENED"}"#)
+        );
+    }
+
+    #[test]
+    fn test_apply_guardrail_output_passed() {
+        let output = apply_guardrail::ApplyGuardrailOutput::builder()
+            .action(GuardrailAction::None)
+            .set_outputs(Some(vec![]))
+            .set_assessments(Some(vec![]))
+            .build()
+            .unwrap();
+
+        let result = apply_guardrail_output_to_moderation_result(output);
+
+        assert!(!result.flagged);
+        assert!(result.categories.is_empty());
+        assert_eq!(
+            result.provider_metadata_json.as_deref(),
+            Some(r#"{"action":"NONE"}"#)
+        );
+    }
+}
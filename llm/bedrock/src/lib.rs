@@ -1,8 +1,13 @@
 use async_utils::get_async_runtime;
 use client::Bedrock;
+use conversions::ImageModelInput;
 use golem_llm::{
     durability::{DurableLLM, ExtendedGuest},
-    golem::llm::llm::{self, ChatEvent, ChatStream, Config, Guest, Message, ToolCall, ToolResult},
+    golem::llm::images::{self, ImageOptions, ImageSize},
+    golem::llm::llm::{
+        self, ChatEvent, ChatStream, Config, ContentPart, Guest, ImageReference, Message,
+        ModerationResult, ToolCall, ToolResult,
+    },
 };
 use golem_rust::bindings::wasi::clocks::monotonic_clock;
 use stream::BedrockChatStream;
@@ -22,7 +27,7 @@ impl Guest for BedrockComponent {
         let runtime = get_async_runtime();
 
         runtime.block_on(async {
-            let bedrock = get_bedrock_client().await;
+            let bedrock = get_bedrock_client(Some(&config.model)).await;
 
             match bedrock {
                 Ok(client) => client.converse(messages, config, None).await,
@@ -39,7 +44,7 @@ impl Guest for BedrockComponent {
         let runtime = get_async_runtime();
 
         runtime.block_on(async {
-            let bedrock = get_bedrock_client().await;
+            let bedrock = get_bedrock_client(Some(&config.model)).await;
 
             match bedrock {
                 Ok(client) => client.converse(messages, config, Some(tool_results)).await,
@@ -51,6 +56,47 @@ impl Guest for BedrockComponent {
     fn stream(messages: Vec<Message>, config: Config) -> ChatStream {
         ChatStream::new(Self::unwrapped_stream(messages, config))
     }
+
+    fn moderate(content: Vec<ContentPart>) -> Result<ModerationResult, llm::Error> {
+        let runtime = get_async_runtime();
+
+        runtime.block_on(async {
+            let client = get_bedrock_client(None).await?;
+            client.apply_guardrail(content).await
+        })
+    }
+}
+
+impl images::Guest for BedrockComponent {
+    fn generate(
+        prompt: String,
+        size: Option<ImageSize>,
+        count: u32,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, llm::Error> {
+        let runtime = get_async_runtime();
+
+        runtime.block_on(async {
+            let input = ImageModelInput::generate(prompt, size, count, options);
+            let client = get_bedrock_client(Some(&input.model_id)).await?;
+            client.invoke_image_model(input).await
+        })
+    }
+
+    fn edit(
+        image: ImageReference,
+        mask: Option<ImageReference>,
+        prompt: String,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, llm::Error> {
+        let runtime = get_async_runtime();
+
+        runtime.block_on(async {
+            let input = ImageModelInput::edit(image, mask, prompt, options).await?;
+            let client = get_bedrock_client(Some(&input.model_id)).await?;
+            client.invoke_image_model(input).await
+        })
+    }
 }
 
 impl ExtendedGuest for BedrockComponent {
@@ -61,7 +107,7 @@ impl ExtendedGuest for BedrockComponent {
         let runtime = get_async_runtime();
 
         runtime.block_on(async {
-            let bedrock = get_bedrock_client().await;
+            let bedrock = get_bedrock_client(Some(&config.model)).await;
 
             match bedrock {
                 Ok(client) => client.converse_stream(messages, config).await,
@@ -128,8 +174,8 @@ impl ExtendedGuest for BedrockComponent {
     }
 }
 
-async fn get_bedrock_client() -> Result<Bedrock, llm::Error> {
-    Bedrock::new().await
+async fn get_bedrock_client(model: Option<&str>) -> Result<Bedrock, llm::Error> {
+    Bedrock::new(model).await
 }
 
 type DurableBedrockComponent = DurableLLM<BedrockComponent>;
@@ -61,6 +61,40 @@ impl CompletionsApi {
     }
 }
 
+/// The Image Generations API client for creating images from a prompt.
+///
+/// Based on https://docs.x.ai/docs/api-reference#image-generations
+pub struct ImageGenerationsApi {
+    api_key: String,
+    client: Client,
+}
+
+impl ImageGenerationsApi {
+    pub fn new(api_key: String) -> Self {
+        let client = Client::builder()
+            .build()
+            .expect("Failed to initialize HTTP client");
+        Self { api_key, client }
+    }
+
+    pub fn generate_images(
+        &self,
+        request: ImageGenerationRequest,
+    ) -> Result<ImageGenerationResponse, Error> {
+        trace!("Sending request to xAI API: {request:?}");
+
+        let response: Response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/v1/images/generations"))
+            .bearer_auth(self.api_key.clone())
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletionsRequest {
     pub messages: Vec<Message>,
@@ -310,6 +344,33 @@ pub struct ChoiceDelta {
     pub role: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageGenerationRequest {
+    pub prompt: String,
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageGenerationResponse {
+    pub data: Vec<GeneratedImage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedImage {
+    #[serde(default)]
+    pub b64_json: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub revised_prompt: Option<String>,
+}
+
 fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, Error> {
     let status = response.status();
     if status.is_success() {
@@ -1,8 +1,14 @@
-use crate::client::{CompletionsRequest, CompletionsResponse, Detail, Effort};
+use crate::client::{
+    CompletionsRequest, CompletionsResponse, Detail, Effort, ImageGenerationRequest,
+    ImageGenerationResponse,
+};
 use base64::{engine::general_purpose, Engine as _};
+use golem_llm::error::unsupported;
+use golem_llm::golem::llm::images::{ImageOptions, ImageSize};
 use golem_llm::golem::llm::llm::{
     ChatEvent, CompleteResponse, Config, ContentPart, Error, ErrorCode, FinishReason, ImageDetail,
-    ImageReference, Message, ResponseMetadata, Role, ToolCall, ToolDefinition, ToolResult, Usage,
+    ImageReference, ImageSource, ImageUrl, Message, ResponseMetadata, Role, ToolCall,
+    ToolDefinition, ToolResult, Usage,
 };
 use std::collections::HashMap;
 
@@ -237,3 +243,83 @@ fn tool_definition_to_tool(tool: ToolDefinition) -> Result<crate::client::Tool,
         }),
     }
 }
+
+const DEFAULT_IMAGE_MODEL: &str = "grok-2-image";
+
+/// xAI image generation does not support size, quality, style, negative prompt or seed
+/// parameters, so these are rejected instead of being silently dropped. Only the model, image
+/// count and the `response_format` / `user` provider options are forwarded.
+pub fn image_generation_request(
+    prompt: String,
+    size: Option<ImageSize>,
+    count: u32,
+    options: ImageOptions,
+) -> Result<ImageGenerationRequest, Error> {
+    if size.is_some() {
+        return Err(unsupported("image size for xAI image generation"));
+    }
+    if options.quality.is_some() {
+        return Err(unsupported("image quality for xAI image generation"));
+    }
+    if options.style.is_some() {
+        return Err(unsupported("image style for xAI image generation"));
+    }
+    if options.negative_prompt.is_some() {
+        return Err(unsupported("negative prompts for xAI image generation"));
+    }
+    if options.seed.is_some() {
+        return Err(unsupported("seeds for xAI image generation"));
+    }
+
+    let provider_options = options
+        .provider_options
+        .into_iter()
+        .map(|kv| (kv.key, kv.value))
+        .collect::<HashMap<_, _>>();
+
+    Ok(ImageGenerationRequest {
+        prompt,
+        model: options
+            .model
+            .unwrap_or_else(|| DEFAULT_IMAGE_MODEL.to_string()),
+        n: Some(count.max(1)),
+        response_format: Some(
+            provider_options
+                .get("response_format")
+                .cloned()
+                .unwrap_or_else(|| "b64_json".to_string()),
+        ),
+        user: provider_options.get("user").cloned(),
+    })
+}
+
+pub fn process_image_generation_response(
+    response: ImageGenerationResponse,
+) -> Result<Vec<ImageReference>, Error> {
+    response
+        .data
+        .into_iter()
+        .map(|image| match (image.b64_json, image.url) {
+            (Some(b64_json), _) => general_purpose::STANDARD
+                .decode(b64_json)
+                .map(|data| {
+                    ImageReference::Inline(ImageSource {
+                        data,
+                        mime_type: "image/jpeg".to_string(),
+                        detail: None,
+                    })
+                })
+                .map_err(|err| Error {
+                    code: ErrorCode::InternalError,
+                    message: format!("Failed to decode generated image: {err}"),
+                    provider_error_json: None,
+                }),
+            (None, Some(url)) => Ok(ImageReference::Url(ImageUrl { url, detail: None })),
+            (None, None) => Err(Error {
+                code: ErrorCode::InternalError,
+                message: "Generated image has neither data nor URL".to_string(),
+                provider_error_json: None,
+            }),
+        })
+        .collect()
+}
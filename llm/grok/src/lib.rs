@@ -1,18 +1,24 @@
 mod client;
 mod conversions;
 
-use crate::client::{ChatCompletionChunk, CompletionsApi, CompletionsRequest, StreamOptions};
+use crate::client::{
+    ChatCompletionChunk, CompletionsApi, CompletionsRequest, ImageGenerationsApi, StreamOptions,
+};
 use crate::conversions::{
-    convert_finish_reason, convert_tool_call, convert_usage, messages_to_request, process_response,
+    convert_finish_reason, convert_tool_call, convert_usage, image_generation_request,
+    messages_to_request, process_image_generation_response, process_response,
     tool_results_to_messages,
 };
 use golem_llm::chat_stream::{LlmChatStream, LlmChatStreamState};
 use golem_llm::config::with_config_key;
 use golem_llm::durability::{DurableLLM, ExtendedGuest};
+use golem_llm::error::unsupported;
 use golem_llm::event_source::EventSource;
+use golem_llm::golem::llm::images;
+use golem_llm::golem::llm::images::{ImageOptions, ImageSize};
 use golem_llm::golem::llm::llm::{
-    ChatEvent, ChatStream, Config, ContentPart, Error, FinishReason, Guest, Message,
-    ResponseMetadata, StreamDelta, StreamEvent, ToolCall, ToolResult,
+    ChatEvent, ChatStream, Config, ContentPart, Error, FinishReason, Guest, ImageReference,
+    Message, ModerationResult, ResponseMetadata, StreamDelta, StreamEvent, ToolCall, ToolResult,
 };
 use golem_rust::wasm_rpc::Pollable;
 use log::trace;
@@ -177,6 +183,37 @@ impl Guest for GrokComponent {
     fn stream(messages: Vec<Message>, config: Config) -> ChatStream {
         ChatStream::new(Self::unwrapped_stream(messages, config))
     }
+
+    fn moderate(_content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+        Err(unsupported("content moderation"))
+    }
+}
+
+impl images::Guest for GrokComponent {
+    fn generate(
+        prompt: String,
+        size: Option<ImageSize>,
+        count: u32,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        with_config_key(Self::ENV_VAR_NAME, Err, |xai_api_key| {
+            let client = ImageGenerationsApi::new(xai_api_key);
+
+            let request = image_generation_request(prompt, size, count, options)?;
+            client
+                .generate_images(request)
+                .and_then(process_image_generation_response)
+        })
+    }
+
+    fn edit(
+        _image: ImageReference,
+        _mask: Option<ImageReference>,
+        _prompt: String,
+        _options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        Err(unsupported("image editing"))
+    }
 }
 
 impl ExtendedGuest for GrokComponent {
@@ -1,4 +1,7 @@
-use crate::golem::llm::llm::{Config, ContentPart, Guest, Message, Role, StreamDelta};
+use crate::golem::llm::images::{self, ImageOptions, ImageSize};
+use crate::golem::llm::llm::{
+    Config, ContentPart, Error, Guest, ImageReference, Message, Role, StreamDelta,
+};
 use golem_rust::wasm_rpc::Pollable;
 use std::marker::PhantomData;
 
@@ -7,6 +10,11 @@ pub struct DurableLLM<Impl> {
     phantom: PhantomData<Impl>,
 }
 
+/// Wraps an image generation implementation with custom durability
+pub struct DurableImages<Impl> {
+    phantom: PhantomData<Impl>,
+}
+
 /// Trait to be implemented in addition to the LLM `Guest` trait when wrapping it with `DurableLLM`.
 pub trait ExtendedGuest: Guest + 'static {
     /// Creates an instance of the LLM specific `ChatStream` without wrapping it in a `Resource`
@@ -61,12 +69,37 @@ pub trait ExtendedGuest: Guest + 'static {
     fn subscribe(stream: &Self::ChatStream) -> Pollable;
 }
 
+/// The `llm-library` world is exported from a single type, so `DurableLLM` forwards the `images`
+/// interface of the provider to `DurableImages`.
+impl<Impl: ExtendedGuest + images::Guest> images::Guest for DurableLLM<Impl> {
+    fn generate(
+        prompt: String,
+        size: Option<ImageSize>,
+        count: u32,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        <DurableImages<Impl> as images::Guest>::generate(prompt, size, count, options)
+    }
+
+    fn edit(
+        image: ImageReference,
+        mask: Option<ImageReference>,
+        prompt: String,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        <DurableImages<Impl> as images::Guest>::edit(image, mask, prompt, options)
+    }
+}
+
 /// When the durability feature flag is off, wrapping with `DurableLLM` is just a passthrough
 #[cfg(not(feature = "durability"))]
 mod passthrough_impl {
-    use crate::durability::{DurableLLM, ExtendedGuest};
+    use crate::durability::{DurableImages, DurableLLM, ExtendedGuest};
+    use crate::golem::llm::images;
+    use crate::golem::llm::images::{ImageOptions, ImageSize};
     use crate::golem::llm::llm::{
-        ChatEvent, ChatStream, Config, Guest, Message, ToolCall, ToolResult,
+        ChatEvent, ChatStream, Config, ContentPart, Error, Guest, ImageReference, Message,
+        ModerationResult, ToolCall, ToolResult,
     };
     use crate::init_logging;
 
@@ -91,6 +124,33 @@ mod passthrough_impl {
             init_logging();
             Impl::stream(messages, config)
         }
+
+        fn moderate(content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+            init_logging();
+            Impl::moderate(content)
+        }
+    }
+
+    impl<Impl: images::Guest> images::Guest for DurableImages<Impl> {
+        fn generate(
+            prompt: String,
+            size: Option<ImageSize>,
+            count: u32,
+            options: ImageOptions,
+        ) -> Result<Vec<ImageReference>, Error> {
+            init_logging();
+            Impl::generate(prompt, size, count, options)
+        }
+
+        fn edit(
+            image: ImageReference,
+            mask: Option<ImageReference>,
+            prompt: String,
+            options: ImageOptions,
+        ) -> Result<Vec<ImageReference>, Error> {
+            init_logging();
+            Impl::edit(image, mask, prompt, options)
+        }
     }
 }
 
@@ -102,14 +162,22 @@ mod passthrough_impl {
 /// stored as input, and the full response stored as output. To serialize these in a way it is
 /// observable by oplog consumers, each relevant data type has to be converted to/from `ValueAndType`
 /// which is implemented using the type classes and builder in the `golem-rust` library.
+///
+/// If redaction rules are configured (see `crate::redaction`), they are applied to the persisted
+/// copies of the requests and responses only. The live result is returned to the caller unchanged
+/// while the worker is running, but a worker recovered from the oplog replays the redacted
+/// responses, and an interrupted stream is continued from its redacted partial response.
 #[cfg(feature = "durability")]
 mod durable_impl {
-    use crate::durability::{DurableLLM, ExtendedGuest};
+    use crate::durability::{DurableImages, DurableLLM, ExtendedGuest};
+    use crate::golem::llm::images;
+    use crate::golem::llm::images::{ImageOptions, ImageSize};
     use crate::golem::llm::llm::{
-        ChatEvent, ChatStream, Config, Guest, GuestChatStream, Message, StreamDelta, StreamEvent,
-        ToolCall, ToolResult,
+        ChatEvent, ChatStream, Config, ContentPart, Error, Guest, GuestChatStream, ImageReference,
+        Message, ModerationResult, StreamDelta, StreamEvent, ToolCall, ToolResult,
     };
     use crate::init_logging;
+    use crate::redaction::redact;
     use golem_rust::bindings::golem::durability::durability::DurableFunctionType;
     #[cfg(not(feature = "nopoll"))]
     use golem_rust::bindings::golem::durability::durability::LazyInitializedPollable;
@@ -134,7 +202,14 @@ mod durable_impl {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
                     Impl::send(messages.clone(), config.clone())
                 });
-                durability.persist_infallible(SendInput { messages, config }, result)
+                let _ = durability.persist_infallible(
+                    SendInput {
+                        messages: redact(messages),
+                        config,
+                    },
+                    redact(result.clone()),
+                );
+                result
             } else {
                 durability.replay_infallible()
             }
@@ -156,14 +231,15 @@ mod durable_impl {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
                     Impl::continue_(messages.clone(), tool_results.clone(), config.clone())
                 });
-                durability.persist_infallible(
+                let _ = durability.persist_infallible(
                     ContinueInput {
-                        messages,
-                        tool_results,
+                        messages: redact(messages),
+                        tool_results: redact(tool_results),
                         config,
                     },
-                    result,
-                )
+                    redact(result.clone()),
+                );
+                result
             } else {
                 durability.replay_infallible()
             }
@@ -184,13 +260,108 @@ mod durable_impl {
                         config.clone(),
                     )))
                 });
-                let _ = durability.persist_infallible(SendInput { messages, config }, NoOutput);
+                let _ = durability.persist_infallible(
+                    SendInput {
+                        messages: redact(messages),
+                        config,
+                    },
+                    NoOutput,
+                );
                 result
             } else {
                 let _: NoOutput = durability.replay_infallible();
                 ChatStream::new(DurableChatStream::<Impl>::replay(messages, config))
             }
         }
+
+        fn moderate(content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+            init_logging();
+
+            let durability = Durability::<ModerationResult, Error>::new(
+                "golem_llm",
+                "moderate",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::moderate(content.clone())
+                });
+                durability.persist(
+                    ModerateInput {
+                        content: redact(content),
+                    },
+                    result,
+                )
+            } else {
+                durability.replay()
+            }
+        }
+    }
+
+    /// Generated images are persisted as the function's output, so on replay they are read back
+    /// from the oplog instead of being generated (and billed) again by the provider.
+    impl<Impl: images::Guest> images::Guest for DurableImages<Impl> {
+        fn generate(
+            prompt: String,
+            size: Option<ImageSize>,
+            count: u32,
+            options: ImageOptions,
+        ) -> Result<Vec<ImageReference>, Error> {
+            init_logging();
+
+            let durability = Durability::<Vec<ImageReference>, Error>::new(
+                "golem_llm",
+                "generate_image",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::generate(prompt.clone(), size, count, options.clone())
+                });
+                durability.persist(
+                    GenerateImageInput {
+                        prompt: redact(prompt),
+                        size,
+                        count,
+                        options,
+                    },
+                    result,
+                )
+            } else {
+                durability.replay()
+            }
+        }
+
+        fn edit(
+            image: ImageReference,
+            mask: Option<ImageReference>,
+            prompt: String,
+            options: ImageOptions,
+        ) -> Result<Vec<ImageReference>, Error> {
+            init_logging();
+
+            let durability = Durability::<Vec<ImageReference>, Error>::new(
+                "golem_llm",
+                "edit_image",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::edit(image.clone(), mask.clone(), prompt.clone(), options.clone())
+                });
+                durability.persist(
+                    EditImageInput {
+                        image,
+                        mask,
+                        prompt: redact(prompt),
+                        options,
+                    },
+                    result,
+                )
+            } else {
+                durability.replay()
+            }
+        }
     }
 
     /// Represents the durable chat stream's state
@@ -316,7 +487,8 @@ mod durable_impl {
                             with_persistence_level(PersistenceLevel::PersistNothing, || {
                                 stream.get_next()
                             });
-                        (durability.persist_infallible(NoInput, result.clone()), None)
+                        let _ = durability.persist_infallible(NoInput, redact(result.clone()));
+                        (result, None)
                     }
                     Some(DurableChatStreamState::Replay {
                         original_messages,
@@ -346,7 +518,8 @@ mod durable_impl {
                                     let next = stream.get_next();
                                     (stream, next)
                                 });
-                            durability.persist_infallible(NoInput, first_live_result.clone());
+                            durability
+                                .persist_infallible(NoInput, redact(first_live_result.clone()));
 
                             (first_live_result, Some(stream))
                         }
@@ -446,6 +619,27 @@ mod durable_impl {
         config: Config,
     }
 
+    #[derive(Debug, IntoValue)]
+    struct ModerateInput {
+        content: Vec<ContentPart>,
+    }
+
+    #[derive(Debug, IntoValue)]
+    struct GenerateImageInput {
+        prompt: String,
+        size: Option<ImageSize>,
+        count: u32,
+        options: ImageOptions,
+    }
+
+    #[derive(Debug, IntoValue)]
+    struct EditImageInput {
+        image: ImageReference,
+        mask: Option<ImageReference>,
+        prompt: String,
+        options: ImageOptions,
+    }
+
     #[derive(Debug, IntoValue)]
     struct NoInput;
 
@@ -461,13 +655,20 @@ mod durable_impl {
         }
     }
 
+    impl From<&Error> for Error {
+        fn from(error: &Error) -> Self {
+            error.clone()
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use crate::durability::durable_impl::SendInput;
+        use crate::golem::llm::images::{ImageOptions, ImageSize};
         use crate::golem::llm::llm::{
             ChatEvent, CompleteResponse, Config, ContentPart, Error, ErrorCode, FinishReason,
-            ImageDetail, ImageReference, ImageSource, ImageUrl, Message, ResponseMetadata, Role,
-            ToolCall, Usage,
+            ImageDetail, ImageReference, ImageSource, ImageUrl, Kv, Message, ModerationCategory,
+            ModerationResult, ResponseMetadata, Role, ToolCall, Usage,
         };
         use golem_rust::value_and_type::{FromValueAndType, IntoValueAndType};
         use golem_rust::wasm_rpc::WitTypeNode;
@@ -640,6 +841,69 @@ mod durable_impl {
             }));
         }
 
+        #[test]
+        fn moderation_result_roundtrip() {
+            roundtrip_test(ModerationResult {
+                flagged: true,
+                categories: vec![
+                    ModerationCategory {
+                        name: "harassment".to_string(),
+                        flagged: true,
+                        score: Some(0.92),
+                    },
+                    ModerationCategory {
+                        name: "violence".to_string(),
+                        flagged: false,
+                        score: None,
+                    },
+                ],
+                provider_metadata_json: Some("{\"model\": \"omni-moderation-latest\"}".to_string()),
+            });
+            roundtrip_test(ModerationResult {
+                flagged: false,
+                categories: vec![],
+                provider_metadata_json: None,
+            });
+        }
+
+        #[test]
+        fn image_size_roundtrip() {
+            roundtrip_test(ImageSize {
+                width: 1024,
+                height: 768,
+            });
+        }
+
+        #[test]
+        fn image_options_roundtrip() {
+            roundtrip_test(ImageOptions {
+                model: Some("gpt-image-1".to_string()),
+                quality: Some("high".to_string()),
+                style: None,
+                negative_prompt: Some("blurry".to_string()),
+                seed: Some(42),
+                provider_options: vec![Kv {
+                    key: "background".to_string(),
+                    value: "transparent".to_string(),
+                }],
+            });
+        }
+
+        #[test]
+        fn generated_images_roundtrip() {
+            roundtrip_test(vec![
+                ImageReference::Inline(ImageSource {
+                    data: vec![137, 80, 78, 71],
+                    mime_type: "image/png".to_string(),
+                    detail: None,
+                }),
+                ImageReference::Url(ImageUrl {
+                    url: "https://example.com/generated.png".to_string(),
+                    detail: None,
+                }),
+            ]);
+        }
+
         #[test]
         fn send_input_encoding() {
             let input = SendInput {
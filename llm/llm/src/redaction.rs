@@ -0,0 +1,331 @@
+use crate::config::get_config_key_or_none;
+use crate::golem::llm::llm::{
+    ChatEvent, CompleteResponse, ContentPart, Message, StreamDelta, StreamEvent, ToolCall,
+    ToolResult,
+};
+use log::warn;
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// Comma separated list of the built-in redaction rules to enable (`email`, `phone`,
+/// `card_number`), or `all` to enable all of them.
+pub const REDACT_ENV_VAR_NAME: &str = "GOLEM_LLM_REDACT";
+
+/// JSON object of custom redaction rules, mapping rule names to regular expressions,
+/// for example `{"account_id": "ACCT-[0-9]{8}"}`.
+pub const REDACT_PATTERNS_ENV_VAR_NAME: &str = "GOLEM_LLM_REDACT_PATTERNS";
+
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}";
+const CARD_NUMBER_PATTERN: &str = r"\b(?:\d[ -]?){12,18}\d\b";
+const PHONE_PATTERN: &str =
+    r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{1,4}\)[\s.-]?)?\b\d{2,4}[\s.-]\d{3,4}[\s.-]?\d{3,4}\b";
+
+struct RedactionRule {
+    name: String,
+    regex: Regex,
+    /// Additional check on a match to reduce false positives
+    validate: Option<fn(&str) -> bool>,
+}
+
+/// Applies the configured redaction rules to text. Every match is replaced with a
+/// `[REDACTED:<rule name>]` placeholder.
+pub struct Redactor {
+    rules: Vec<RedactionRule>,
+}
+
+impl Redactor {
+    /// Creates a redactor from the `GOLEM_LLM_REDACT` and `GOLEM_LLM_REDACT_PATTERNS` environment
+    /// variables. Returns `None` if no redaction rules are configured.
+    pub fn from_env() -> Option<Self> {
+        let builtin = get_config_key_or_none(REDACT_ENV_VAR_NAME).unwrap_or_default();
+        let custom = get_config_key_or_none(REDACT_PATTERNS_ENV_VAR_NAME).unwrap_or_default();
+        Self::from_config(&builtin, &custom)
+    }
+
+    fn from_config(builtin: &str, custom: &str) -> Option<Self> {
+        let mut rules = Vec::new();
+
+        let names = builtin
+            .split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>();
+        let all = names.iter().any(|name| name == "all");
+        let enabled = |name: &str| all || names.iter().any(|n| n == name);
+
+        // Card numbers are matched before phone numbers, as the phone pattern would also match
+        // parts of a card number
+        if enabled("email") {
+            rules.push(builtin_rule("email", EMAIL_PATTERN, None));
+        }
+        if enabled("card_number") {
+            rules.push(builtin_rule(
+                "card_number",
+                CARD_NUMBER_PATTERN,
+                Some(is_valid_card_number),
+            ));
+        }
+        if enabled("phone") {
+            rules.push(builtin_rule("phone", PHONE_PATTERN, Some(is_phone_number)));
+        }
+        for name in &names {
+            if name != "all" && !["email", "card_number", "phone"].contains(&name.as_str()) {
+                warn!("Unknown redaction rule in {REDACT_ENV_VAR_NAME}: {name}");
+            }
+        }
+
+        if !custom.trim().is_empty() {
+            match serde_json::from_str::<BTreeMap<String, String>>(custom) {
+                Ok(patterns) => {
+                    for (name, pattern) in patterns {
+                        match Regex::new(&pattern) {
+                            Ok(regex) => rules.push(RedactionRule {
+                                name,
+                                regex,
+                                validate: None,
+                            }),
+                            Err(err) => {
+                                warn!("Ignoring invalid redaction pattern {name}: {err}")
+                            }
+                        }
+                    }
+                }
+                Err(err) => warn!("Failed to parse {REDACT_PATTERNS_ENV_VAR_NAME}: {err}"),
+            }
+        }
+
+        if rules.is_empty() {
+            None
+        } else {
+            Some(Self { rules })
+        }
+    }
+
+    /// Replaces every match of the redaction rules in the given text
+    pub fn redact_text(&self, text: &str) -> String {
+        let mut result = text.to_string();
+        for rule in &self.rules {
+            result = rule
+                .regex
+                .replace_all(&result, |captures: &Captures| {
+                    let matched = &captures[0];
+                    if rule.validate.is_none_or(|validate| validate(matched)) {
+                        format!("[REDACTED:{}]", rule.name)
+                    } else {
+                        matched.to_string()
+                    }
+                })
+                .into_owned();
+        }
+        result
+    }
+}
+
+fn builtin_rule(name: &str, pattern: &str, validate: Option<fn(&str) -> bool>) -> RedactionRule {
+    RedactionRule {
+        name: name.to_string(),
+        regex: Regex::new(pattern).expect("Built-in redaction patterns are valid"),
+        validate,
+    }
+}
+
+/// Checks the Luhn checksum of a candidate card number
+fn is_valid_card_number(candidate: &str) -> bool {
+    let digits = candidate
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, digit)| {
+            if idx % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+fn is_phone_number(candidate: &str) -> bool {
+    let digit_count = candidate.chars().filter(|c| c.is_ascii_digit()).count();
+    (9..=15).contains(&digit_count)
+}
+
+/// Returns the redactor configured for this worker, if any
+pub fn redactor() -> Option<&'static Redactor> {
+    static REDACTOR: OnceLock<Option<Redactor>> = OnceLock::new();
+    REDACTOR.get_or_init(Redactor::from_env).as_ref()
+}
+
+/// Redacts a value with the configured redactor, or returns it unchanged if redaction
+/// is not enabled.
+pub fn redact<T: Redact>(value: T) -> T {
+    match redactor() {
+        Some(redactor) => value.redact(redactor),
+        None => value,
+    }
+}
+
+/// Types which may contain PII in their text content
+pub trait Redact {
+    fn redact(self, redactor: &Redactor) -> Self;
+}
+
+impl Redact for String {
+    fn redact(self, redactor: &Redactor) -> Self {
+        redactor.redact_text(&self)
+    }
+}
+
+impl<T: Redact> Redact for Option<T> {
+    fn redact(self, redactor: &Redactor) -> Self {
+        self.map(|value| value.redact(redactor))
+    }
+}
+
+impl<T: Redact> Redact for Vec<T> {
+    fn redact(self, redactor: &Redactor) -> Self {
+        self.into_iter()
+            .map(|value| value.redact(redactor))
+            .collect()
+    }
+}
+
+impl<A: Redact, B: Redact> Redact for (A, B) {
+    fn redact(self, redactor: &Redactor) -> Self {
+        (self.0.redact(redactor), self.1.redact(redactor))
+    }
+}
+
+impl Redact for ContentPart {
+    fn redact(self, redactor: &Redactor) -> Self {
+        match self {
+            ContentPart::Text(text) => ContentPart::Text(text.redact(redactor)),
+            ContentPart::Image(image) => ContentPart::Image(image),
+        }
+    }
+}
+
+impl Redact for Message {
+    fn redact(self, redactor: &Redactor) -> Self {
+        Message {
+            content: self.content.redact(redactor),
+            ..self
+        }
+    }
+}
+
+impl Redact for ToolCall {
+    fn redact(self, redactor: &Redactor) -> Self {
+        ToolCall {
+            arguments_json: self.arguments_json.redact(redactor),
+            ..self
+        }
+    }
+}
+
+impl Redact for ToolResult {
+    fn redact(self, redactor: &Redactor) -> Self {
+        match self {
+            ToolResult::Success(mut success) => {
+                success.result_json = success.result_json.redact(redactor);
+                ToolResult::Success(success)
+            }
+            ToolResult::Error(mut failure) => {
+                failure.error_message = failure.error_message.redact(redactor);
+                ToolResult::Error(failure)
+            }
+        }
+    }
+}
+
+impl Redact for CompleteResponse {
+    fn redact(self, redactor: &Redactor) -> Self {
+        CompleteResponse {
+            content: self.content.redact(redactor),
+            tool_calls: self.tool_calls.redact(redactor),
+            ..self
+        }
+    }
+}
+
+impl Redact for ChatEvent {
+    fn redact(self, redactor: &Redactor) -> Self {
+        match self {
+            ChatEvent::Message(response) => ChatEvent::Message(response.redact(redactor)),
+            ChatEvent::ToolRequest(tool_calls) => {
+                ChatEvent::ToolRequest(tool_calls.redact(redactor))
+            }
+            ChatEvent::Error(error) => ChatEvent::Error(error),
+        }
+    }
+}
+
+impl Redact for StreamDelta {
+    fn redact(self, redactor: &Redactor) -> Self {
+        StreamDelta {
+            content: self.content.redact(redactor),
+            tool_calls: self.tool_calls.redact(redactor),
+        }
+    }
+}
+
+impl Redact for StreamEvent {
+    fn redact(self, redactor: &Redactor) -> Self {
+        match self {
+            StreamEvent::Delta(delta) => StreamEvent::Delta(delta.redact(redactor)),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::redaction::Redactor;
+
+    #[test]
+    fn redacts_builtin_rules() {
+        let redactor = Redactor::from_config("all", "").unwrap();
+        assert_eq!(
+            redactor.redact_text(
+                "Contact jane.doe@example.com or +1 415-555-0132, card 4111 1111 1111 1111"
+            ),
+            "Contact [REDACTED:email] or [REDACTED:phone], card [REDACTED:card_number]"
+        );
+    }
+
+    #[test]
+    fn keeps_non_matching_numbers() {
+        let redactor = Redactor::from_config("phone,card_number", "").unwrap();
+        let text = "Order 1234567890123456 was placed on 2024-01-01 at 10:30";
+        assert_eq!(redactor.redact_text(text), text);
+    }
+
+    #[test]
+    fn redacts_custom_patterns() {
+        let redactor = Redactor::from_config("", r#"{"account_id": "ACCT-[0-9]{8}"}"#).unwrap();
+        assert_eq!(
+            redactor.redact_text("Account ACCT-12345678 is overdue"),
+            "Account [REDACTED:account_id] is overdue"
+        );
+    }
+
+    #[test]
+    fn disabled_without_rules() {
+        assert!(Redactor::from_config("", "").is_none());
+    }
+}
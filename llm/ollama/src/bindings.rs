@@ -2,14 +2,1723 @@
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
 //   * with "golem:llm/llm@1.0.0" = "golem_llm::golem::llm::llm"
+//   * with "golem:llm/images@1.0.0" = "golem_llm::golem::llm::images"
 //   * generate_unused_types
 use golem_llm::golem::llm::llm as __with_name0;
+use golem_llm::golem::llm::images as __with_name1;
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod golem {
+        pub mod llm_ollama {
+            #[allow(dead_code, clippy::all)]
+            pub mod models {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Error = super::super::super::super::__with_name0::Error;
+                /// --- Model information ---
+                #[derive(Clone)]
+                pub struct ModelDetails {
+                    pub parent_model: Option<_rt::String>,
+                    pub format: Option<_rt::String>,
+                    pub family: Option<_rt::String>,
+                    pub families: Option<_rt::Vec<_rt::String>>,
+                    pub parameter_size: Option<_rt::String>,
+                    pub quantization_level: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for ModelDetails {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ModelDetails")
+                            .field("parent-model", &self.parent_model)
+                            .field("format", &self.format)
+                            .field("family", &self.family)
+                            .field("families", &self.families)
+                            .field("parameter-size", &self.parameter_size)
+                            .field("quantization-level", &self.quantization_level)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct ModelInfo {
+                    pub name: _rt::String,
+                    pub model: _rt::String,
+                    pub modified_at: Option<_rt::String>,
+                    pub size: u64,
+                    pub digest: _rt::String,
+                    pub details: Option<ModelDetails>,
+                }
+                impl ::core::fmt::Debug for ModelInfo {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ModelInfo")
+                            .field("name", &self.name)
+                            .field("model", &self.model)
+                            .field("modified-at", &self.modified_at)
+                            .field("size", &self.size)
+                            .field("digest", &self.digest)
+                            .field("details", &self.details)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct ModelDescription {
+                    pub modelfile: Option<_rt::String>,
+                    pub parameters: Option<_rt::String>,
+                    pub template: Option<_rt::String>,
+                    pub details: Option<ModelDetails>,
+                    pub model_info_json: Option<_rt::String>,
+                    pub capabilities: _rt::Vec<_rt::String>,
+                }
+                impl ::core::fmt::Debug for ModelDescription {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ModelDescription")
+                            .field("modelfile", &self.modelfile)
+                            .field("parameters", &self.parameters)
+                            .field("template", &self.template)
+                            .field("details", &self.details)
+                            .field("model-info-json", &self.model_info_json)
+                            .field("capabilities", &self.capabilities)
+                            .finish()
+                    }
+                }
+                /// --- Pulling models ---
+                #[derive(Clone)]
+                pub struct PullProgress {
+                    pub status: _rt::String,
+                    pub digest: Option<_rt::String>,
+                    pub total: Option<u64>,
+                    pub completed: Option<u64>,
+                }
+                impl ::core::fmt::Debug for PullProgress {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("PullProgress")
+                            .field("status", &self.status)
+                            .field("digest", &self.digest)
+                            .field("total", &self.total)
+                            .field("completed", &self.completed)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum PullEvent {
+                    Progress(PullProgress),
+                    Error(Error),
+                }
+                impl ::core::fmt::Debug for PullEvent {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            PullEvent::Progress(e) => {
+                                f.debug_tuple("PullEvent::Progress").field(e).finish()
+                            }
+                            PullEvent::Error(e) => {
+                                f.debug_tuple("PullEvent::Error").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct PullStream {
+                    handle: _rt::Resource<PullStream>,
+                }
+                type _PullStreamRep<T> = Option<T>;
+                impl PullStream {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `PullStream`.
+                    pub fn new<T: GuestPullStream>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _PullStreamRep<T> = Some(val);
+                        let ptr: *mut _PullStreamRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestPullStream>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestPullStream>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestPullStream>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _PullStreamRep<T>);
+                    }
+                    fn as_ptr<T: GuestPullStream>(&self) -> *mut _PullStreamRep<T> {
+                        PullStream::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`PullStream`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct PullStreamBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a PullStream>,
+                }
+                impl<'a> PullStreamBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestPullStream>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _PullStreamRep<T> {
+                        PullStream::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for PullStream {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]golem:llm-ollama/models@1.0.0"
+                            )]
+                            extern "C" {
+                                #[link_name = "[resource-drop]pull-stream"]
+                                fn drop(_: u32);
+                            }
+                            drop(_handle);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_pull_stream_get_next_cabi<
+                    T: GuestPullStream,
+                >(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_next(
+                        PullStreamBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec8 = e;
+                            let len8 = vec8.len();
+                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec8.len() * 64,
+                                8,
+                            );
+                            let result8 = if layout8.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout8);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec8.into_iter().enumerate() {
+                                let base = result8.add(i * 64);
+                                {
+                                    match e {
+                                        PullEvent::Progress(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            let PullProgress {
+                                                status: status2,
+                                                digest: digest2,
+                                                total: total2,
+                                                completed: completed2,
+                                            } = e;
+                                            let vec3 = (status2.into_bytes()).into_boxed_slice();
+                                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                                            let len3 = vec3.len();
+                                            ::core::mem::forget(vec3);
+                                            *base.add(12).cast::<usize>() = len3;
+                                            *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                            match digest2 {
+                                                Some(e) => {
+                                                    *base.add(16).cast::<u8>() = (1i32) as u8;
+                                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                                    let len4 = vec4.len();
+                                                    ::core::mem::forget(vec4);
+                                                    *base.add(21).cast::<usize>() = len4;
+                                                    *base.add(17).cast::<*mut u8>() = ptr4.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(16).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match total2 {
+                                                Some(e) => {
+                                                    *base.add(32).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(40).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                None => {
+                                                    *base.add(32).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match completed2 {
+                                                Some(e) => {
+                                                    *base.add(48).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(56).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                None => {
+                                                    *base.add(48).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        PullEvent::Error(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            let super::super::super::super::__with_name0::Error {
+                                                code: code5,
+                                                message: message5,
+                                                provider_error_json: provider_error_json5,
+                                            } = e;
+                                            *base.add(8).cast::<u8>() = (code5.clone() as i32) as u8;
+                                            let vec6 = (message5.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(16).cast::<usize>() = len6;
+                                            *base.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                                            match provider_error_json5 {
+                                                Some(e) => {
+                                                    *base.add(20).cast::<u8>() = (1i32) as u8;
+                                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                    let len7 = vec7.len();
+                                                    ::core::mem::forget(vec7);
+                                                    *base.add(25).cast::<usize>() = len7;
+                                                    *base.add(21).cast::<*mut u8>() = ptr7.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(20).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                }
+                            }
+                            *ptr1.add(5).cast::<usize>() = len8;
+                            *ptr1.add(1).cast::<*mut u8>() = result8;
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_pull_stream_get_next<
+                    T: GuestPullStream,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0.add(1).cast::<*mut u8>();
+                            let l2 = *arg0.add(5).cast::<usize>();
+                            let base14 = l1;
+                            let len14 = l2;
+                            for i in 0..len14 {
+                                let base = base14.add(i * 64);
+                                {
+                                    let l3 = i32::from(*base.add(0).cast::<u8>());
+                                    match l3 {
+                                        0 => {
+                                            let l4 = *base.add(8).cast::<*mut u8>();
+                                            let l5 = *base.add(12).cast::<usize>();
+                                            _rt::cabi_dealloc(l4, l5, 1);
+                                            let l6 = i32::from(*base.add(16).cast::<u8>());
+                                            match l6 {
+                                                0 => {}
+                                                _ => {
+                                                    let l7 = *base.add(17).cast::<*mut u8>();
+                                                    let l8 = *base.add(21).cast::<usize>();
+                                                    _rt::cabi_dealloc(l7, l8, 1);
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            let l9 = *base.add(12).cast::<*mut u8>();
+                                            let l10 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l9, l10, 1);
+                                            let l11 = i32::from(*base.add(20).cast::<u8>());
+                                            match l11 {
+                                                0 => {}
+                                                _ => {
+                                                    let l12 = *base.add(21).cast::<*mut u8>();
+                                                    let l13 = *base.add(25).cast::<usize>();
+                                                    _rt::cabi_dealloc(l12, l13, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base14, len14 * 64, 8);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_pull_stream_blocking_get_next_cabi<
+                    T: GuestPullStream,
+                >(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::blocking_get_next(
+                        PullStreamBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec8 = result0;
+                    let len8 = vec8.len();
+                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec8.len() * 64,
+                        8,
+                    );
+                    let result8 = if layout8.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout8);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec8.into_iter().enumerate() {
+                        let base = result8.add(i * 64);
+                        {
+                            match e {
+                                PullEvent::Progress(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let PullProgress {
+                                        status: status2,
+                                        digest: digest2,
+                                        total: total2,
+                                        completed: completed2,
+                                    } = e;
+                                    let vec3 = (status2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(12).cast::<usize>() = len3;
+                                    *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                    match digest2 {
+                                        Some(e) => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec4 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                                            let len4 = vec4.len();
+                                            ::core::mem::forget(vec4);
+                                            *base.add(21).cast::<usize>() = len4;
+                                            *base.add(17).cast::<*mut u8>() = ptr4.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match total2 {
+                                        Some(e) => {
+                                            *base.add(32).cast::<u8>() = (1i32) as u8;
+                                            *base.add(40).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *base.add(32).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match completed2 {
+                                        Some(e) => {
+                                            *base.add(48).cast::<u8>() = (1i32) as u8;
+                                            *base.add(56).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *base.add(48).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                PullEvent::Error(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let super::super::super::super::__with_name0::Error {
+                                        code: code5,
+                                        message: message5,
+                                        provider_error_json: provider_error_json5,
+                                    } = e;
+                                    *base.add(8).cast::<u8>() = (code5.clone() as i32) as u8;
+                                    let vec6 = (message5.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base.add(16).cast::<usize>() = len6;
+                                    *base.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                                    match provider_error_json5 {
+                                        Some(e) => {
+                                            *base.add(20).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base.add(25).cast::<usize>() = len7;
+                                            *base.add(21).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(20).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len8;
+                    *ptr1.add(0).cast::<*mut u8>() = result8;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_pull_stream_blocking_get_next<
+                    T: GuestPullStream,
+                >(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base13 = l0;
+                    let len13 = l1;
+                    for i in 0..len13 {
+                        let base = base13.add(i * 64);
+                        {
+                            let l2 = i32::from(*base.add(0).cast::<u8>());
+                            match l2 {
+                                0 => {
+                                    let l3 = *base.add(8).cast::<*mut u8>();
+                                    let l4 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = i32::from(*base.add(16).cast::<u8>());
+                                    match l5 {
+                                        0 => {}
+                                        _ => {
+                                            let l6 = *base.add(17).cast::<*mut u8>();
+                                            let l7 = *base.add(21).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l8 = *base.add(12).cast::<*mut u8>();
+                                    let l9 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                    let l10 = i32::from(*base.add(20).cast::<u8>());
+                                    match l10 {
+                                        0 => {}
+                                        _ => {
+                                            let l11 = *base.add(21).cast::<*mut u8>();
+                                            let l12 = *base.add(25).cast::<usize>();
+                                            _rt::cabi_dealloc(l11, l12, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    _rt::cabi_dealloc(base13, len13 * 64, 8);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_pull_model_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::pull_model(
+                        _rt::string_lift(bytes0),
+                        _rt::bool_lift(arg2 as u8),
+                    );
+                    (result1).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_list_models_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::list_models();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec15 = e;
+                            let len15 = vec15.len();
+                            let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec15.len() * 104,
+                                8,
+                            );
+                            let result15 = if layout15.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout15);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec15.into_iter().enumerate() {
+                                let base = result15.add(i * 104);
+                                {
+                                    let ModelInfo {
+                                        name: name2,
+                                        model: model2,
+                                        modified_at: modified_at2,
+                                        size: size2,
+                                        digest: digest2,
+                                        details: details2,
+                                    } = e;
+                                    let vec3 = (name2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                    let vec4 = (model2.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(12).cast::<usize>() = len4;
+                                    *base.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                    match modified_at2 {
+                                        Some(e) => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                            let len5 = vec5.len();
+                                            ::core::mem::forget(vec5);
+                                            *base.add(21).cast::<usize>() = len5;
+                                            *base.add(17).cast::<*mut u8>() = ptr5.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(32).cast::<i64>() = _rt::as_i64(size2);
+                                    let vec6 = (digest2.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base.add(44).cast::<usize>() = len6;
+                                    *base.add(40).cast::<*mut u8>() = ptr6.cast_mut();
+                                    match details2 {
+                                        Some(e) => {
+                                            *base.add(48).cast::<u8>() = (1i32) as u8;
+                                            let ModelDetails {
+                                                parent_model: parent_model7,
+                                                format: format7,
+                                                family: family7,
+                                                families: families7,
+                                                parameter_size: parameter_size7,
+                                                quantization_level: quantization_level7,
+                                            } = e;
+                                            match parent_model7 {
+                                                Some(e) => {
+                                                    *base.add(49).cast::<u8>() = (1i32) as u8;
+                                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                    let len8 = vec8.len();
+                                                    ::core::mem::forget(vec8);
+                                                    *base.add(54).cast::<usize>() = len8;
+                                                    *base.add(50).cast::<*mut u8>() = ptr8.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(49).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match format7 {
+                                                Some(e) => {
+                                                    *base.add(58).cast::<u8>() = (1i32) as u8;
+                                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                                    let len9 = vec9.len();
+                                                    ::core::mem::forget(vec9);
+                                                    *base.add(63).cast::<usize>() = len9;
+                                                    *base.add(59).cast::<*mut u8>() = ptr9.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(58).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match family7 {
+                                                Some(e) => {
+                                                    *base.add(67).cast::<u8>() = (1i32) as u8;
+                                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                    let len10 = vec10.len();
+                                                    ::core::mem::forget(vec10);
+                                                    *base.add(72).cast::<usize>() = len10;
+                                                    *base.add(68).cast::<*mut u8>() = ptr10.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(67).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match families7 {
+                                                Some(e) => {
+                                                    *base.add(76).cast::<u8>() = (1i32) as u8;
+                                                    let vec12 = e;
+                                                    let len12 = vec12.len();
+                                                    let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                        vec12.len() * 8,
+                                                        4,
+                                                    );
+                                                    let result12 = if layout12.size() != 0 {
+                                                        let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                                        if ptr.is_null() {
+                                                            _rt::alloc::handle_alloc_error(layout12);
+                                                        }
+                                                        ptr
+                                                    } else {
+                                                        ::core::ptr::null_mut()
+                                                    };
+                                                    for (i, e) in vec12.into_iter().enumerate() {
+                                                        let base = result12.add(i * 8);
+                                                        {
+                                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                            let len11 = vec11.len();
+                                                            ::core::mem::forget(vec11);
+                                                            *base.add(4).cast::<usize>() = len11;
+                                                            *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                                        }
+                                                    }
+                                                    *base.add(81).cast::<usize>() = len12;
+                                                    *base.add(77).cast::<*mut u8>() = result12;
+                                                }
+                                                None => {
+                                                    *base.add(76).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match parameter_size7 {
+                                                Some(e) => {
+                                                    *base.add(85).cast::<u8>() = (1i32) as u8;
+                                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                    let len13 = vec13.len();
+                                                    ::core::mem::forget(vec13);
+                                                    *base.add(90).cast::<usize>() = len13;
+                                                    *base.add(86).cast::<*mut u8>() = ptr13.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(85).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match quantization_level7 {
+                                                Some(e) => {
+                                                    *base.add(94).cast::<u8>() = (1i32) as u8;
+                                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                    let len14 = vec14.len();
+                                                    ::core::mem::forget(vec14);
+                                                    *base.add(99).cast::<usize>() = len14;
+                                                    *base.add(95).cast::<*mut u8>() = ptr14.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(94).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        None => {
+                                            *base.add(48).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr1.add(5).cast::<usize>() = len15;
+                            *ptr1.add(1).cast::<*mut u8>() = result15;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::__with_name0::Error {
+                                code: code16,
+                                message: message16,
+                                provider_error_json: provider_error_json16,
+                            } = e;
+                            *ptr1.add(1).cast::<u8>() = (code16.clone() as i32) as u8;
+                            let vec17 = (message16.into_bytes()).into_boxed_slice();
+                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                            let len17 = vec17.len();
+                            ::core::mem::forget(vec17);
+                            *ptr1.add(9).cast::<usize>() = len17;
+                            *ptr1.add(5).cast::<*mut u8>() = ptr17.cast_mut();
+                            match provider_error_json16 {
+                                Some(e) => {
+                                    *ptr1.add(13).cast::<u8>() = (1i32) as u8;
+                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr1.add(18).cast::<usize>() = len18;
+                                    *ptr1.add(14).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                None => {
+                                    *ptr1.add(13).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_list_models<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(1).cast::<*mut u8>();
+                            let l2 = *arg0.add(5).cast::<usize>();
+                            let base34 = l1;
+                            let len34 = l2;
+                            for i in 0..len34 {
+                                let base = base34.add(i * 104);
+                                {
+                                    let l3 = *base.add(0).cast::<*mut u8>();
+                                    let l4 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = *base.add(8).cast::<*mut u8>();
+                                    let l6 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                    let l7 = i32::from(*base.add(16).cast::<u8>());
+                                    match l7 {
+                                        0 => {}
+                                        _ => {
+                                            let l8 = *base.add(17).cast::<*mut u8>();
+                                            let l9 = *base.add(21).cast::<usize>();
+                                            _rt::cabi_dealloc(l8, l9, 1);
+                                        }
+                                    }
+                                    let l10 = *base.add(40).cast::<*mut u8>();
+                                    let l11 = *base.add(44).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                    let l12 = i32::from(*base.add(48).cast::<u8>());
+                                    match l12 {
+                                        0 => {}
+                                        _ => {
+                                            let l13 = i32::from(*base.add(49).cast::<u8>());
+                                            match l13 {
+                                                0 => {}
+                                                _ => {
+                                                    let l14 = *base.add(50).cast::<*mut u8>();
+                                                    let l15 = *base.add(54).cast::<usize>();
+                                                    _rt::cabi_dealloc(l14, l15, 1);
+                                                }
+                                            }
+                                            let l16 = i32::from(*base.add(58).cast::<u8>());
+                                            match l16 {
+                                                0 => {}
+                                                _ => {
+                                                    let l17 = *base.add(59).cast::<*mut u8>();
+                                                    let l18 = *base.add(63).cast::<usize>();
+                                                    _rt::cabi_dealloc(l17, l18, 1);
+                                                }
+                                            }
+                                            let l19 = i32::from(*base.add(67).cast::<u8>());
+                                            match l19 {
+                                                0 => {}
+                                                _ => {
+                                                    let l20 = *base.add(68).cast::<*mut u8>();
+                                                    let l21 = *base.add(72).cast::<usize>();
+                                                    _rt::cabi_dealloc(l20, l21, 1);
+                                                }
+                                            }
+                                            let l22 = i32::from(*base.add(76).cast::<u8>());
+                                            match l22 {
+                                                0 => {}
+                                                _ => {
+                                                    let l23 = *base.add(77).cast::<*mut u8>();
+                                                    let l24 = *base.add(81).cast::<usize>();
+                                                    let base27 = l23;
+                                                    let len27 = l24;
+                                                    for i in 0..len27 {
+                                                        let base = base27.add(i * 8);
+                                                        {
+                                                            let l25 = *base.add(0).cast::<*mut u8>();
+                                                            let l26 = *base.add(4).cast::<usize>();
+                                                            _rt::cabi_dealloc(l25, l26, 1);
+                                                        }
+                                                    }
+                                                    _rt::cabi_dealloc(base27, len27 * 8, 4);
+                                                }
+                                            }
+                                            let l28 = i32::from(*base.add(85).cast::<u8>());
+                                            match l28 {
+                                                0 => {}
+                                                _ => {
+                                                    let l29 = *base.add(86).cast::<*mut u8>();
+                                                    let l30 = *base.add(90).cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                }
+                                            }
+                                            let l31 = i32::from(*base.add(94).cast::<u8>());
+                                            match l31 {
+                                                0 => {}
+                                                _ => {
+                                                    let l32 = *base.add(95).cast::<*mut u8>();
+                                                    let l33 = *base.add(99).cast::<usize>();
+                                                    _rt::cabi_dealloc(l32, l33, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base34, len34 * 104, 8);
+                        }
+                        _ => {
+                            let l35 = *arg0.add(5).cast::<*mut u8>();
+                            let l36 = *arg0.add(9).cast::<usize>();
+                            _rt::cabi_dealloc(l35, l36, 1);
+                            let l37 = i32::from(*arg0.add(13).cast::<u8>());
+                            match l37 {
+                                0 => {}
+                                _ => {
+                                    let l38 = *arg0.add(14).cast::<*mut u8>();
+                                    let l39 = *arg0.add(18).cast::<usize>();
+                                    _rt::cabi_dealloc(l38, l39, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_show_model_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::show_model(_rt::string_lift(bytes0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let ModelDescription {
+                                modelfile: modelfile3,
+                                parameters: parameters3,
+                                template: template3,
+                                details: details3,
+                                model_info_json: model_info_json3,
+                                capabilities: capabilities3,
+                            } = e;
+                            match modelfile3 {
+                                Some(e) => {
+                                    *ptr2.add(1).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(6).cast::<usize>() = len4;
+                                    *ptr2.add(2).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(1).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match parameters3 {
+                                Some(e) => {
+                                    *ptr2.add(10).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(15).cast::<usize>() = len5;
+                                    *ptr2.add(11).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(10).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match template3 {
+                                Some(e) => {
+                                    *ptr2.add(19).cast::<u8>() = (1i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(24).cast::<usize>() = len6;
+                                    *ptr2.add(20).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(19).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match details3 {
+                                Some(e) => {
+                                    *ptr2.add(28).cast::<u8>() = (1i32) as u8;
+                                    let ModelDetails {
+                                        parent_model: parent_model7,
+                                        format: format7,
+                                        family: family7,
+                                        families: families7,
+                                        parameter_size: parameter_size7,
+                                        quantization_level: quantization_level7,
+                                    } = e;
+                                    match parent_model7 {
+                                        Some(e) => {
+                                            *ptr2.add(29).cast::<u8>() = (1i32) as u8;
+                                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                                            let len8 = vec8.len();
+                                            ::core::mem::forget(vec8);
+                                            *ptr2.add(34).cast::<usize>() = len8;
+                                            *ptr2.add(30).cast::<*mut u8>() = ptr8.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(29).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match format7 {
+                                        Some(e) => {
+                                            *ptr2.add(38).cast::<u8>() = (1i32) as u8;
+                                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *ptr2.add(43).cast::<usize>() = len9;
+                                            *ptr2.add(39).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(38).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match family7 {
+                                        Some(e) => {
+                                            *ptr2.add(47).cast::<u8>() = (1i32) as u8;
+                                            let vec10 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                                            let len10 = vec10.len();
+                                            ::core::mem::forget(vec10);
+                                            *ptr2.add(52).cast::<usize>() = len10;
+                                            *ptr2.add(48).cast::<*mut u8>() = ptr10.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(47).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match families7 {
+                                        Some(e) => {
+                                            *ptr2.add(56).cast::<u8>() = (1i32) as u8;
+                                            let vec12 = e;
+                                            let len12 = vec12.len();
+                                            let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec12.len() * 8,
+                                                4,
+                                            );
+                                            let result12 = if layout12.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout12);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec12.into_iter().enumerate() {
+                                                let base = result12.add(i * 8);
+                                                {
+                                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                    let len11 = vec11.len();
+                                                    ::core::mem::forget(vec11);
+                                                    *base.add(4).cast::<usize>() = len11;
+                                                    *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                                }
+                                            }
+                                            *ptr2.add(61).cast::<usize>() = len12;
+                                            *ptr2.add(57).cast::<*mut u8>() = result12;
+                                        }
+                                        None => {
+                                            *ptr2.add(56).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match parameter_size7 {
+                                        Some(e) => {
+                                            *ptr2.add(65).cast::<u8>() = (1i32) as u8;
+                                            let vec13 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            ::core::mem::forget(vec13);
+                                            *ptr2.add(70).cast::<usize>() = len13;
+                                            *ptr2.add(66).cast::<*mut u8>() = ptr13.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(65).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match quantization_level7 {
+                                        Some(e) => {
+                                            *ptr2.add(74).cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr2.add(79).cast::<usize>() = len14;
+                                            *ptr2.add(75).cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(74).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr2.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match model_info_json3 {
+                                Some(e) => {
+                                    *ptr2.add(83).cast::<u8>() = (1i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr2.add(88).cast::<usize>() = len15;
+                                    *ptr2.add(84).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(83).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec17 = capabilities3;
+                            let len17 = vec17.len();
+                            let layout17 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec17.len() * 8,
+                                4,
+                            );
+                            let result17 = if layout17.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout17);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec17.into_iter().enumerate() {
+                                let base = result17.add(i * 8);
+                                {
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *base.add(4).cast::<usize>() = len16;
+                                    *base.add(0).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                            }
+                            *ptr2.add(97).cast::<usize>() = len17;
+                            *ptr2.add(93).cast::<*mut u8>() = result17;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::__with_name0::Error {
+                                code: code18,
+                                message: message18,
+                                provider_error_json: provider_error_json18,
+                            } = e;
+                            *ptr2.add(1).cast::<u8>() = (code18.clone() as i32) as u8;
+                            let vec19 = (message18.into_bytes()).into_boxed_slice();
+                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                            let len19 = vec19.len();
+                            ::core::mem::forget(vec19);
+                            *ptr2.add(9).cast::<usize>() = len19;
+                            *ptr2.add(5).cast::<*mut u8>() = ptr19.cast_mut();
+                            match provider_error_json18 {
+                                Some(e) => {
+                                    *ptr2.add(13).cast::<u8>() = (1i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr2.add(18).cast::<usize>() = len20;
+                                    *ptr2.add(14).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(13).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_show_model<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = i32::from(*arg0.add(1).cast::<u8>());
+                            match l1 {
+                                0 => {}
+                                _ => {
+                                    let l2 = *arg0.add(2).cast::<*mut u8>();
+                                    let l3 = *arg0.add(6).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                            let l4 = i32::from(*arg0.add(10).cast::<u8>());
+                            match l4 {
+                                0 => {}
+                                _ => {
+                                    let l5 = *arg0.add(11).cast::<*mut u8>();
+                                    let l6 = *arg0.add(15).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                            }
+                            let l7 = i32::from(*arg0.add(19).cast::<u8>());
+                            match l7 {
+                                0 => {}
+                                _ => {
+                                    let l8 = *arg0.add(20).cast::<*mut u8>();
+                                    let l9 = *arg0.add(24).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                            }
+                            let l10 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l10 {
+                                0 => {}
+                                _ => {
+                                    let l11 = i32::from(*arg0.add(29).cast::<u8>());
+                                    match l11 {
+                                        0 => {}
+                                        _ => {
+                                            let l12 = *arg0.add(30).cast::<*mut u8>();
+                                            let l13 = *arg0.add(34).cast::<usize>();
+                                            _rt::cabi_dealloc(l12, l13, 1);
+                                        }
+                                    }
+                                    let l14 = i32::from(*arg0.add(38).cast::<u8>());
+                                    match l14 {
+                                        0 => {}
+                                        _ => {
+                                            let l15 = *arg0.add(39).cast::<*mut u8>();
+                                            let l16 = *arg0.add(43).cast::<usize>();
+                                            _rt::cabi_dealloc(l15, l16, 1);
+                                        }
+                                    }
+                                    let l17 = i32::from(*arg0.add(47).cast::<u8>());
+                                    match l17 {
+                                        0 => {}
+                                        _ => {
+                                            let l18 = *arg0.add(48).cast::<*mut u8>();
+                                            let l19 = *arg0.add(52).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                        }
+                                    }
+                                    let l20 = i32::from(*arg0.add(56).cast::<u8>());
+                                    match l20 {
+                                        0 => {}
+                                        _ => {
+                                            let l21 = *arg0.add(57).cast::<*mut u8>();
+                                            let l22 = *arg0.add(61).cast::<usize>();
+                                            let base25 = l21;
+                                            let len25 = l22;
+                                            for i in 0..len25 {
+                                                let base = base25.add(i * 8);
+                                                {
+                                                    let l23 = *base.add(0).cast::<*mut u8>();
+                                                    let l24 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l23, l24, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base25, len25 * 8, 4);
+                                        }
+                                    }
+                                    let l26 = i32::from(*arg0.add(65).cast::<u8>());
+                                    match l26 {
+                                        0 => {}
+                                        _ => {
+                                            let l27 = *arg0.add(66).cast::<*mut u8>();
+                                            let l28 = *arg0.add(70).cast::<usize>();
+                                            _rt::cabi_dealloc(l27, l28, 1);
+                                        }
+                                    }
+                                    let l29 = i32::from(*arg0.add(74).cast::<u8>());
+                                    match l29 {
+                                        0 => {}
+                                        _ => {
+                                            let l30 = *arg0.add(75).cast::<*mut u8>();
+                                            let l31 = *arg0.add(79).cast::<usize>();
+                                            _rt::cabi_dealloc(l30, l31, 1);
+                                        }
+                                    }
+                                }
+                            }
+                            let l32 = i32::from(*arg0.add(83).cast::<u8>());
+                            match l32 {
+                                0 => {}
+                                _ => {
+                                    let l33 = *arg0.add(84).cast::<*mut u8>();
+                                    let l34 = *arg0.add(88).cast::<usize>();
+                                    _rt::cabi_dealloc(l33, l34, 1);
+                                }
+                            }
+                            let l35 = *arg0.add(93).cast::<*mut u8>();
+                            let l36 = *arg0.add(97).cast::<usize>();
+                            let base39 = l35;
+                            let len39 = l36;
+                            for i in 0..len39 {
+                                let base = base39.add(i * 8);
+                                {
+                                    let l37 = *base.add(0).cast::<*mut u8>();
+                                    let l38 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l37, l38, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base39, len39 * 8, 4);
+                        }
+                        _ => {
+                            let l40 = *arg0.add(5).cast::<*mut u8>();
+                            let l41 = *arg0.add(9).cast::<usize>();
+                            _rt::cabi_dealloc(l40, l41, 1);
+                            let l42 = i32::from(*arg0.add(13).cast::<u8>());
+                            match l42 {
+                                0 => {}
+                                _ => {
+                                    let l43 = *arg0.add(14).cast::<*mut u8>();
+                                    let l44 = *arg0.add(18).cast::<usize>();
+                                    _rt::cabi_dealloc(l43, l44, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_delete_model_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::delete_model(_rt::string_lift(bytes0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::__with_name0::Error {
+                                code: code3,
+                                message: message3,
+                                provider_error_json: provider_error_json3,
+                            } = e;
+                            *ptr2.add(1).cast::<u8>() = (code3.clone() as i32) as u8;
+                            let vec4 = (message3.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr2.add(9).cast::<usize>() = len4;
+                            *ptr2.add(5).cast::<*mut u8>() = ptr4.cast_mut();
+                            match provider_error_json3 {
+                                Some(e) => {
+                                    *ptr2.add(13).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(18).cast::<usize>() = len5;
+                                    *ptr2.add(14).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(13).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_delete_model<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0.add(5).cast::<*mut u8>();
+                            let l2 = *arg0.add(9).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = i32::from(*arg0.add(13).cast::<u8>());
+                            match l3 {
+                                0 => {}
+                                _ => {
+                                    let l4 = *arg0.add(14).cast::<*mut u8>();
+                                    let l5 = *arg0.add(18).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type PullStream: GuestPullStream;
+                    /// --- Core Functions ---
+                    fn pull_model(model: _rt::String, insecure: bool) -> PullStream;
+                    fn list_models() -> Result<_rt::Vec<ModelInfo>, Error>;
+                    fn show_model(model: _rt::String) -> Result<ModelDescription, Error>;
+                    fn delete_model(model: _rt::String) -> Result<(), Error>;
+                }
+                pub trait GuestPullStream: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]golem:llm-ollama/models@1.0.0"
+                            )]
+                            extern "C" {
+                                #[link_name = "[resource-new]pull-stream"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]golem:llm-ollama/models@1.0.0"
+                            )]
+                            extern "C" {
+                                #[link_name = "[resource-rep]pull-stream"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    fn get_next(&self) -> Option<_rt::Vec<PullEvent>>;
+                    fn blocking_get_next(&self) -> _rt::Vec<PullEvent>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_golem_llm_ollama_models_1_0_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[export_name =
+                        "golem:llm-ollama/models@1.0.0#[method]pull-stream.get-next"]
+                        unsafe extern "C" fn export_method_pull_stream_get_next(arg0 : *
+                        mut u8,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_pull_stream_get_next_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::PullStream > (arg0) } #[export_name
+                        =
+                        "cabi_post_golem:llm-ollama/models@1.0.0#[method]pull-stream.get-next"]
+                        unsafe extern "C" fn
+                        _post_return_method_pull_stream_get_next(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_pull_stream_get_next::<<$ty as
+                        $($path_to_types)*:: Guest >::PullStream > (arg0) } #[export_name
+                        =
+                        "golem:llm-ollama/models@1.0.0#[method]pull-stream.blocking-get-next"]
+                        unsafe extern "C" fn
+                        export_method_pull_stream_blocking_get_next(arg0 : * mut u8,) ->
+                        * mut u8 { $($path_to_types)*::
+                        _export_method_pull_stream_blocking_get_next_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::PullStream > (arg0) } #[export_name
+                        =
+                        "cabi_post_golem:llm-ollama/models@1.0.0#[method]pull-stream.blocking-get-next"]
+                        unsafe extern "C" fn
+                        _post_return_method_pull_stream_blocking_get_next(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_pull_stream_blocking_get_next::<<$ty as
+                        $($path_to_types)*:: Guest >::PullStream > (arg0) } #[export_name
+                        = "golem:llm-ollama/models@1.0.0#pull-model"] unsafe extern "C"
+                        fn export_pull_model(arg0 : * mut u8, arg1 : usize, arg2 : i32,)
+                        -> i32 { $($path_to_types)*:: _export_pull_model_cabi::<$ty >
+                        (arg0, arg1, arg2) } #[export_name =
+                        "golem:llm-ollama/models@1.0.0#list-models"] unsafe extern "C" fn
+                        export_list_models() -> * mut u8 { $($path_to_types)*::
+                        _export_list_models_cabi::<$ty > () } #[export_name =
+                        "cabi_post_golem:llm-ollama/models@1.0.0#list-models"] unsafe
+                        extern "C" fn _post_return_list_models(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_list_models::<$ty > (arg0) }
+                        #[export_name = "golem:llm-ollama/models@1.0.0#show-model"]
+                        unsafe extern "C" fn export_show_model(arg0 : * mut u8, arg1 :
+                        usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_show_model_cabi::<$ty > (arg0, arg1) } #[export_name =
+                        "cabi_post_golem:llm-ollama/models@1.0.0#show-model"] unsafe
+                        extern "C" fn _post_return_show_model(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_show_model::<$ty > (arg0) }
+                        #[export_name = "golem:llm-ollama/models@1.0.0#delete-model"]
+                        unsafe extern "C" fn export_delete_model(arg0 : * mut u8, arg1 :
+                        usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_delete_model_cabi::<$ty > (arg0, arg1) } #[export_name =
+                        "cabi_post_golem:llm-ollama/models@1.0.0#delete-model"] unsafe
+                        extern "C" fn _post_return_delete_model(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_delete_model::<$ty > (arg0) }
+                        const _ : () = { #[doc(hidden)] #[export_name =
+                        "golem:llm-ollama/models@1.0.0#[dtor]pull-stream"]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { $($path_to_types)*:: PullStream::dtor::< <$ty as
+                        $($path_to_types)*:: Guest >::PullStream > (rep) } }; };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_golem_llm_ollama_models_1_0_0_cabi;
+                #[repr(align(1))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 101]);
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 101],
+                );
+            }
+        }
+    }
+}
+#[rustfmt::skip]
+mod _rt {
+    pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub use alloc_crate::boxed::Box;
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub use alloc_crate::alloc;
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    extern crate alloc as alloc_crate;
+}
+/// Generates `#[no_mangle]` functions to export the specified type as the
+/// root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+macro_rules! __export_llm_library_impl {
+    ($ty:ident) => {
+        self::export!($ty with_types_in self);
+    };
+    ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
+        $($path_to_types_root)*::
+        exports::golem::llm_ollama::models::__export_golem_llm_ollama_models_1_0_0_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::golem::llm_ollama::models);
+    };
+}
+#[doc(inline)]
+pub(crate) use __export_llm_library_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.36.0:golem:llm-ollama@1.0.0:llm-library:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1759] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xdd\x0c\x01A\x02\x01\
-A\x02\x01BO\x01m\x04\x04user\x09assistant\x06system\x04tool\x04\0\x04role\x03\0\0\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2969] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x97\x16\x01A\x02\x01\
+A\x09\x01BW\x01m\x04\x04user\x09assistant\x06system\x04tool\x04\0\x04role\x03\0\0\
 \x01m\x06\x0finvalid-request\x15authentication-failed\x13rate-limit-exceeded\x0e\
 internal-error\x0bunsupported\x07unknown\x04\0\x0aerror-code\x03\0\x02\x01m\x06\x04\
 stop\x06length\x0atool-calls\x0econtent-filter\x05error\x05other\x04\0\x0dfinish\
@@ -36,15 +1745,42 @@ sages\x13provider-error-json\x12\x04\0\x05error\x03\03\x01q\x03\x07message\x012\
 \x0ctool-request\x010\0\x05error\x014\0\x04\0\x0achat-event\x03\05\x01k\x13\x01k\
 0\x01r\x02\x07content7\x0atool-calls8\x04\0\x0cstream-delta\x03\09\x01q\x03\x05d\
 elta\x01:\0\x06finish\x01/\0\x05error\x014\0\x04\0\x0cstream-event\x03\0;\x04\0\x0b\
-chat-stream\x03\x01\x01h=\x01p<\x01k?\x01@\x01\x04self>\0\xc0\0\x04\0\x1c[method\
-]chat-stream.get-next\x01A\x01@\x01\x04self>\0?\x04\0%[method]chat-stream.blocki\
-ng-get-next\x01B\x01p\x15\x01@\x02\x08messages\xc3\0\x06config)\06\x04\0\x04send\
-\x01D\x01o\x02\x19\x20\x01p\xc5\0\x01@\x03\x08messages\xc3\0\x0ctool-results\xc6\
-\0\x06config)\06\x04\0\x08continue\x01G\x01i=\x01@\x02\x08messages\xc3\0\x06conf\
-ig)\0\xc8\0\x04\0\x06stream\x01I\x04\0\x13golem:llm/llm@1.0.0\x05\0\x04\0\"golem\
-:llm-ollama/llm-library@1.0.0\x04\0\x0b\x11\x01\0\x0bllm-library\x03\0\0\0G\x09p\
-roducers\x01\x0cprocessed-by\x02\x0dwit-component\x070.220.0\x10wit-bindgen-rust\
-\x060.36.0";
+chat-stream\x03\x01\x01r\x03\x04names\x07flagged\x7f\x05score#\x04\0\x13moderati\
+on-category\x03\0>\x01p?\x01r\x03\x07flagged\x7f\x0acategories\xc0\0\x16provider\
+-metadata-json\x12\x04\0\x11moderation-result\x03\0A\x01h=\x01p<\x01k\xc4\0\x01@\
+\x01\x04self\xc3\0\0\xc5\0\x04\0\x1c[method]chat-stream.get-next\x01F\x01@\x01\x04\
+self\xc3\0\0\xc4\0\x04\0%[method]chat-stream.blocking-get-next\x01G\x01p\x15\x01\
+@\x02\x08messages\xc8\0\x06config)\06\x04\0\x04send\x01I\x01o\x02\x19\x20\x01p\xca\
+\0\x01@\x03\x08messages\xc8\0\x0ctool-results\xcb\0\x06config)\06\x04\0\x08conti\
+nue\x01L\x01i=\x01@\x02\x08messages\xc8\0\x06config)\0\xcd\0\x04\0\x06stream\x01\
+N\x01j\x01\xc2\0\x014\x01@\x01\x07content\x13\0\xcf\0\x04\0\x08moderate\x01P\x04\
+\0\x13golem:llm/llm@1.0.0\x05\0\x02\x03\0\0\x05error\x01B&\x02\x03\x02\x01\x01\x04\
+\0\x05error\x03\0\0\x01ks\x01ps\x01k\x03\x01r\x06\x0cparent-model\x02\x06format\x02\
+\x06family\x02\x08families\x04\x0eparameter-size\x02\x12quantization-level\x02\x04\
+\0\x0dmodel-details\x03\0\x05\x01k\x06\x01r\x06\x04names\x05models\x0bmodified-a\
+t\x02\x04sizew\x06digests\x07details\x07\x04\0\x0amodel-info\x03\0\x08\x01r\x06\x09\
+modelfile\x02\x0aparameters\x02\x08template\x02\x07details\x07\x0fmodel-info-jso\
+n\x02\x0ccapabilities\x03\x04\0\x11model-description\x03\0\x0a\x01kw\x01r\x04\x06\
+statuss\x06digest\x02\x05total\x0c\x09completed\x0c\x04\0\x0dpull-progress\x03\0\
+\x0d\x01q\x02\x08progress\x01\x0e\0\x05error\x01\x01\0\x04\0\x0apull-event\x03\0\
+\x0f\x04\0\x0bpull-stream\x03\x01\x01h\x11\x01p\x10\x01k\x13\x01@\x01\x04self\x12\
+\0\x14\x04\0\x1c[method]pull-stream.get-next\x01\x15\x01@\x01\x04self\x12\0\x13\x04\
+\0%[method]pull-stream.blocking-get-next\x01\x16\x01i\x11\x01@\x02\x05models\x08\
+insecure\x7f\0\x17\x04\0\x0apull-model\x01\x18\x01p\x09\x01j\x01\x19\x01\x01\x01\
+@\0\0\x1a\x04\0\x0blist-models\x01\x1b\x01j\x01\x0b\x01\x01\x01@\x01\x05models\0\
+\x1c\x04\0\x0ashow-model\x01\x1d\x01j\0\x01\x01\x01@\x01\x05models\0\x1e\x04\0\x0c\
+delete-model\x01\x1f\x04\0\x1dgolem:llm-ollama/models@1.0.0\x05\x02\x02\x03\0\0\x0f\
+image-reference\x02\x03\0\0\x02kv\x01B\x15\x02\x03\x02\x01\x03\x04\0\x0fimage-re\
+ference\x03\0\0\x02\x03\x02\x01\x01\x04\0\x05error\x03\0\x02\x02\x03\x02\x01\x04\
+\x04\0\x02kv\x03\0\x04\x01r\x02\x05widthy\x06heighty\x04\0\x0aimage-size\x03\0\x06\
+\x01ks\x01kw\x01p\x05\x01r\x06\x05model\x08\x07quality\x08\x05style\x08\x0fnegat\
+ive-prompt\x08\x04seed\x09\x10provider-options\x0a\x04\0\x0dimage-options\x03\0\x0b\
+\x01k\x07\x01p\x01\x01j\x01\x0e\x01\x03\x01@\x04\x06prompts\x04size\x0d\x05count\
+y\x07options\x0c\0\x0f\x04\0\x08generate\x01\x10\x01k\x01\x01@\x04\x05image\x01\x04\
+mask\x11\x06prompts\x07options\x0c\0\x0f\x04\0\x04edit\x01\x12\x04\0\x16golem:ll\
+m/images@1.0.0\x05\x05\x04\0\"golem:llm-ollama/llm-library@1.0.0\x04\0\x0b\x11\x01\
+\0\x0bllm-library\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compone\
+nt\x070.220.0\x10wit-bindgen-rust\x060.36.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -15,22 +15,33 @@ use reqwest::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use url::Url;
 
+fn base_url() -> String {
+    std::env::var("GOLEM_OLLAMA_BASE_URL").unwrap_or("http://localhost:11434".to_string())
+}
+
+/// Pulling a missing model before retrying a chat request is opt-in, as it can download gigabytes of data
+fn auto_pull_enabled() -> bool {
+    std::env::var("GOLEM_OLLAMA_AUTO_PULL")
+        .map(|value| matches!(value.to_lowercase().as_str(), "true" | "1" | "yes"))
+        .unwrap_or(false)
+}
+
 pub struct OllamaApi {
     default_model: String,
     base_url: String,
+    auto_pull: bool,
     client: Client,
 }
 
 impl OllamaApi {
     pub fn new(default_model: String) -> Self {
-        let base_url =
-            std::env::var("GOLEM_OLLAMA_BASE_URL").unwrap_or("http://localhost:11434".to_string());
         let client = Client::builder()
             .build()
             .expect("Failed to initialize HTTP client");
         Self {
             default_model,
-            base_url,
+            base_url: base_url(),
+            auto_pull: auto_pull_enabled(),
             client,
         }
     }
@@ -44,17 +55,11 @@ impl OllamaApi {
             modified_params.model = Some(self.default_model.clone())
         };
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
-        let url = format!("{}/api/chat", self.base_url);
-        let response: Response = self
-            .client
-            .request(Method::POST, url)
-            .headers(headers)
-            .json(&modified_params)
-            .send()
-            .map_err(|err| from_reqwest_error("Request failed", err))?;
+        let mut response = self.post_chat(&modified_params, "application/json")?;
+        if self.should_pull_missing_model(response.status()) {
+            self.pull_missing_model(&modified_params)?;
+            response = self.post_chat(&modified_params, "application/json")?;
+        }
 
         handle_response::<CompletionsResponse>(response)
     }
@@ -68,7 +73,22 @@ impl OllamaApi {
             modified_params.model = Some(self.default_model.clone())
         };
 
-        let json_body = serde_json::to_string(&modified_params).map_err(|e| Error {
+        let mut response = self.post_chat(&modified_params, "application/x-ndjson")?;
+        if self.should_pull_missing_model(response.status()) {
+            self.pull_missing_model(&modified_params)?;
+            response = self.post_chat(&modified_params, "application/x-ndjson")?;
+        }
+
+        EventSource::new(response)
+            .map_err(|err| from_event_source_error("Failed to create EventSource stream", err))
+    }
+
+    fn post_chat(
+        &self,
+        params: &CompletionsRequest,
+        accept: &'static str,
+    ) -> Result<Response, Error> {
+        let json_body = serde_json::to_string(params).map_err(|e| Error {
             code: ErrorCode::InternalError,
             message: format!("Failed to serialize request body: {e}"),
             provider_error_json: None,
@@ -76,19 +96,146 @@ impl OllamaApi {
 
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert("Accept", HeaderValue::from_static("application/x-ndjson"));
+        headers.insert("Accept", HeaderValue::from_static(accept));
 
         let url = format!("{}/api/chat", self.base_url);
-        let response = self
-            .client
+        self.client
             .request(Method::POST, url)
             .headers(headers)
             .body(json_body)
             .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))
+    }
+
+    /// Ollama responds with 404 to chat requests when the requested model has not been pulled yet
+    fn should_pull_missing_model(&self, status: StatusCode) -> bool {
+        self.auto_pull && status == StatusCode::NOT_FOUND
+    }
+
+    fn pull_missing_model(&self, params: &CompletionsRequest) -> Result<(), Error> {
+        let model = params
+            .model
+            .clone()
+            .unwrap_or_else(|| self.default_model.clone());
+        trace!("Model {model} not found, pulling it before retrying the request");
+
+        let response = ModelsApi::new().pull_model(model.clone(), false)?;
+        if response.status == "success" {
+            Ok(())
+        } else {
+            Err(Error {
+                code: ErrorCode::InternalError,
+                message: format!("Failed to pull model {model}: {}", response.status),
+                provider_error_json: None,
+            })
+        }
+    }
+}
+
+/// The Ollama API client for managing the locally available models.
+///
+/// Based on https://github.com/ollama/ollama/blob/main/docs/api.md#list-local-models
+pub struct ModelsApi {
+    base_url: String,
+    client: Client,
+}
+
+impl Default for ModelsApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModelsApi {
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .build()
+            .expect("Failed to initialize HTTP client");
+        Self {
+            base_url: base_url(),
+            client,
+        }
+    }
+
+    /// Pulls a model and waits until it has been fully downloaded
+    pub fn pull_model(&self, model: String, insecure: bool) -> Result<PullResponse, Error> {
+        let request = PullRequest {
+            model,
+            insecure: Some(insecure),
+            stream: false,
+        };
+        trace!("Sending request to Ollama API: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{}/api/pull", self.base_url))
+            .json(&request)
+            .send()
             .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        handle_response::<PullResponse>(response)
+    }
+
+    /// Pulls a model, returning an NDJSON stream of `PullResponse` progress updates
+    pub fn pull_model_stream(&self, model: String, insecure: bool) -> Result<EventSource, Error> {
+        let request = PullRequest {
+            model,
+            insecure: Some(insecure),
+            stream: true,
+        };
+        trace!("Sending request to Ollama API: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{}/api/pull", self.base_url))
+            .header("Accept", HeaderValue::from_static("application/x-ndjson"))
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
         EventSource::new(response)
             .map_err(|err| from_event_source_error("Failed to create EventSource stream", err))
     }
+
+    pub fn list_models(&self) -> Result<ListModelsResponse, Error> {
+        trace!("Sending request to Ollama API: list models");
+
+        let response = self
+            .client
+            .request(Method::GET, format!("{}/api/tags", self.base_url))
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        handle_response::<ListModelsResponse>(response)
+    }
+
+    pub fn show_model(&self, model: String) -> Result<ShowModelResponse, Error> {
+        let request = ModelRequest { model };
+        trace!("Sending request to Ollama API: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{}/api/show", self.base_url))
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        handle_response::<ShowModelResponse>(response)
+    }
+
+    pub fn delete_model(&self, model: String) -> Result<(), Error> {
+        let request = ModelRequest { model };
+        trace!("Sending request to Ollama API: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::DELETE, format!("{}/api/delete", self.base_url))
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        handle_empty_response(response)
+    }
 }
 
 /// GenerateOptions is Options for generating completions
@@ -267,13 +414,95 @@ pub struct Function {
     pub arguments: serde_json::Value,
 }
 
+/// Request body of https://github.com/ollama/ollama/blob/main/docs/api.md#pull-a-model
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullRequest {
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    pub stream: bool,
+}
+
+/// A single progress update of a model pull, or the final response when not streaming
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullResponse {
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModelRequest {
+    pub model: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListModelsResponse {
+    pub models: Vec<LocalModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LocalModel {
+    pub name: String,
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<String>,
+    pub size: u64,
+    pub digest: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<ModelDetails>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModelDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub families: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantization_level: Option<String>,
+}
+
+/// Response of https://github.com/ollama/ollama/blob/main/docs/api.md#show-model-information
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShowModelResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modelfile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<ModelDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_info: Option<serde_json::Value>,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OllamaRequestError {
-    status_code: i32,
+    #[serde(default)]
+    status_code: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error_message: Option<String>,
+    /// Ollama's own endpoints report failures as `{ "error": "..." }`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 pub fn handle_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, Error> {
@@ -294,28 +523,40 @@ pub fn handle_response<T: DeserializeOwned + Debug>(response: Response) -> Resul
                 }),
             }
         }
-        _ => {
-            let raw_error_body = response
-                .text()
-                .map_err(|err| from_reqwest_error("Failed to receive error response body", err))?;
-            trace!("Received {status} response from OpenRouter API: {raw_error_body:?}");
+        _ => Err(parse_error_response(status, response)?),
+    }
+}
 
-            let error_body: OllamaRequestError =
-                serde_json::from_str(&raw_error_body).map_err(|err| Error {
-                    code: ErrorCode::InternalError,
-                    message: format!("Failed to parse error response body: {err}"),
-                    provider_error_json: Some(raw_error_body),
-                })?;
+pub fn handle_empty_response(response: Response) -> Result<(), Error> {
+    let status = response.status();
 
-            Err(Error {
-                code: error_code_from_status(status),
-                message: error_body.status.unwrap_or_default(),
-                provider_error_json: error_body.error_message,
-            })
-        }
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(parse_error_response(status, response)?)
     }
 }
 
+fn parse_error_response(status: StatusCode, response: Response) -> Result<Error, Error> {
+    let raw_error_body = response
+        .text()
+        .map_err(|err| from_reqwest_error("Failed to receive error response body", err))?;
+    trace!("Received {status} response from Ollama API: {raw_error_body:?}");
+
+    let error_body: OllamaRequestError =
+        serde_json::from_str(&raw_error_body).map_err(|err| Error {
+            code: ErrorCode::InternalError,
+            message: format!("Failed to parse error response body: {err}"),
+            provider_error_json: Some(raw_error_body.clone()),
+        })?;
+
+    Ok(Error {
+        code: error_code_from_status(status),
+        message: error_body.status.or(error_body.error).unwrap_or_default(),
+        provider_error_json: error_body.error_message.or(Some(raw_error_body)),
+    })
+}
+
 pub fn image_to_base64(source: &str) -> Result<String, Box<dyn std::error::Error>> {
     let bytes = if Url::parse(source).is_ok() {
         let client = Client::new();
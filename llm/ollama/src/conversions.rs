@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
+use crate::bindings::exports::golem::llm_ollama::models::{
+    ModelDescription, ModelDetails as WitModelDetails, ModelInfo, PullEvent, PullProgress,
+};
 use crate::client::{
-    image_to_base64, CompletionsRequest, CompletionsResponse, FunctionTool, MessageRequest,
-    MessageRole, OllamaModelOptions, Tool,
+    image_to_base64, CompletionsRequest, CompletionsResponse, FunctionTool, LocalModel,
+    MessageRequest, MessageRole, ModelDetails, OllamaModelOptions, PullResponse, ShowModelResponse,
+    Tool,
 };
 use base64::{engine::general_purpose, Engine};
 use golem_llm::golem::llm::llm::{
@@ -244,3 +248,214 @@ pub fn get_provider_metadata(response: &CompletionsResponse) -> String {
         response.eval_count.unwrap_or(0)
     )
 }
+
+pub fn local_model_to_model_info(model: LocalModel) -> ModelInfo {
+    ModelInfo {
+        name: model.name,
+        model: model.model,
+        modified_at: model.modified_at,
+        size: model.size,
+        digest: model.digest,
+        details: model.details.map(model_details_to_wit),
+    }
+}
+
+pub fn show_model_response_to_description(response: ShowModelResponse) -> ModelDescription {
+    ModelDescription {
+        modelfile: response.modelfile,
+        parameters: response.parameters,
+        template: response.template,
+        details: response.details.map(model_details_to_wit),
+        model_info_json: response.model_info.map(|info| info.to_string()),
+        capabilities: response.capabilities,
+    }
+}
+
+fn model_details_to_wit(details: ModelDetails) -> WitModelDetails {
+    WitModelDetails {
+        parent_model: details.parent_model,
+        format: details.format,
+        family: details.family,
+        families: details.families,
+        parameter_size: details.parameter_size,
+        quantization_level: details.quantization_level,
+    }
+}
+
+/// Ollama reports failures during a pull as a progress line with an `error` field
+pub fn pull_response_to_pull_event(response: PullResponse) -> PullEvent {
+    match response.error {
+        Some(error) => PullEvent::Error(Error {
+            code: ErrorCode::InternalError,
+            message: error,
+            provider_error_json: None,
+        }),
+        None => PullEvent::Progress(PullProgress {
+            status: response.status,
+            digest: response.digest,
+            total: response.total,
+            completed: response.completed,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ListModelsResponse;
+
+    #[test]
+    fn test_pull_progress_to_pull_event() {
+        let response: PullResponse = serde_json::from_str(
+            r#"{"status":"pulling 6a0746a1ec1a","digest":"sha256:6a0746a1ec1aef3e7ec53868f220ff6e389f6f8ef87a01d77c96807de94ca2aa","total":4661211424,"completed":1048576}"#,
+        )
+        .unwrap();
+
+        match pull_response_to_pull_event(response) {
+            PullEvent::Progress(progress) => {
+                assert_eq!(progress.status, "pulling 6a0746a1ec1a");
+                assert_eq!(
+                    progress.digest.as_deref(),
+                    Some("sha256:6a0746a1ec1aef3e7ec53868f220ff6e389f6f8ef87a01d77c96807de94ca2aa")
+                );
+                assert_eq!(progress.total, Some(4661211424));
+                assert_eq!(progress.completed, Some(1048576));
+            }
+            PullEvent::Error(error) => panic!("Unexpected error: {error:?}"),
+        }
+
+        let response: PullResponse = serde_json::from_str(r#"{"status":"success"}"#).unwrap();
+
+        match pull_response_to_pull_event(response) {
+            PullEvent::Progress(progress) => {
+                assert_eq!(progress.status, "success");
+                assert_eq!(progress.digest, None);
+                assert_eq!(progress.total, None);
+            }
+            PullEvent::Error(error) => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_pull_error_to_pull_event() {
+        let response: PullResponse = serde_json::from_str(
+            r#"{"status":"","error":"pull model manifest: file does not exist"}"#,
+        )
+        .unwrap();
+
+        match pull_response_to_pull_event(response) {
+            PullEvent::Error(error) => {
+                assert!(matches!(error.code, ErrorCode::InternalError));
+                assert_eq!(error.message, "pull model manifest: file does not exist");
+            }
+            PullEvent::Progress(progress) => panic!("Unexpected progress: {progress:?}"),
+        }
+    }
+
+    #[test]
+    fn test_local_model_to_model_info() {
+        let response: ListModelsResponse = serde_json::from_str(
+            r#"{
+                "models": [
+                    {
+                        "name": "llama3.2:latest",
+                        "model": "llama3.2:latest",
+                        "modified_at": "2025-05-04T17:37:44.706015396-07:00",
+                        "size": 2019393189,
+                        "digest": "a80c4f17acd55265feec403c7aef86be0c25983ab279d83f3bcd3abbcb5b8b72",
+                        "details": {
+                            "parent_model": "",
+                            "format": "gguf",
+                            "family": "llama",
+                            "families": ["llama"],
+                            "parameter_size": "3.2B",
+                            "quantization_level": "Q4_K_M"
+                        }
+                    },
+                    {
+                        "name": "custom:latest",
+                        "model": "custom:latest",
+                        "size": 42,
+                        "digest": "sha256:1234"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let models = response
+            .models
+            .into_iter()
+            .map(local_model_to_model_info)
+            .collect::<Vec<_>>();
+
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "llama3.2:latest");
+        assert_eq!(models[0].size, 2019393189);
+        assert_eq!(
+            models[0].modified_at.as_deref(),
+            Some("2025-05-04T17:37:44.706015396-07:00")
+        );
+        let details = models[0].details.as_ref().unwrap();
+        assert_eq!(details.family.as_deref(), Some("llama"));
+        assert_eq!(details.families, Some(vec!["llama".to_string()]));
+        assert_eq!(details.parameter_size.as_deref(), Some("3.2B"));
+        assert_eq!(details.quantization_level.as_deref(), Some("Q4_K_M"));
+        assert_eq!(models[1].modified_at, None);
+        assert!(models[1].details.is_none());
+    }
+
+    #[test]
+    fn test_show_model_response_to_description() {
+        let response: ShowModelResponse = serde_json::from_str(
+            r#"{
+                "modelfile": "FROM llama3.2:latest",
+                "parameters": "stop \"<|eot_id|>\"",
+                "template": "{{ .Prompt }}",
+                "details": {
+                    "parent_model": "",
+                    "format": "gguf",
+                    "family": "llama",
+                    "families": ["llama"],
+                    "parameter_size": "3.2B",
+                    "quantization_level": "Q4_K_M"
+                },
+                "model_info": {
+                    "general.architecture": "llama",
+                    "llama.context_length": 131072
+                },
+                "capabilities": ["completion", "tools"]
+            }"#,
+        )
+        .unwrap();
+
+        let description = show_model_response_to_description(response);
+
+        assert_eq!(
+            description.modelfile.as_deref(),
+            Some("FROM llama3.2:latest")
+        );
+        assert_eq!(description.template.as_deref(), Some("{{ .Prompt }}"));
+        assert_eq!(
+            description.details.and_then(|details| details.format),
+            Some("gguf".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&description.model_info_json.unwrap())
+                .unwrap(),
+            serde_json::json!({"general.architecture": "llama", "llama.context_length": 131072})
+        );
+        assert_eq!(description.capabilities, vec!["completion", "tools"]);
+    }
+
+    #[test]
+    fn test_show_model_response_without_optional_fields() {
+        let response: ShowModelResponse = serde_json::from_str(r#"{}"#).unwrap();
+
+        let description = show_model_response_to_description(response);
+
+        assert!(description.details.is_none());
+        assert_eq!(description.model_info_json, None);
+        assert!(description.capabilities.is_empty());
+    }
+}
@@ -5,17 +5,24 @@ use conversions::{messages_to_request, process_response};
 use golem_llm::{
     chat_stream::{LlmChatStream, LlmChatStreamState},
     durability::{DurableLLM, ExtendedGuest},
+    error::unsupported,
     event_source::EventSource,
+    golem::llm::images::{self, ImageOptions, ImageSize},
     golem::llm::llm::{
-        ChatEvent, ChatStream, Config, ContentPart, Error, FinishReason, Guest, Message,
-        ResponseMetadata, Role, StreamDelta, StreamEvent, ToolCall, ToolResult, Usage,
+        ChatEvent, ChatStream, Config, ContentPart, Error, FinishReason, Guest, ImageReference,
+        Message, ModerationResult, ResponseMetadata, Role, StreamDelta, StreamEvent, ToolCall,
+        ToolResult, Usage,
     },
 };
 use golem_rust::wasm_rpc::Pollable;
 use log::trace;
+use models::OllamaModelsComponent;
 
+#[allow(static_mut_refs, unused_imports, clippy::all)]
+mod bindings;
 mod client;
 mod conversions;
+mod models;
 
 struct OllamaChatStream {
     stream: RefCell<Option<EventSource>>,
@@ -225,6 +232,30 @@ impl Guest for OllamaComponent {
     fn stream(messages: Vec<Message>, config: Config) -> ChatStream {
         ChatStream::new(Self::unwrapped_stream(messages, config.clone()))
     }
+
+    fn moderate(_content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+        Err(unsupported("content moderation"))
+    }
+}
+
+impl images::Guest for OllamaComponent {
+    fn generate(
+        _prompt: String,
+        _size: Option<ImageSize>,
+        _count: u32,
+        _options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        Err(unsupported("image generation"))
+    }
+
+    fn edit(
+        _image: ImageReference,
+        _mask: Option<ImageReference>,
+        _prompt: String,
+        _options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        Err(unsupported("image editing"))
+    }
 }
 
 impl ExtendedGuest for OllamaComponent {
@@ -297,3 +328,5 @@ impl ExtendedGuest for OllamaComponent {
 type DurableOllamaComponent = DurableLLM<OllamaComponent>;
 
 golem_llm::export_llm!(DurableOllamaComponent with_types_in golem_llm);
+
+bindings::export!(OllamaModelsComponent with_types_in bindings);
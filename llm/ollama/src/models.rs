@@ -0,0 +1,169 @@
+use crate::bindings::exports::golem::llm_ollama::models::{
+    Guest, GuestPullStream, ModelDescription, ModelInfo, PullEvent, PullStream,
+};
+use crate::client::{ModelsApi, PullResponse};
+use crate::conversions::{
+    local_model_to_model_info, pull_response_to_pull_event, show_model_response_to_description,
+};
+use golem_llm::event_source::{Event, EventSource, MessageEvent};
+use golem_llm::golem::llm::llm::{Error, ErrorCode};
+use golem_llm::init_logging;
+use golem_rust::wasm_rpc::Pollable;
+use log::trace;
+use std::cell::RefCell;
+use std::task::Poll;
+
+/// Streams the NDJSON progress updates of `/api/pull`
+pub struct OllamaPullStream {
+    stream: RefCell<Option<EventSource>>,
+    failure: Option<Error>,
+    finished: RefCell<bool>,
+}
+
+impl OllamaPullStream {
+    pub fn new(stream: EventSource) -> Self {
+        OllamaPullStream {
+            stream: RefCell::new(Some(stream)),
+            failure: None,
+            finished: RefCell::new(false),
+        }
+    }
+
+    pub fn failed(error: Error) -> Self {
+        OllamaPullStream {
+            stream: RefCell::new(None),
+            failure: Some(error),
+            finished: RefCell::new(false),
+        }
+    }
+
+    fn subscribe(&self) -> Pollable {
+        if let Some(stream) = self.stream.borrow().as_ref() {
+            stream.subscribe()
+        } else {
+            golem_rust::bindings::wasi::clocks::monotonic_clock::subscribe_duration(0)
+        }
+    }
+
+    fn set_finished(&self) {
+        *self.finished.borrow_mut() = true;
+    }
+
+    fn decode_message(raw: &str) -> Result<PullEvent, String> {
+        trace!("Parsing NDJSON line: {raw}");
+        let response: PullResponse =
+            serde_json::from_str(raw.trim()).map_err(|e| format!("JSON parse error: {e}"))?;
+        Ok(pull_response_to_pull_event(response))
+    }
+}
+
+impl GuestPullStream for OllamaPullStream {
+    fn get_next(&self) -> Option<Vec<PullEvent>> {
+        if *self.finished.borrow() {
+            return Some(vec![]);
+        }
+
+        let mut stream = self.stream.borrow_mut();
+        if let Some(stream) = stream.as_mut() {
+            match stream.poll_next() {
+                Poll::Ready(None) => {
+                    self.set_finished();
+                    Some(vec![])
+                }
+                Poll::Ready(Some(Err(golem_llm::event_source::error::Error::StreamEnded))) => {
+                    self.set_finished();
+                    Some(vec![])
+                }
+                Poll::Ready(Some(Err(error))) => {
+                    self.set_finished();
+                    Some(vec![PullEvent::Error(Error {
+                        code: ErrorCode::InternalError,
+                        message: error.to_string(),
+                        provider_error_json: None,
+                    })])
+                }
+                Poll::Ready(Some(Ok(Event::Open))) => None,
+                Poll::Ready(Some(Ok(Event::Message(MessageEvent { data, .. })))) => {
+                    match Self::decode_message(&data) {
+                        Ok(event) => {
+                            if matches!(event, PullEvent::Error(_)) {
+                                self.set_finished();
+                            }
+                            Some(vec![event])
+                        }
+                        Err(error) => Some(vec![PullEvent::Error(Error {
+                            code: ErrorCode::InternalError,
+                            message: error,
+                            provider_error_json: None,
+                        })]),
+                    }
+                }
+                Poll::Pending => None,
+            }
+        } else if let Some(error) = self.failure.clone() {
+            self.set_finished();
+            Some(vec![PullEvent::Error(error)])
+        } else {
+            None
+        }
+    }
+
+    fn blocking_get_next(&self) -> Vec<PullEvent> {
+        let pollable = self.subscribe();
+        let mut result = Vec::new();
+        loop {
+            pollable.block();
+            match self.get_next() {
+                Some(events) => {
+                    result.extend(events);
+                    break result;
+                }
+                None => continue,
+            }
+        }
+    }
+}
+
+pub struct OllamaModelsComponent;
+
+impl Guest for OllamaModelsComponent {
+    type PullStream = OllamaPullStream;
+
+    fn pull_model(model: String, insecure: bool) -> PullStream {
+        init_logging();
+
+        let client = ModelsApi::new();
+        match client.pull_model_stream(model, insecure) {
+            Ok(stream) => PullStream::new(OllamaPullStream::new(stream)),
+            Err(err) => PullStream::new(OllamaPullStream::failed(err)),
+        }
+    }
+
+    fn list_models() -> Result<Vec<ModelInfo>, Error> {
+        init_logging();
+
+        let client = ModelsApi::new();
+        let response = client.list_models()?;
+        Ok(response
+            .models
+            .into_iter()
+            .map(local_model_to_model_info)
+            .collect())
+    }
+
+    fn show_model(model: String) -> Result<ModelDescription, Error> {
+        init_logging();
+
+        let client = ModelsApi::new();
+        client
+            .show_model(model)
+            .map(show_model_response_to_description)
+    }
+
+    fn delete_model(model: String) -> Result<(), Error> {
+        init_logging();
+
+        let client = ModelsApi::new();
+        client.delete_model(model)
+    }
+}
@@ -6,6 +6,7 @@ use reqwest::header::HeaderValue;
 use reqwest::{Client, Method, Response};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 const BASE_URL: &str = "https://api.openai.com";
@@ -71,6 +72,135 @@ impl ResponsesApi {
     }
 }
 
+/// The OpenAI API client for classifying content with the moderation models.
+///
+/// Based on https://platform.openai.com/docs/api-reference/moderations/create
+pub struct ModerationsApi {
+    openai_api_key: String,
+    client: Client,
+}
+
+impl ModerationsApi {
+    pub fn new(openai_api_key: String) -> Self {
+        let client = Client::builder()
+            .build()
+            .expect("Failed to initialize HTTP client");
+        Self {
+            openai_api_key,
+            client,
+        }
+    }
+
+    pub fn create_moderation(
+        &self,
+        request: CreateModerationRequest,
+    ) -> Result<CreateModerationResponse, Error> {
+        trace!("Sending request to OpenAI API: {request:?}");
+
+        let response: Response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/v1/moderations"))
+            .bearer_auth(&self.openai_api_key)
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+}
+
+/// The OpenAI API client for generating and editing images.
+///
+/// Based on https://platform.openai.com/docs/api-reference/images
+pub struct ImagesApi {
+    openai_api_key: String,
+    client: Client,
+}
+
+impl ImagesApi {
+    pub fn new(openai_api_key: String) -> Self {
+        let client = Client::builder()
+            .build()
+            .expect("Failed to initialize HTTP client");
+        Self {
+            openai_api_key,
+            client,
+        }
+    }
+
+    pub fn create_image(&self, request: CreateImageRequest) -> Result<ImagesResponse, Error> {
+        trace!("Sending request to OpenAI API: {request:?}");
+
+        let response: Response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/v1/images/generations"))
+            .bearer_auth(&self.openai_api_key)
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    pub fn create_image_edit(
+        &self,
+        request: CreateImageEditRequest,
+    ) -> Result<ImagesResponse, Error> {
+        trace!(
+            "Sending image edit request to OpenAI API: model={:?}, prompt={:?}",
+            request.model,
+            request.prompt
+        );
+
+        // multipart is not supported by the golem reqwest fork, so the body is built manually
+        let boundary = "----golem-llm-openai-image-edit";
+        let body = build_image_edit_multipart_body(&request, boundary);
+
+        let response: Response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/v1/images/edits"))
+            .bearer_auth(&self.openai_api_key)
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={boundary}"),
+            )
+            .body(body)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    /// Downloads an image referenced by URL, returning its bytes and content type
+    pub fn download_image(&self, url: &str) -> Result<(Vec<u8>, Option<String>), Error> {
+        let response: Response = self
+            .client
+            .get(url)
+            .send()
+            .map_err(|err| from_reqwest_error("Failed to download image", err))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error {
+                code: error_code_from_status(status),
+                message: format!("Failed to download image from {url}: {status}"),
+                provider_error_json: None,
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let bytes = response
+            .bytes()
+            .map_err(|err| from_reqwest_error("Failed to read image body", err))?;
+
+        Ok((bytes.to_vec(), content_type))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateModelResponseRequest {
     pub input: Input,
@@ -270,6 +400,154 @@ pub struct ResponseOutputItemDone {
     pub output_index: u32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateModerationRequest {
+    pub input: Vec<ModerationInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ModerationInput {
+    #[serde(rename = "text")]
+    Text { text: String },
+    #[serde(rename = "image_url")]
+    ImageUrl { image_url: ModerationImageUrl },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationImageUrl {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateModerationResponse {
+    pub id: String,
+    pub model: String,
+    pub results: Vec<ModerationResultObject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationResultObject {
+    pub flagged: bool,
+    pub categories: BTreeMap<String, bool>,
+    pub category_scores: BTreeMap<String, f32>,
+    #[serde(default)]
+    pub category_applied_input_types: Option<BTreeMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateImageRequest {
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+    #[serde(flatten)]
+    pub provider_options: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateImageEditRequest {
+    pub image: ImageFile,
+    pub mask: Option<ImageFile>,
+    pub prompt: String,
+    pub model: Option<String>,
+    pub n: Option<u32>,
+    pub size: Option<String>,
+    pub quality: Option<String>,
+    pub response_format: Option<String>,
+    pub provider_options: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageFile {
+    pub data: Vec<u8>,
+    pub mime_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagesResponse {
+    pub created: u64,
+    pub data: Vec<ImageObject>,
+    #[serde(default)]
+    pub output_format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageObject {
+    #[serde(default)]
+    pub b64_json: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub revised_prompt: Option<String>,
+}
+
+fn build_image_edit_multipart_body(request: &CreateImageEditRequest, boundary: &str) -> Vec<u8> {
+    fn add_file(body: &mut Vec<u8>, boundary: &str, name: &str, file: &ImageFile) {
+        let extension = file.mime_type.strip_prefix("image/").unwrap_or("png");
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        body.extend_from_slice(
+            format!(
+                "Content-Disposition: form-data; name=\"{name}\"; filename=\"{name}.{extension}\"\r\n"
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(format!("Content-Type: {}\r\n\r\n", file.mime_type).as_bytes());
+        body.extend_from_slice(&file.data);
+        body.extend_from_slice(b"\r\n");
+    }
+
+    fn add_field(body: &mut Vec<u8>, boundary: &str, name: &str, value: &str) {
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        body.extend_from_slice(
+            format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n").as_bytes(),
+        );
+        body.extend_from_slice(value.as_bytes());
+        body.extend_from_slice(b"\r\n");
+    }
+
+    let mut body = Vec::new();
+
+    add_file(&mut body, boundary, "image", &request.image);
+    if let Some(mask) = &request.mask {
+        add_file(&mut body, boundary, "mask", mask);
+    }
+    add_field(&mut body, boundary, "prompt", &request.prompt);
+    if let Some(model) = &request.model {
+        add_field(&mut body, boundary, "model", model);
+    }
+    if let Some(n) = request.n {
+        add_field(&mut body, boundary, "n", &n.to_string());
+    }
+    if let Some(size) = &request.size {
+        add_field(&mut body, boundary, "size", size);
+    }
+    if let Some(quality) = &request.quality {
+        add_field(&mut body, boundary, "quality", quality);
+    }
+    if let Some(response_format) = &request.response_format {
+        add_field(&mut body, boundary, "response_format", response_format);
+    }
+    for (key, value) in &request.provider_options {
+        add_field(&mut body, boundary, key, value);
+    }
+
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+    body
+}
+
 fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, Error> {
     let status = response.status();
     if status.is_success() {
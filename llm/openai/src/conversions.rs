@@ -1,15 +1,19 @@
 use crate::client::{
-    CreateModelResponseRequest, CreateModelResponseResponse, Detail, InnerInput, InnerInputItem,
-    Input, InputItem, OutputItem, OutputMessageContent, Tool,
+    CreateImageEditRequest, CreateImageRequest, CreateModelResponseRequest,
+    CreateModelResponseResponse, CreateModerationResponse, Detail, ImageFile, ImagesResponse,
+    InnerInput, InnerInputItem, Input, InputItem, ModerationImageUrl, ModerationInput, OutputItem,
+    OutputMessageContent, Tool,
 };
 use base64::{engine::general_purpose, Engine as _};
-use golem_llm::error::error_code_from_status;
+use golem_llm::error::{error_code_from_status, unsupported};
+use golem_llm::golem::llm::images::{ImageOptions, ImageSize};
 use golem_llm::golem::llm::llm::{
     ChatEvent, CompleteResponse, Config, ContentPart, Error, ErrorCode, ImageDetail,
-    ImageReference, Message, ResponseMetadata, Role, ToolCall, ToolDefinition, ToolResult, Usage,
+    ImageReference, ImageSource, ImageUrl, Kv, Message, ModerationCategory, ModerationResult,
+    ResponseMetadata, Role, ToolCall, ToolDefinition, ToolResult, Usage,
 };
 use reqwest::StatusCode;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 
 pub fn create_request(
@@ -238,3 +242,352 @@ pub fn create_response_metadata(response: &CreateModelResponseResponse) -> Respo
         provider_metadata_json: response.metadata.as_ref().map(|m| m.to_string()),
     }
 }
+
+pub fn content_parts_to_moderation_input(content: Vec<ContentPart>) -> Vec<ModerationInput> {
+    content
+        .into_iter()
+        .map(|part| match part {
+            ContentPart::Text(text) => ModerationInput::Text { text },
+            ContentPart::Image(ImageReference::Url(image_url)) => ModerationInput::ImageUrl {
+                image_url: ModerationImageUrl { url: image_url.url },
+            },
+            ContentPart::Image(ImageReference::Inline(image_source)) => {
+                let base64_data = general_purpose::STANDARD.encode(&image_source.data);
+                let mime_type = &image_source.mime_type;
+                ModerationInput::ImageUrl {
+                    image_url: ModerationImageUrl {
+                        url: format!("data:{mime_type};base64,{base64_data}"),
+                    },
+                }
+            }
+        })
+        .collect()
+}
+
+/// Merges the per-input moderation results into a single result. A category is flagged if it was
+/// flagged for any of the inputs, and its score is the highest score reported for it.
+pub fn process_moderation_response(response: CreateModerationResponse) -> ModerationResult {
+    let mut flagged = false;
+    let mut categories: BTreeMap<String, (bool, Option<f32>)> = BTreeMap::new();
+
+    for result in &response.results {
+        flagged |= result.flagged;
+        for (name, category_flagged) in &result.categories {
+            let entry = categories.entry(name.clone()).or_insert((false, None));
+            entry.0 |= *category_flagged;
+        }
+        for (name, score) in &result.category_scores {
+            let entry = categories.entry(name.clone()).or_insert((false, None));
+            entry.1 = Some(entry.1.map_or(*score, |current| current.max(*score)));
+        }
+    }
+
+    let provider_metadata = serde_json::json!({
+        "id": response.id,
+        "model": response.model,
+        "category_applied_input_types": response
+            .results
+            .iter()
+            .map(|result| result.category_applied_input_types.clone())
+            .collect::<Vec<_>>(),
+    });
+
+    ModerationResult {
+        flagged,
+        categories: categories
+            .into_iter()
+            .map(|(name, (flagged, score))| ModerationCategory {
+                name,
+                flagged,
+                score,
+            })
+            .collect(),
+        provider_metadata_json: Some(provider_metadata.to_string()),
+    }
+}
+
+const DEFAULT_IMAGE_MODEL: &str = "gpt-image-1";
+
+pub fn create_image_request(
+    prompt: String,
+    size: Option<ImageSize>,
+    count: u32,
+    options: ImageOptions,
+) -> Result<CreateImageRequest, Error> {
+    check_image_options(&options)?;
+
+    let model = options
+        .model
+        .unwrap_or_else(|| DEFAULT_IMAGE_MODEL.to_string());
+    let mut provider_options = image_provider_options(options.provider_options);
+    let response_format = provider_options
+        .remove("response_format")
+        .map(form_field_value)
+        .or_else(|| default_response_format(&model));
+
+    Ok(CreateImageRequest {
+        prompt,
+        model: Some(model),
+        n: Some(count.max(1)),
+        size: size.map(image_size_to_string),
+        quality: options.quality,
+        style: options.style,
+        response_format,
+        provider_options,
+    })
+}
+
+pub fn create_image_edit_request(
+    image: ImageFile,
+    mask: Option<ImageFile>,
+    prompt: String,
+    options: ImageOptions,
+) -> Result<CreateImageEditRequest, Error> {
+    check_image_options(&options)?;
+    if options.style.is_some() {
+        return Err(unsupported("style for OpenAI image edits"));
+    }
+
+    let model = options
+        .model
+        .unwrap_or_else(|| DEFAULT_IMAGE_MODEL.to_string());
+    let mut provider_options = image_provider_options(options.provider_options);
+    let n = provider_options
+        .remove("n")
+        .map(|n| {
+            n.as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or_else(|| Error {
+                    code: ErrorCode::InvalidRequest,
+                    message: format!("Invalid n: {n}, expected a positive integer"),
+                    provider_error_json: None,
+                })
+        })
+        .transpose()?;
+    let size = provider_options.remove("size").map(form_field_value);
+    let response_format = provider_options
+        .remove("response_format")
+        .map(form_field_value)
+        .or_else(|| default_response_format(&model));
+
+    Ok(CreateImageEditRequest {
+        image,
+        mask,
+        prompt,
+        model: Some(model),
+        n,
+        size,
+        quality: options.quality,
+        response_format,
+        provider_options: provider_options
+            .into_iter()
+            .map(|(key, value)| (key, form_field_value(value)))
+            .collect(),
+    })
+}
+
+/// The OpenAI image APIs have no negative prompt or seed parameters, so these options are rejected
+/// instead of being silently dropped.
+fn check_image_options(options: &ImageOptions) -> Result<(), Error> {
+    if options.negative_prompt.is_some() {
+        Err(unsupported("negative prompts for OpenAI image generation"))
+    } else if options.seed.is_some() {
+        Err(unsupported("seeds for OpenAI image generation"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Provider options are passed to the image APIs as additional request fields, with their values
+/// interpreted as JSON when possible.
+fn image_provider_options(provider_options: Vec<Kv>) -> BTreeMap<String, serde_json::Value> {
+    provider_options
+        .into_iter()
+        .map(|kv| {
+            let value =
+                serde_json::from_str(&kv.value).unwrap_or(serde_json::Value::String(kv.value));
+            (kv.key, value)
+        })
+        .collect()
+}
+
+/// Image edits are sent as multipart form data, where every field is a string
+fn form_field_value(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+/// The DALL·E models return URLs by default which expire after an hour, so base64 encoded images
+/// are requested instead. The GPT image models always return base64 encoded images and do not
+/// accept the `response_format` parameter.
+fn default_response_format(model: &str) -> Option<String> {
+    if model.starts_with("dall-e") {
+        Some("b64_json".to_string())
+    } else {
+        None
+    }
+}
+
+fn image_size_to_string(size: ImageSize) -> String {
+    format!("{}x{}", size.width, size.height)
+}
+
+pub fn image_reference_to_image_file(
+    image: ImageReference,
+    download: impl Fn(&str) -> Result<(Vec<u8>, Option<String>), Error>,
+) -> Result<ImageFile, Error> {
+    match image {
+        ImageReference::Inline(source) => Ok(ImageFile {
+            data: source.data,
+            mime_type: source.mime_type,
+        }),
+        ImageReference::Url(image_url) => {
+            let (data, content_type) = download(&image_url.url)?;
+            Ok(ImageFile {
+                data,
+                mime_type: content_type.unwrap_or_else(|| "image/png".to_string()),
+            })
+        }
+    }
+}
+
+pub fn process_images_response(response: ImagesResponse) -> Result<Vec<ImageReference>, Error> {
+    let mime_type = format!(
+        "image/{}",
+        response.output_format.as_deref().unwrap_or("png")
+    );
+
+    response
+        .data
+        .into_iter()
+        .map(|image| match (image.b64_json, image.url) {
+            (Some(b64_json), _) => general_purpose::STANDARD
+                .decode(b64_json)
+                .map(|data| {
+                    ImageReference::Inline(ImageSource {
+                        data,
+                        mime_type: mime_type.clone(),
+                        detail: None,
+                    })
+                })
+                .map_err(|err| Error {
+                    code: ErrorCode::InternalError,
+                    message: format!("Failed to decode generated image: {err}"),
+                    provider_error_json: None,
+                }),
+            (None, Some(url)) => Ok(ImageReference::Url(ImageUrl { url, detail: None })),
+            (None, None) => Err(Error {
+                code: ErrorCode::InternalError,
+                message: "Generated image has neither data nor URL".to_string(),
+                provider_error_json: None,
+            }),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category<'a>(result: &'a ModerationResult, name: &str) -> &'a ModerationCategory {
+        result
+            .categories
+            .iter()
+            .find(|category| category.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_process_flagged_moderation_response() {
+        let response: CreateModerationResponse = serde_json::from_str(
+            r#"{
+                "id": "modr-0d9740456c391e43c445bf0f010940c7",
+                "model": "omni-moderation-latest",
+                "results": [
+                    {
+                        "flagged": true,
+                        "categories": {
+                            "harassment": true,
+                            "harassment/threatening": true,
+                            "sexual": false,
+                            "hate": false,
+                            "violence": true,
+                            "self-harm": false
+                        },
+                        "category_scores": {
+                            "harassment": 0.8189693396524255,
+                            "harassment/threatening": 0.804985420696006,
+                            "sexual": 0.00002909041,
+                            "hate": 0.010580211,
+                            "violence": 0.9223892,
+                            "self-harm": 0.0000117
+                        },
+                        "category_applied_input_types": {
+                            "harassment": ["text"],
+                            "harassment/threatening": ["text"],
+                            "sexual": ["text", "image"],
+                            "hate": ["text"],
+                            "violence": ["text", "image"],
+                            "self-harm": ["text", "image"]
+                        }
+                    },
+                    {
+                        "flagged": false,
+                        "categories": {"harassment": false, "sexual": false, "violence": false},
+                        "category_scores": {"harassment": 0.2, "sexual": 0.4, "violence": 0.1}
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let result = process_moderation_response(response);
+
+        assert!(result.flagged);
+        assert_eq!(result.categories.len(), 6);
+        let violence = category(&result, "violence");
+        assert!(violence.flagged);
+        assert_eq!(violence.score, Some(0.9223892));
+        // The scores of the inputs are combined to the highest one
+        let sexual = category(&result, "sexual");
+        assert!(!sexual.flagged);
+        assert_eq!(sexual.score, Some(0.4));
+        assert!(category(&result, "harassment/threatening").flagged);
+
+        let metadata: serde_json::Value =
+            serde_json::from_str(result.provider_metadata_json.as_deref().unwrap()).unwrap();
+        assert_eq!(metadata["id"], "modr-0d9740456c391e43c445bf0f010940c7");
+        assert_eq!(metadata["model"], "omni-moderation-latest");
+        assert_eq!(
+            metadata["category_applied_input_types"][0]["violence"],
+            serde_json::json!(["text", "image"])
+        );
+    }
+
+    #[test]
+    fn test_process_unflagged_moderation_response() {
+        let response: CreateModerationResponse = serde_json::from_str(
+            r#"{
+                "id": "modr-5f1a4c2e",
+                "model": "text-moderation-007",
+                "results": [
+                    {
+                        "flagged": false,
+                        "categories": {"hate": false, "violence": false},
+                        "category_scores": {"hate": 0.0001, "violence": 0.0003}
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let result = process_moderation_response(response);
+
+        assert!(!result.flagged);
+        assert!(result.categories.iter().all(|category| !category.flagged));
+        assert_eq!(category(&result, "hate").score, Some(0.0001));
+        assert_eq!(category(&result, "violence").score, Some(0.0003));
+    }
+}
@@ -1,18 +1,22 @@
 use crate::client::{
-    CreateModelResponseResponse, InputItem, OutputItem, ResponseOutputItemDone,
-    ResponseOutputTextDelta, ResponsesApi,
+    CreateModelResponseResponse, CreateModerationRequest, ImagesApi, InputItem, ModerationsApi,
+    OutputItem, ResponseOutputItemDone, ResponseOutputTextDelta, ResponsesApi,
 };
 use crate::conversions::{
-    create_request, create_response_metadata, messages_to_input_items, parse_error_code,
-    process_model_response, tool_defs_to_tools, tool_results_to_input_items,
+    content_parts_to_moderation_input, create_image_edit_request, create_image_request,
+    create_request, create_response_metadata, image_reference_to_image_file,
+    messages_to_input_items, parse_error_code, process_images_response, process_model_response,
+    process_moderation_response, tool_defs_to_tools, tool_results_to_input_items,
 };
 use golem_llm::chat_stream::{LlmChatStream, LlmChatStreamState};
-use golem_llm::config::with_config_key;
+use golem_llm::config::{get_config_key_or_none, with_config_key};
 use golem_llm::durability::{DurableLLM, ExtendedGuest};
 use golem_llm::event_source::EventSource;
+use golem_llm::golem::llm::images;
+use golem_llm::golem::llm::images::{ImageOptions, ImageSize};
 use golem_llm::golem::llm::llm::{
-    ChatEvent, ChatStream, Config, ContentPart, Error, ErrorCode, Guest, Message, StreamDelta,
-    StreamEvent, ToolCall, ToolResult,
+    ChatEvent, ChatStream, Config, ContentPart, Error, ErrorCode, Guest, ImageReference, Message,
+    ModerationResult, StreamDelta, StreamEvent, ToolCall, ToolResult,
 };
 use golem_rust::wasm_rpc::Pollable;
 use log::trace;
@@ -159,6 +163,7 @@ struct OpenAIComponent;
 
 impl OpenAIComponent {
     const ENV_VAR_NAME: &'static str = "OPENAI_API_KEY";
+    const MODERATION_MODEL_ENV_VAR_NAME: &'static str = "OPENAI_MODERATION_MODEL";
 
     fn request(client: ResponsesApi, items: Vec<InputItem>, config: Config) -> ChatEvent {
         match tool_defs_to_tools(&config.tools) {
@@ -221,6 +226,60 @@ impl Guest for OpenAIComponent {
     fn stream(messages: Vec<Message>, config: Config) -> ChatStream {
         ChatStream::new(Self::unwrapped_stream(messages, config))
     }
+
+    fn moderate(content: Vec<ContentPart>) -> Result<ModerationResult, Error> {
+        with_config_key(Self::ENV_VAR_NAME, Err, |openai_api_key| {
+            let client = ModerationsApi::new(openai_api_key);
+
+            let request = CreateModerationRequest {
+                input: content_parts_to_moderation_input(content),
+                model: get_config_key_or_none(Self::MODERATION_MODEL_ENV_VAR_NAME),
+            };
+            client
+                .create_moderation(request)
+                .map(process_moderation_response)
+        })
+    }
+}
+
+impl images::Guest for OpenAIComponent {
+    fn generate(
+        prompt: String,
+        size: Option<ImageSize>,
+        count: u32,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        with_config_key(Self::ENV_VAR_NAME, Err, |openai_api_key| {
+            let client = ImagesApi::new(openai_api_key);
+
+            let request = create_image_request(prompt, size, count, options)?;
+            client
+                .create_image(request)
+                .and_then(process_images_response)
+        })
+    }
+
+    fn edit(
+        image: ImageReference,
+        mask: Option<ImageReference>,
+        prompt: String,
+        options: ImageOptions,
+    ) -> Result<Vec<ImageReference>, Error> {
+        with_config_key(Self::ENV_VAR_NAME, Err, |openai_api_key| {
+            let client = ImagesApi::new(openai_api_key);
+
+            let download = |url: &str| client.download_image(url);
+            let image = image_reference_to_image_file(image, download)?;
+            let mask = mask
+                .map(|mask| image_reference_to_image_file(mask, download))
+                .transpose()?;
+
+            let request = create_image_edit_request(image, mask, prompt, options)?;
+            client
+                .create_image_edit(request)
+                .and_then(process_images_response)
+        })
+    }
 }
 
 impl ExtendedGuest for OpenAIComponent {
@@ -270,6 +270,24 @@ impl AlgoliaSearchApi {
         }
     }
 
+    /// Runs the searches of several indexes in one request
+    pub fn multiple_queries(
+        &self,
+        request: &MultipleQueriesRequest,
+    ) -> Result<MultipleQueriesResponse, SearchError> {
+        trace!("Running {} queries", request.requests.len());
+
+        let url = format!("{}/1/indexes/*/queries", self.search_url);
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(request)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to run multiple queries: {e}")))?;
+
+        parse_response(response)
+    }
+
     pub fn get_settings(&self, index_name: &str) -> Result<IndexSettings, SearchError> {
         trace!("Getting settings for index: {index_name}");
 
@@ -283,10 +301,12 @@ impl AlgoliaSearchApi {
         parse_response(response)
     }
 
+    /// Updates the settings present in `settings`, leaving the other settings of the index as
+    /// they are
     pub fn set_settings(
         &self,
         index_name: &str,
-        settings: &IndexSettings,
+        settings: &(impl Serialize + Debug),
     ) -> Result<SetSettingsResponse, SearchError> {
         trace!("Setting settings for index: {index_name}");
 
@@ -301,7 +321,93 @@ impl AlgoliaSearchApi {
         parse_response(response)
     }
 
-    pub fn _wait_for_task(&self, index_name: &str, task_id: u64) -> Result<(), SearchError> {
+    /// Replaces all synonyms of the index
+    pub fn save_synonyms(
+        &self,
+        index_name: &str,
+        synonyms: &[Synonym],
+    ) -> Result<SaveSynonymsResponse, SearchError> {
+        trace!("Saving {} synonyms for index: {index_name}", synonyms.len());
+
+        let url = format!(
+            "{}/1/indexes/{}/synonyms/batch?replaceExistingSynonyms=true",
+            self.write_url, index_name
+        );
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(synonyms)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to save synonyms: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn search_synonyms(
+        &self,
+        index_name: &str,
+        request: &SearchSynonymsRequest,
+    ) -> Result<SearchSynonymsResponse, SearchError> {
+        trace!("Searching synonyms for index: {index_name}");
+
+        let url = format!(
+            "{}/1/indexes/{}/synonyms/search",
+            self.search_url, index_name
+        );
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(request)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to search synonyms: {e}")))?;
+
+        parse_response(response)
+    }
+
+    /// Copies or moves an index to `request.destination`, replacing the destination index
+    pub fn operation_index(
+        &self,
+        index_name: &str,
+        request: &OperationIndexRequest,
+    ) -> Result<OperationIndexResponse, SearchError> {
+        trace!(
+            "Running {} operation from index {index_name} to {}",
+            request.operation,
+            request.destination
+        );
+
+        let url = format!("{}/1/indexes/{}/operation", self.write_url, index_name);
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(request)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to run index operation: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn get_task(
+        &self,
+        index_name: &str,
+        task_id: u64,
+    ) -> Result<TaskStatusResponse, SearchError> {
+        trace!("Getting task {task_id} on index {index_name}");
+
+        let url = format!(
+            "{}/1/indexes/{}/task/{}",
+            self.write_url, index_name, task_id
+        );
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to get task: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn wait_for_task(&self, index_name: &str, task_id: u64) -> Result<(), SearchError> {
         trace!("Waiting for task {task_id} on index {index_name}");
         let url = format!(
             "{}/1/indexes/{}/task/{}",
@@ -400,6 +506,25 @@ pub struct SearchResponse {
     pub params: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipleQueriesRequest {
+    pub requests: Vec<IndexQuery>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexQuery {
+    #[serde(rename = "indexName")]
+    pub index_name: String,
+    #[serde(flatten)]
+    pub query: SearchQuery,
+}
+
+/// Responses of the queries, in the order of the queries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipleQueriesResponse {
+    pub results: Vec<SearchResponse>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchHit {
     #[serde(rename = "objectID")]
@@ -514,6 +639,70 @@ pub struct SetSettingsResponse {
     pub task_id: u64,
 }
 
+/// Ranking criteria of an index. Unlike `IndexSettings`, empty lists are sent too, so that they
+/// clear the criteria set before.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RankingSettings {
+    pub ranking: Vec<String>,
+    #[serde(rename = "customRanking")]
+    pub custom_ranking: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Synonym {
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    /// `synonym`, `oneWaySynonym`, or one of the alternative correction and placeholder types
+    #[serde(rename = "type")]
+    pub synonym_type: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub synonyms: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchSynonymsRequest {
+    pub query: String,
+    pub page: u32,
+    #[serde(rename = "hitsPerPage")]
+    pub hits_per_page: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchSynonymsResponse {
+    pub hits: Vec<Synonym>,
+    #[serde(rename = "nbHits")]
+    pub nb_hits: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveSynonymsResponse {
+    #[serde(rename = "taskID")]
+    pub task_id: u64,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationIndexRequest {
+    pub operation: String,
+    pub destination: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationIndexResponse {
+    #[serde(rename = "taskID")]
+    pub task_id: u64,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatusResponse {
+    pub status: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchRequest {
     pub requests: Vec<BatchOperation>,
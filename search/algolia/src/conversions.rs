@@ -1,12 +1,32 @@
 use crate::client::{
-    AlgoliaObject, IndexSettings, SearchHit as AlgoliaSearchHit, SearchQuery as AlgoliaSearchQuery,
-    SearchResponse,
+    AlgoliaObject, IndexSettings, RankingSettings, SearchHit as AlgoliaSearchHit,
+    SearchQuery as AlgoliaSearchQuery, SearchResponse, Synonym,
+};
+use golem_search::aggregation::{facet_fields, field_facet_results, validate_aggregations};
+use golem_search::error::{internal_error, invalid_query, unsupported};
+use golem_search::filter::{
+    format_plain_value, parse_filter_expr, range_bounds, Condition, Filter,
 };
 use golem_search::golem::search::types::{
-    Doc, FieldType, Schema, SchemaField, SearchHit, SearchQuery, SearchResults,
+    AggregationKind, Doc, FieldType, FilterValue, ReindexTransform, RelevanceSettings, Schema,
+    SchemaField, SearchError, SearchHit, SearchQuery, SearchResults, SynonymSet, TaskId, TaskState,
+    TaskStatus,
 };
+use golem_search::relevance::{parse_ranking_rule, RankingRule};
 use serde_json::{Map, Value};
 
+/// Ranking criteria of a new Algolia index, used when the relevance settings have no built-in rule
+const DEFAULT_RANKING: [&str; 8] = [
+    "typo",
+    "geo",
+    "words",
+    "filters",
+    "proximity",
+    "attribute",
+    "exact",
+    "custom",
+];
+
 pub fn doc_to_algolia_object(doc: Doc) -> Result<AlgoliaObject, String> {
     let content: Value = serde_json::from_str(&doc.content)
         .map_err(|e| format!("Failed to parse document content as JSON: {e}"))?;
@@ -22,10 +42,25 @@ pub fn algolia_object_to_doc(obj: AlgoliaObject) -> Doc {
     Doc {
         id: obj.object_id.unwrap_or_else(|| "unknown".to_string()),
         content,
+        embeddings: vec![],
     }
 }
 
-pub fn search_query_to_algolia_query(query: SearchQuery) -> AlgoliaSearchQuery {
+pub fn search_query_to_algolia_query(
+    query: SearchQuery,
+) -> Result<AlgoliaSearchQuery, SearchError> {
+    validate_aggregations(&query)?;
+    if query
+        .aggregations
+        .iter()
+        .any(|aggregation| !matches!(aggregation.kind, AggregationKind::Stats))
+    {
+        return Err(unsupported(
+            "Range and date histogram aggregations in Algolia",
+        ));
+    }
+    let facets = facet_fields(&query);
+
     let mut algolia_query = AlgoliaSearchQuery {
         query: query.q,
         filters: None,
@@ -34,16 +69,24 @@ pub fn search_query_to_algolia_query(query: SearchQuery) -> AlgoliaSearchQuery {
         hits_per_page: query.per_page,
         offset: query.offset,
         length: None,
-        facets: query.facets,
+        facets,
         attributes_to_retrieve: vec![],
         typo_tolerance: None,
         analytics: Some(false),
     };
 
+    if query.vector_query.is_some() {
+        return Err(unsupported("Vector search in Algolia"));
+    }
+
     // Handle filters - Algolia uses the filters field for general attribute filtering
-    if !query.filters.is_empty() {
-        // Each filter should be in the format "attribute:value" or "attribute>value", etc.
-        algolia_query.filters = Some(query.filters.join(" AND "));
+    // Each filter should be in the format "attribute:value" or "attribute>value", etc.
+    let mut filters = query.filters;
+    if let Some(expr) = &query.filter_expr {
+        filters.push(filter_to_algolia(&parse_filter_expr(expr)?)?);
+    }
+    if !filters.is_empty() {
+        algolia_query.filters = Some(filters.join(" AND "));
     }
 
     // Handle sort - convert to Algolia's ranking format
@@ -79,24 +122,166 @@ pub fn search_query_to_algolia_query(query: SearchQuery) -> AlgoliaSearchQuery {
         }
     }
 
-    algolia_query
+    Ok(algolia_query)
+}
+
+/// Compiles a typed filter to the Algolia filters syntax. Algolia filters are conjunctions of
+/// disjunctions, where a disjunction only combines non-negated conditions of the same kind, either
+/// facet or numeric. Missing fields, geo filters and negated ranges are not supported either.
+pub fn filter_to_algolia(filter: &Filter) -> Result<String, SearchError> {
+    match filter {
+        Filter::Is(condition) => condition_to_algolia(condition, false),
+        Filter::IsNot(condition) => condition_to_algolia(condition, true),
+        Filter::And(operands) => Ok(operands
+            .iter()
+            .map(filter_to_algolia)
+            .collect::<Result<Vec<_>, _>>()?
+            .join(" AND ")),
+        Filter::Or(operands) => {
+            let mut comparisons = Vec::new();
+            for operand in operands {
+                collect_algolia_disjunction(operand, &mut comparisons)?;
+            }
+            algolia_disjunction(comparisons)
+        }
+    }
+}
+
+/// A single Algolia comparison and whether it is a numeric one
+type AlgoliaComparison = (String, bool);
+
+fn collect_algolia_disjunction(
+    filter: &Filter,
+    comparisons: &mut Vec<AlgoliaComparison>,
+) -> Result<(), SearchError> {
+    match filter {
+        Filter::Is(Condition::Eq(filter)) => {
+            comparisons.push(algolia_comparison(&filter.field, &filter.value, false));
+        }
+        Filter::Is(Condition::In(filter)) => comparisons.extend(
+            filter
+                .values
+                .iter()
+                .map(|value| algolia_comparison(&filter.field, value, false)),
+        ),
+        Filter::Is(Condition::Range(filter)) if range_bounds(filter).len() == 1 => {
+            comparisons.push((
+                condition_to_algolia(&Condition::Range(filter.clone()), false)?,
+                true,
+            ));
+        }
+        Filter::Or(operands) => {
+            for operand in operands {
+                collect_algolia_disjunction(operand, comparisons)?;
+            }
+        }
+        _ => {
+            return Err(unsupported(
+                "Algolia only supports OR between non-negated single conditions",
+            ))
+        }
+    }
+    Ok(())
 }
 
-pub fn algolia_response_to_search_results(response: SearchResponse) -> SearchResults {
+fn algolia_disjunction(comparisons: Vec<AlgoliaComparison>) -> Result<String, SearchError> {
+    if comparisons.iter().any(|(_, numeric)| *numeric)
+        && comparisons.iter().any(|(_, numeric)| !*numeric)
+    {
+        return Err(unsupported(
+            "Algolia does not support OR between facet and numeric filters",
+        ));
+    }
+    let comparisons = comparisons
+        .into_iter()
+        .map(|(comparison, _)| comparison)
+        .collect::<Vec<_>>();
+    Ok(match comparisons.as_slice() {
+        [comparison] => comparison.clone(),
+        _ => format!("({})", comparisons.join(" OR ")),
+    })
+}
+
+fn condition_to_algolia(condition: &Condition, negated: bool) -> Result<String, SearchError> {
+    match condition {
+        Condition::Eq(filter) => Ok(algolia_comparison(&filter.field, &filter.value, negated).0),
+        Condition::In(filter) if negated => Ok(filter
+            .values
+            .iter()
+            .map(|value| algolia_comparison(&filter.field, value, true).0)
+            .collect::<Vec<_>>()
+            .join(" AND ")),
+        Condition::In(filter) => algolia_disjunction(
+            filter
+                .values
+                .iter()
+                .map(|value| algolia_comparison(&filter.field, value, false))
+                .collect(),
+        ),
+        Condition::Range(filter) if !negated => Ok(range_bounds(filter)
+            .into_iter()
+            .map(|(operator, value)| match value {
+                FilterValue::Integer(_) | FilterValue::Float(_) => Ok(format!(
+                    "{} {operator} {}",
+                    filter.field,
+                    format_plain_value(value).unwrap_or_default()
+                )),
+                _ => Err(unsupported("Non-numeric range filters in Algolia")),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join(" AND ")),
+        Condition::Range(_) => Err(unsupported("Negated range filters in Algolia")),
+        Condition::Exists(_) => Err(unsupported("Exists filters in Algolia")),
+        Condition::GeoRadius(_) => Err(unsupported("Geo filters in Algolia")),
+    }
+}
+
+/// Compares a field to a value, as a facet filter for text and booleans and as a numeric filter
+/// for numbers
+fn algolia_comparison(field: &str, value: &FilterValue, negated: bool) -> AlgoliaComparison {
+    match value {
+        FilterValue::Text(text) => {
+            let not = if negated { "NOT " } else { "" };
+            let text = text.replace('\\', "\\\\").replace('"', "\\\"");
+            (format!("{not}{field}:\"{text}\""), false)
+        }
+        FilterValue::Boolean(value) => {
+            let not = if negated { "NOT " } else { "" };
+            (format!("{not}{field}:{value}"), false)
+        }
+        value => {
+            let operator = if negated { "!=" } else { "=" };
+            let value = format_plain_value(value).unwrap_or_default();
+            (format!("{field} {operator} {value}"), true)
+        }
+    }
+}
+
+/// Converts a search response, `query` is the search query the response belongs to
+pub fn algolia_response_to_search_results(
+    response: SearchResponse,
+    query: &SearchQuery,
+) -> SearchResults {
     let hits = response
         .hits
         .into_iter()
         .map(algolia_hit_to_search_hit)
         .collect();
 
+    let counts = response.facets.unwrap_or_default();
+    let stats = response.facets_stats.unwrap_or_default();
+    let facets = field_facet_results(
+        query,
+        counts.as_object().unwrap_or(&Map::new()),
+        stats.as_object().unwrap_or(&Map::new()),
+    );
+
     SearchResults {
         total: Some(response.nb_hits),
         page: Some(response.page),
         per_page: Some(response.hits_per_page),
         hits,
-        facets: response
-            .facets
-            .map(|f| serde_json::to_string(&f).unwrap_or_default()),
+        facets,
         took_ms: Some(response.processing_time_ms),
     }
 }
@@ -116,6 +301,26 @@ pub fn algolia_hit_to_search_hit(hit: AlgoliaSearchHit) -> SearchHit {
     }
 }
 
+/// Validates that the schema can be applied to the index settings, Algolia has no vector fields
+pub fn validate_algolia_schema(schema: &Schema) -> Result<(), SearchError> {
+    if schema
+        .fields
+        .iter()
+        .any(|field| field.field_type == FieldType::Vector)
+    {
+        return Err(unsupported("Vector fields in Algolia"));
+    }
+    Ok(())
+}
+
+/// Validates that the document can be saved as an object, Algolia does not store embeddings
+pub fn validate_algolia_doc(doc: &Doc) -> Result<(), SearchError> {
+    if !doc.embeddings.is_empty() {
+        return Err(unsupported("Document embeddings in Algolia"));
+    }
+    Ok(())
+}
+
 pub fn schema_to_algolia_settings(schema: Schema) -> IndexSettings {
     let mut settings = IndexSettings::default();
 
@@ -160,6 +365,10 @@ pub fn schema_to_algolia_settings(schema: Schema) -> IndexSettings {
                     settings.attributes_for_faceting.push(field.name.clone());
                 }
             }
+            FieldType::Vector => {
+                // Rejected by validate_algolia_schema, Algolia has no vector fields
+                continue;
+            }
         }
 
         // Handle sorting - in Algolia, sorting is done via custom ranking
@@ -185,6 +394,7 @@ pub fn algolia_settings_to_schema(settings: IndexSettings) -> Schema {
             facet: false,
             sort: false,
             index: true,
+            vector: None,
         });
     }
 
@@ -209,6 +419,7 @@ pub fn algolia_settings_to_schema(settings: IndexSettings) -> Schema {
                 facet: true,
                 sort: false,
                 index: false,
+                vector: None,
             });
         }
     }
@@ -226,6 +437,7 @@ pub fn algolia_settings_to_schema(settings: IndexSettings) -> Schema {
                     facet: false,
                     sort: true,
                     index: false,
+                    vector: None,
                 });
             }
         }
@@ -265,16 +477,205 @@ pub fn create_retry_query(original_query: &SearchQuery, partial_hits: &[SearchHi
     retry_query
 }
 
+/// Outcome of each object of a batch, an object succeeded if the batch response acknowledges its
+/// object ID and waiting for the task of the batch, if requested, succeeded
+pub fn batch_results(
+    object_ids: &[String],
+    acknowledged: &[String],
+    task: Result<(), SearchError>,
+) -> Vec<Result<(), SearchError>> {
+    object_ids
+        .iter()
+        .map(|object_id| {
+            if acknowledged.contains(object_id) {
+                task.clone()
+            } else {
+                Err(internal_error(format!(
+                    "Object {object_id} was not acknowledged by the batch"
+                )))
+            }
+        })
+        .collect()
+}
+
+/// Checks that a reindex can be done by copying the index, which keeps every object as it is
+pub fn validate_reindex_transform(transform: Option<&ReindexTransform>) -> Result<(), SearchError> {
+    match transform {
+        Some(transform) if transform.filter.is_some() || !transform.fields.is_empty() => {
+            Err(unsupported("Reindex transforms in Algolia"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Id of a write or reindex task. Algolia task ids are only unique within an index, so the id
+/// includes the index running the task.
+pub fn task_id(index_name: &str, task_id: u64) -> String {
+    format!("{index_name}:{task_id}")
+}
+
+/// Index and Algolia task id of a task id made by `task_id`
+pub fn parse_task_id(id: &str) -> Result<(String, u64), SearchError> {
+    id.rsplit_once(':')
+        .and_then(|(index_name, task_id)| Some((index_name.to_string(), task_id.parse().ok()?)))
+        .ok_or_else(|| invalid_query(format!("Unknown task: {id}")))
+}
+
+/// Status of a task from its Algolia status. Algolia only tells whether the task is published,
+/// failed writes are rejected before a task is created.
+pub fn task_status(id: TaskId, status: &str) -> TaskStatus {
+    let state = if status == "published" {
+        TaskState::Succeeded
+    } else {
+        TaskState::Processing
+    };
+    TaskStatus {
+        id,
+        state,
+        error: None,
+    }
+}
+
+/// Ranking criteria and synonyms replacing the relevance settings of an index. `field:order` rules
+/// become the custom ranking, which Algolia applies at the position of the `custom` criterion.
+pub fn relevance_settings_to_algolia(
+    settings: &RelevanceSettings,
+) -> Result<(RankingSettings, Vec<Synonym>), SearchError> {
+    if !settings.stop_words.is_empty() {
+        return Err(unsupported("Custom stop words in Algolia"));
+    }
+    if settings.provider_settings.is_some() {
+        return Err(unsupported("Provider relevance settings in Algolia"));
+    }
+
+    let rules = settings
+        .ranking_rules
+        .iter()
+        .map(|rule| parse_ranking_rule(rule))
+        .collect::<Result<Vec<_>, _>>()?;
+    let has_builtin = rules
+        .iter()
+        .any(|rule| matches!(rule, RankingRule::Builtin(_)));
+    let has_custom = rules
+        .iter()
+        .any(|rule| matches!(rule, RankingRule::Builtin("custom")));
+
+    let mut ranking = Vec::new();
+    let mut custom_ranking = Vec::new();
+    for rule in rules {
+        match rule {
+            RankingRule::Field { field, descending } => {
+                if custom_ranking.is_empty() && !has_custom {
+                    ranking.push("custom".to_string());
+                }
+                let order = if descending { "desc" } else { "asc" };
+                custom_ranking.push(format!("{order}({field})"));
+            }
+            RankingRule::Builtin(name) => ranking.push(name.to_string()),
+        }
+    }
+    if !has_builtin {
+        ranking = DEFAULT_RANKING.map(str::to_string).to_vec();
+    }
+
+    let synonyms = settings
+        .synonyms
+        .iter()
+        .enumerate()
+        .map(|(i, set)| Synonym {
+            object_id: format!("golem-synonym-{i}"),
+            synonym_type: if set.root.is_some() {
+                "oneWaySynonym".to_string()
+            } else {
+                "synonym".to_string()
+            },
+            synonyms: set.synonyms.clone(),
+            input: set.root.clone(),
+        })
+        .collect();
+
+    Ok((
+        RankingSettings {
+            ranking,
+            custom_ranking,
+        },
+        synonyms,
+    ))
+}
+
+/// Relevance settings of an index, synonyms of the other Algolia types are left out
+pub fn algolia_settings_to_relevance(
+    settings: &IndexSettings,
+    synonyms: Vec<Synonym>,
+) -> RelevanceSettings {
+    let custom_rules = settings
+        .custom_ranking
+        .iter()
+        .filter_map(|criterion| {
+            let field = extract_field_from_ranking(criterion)?;
+            let order = if criterion.starts_with("desc(") {
+                "desc"
+            } else {
+                "asc"
+            };
+            Some(format!("{field}:{order}"))
+        })
+        .collect::<Vec<_>>();
+
+    let ranking_rules = if settings.ranking.is_empty() || settings.ranking == DEFAULT_RANKING {
+        custom_rules
+    } else {
+        settings
+            .ranking
+            .iter()
+            .flat_map(|criterion| {
+                if criterion == "custom" && !custom_rules.is_empty() {
+                    custom_rules.clone()
+                } else {
+                    vec![criterion.clone()]
+                }
+            })
+            .collect()
+    };
+
+    let synonyms = synonyms
+        .into_iter()
+        .filter_map(|synonym| match synonym.synonym_type.as_str() {
+            "synonym" => Some(SynonymSet {
+                synonyms: synonym.synonyms,
+                root: None,
+            }),
+            "oneWaySynonym" => Some(SynonymSet {
+                synonyms: synonym.synonyms,
+                root: synonym.input,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    RelevanceSettings {
+        synonyms,
+        stop_words: vec![],
+        ranking_rules,
+        provider_settings: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use golem_search::golem::search::types::{HighlightConfig, SearchConfig};
+    use golem_search::test_util::conformance_cases;
+    use golem_search::golem::search::types::{
+        Aggregation, Embedding, FilterExpr, FilterNode, HighlightConfig, RangeBucket, SearchConfig,
+        VectorQuery,
+    };
 
     #[test]
     fn test_doc_to_algolia_object() {
         let doc = Doc {
             id: "test-id".to_string(),
             content: r#"{"title": "Test Document", "content": "This is a test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let algolia_obj = doc_to_algolia_object(doc).unwrap();
@@ -288,6 +689,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: "invalid json".to_string(),
+            embeddings: vec![],
         };
 
         let result = doc_to_algolia_object(doc);
@@ -329,8 +731,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test query".to_string()),
             filters: vec!["category:electronics".to_string(), "price:>100".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["price:desc".to_string()],
             facets: vec!["category".to_string(), "brand".to_string()],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(20),
             offset: None,
@@ -343,7 +748,7 @@ mod tests {
             config: None,
         };
 
-        let algolia_query = search_query_to_algolia_query(search_query);
+        let algolia_query = search_query_to_algolia_query(search_query).unwrap();
         assert_eq!(algolia_query.query, Some("test query".to_string()));
         assert_eq!(
             algolia_query.filters,
@@ -357,13 +762,56 @@ mod tests {
         assert_eq!(algolia_query.hits_per_page, Some(20));
     }
 
+    #[test]
+    fn test_search_query_aggregations() {
+        let stats = Aggregation {
+            name: "price_stats".to_string(),
+            field: "price".to_string(),
+            kind: AggregationKind::Stats,
+        };
+        let mut search_query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["category".to_string()],
+            aggregations: vec![stats],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let algolia_query = search_query_to_algolia_query(search_query.clone()).unwrap();
+        assert_eq!(algolia_query.facets, vec!["category", "price"]);
+
+        search_query.aggregations.push(Aggregation {
+            name: "price_ranges".to_string(),
+            field: "price".to_string(),
+            kind: AggregationKind::Range(vec![RangeBucket {
+                key: None,
+                from: None,
+                to: Some(100.0),
+            }]),
+        });
+        assert!(matches!(
+            search_query_to_algolia_query(search_query),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
     #[test]
     fn test_search_query_with_config() {
         let search_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -381,7 +829,7 @@ mod tests {
             }),
         };
 
-        let algolia_query = search_query_to_algolia_query(search_query);
+        let algolia_query = search_query_to_algolia_query(search_query).unwrap();
         assert_eq!(
             algolia_query.attributes_to_retrieve,
             vec!["title".to_string(), "price".to_string()]
@@ -390,6 +838,46 @@ mod tests {
         assert_eq!(algolia_query.analytics, Some(true));
     }
 
+    #[test]
+    fn test_vector_search_unsupported() {
+        let search_query = SearchQuery {
+            q: Some("space opera".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.5, -0.25],
+                k: 5,
+                hybrid_weight: None,
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        assert!(matches!(
+            search_query_to_algolia_query(search_query),
+            Err(SearchError::Unsupported)
+        ));
+
+        let doc = Doc {
+            id: "doc1".to_string(),
+            content: "{}".to_string(),
+            embeddings: vec![Embedding {
+                field: "title_vector".to_string(),
+                values: vec![0.5, -0.25],
+            }],
+        };
+        assert!(matches!(
+            validate_algolia_doc(&doc),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
     #[test]
     fn test_schema_conversion() {
         let schema = Schema {
@@ -401,6 +889,7 @@ mod tests {
                     facet: false,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "category".to_string(),
@@ -409,6 +898,7 @@ mod tests {
                     facet: true,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "price".to_string(),
@@ -417,6 +907,7 @@ mod tests {
                     facet: true,
                     sort: true,
                     index: false,
+                    vector: None,
                 },
             ],
             primary_key: Some("id".to_string()),
@@ -465,22 +956,56 @@ mod tests {
             nb_pages: 1,
             hits_per_page: 20,
             processing_time_ms: 5,
-            facets: Some(serde_json::json!({"category": {"electronics": 1}})),
-            facets_stats: None,
+            facets: Some(serde_json::json!({
+                "category": {"electronics": 1},
+                "price": {"99.5": 1}
+            })),
+            facets_stats: Some(serde_json::json!({
+                "price": {"min": 99.5, "max": 99.5, "avg": 99.5, "sum": 99.5}
+            })),
             exhaustive_nb_hits: true,
             exhaustive_facets_count: true,
             query: "test".to_string(),
             params: "q=test".to_string(),
         };
 
-        let search_results = algolia_response_to_search_results(algolia_response);
+        let query = SearchQuery {
+            q: Some("test".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["category".to_string()],
+            aggregations: vec![Aggregation {
+                name: "price_stats".to_string(),
+                field: "price".to_string(),
+                kind: AggregationKind::Stats,
+            }],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let search_results = algolia_response_to_search_results(algolia_response, &query);
         assert_eq!(search_results.total, Some(1));
         assert_eq!(search_results.page, Some(0));
         assert_eq!(search_results.per_page, Some(20));
         assert_eq!(search_results.hits.len(), 1);
         assert_eq!(search_results.hits[0].id, "doc1");
         assert_eq!(search_results.hits[0].score, Some(100.0));
-        assert!(search_results.facets.is_some());
+        assert_eq!(search_results.facets.len(), 2);
+        assert_eq!(search_results.facets[0].name, "category");
+        assert_eq!(search_results.facets[0].buckets[0].value, "electronics");
+        assert_eq!(search_results.facets[1].name, "price_stats");
+        assert_eq!(
+            search_results.facets[1]
+                .stats
+                .as_ref()
+                .and_then(|stats| stats.sum),
+            Some(99.5)
+        );
         assert_eq!(search_results.took_ms, Some(5));
     }
 
@@ -489,8 +1014,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(10),
             offset: None,
@@ -514,8 +1042,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(10),
             offset: Some(20),
@@ -547,4 +1078,174 @@ mod tests {
         assert_eq!(extract_field_from_ranking("invalid"), None);
         assert_eq!(extract_field_from_ranking("desc()"), Some("".to_string()));
     }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        for (name, expr) in conformance_cases() {
+            let filter = parse_filter_expr(&expr).unwrap();
+            let expected = match name {
+                "eq_text" => Some(r#"category:"books""#),
+                "eq_integer" => Some("year = 2020"),
+                "eq_boolean" => Some("in_stock:true"),
+                "eq_escaped_text" => Some(r#"title:"say \"hi\"""#),
+                "ne" => Some(r#"NOT category:"books""#),
+                "range" => Some("price >= 10 AND price < 20.5"),
+                "in" => Some(r#"(category:"books" OR category:"music")"#),
+                "exists" => None,
+                "geo_radius" => None,
+                // Algolia cannot combine the numeric and the facet filter with OR
+                "and_or" => None,
+                "not_and" => None,
+                "not_in" => Some(r#"NOT category:"books" AND NOT category:"music""#),
+                "not_range" => None,
+                _ => panic!("No expected Algolia filter for case {name}"),
+            };
+            match expected {
+                Some(expected) => assert_eq!(
+                    filter_to_algolia(&filter).unwrap(),
+                    expected,
+                    "filter case {name}"
+                ),
+                None => assert!(
+                    matches!(filter_to_algolia(&filter), Err(SearchError::Unsupported)),
+                    "filter case {name}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_results() {
+        let object_ids = vec!["doc1".to_string(), "doc2".to_string()];
+        let acknowledged = vec!["doc1".to_string()];
+
+        let results = batch_results(&object_ids, &acknowledged, Ok(()));
+        assert_eq!(results[0], Ok(()));
+        assert!(matches!(results[1], Err(SearchError::Internal(_))));
+
+        let timeout = SearchError::Internal("Task 42 did not complete in time.".to_string());
+        let results = batch_results(&object_ids, &acknowledged, Err(timeout.clone()));
+        assert_eq!(results[0], Err(timeout));
+        assert!(matches!(
+            &results[1],
+            Err(SearchError::Internal(message)) if message.contains("not acknowledged")
+        ));
+    }
+
+    #[test]
+    fn test_validate_reindex_transform() {
+        assert!(validate_reindex_transform(None).is_ok());
+        let transform = ReindexTransform {
+            filter: None,
+            fields: vec![],
+        };
+        assert!(validate_reindex_transform(Some(&transform)).is_ok());
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Exists("title".to_string())],
+            }),
+            fields: vec![],
+        };
+        assert_eq!(
+            validate_reindex_transform(Some(&transform)),
+            Err(SearchError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_task_id() {
+        let id = task_id("products:v1", 42);
+        assert_eq!(parse_task_id(&id).unwrap(), ("products:v1".to_string(), 42));
+        assert!(matches!(
+            parse_task_id("products"),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            parse_task_id("products:pending"),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_task_status() {
+        let published = task_status("products:42".to_string(), "published");
+        assert_eq!(published.id, "products:42");
+        assert_eq!(published.state, TaskState::Succeeded);
+        assert_eq!(published.error, None);
+        assert_eq!(
+            task_status("products:43".to_string(), "notPublished").state,
+            TaskState::Processing
+        );
+    }
+
+    #[test]
+    fn test_relevance_settings_to_algolia() {
+        let relevance = RelevanceSettings {
+            synonyms: vec![
+                SynonymSet {
+                    synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                    root: None,
+                },
+                SynonymSet {
+                    synonyms: vec!["television".to_string()],
+                    root: Some("tv".to_string()),
+                },
+            ],
+            stop_words: vec![],
+            ranking_rules: vec!["year:desc".to_string()],
+            provider_settings: None,
+        };
+
+        let (ranking, synonyms) = relevance_settings_to_algolia(&relevance).unwrap();
+        assert_eq!(ranking.ranking, DEFAULT_RANKING.to_vec());
+        assert_eq!(ranking.custom_ranking, vec!["desc(year)".to_string()]);
+        assert_eq!(synonyms[0].synonym_type, "synonym");
+        assert_eq!(synonyms[1].synonym_type, "oneWaySynonym");
+        assert_eq!(synonyms[1].input, Some("tv".to_string()));
+
+        let settings = IndexSettings {
+            ranking: ranking.ranking,
+            custom_ranking: ranking.custom_ranking,
+            ..Default::default()
+        };
+        assert_eq!(
+            algolia_settings_to_relevance(&settings, synonyms),
+            relevance
+        );
+    }
+
+    #[test]
+    fn test_relevance_settings_with_builtin_rules() {
+        let relevance = RelevanceSettings {
+            synonyms: vec![],
+            stop_words: vec![],
+            ranking_rules: vec![
+                "words".to_string(),
+                "price:asc".to_string(),
+                "typo".to_string(),
+            ],
+            provider_settings: None,
+        };
+
+        let (ranking, _) = relevance_settings_to_algolia(&relevance).unwrap();
+        assert_eq!(ranking.ranking, vec!["words", "custom", "typo"]);
+        assert_eq!(ranking.custom_ranking, vec!["asc(price)"]);
+
+        let settings = IndexSettings {
+            ranking: ranking.ranking,
+            custom_ranking: ranking.custom_ranking,
+            ..Default::default()
+        };
+        assert_eq!(algolia_settings_to_relevance(&settings, vec![]), relevance);
+
+        let stop_words = RelevanceSettings {
+            stop_words: vec!["the".to_string()],
+            ..relevance
+        };
+        assert!(matches!(
+            relevance_settings_to_algolia(&stop_words),
+            Err(SearchError::Unsupported)
+        ));
+    }
 }
@@ -1,21 +1,37 @@
-use crate::client::AlgoliaSearchApi;
+use crate::client::{
+    AlgoliaSearchApi, IndexQuery, MultipleQueriesRequest, OperationIndexRequest,
+    SearchSynonymsRequest,
+};
 use crate::conversions::{
-    algolia_object_to_doc, algolia_response_to_search_results, algolia_settings_to_schema,
-    create_retry_query, doc_to_algolia_object, schema_to_algolia_settings,
-    search_query_to_algolia_query,
+    algolia_object_to_doc, algolia_response_to_search_results, algolia_settings_to_relevance,
+    algolia_settings_to_schema, batch_results, create_retry_query, doc_to_algolia_object,
+    parse_task_id, relevance_settings_to_algolia, schema_to_algolia_settings,
+    search_query_to_algolia_query, task_id, task_status, validate_algolia_doc,
+    validate_algolia_schema, validate_reindex_transform,
 };
 use golem_rust::wasm_rpc::Pollable;
+use golem_search::bulk::bulk_write;
 use golem_search::config::with_config_keys;
+use golem_search::consistency::{bulk_consistency, waits_for_task, write_consistency};
 use golem_search::durability::{DurableSearch, ExtendedGuest};
+use golem_search::error::unsupported;
+use golem_search::federation::{multi_search_queries, multi_search_results};
 use golem_search::golem::search::core::{Guest, GuestSearchStream, SearchStream};
 use golem_search::golem::search::types::{
-    Doc, DocumentId, IndexName, Schema, SearchError, SearchHit, SearchQuery, SearchResults,
+    BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+    MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
 };
+use golem_search::reindex::{run_reindex, ReindexTask};
+use golem_search::relevance::validate_relevance_settings;
 use std::cell::{Cell, RefCell};
 
 mod client;
 mod conversions;
 
+/// Largest page of synonyms returned by Algolia
+const SYNONYMS_PAGE_SIZE: u32 = 1000;
+
 struct AlgoliaSearchStream {
     client: AlgoliaSearchApi,
     index_name: String,
@@ -51,11 +67,14 @@ impl GuestSearchStream for AlgoliaSearchStream {
         let mut search_query = self.query.clone();
         search_query.page = Some(self.current_page.get());
 
-        let algolia_query = search_query_to_algolia_query(search_query);
+        let Ok(algolia_query) = search_query_to_algolia_query(search_query) else {
+            self.finished.set(true);
+            return Some(vec![]);
+        };
 
         match self.client.search(&self.index_name, &algolia_query) {
             Ok(response) => {
-                let search_results = algolia_response_to_search_results(response);
+                let search_results = algolia_response_to_search_results(response, &self.query);
 
                 let current_page = self.current_page.get();
                 let total_pages = if let (Some(total), Some(per_page)) =
@@ -145,59 +164,103 @@ impl Guest for AlgoliaComponent {
         }
     }
 
-    fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+    fn upsert(
+        index: IndexName,
+        doc: Doc,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
+        validate_algolia_doc(&doc)?;
         let client = Self::create_client()?;
         let algolia_object = doc_to_algolia_object(doc).map_err(SearchError::InvalidQuery)?;
 
-        match client.save_object(&index, &algolia_object) {
-            Ok(response) => {
-                let _ = response;
-                Ok(())
-            }
-            Err(e) => Err(e),
+        let response = client.save_object(&index, &algolia_object)?;
+        if waits_for_task(write_consistency(options.as_ref())) {
+            client.wait_for_task(&index, response.task_id)?;
         }
+
+        Ok(Some(task_id(&index, response.task_id)))
     }
 
-    fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+    fn upsert_many(
+        index: IndexName,
+        docs: Vec<Doc>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-        let mut algolia_objects = Vec::new();
-
-        for doc in docs {
-            let algolia_object = doc_to_algolia_object(doc).map_err(SearchError::InvalidQuery)?;
-            algolia_objects.push(algolia_object);
-        }
-
-        match client.save_objects(&index, &algolia_objects) {
-            Ok(response) => {
-                let _ = response;
-                Ok(())
-            }
-            Err(e) => Err(e),
-        }
+        let wait = waits_for_task(bulk_consistency(options.as_ref()));
+        let mut tasks = Vec::new();
+
+        let mut result = bulk_write(
+            docs,
+            options.as_ref(),
+            |doc| doc.id.clone(),
+            |doc| {
+                validate_algolia_doc(&doc)?;
+                doc_to_algolia_object(doc).map_err(SearchError::InvalidQuery)
+            },
+            |algolia_objects| {
+                let object_ids = algolia_objects
+                    .iter()
+                    .map(|object| object.object_id.clone().unwrap_or_default())
+                    .collect::<Vec<_>>();
+                let response = client.save_objects(&index, &algolia_objects)?;
+                tasks.push(task_id(&index, response.task_id));
+                // A task which is not processed in time fails the objects of its chunk only
+                let task = if wait {
+                    client.wait_for_task(&index, response.task_id)
+                } else {
+                    Ok(())
+                };
+                Ok(batch_results(&object_ids, &response.object_ids, task))
+            },
+        )?;
+        result.tasks = tasks;
+        Ok(result)
     }
 
-    fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+    fn delete(
+        index: IndexName,
+        id: DocumentId,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
 
-        match client.delete_object(&index, &id) {
-            Ok(response) => {
-                let _ = response;
-                Ok(())
-            }
-            Err(e) => Err(e),
+        let response = client.delete_object(&index, &id)?;
+        if waits_for_task(write_consistency(options.as_ref())) {
+            client.wait_for_task(&index, response.task_id)?;
         }
+
+        Ok(Some(task_id(&index, response.task_id)))
     }
 
-    fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+    fn delete_many(
+        index: IndexName,
+        ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-
-        match client.delete_objects(&index, &ids) {
-            Ok(response) => {
-                let _ = response;
-                Ok(())
-            }
-            Err(e) => Err(e),
-        }
+        let wait = waits_for_task(bulk_consistency(options.as_ref()));
+        let mut tasks = Vec::new();
+
+        let mut result = bulk_write(
+            ids,
+            options.as_ref(),
+            |id| id.clone(),
+            Ok,
+            |ids| {
+                let response = client.delete_objects(&index, &ids)?;
+                tasks.push(task_id(&index, response.task_id));
+                // A task which is not processed in time fails the objects of its chunk only
+                let task = if wait {
+                    client.wait_for_task(&index, response.task_id)
+                } else {
+                    Ok(())
+                };
+                Ok(batch_results(&ids, &response.object_ids, task))
+            },
+        )?;
+        result.tasks = tasks;
+        Ok(result)
     }
 
     fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
@@ -210,18 +273,54 @@ impl Guest for AlgoliaComponent {
         }
     }
 
+    fn get_task_status(id: TaskId) -> Result<TaskStatus, SearchError> {
+        let (index, algolia_task_id) = parse_task_id(&id)?;
+        let client = Self::create_client()?;
+        let task = client.get_task(&index, algolia_task_id)?;
+        Ok(task_status(id, &task.status))
+    }
+
     fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
         let client = Self::create_client()?;
-        let algolia_query = search_query_to_algolia_query(query);
+        let algolia_query = search_query_to_algolia_query(query.clone())?;
 
         match client.search(&index, &algolia_query) {
-            Ok(response) => Ok(algolia_response_to_search_results(response)),
+            Ok(response) => Ok(algolia_response_to_search_results(response, &query)),
             Err(e) => Err(e),
         }
     }
 
+    fn multi_search(
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    ) -> Result<MultiSearchResults, SearchError> {
+        let client = Self::create_client()?;
+        let queries = multi_search_queries(queries, federation.as_ref())?;
+        let requests = queries
+            .iter()
+            .map(|(index, query)| {
+                Ok(IndexQuery {
+                    index_name: index.clone(),
+                    query: search_query_to_algolia_query(query.clone())?,
+                })
+            })
+            .collect::<Result<Vec<_>, SearchError>>()?;
+
+        let response = client.multiple_queries(&MultipleQueriesRequest { requests })?;
+        let results = queries
+            .into_iter()
+            .zip(response.results)
+            .map(|((index, query), response)| {
+                let results = algolia_response_to_search_results(response, &query);
+                (index, results)
+            })
+            .collect();
+        Ok(multi_search_results(results, federation.as_ref()))
+    }
+
     fn stream_search(index: IndexName, query: SearchQuery) -> Result<SearchStream, SearchError> {
         let client = Self::create_client()?;
+        search_query_to_algolia_query(query.clone())?;
         let stream = AlgoliaSearchStream::new(client, index, query);
         Ok(SearchStream::new(stream))
     }
@@ -236,6 +335,7 @@ impl Guest for AlgoliaComponent {
     }
 
     fn update_schema(index: IndexName, schema: Schema) -> Result<(), SearchError> {
+        validate_algolia_schema(&schema)?;
         let client = Self::create_client()?;
         let settings = schema_to_algolia_settings(schema);
 
@@ -243,6 +343,72 @@ impl Guest for AlgoliaComponent {
 
         Ok(())
     }
+
+    fn create_alias(_alias: String, _index: IndexName) -> Result<(), SearchError> {
+        Err(unsupported("Aliases in Algolia"))
+    }
+
+    fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        // Algolia has no aliases, the index is moved to the alias name instead, atomically
+        // replacing the index previously found there
+        let client = Self::create_client()?;
+        let request = OperationIndexRequest {
+            operation: "move".to_string(),
+            destination: alias,
+        };
+        let response = client.operation_index(&index, &request)?;
+        client.wait_for_task(&index, response.task_id)
+    }
+
+    fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+        Err(unsupported("Aliases in Algolia"))
+    }
+
+    fn reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexResult, SearchError> {
+        run_reindex::<Self>(source, target, transform)
+    }
+
+    fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+        let client = Self::create_client()?;
+
+        let settings = client.get_settings(&index)?;
+        let mut synonyms = Vec::new();
+        let mut page = 0;
+        loop {
+            let request = SearchSynonymsRequest {
+                query: String::new(),
+                page,
+                hits_per_page: SYNONYMS_PAGE_SIZE,
+            };
+            let response = client.search_synonyms(&index, &request)?;
+            let last_page = response.hits.len() < SYNONYMS_PAGE_SIZE as usize;
+            synonyms.extend(response.hits);
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(algolia_settings_to_relevance(&settings, synonyms))
+    }
+
+    fn update_relevance_settings(
+        index: IndexName,
+        settings: RelevanceSettings,
+    ) -> Result<(), SearchError> {
+        validate_relevance_settings(&settings)?;
+        let (ranking, synonyms) = relevance_settings_to_algolia(&settings)?;
+        let client = Self::create_client()?;
+
+        let response = client.set_settings(&index, &ranking)?;
+        client.wait_for_task(&index, response.task_id)?;
+        let response = client.save_synonyms(&index, &synonyms)?;
+        client.wait_for_task(&index, response.task_id)
+    }
 }
 
 impl ExtendedGuest for AlgoliaComponent {
@@ -260,6 +426,33 @@ impl ExtendedGuest for AlgoliaComponent {
     fn subscribe(stream: &Self::SearchStream) -> Pollable {
         stream.subscribe()
     }
+
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError> {
+        validate_reindex_transform(transform.as_ref())?;
+        let client = Self::create_client()?;
+        let request = OperationIndexRequest {
+            operation: "copy".to_string(),
+            destination: target,
+        };
+        let response = client.operation_index(&source, &request)?;
+        Ok(ReindexTask::Running(task_id(&source, response.task_id)))
+    }
+
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError> {
+        let (index, task_id) = parse_task_id(task_id)?;
+        let client = Self::create_client()?;
+        let task = client.get_task(&index, task_id)?;
+        if task.status == "published" {
+            // Algolia does not report the number of copied objects
+            Ok(Some(ReindexResult { copied: None }))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 type DurableAlgoliaComponent = DurableSearch<AlgoliaComponent>;
@@ -5,6 +5,7 @@ use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 /// The Elasticsearch Search API client for managing indices and performing search
@@ -50,6 +51,8 @@ pub struct ElasticsearchQuery {
     pub aggs: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _source: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub knn: Option<Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -111,6 +114,19 @@ pub struct ElasticsearchBulkAction {
     pub id: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ElasticsearchMultiSearchResponse {
+    pub responses: Vec<ElasticsearchMultiSearchItem>,
+}
+
+/// Response of one search of an `_msearch` request, which fails on its own
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ElasticsearchMultiSearchItem {
+    Error { error: Value, status: u16 },
+    Results(ElasticsearchSearchResponse),
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct ElasticsearchBulkResponse {
@@ -138,6 +154,24 @@ pub struct ElasticsearchIndexInfo {
     pub pri_store_size: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct ElasticsearchIndexAliases {
+    #[serde(default)]
+    pub aliases: HashMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ElasticsearchTaskStarted {
+    pub task: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ElasticsearchTask {
+    pub completed: bool,
+    pub response: Option<Value>,
+    pub error: Option<Value>,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct ElasticsearchErrorResponse {
@@ -269,10 +303,14 @@ impl ElasticsearchApi {
         index_name: &str,
         id: &str,
         document: &Value,
+        refresh: &str,
     ) -> Result<(), SearchError> {
         trace!("Indexing document {id} in index: {index_name}");
 
-        let url = format!("{}/{}/_doc/{}", self.base_url, index_name, id);
+        let url = format!(
+            "{}/{}/_doc/{}?refresh={}",
+            self.base_url, index_name, id, refresh
+        );
 
         let response = self
             .create_request(Method::PUT, &url)
@@ -281,17 +319,20 @@ impl ElasticsearchApi {
             .map_err(|e| internal_error(format!("Failed to index document: {e}")))?;
 
         if response.status().is_success() {
-            self.refresh_index(index_name)?;
             Ok(())
         } else {
             Err(search_error_from_status(response.status()))
         }
     }
 
-    pub fn bulk_index(&self, operations: &str) -> Result<ElasticsearchBulkResponse, SearchError> {
+    pub fn bulk_index(
+        &self,
+        operations: &str,
+        refresh: &str,
+    ) -> Result<ElasticsearchBulkResponse, SearchError> {
         trace!("Performing bulk index operation");
 
-        let url = format!("{}/_bulk", self.base_url);
+        let url = format!("{}/_bulk?refresh={}", self.base_url, refresh);
 
         // Building request without create_request to avoid Content-Type conflicts
         let mut builder = self
@@ -314,10 +355,18 @@ impl ElasticsearchApi {
         parse_response(response)
     }
 
-    pub fn delete_document(&self, index_name: &str, id: &str) -> Result<(), SearchError> {
+    pub fn delete_document(
+        &self,
+        index_name: &str,
+        id: &str,
+        refresh: &str,
+    ) -> Result<(), SearchError> {
         trace!("Deleting document {id} from index: {index_name}");
 
-        let url = format!("{}/{}/_doc/{}", self.base_url, index_name, id);
+        let url = format!(
+            "{}/{}/_doc/{}?refresh={}",
+            self.base_url, index_name, id, refresh
+        );
 
         let response = self
             .create_request(Method::DELETE, &url)
@@ -374,6 +423,34 @@ impl ElasticsearchApi {
         parse_response(response)
     }
 
+    pub fn multi_search(
+        &self,
+        searches: &str,
+    ) -> Result<ElasticsearchMultiSearchResponse, SearchError> {
+        trace!("Performing multi-search");
+
+        let url = format!("{}/_msearch", self.base_url);
+
+        // Building request without create_request to avoid Content-Type conflicts
+        let mut builder = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/x-ndjson")
+            .body(searches.to_string());
+
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("ApiKey {api_key}"));
+        } else if let (Some(username), Some(password)) = (&self.username, &self.password) {
+            builder = builder.basic_auth(username, Some(password));
+        }
+
+        let response = builder
+            .send()
+            .map_err(|e| internal_error(format!("Failed to perform multi-search: {e}")))?;
+
+        parse_response(response)
+    }
+
     pub fn search_with_scroll(
         &self,
         index_name: &str,
@@ -481,23 +558,129 @@ impl ElasticsearchApi {
         }
     }
 
-    pub fn refresh_index(&self, index_name: &str) -> Result<(), SearchError> {
-        trace!("Refreshing index: {index_name}");
+    pub fn get_settings(&self, index_name: &str) -> Result<Value, SearchError> {
+        trace!("Getting settings for index: {index_name}");
+
+        let url = format!("{}/{}/_settings", self.base_url, index_name);
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to get settings: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn put_settings(&self, index_name: &str, settings: &Value) -> Result<(), SearchError> {
+        trace!("Putting settings for index: {index_name}");
+
+        let url = format!("{}/{}/_settings", self.base_url, index_name);
+
+        let response = self
+            .create_request(Method::PUT, &url)
+            .json(settings)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to put settings: {e}")))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(search_error_from_status(response.status()))
+        }
+    }
+
+    /// Closes the index, or opens it again when `open` is set
+    pub fn set_index_open(&self, index_name: &str, open: bool) -> Result<(), SearchError> {
+        let action = if open { "_open" } else { "_close" };
+        trace!("Running {action} on index: {index_name}");
 
-        let url = format!("{}/{}/_refresh", self.base_url, index_name);
+        let url = format!("{}/{}/{}", self.base_url, index_name, action);
 
         let response = self
             .create_request(Method::POST, &url)
             .send()
-            .map_err(|e| internal_error(format!("Failed to refresh index: {e}")))?;
+            .map_err(|e| internal_error(format!("Failed to {action} index: {e}")))?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            // Refreshing is not critical, so we can ignore errors
+            Err(search_error_from_status(response.status()))
+        }
+    }
+
+    pub fn update_aliases(&self, actions: &Value) -> Result<(), SearchError> {
+        trace!("Updating aliases: {actions}");
+
+        let url = format!("{}/_aliases", self.base_url);
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(actions)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to update aliases: {e}")))?;
+
+        if response.status().is_success() {
             Ok(())
+        } else {
+            Err(search_error_from_status(response.status()))
         }
     }
+
+    /// Aliases of the indices, keyed by index name. Only the aliases named `alias` are returned
+    /// if it is given, and none if it does not exist.
+    pub fn get_aliases(
+        &self,
+        alias: Option<&str>,
+    ) -> Result<HashMap<String, ElasticsearchIndexAliases>, SearchError> {
+        trace!("Getting aliases: {alias:?}");
+
+        let url = match alias {
+            Some(alias) => format!("{}/_alias/{}", self.base_url, alias),
+            None => format!("{}/_alias", self.base_url),
+        };
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to get aliases: {e}")))?;
+
+        if alias.is_some() && response.status() == 404 {
+            Ok(HashMap::new())
+        } else {
+            parse_response(response)
+        }
+    }
+
+    /// Starts a reindex task on the cluster without waiting for it to complete
+    pub fn start_reindex(&self, request: &Value) -> Result<ElasticsearchTaskStarted, SearchError> {
+        trace!("Starting reindex: {request}");
+
+        let url = format!(
+            "{}/_reindex?refresh=true&wait_for_completion=false",
+            self.base_url
+        );
+
+        let response = self
+            .create_request(Method::POST, &url)
+            .json(request)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to start reindex: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn get_task(&self, task_id: &str) -> Result<ElasticsearchTask, SearchError> {
+        trace!("Getting task: {task_id}");
+
+        let url = format!("{}/_tasks/{}", self.base_url, task_id);
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to get task: {e}")))?;
+
+        parse_response(response)
+    }
 }
 
 fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, SearchError> {
@@ -1,11 +1,23 @@
 use crate::client::{
-    ElasticsearchHit, ElasticsearchMappings, ElasticsearchQuery, ElasticsearchSearchResponse,
-    ElasticsearchSettings,
+    ElasticsearchBulkResponse, ElasticsearchHit, ElasticsearchIndexAliases, ElasticsearchMappings,
+    ElasticsearchMultiSearchItem, ElasticsearchMultiSearchResponse, ElasticsearchQuery,
+    ElasticsearchSearchResponse, ElasticsearchSettings, ElasticsearchTask,
 };
+use golem_search::aggregation::{
+    aggregations_to_query_dsl, facet_results_from_aggregations, validate_aggregations,
+};
+use golem_search::bulk::bulk_item_error;
+use golem_search::error::internal_error;
+use golem_search::filter::{filter_to_query_dsl, parse_filter_expr};
 use golem_search::golem::search::types::{
-    Doc, FieldType, Schema, SchemaField, SearchHit, SearchQuery, SearchResults,
+    Doc, FieldType, IndexAlias, ReindexResult, ReindexTransform, RelevanceSettings, Schema,
+    SchemaField, SearchError, SearchHit, SearchQuery, SearchResults, VectorConfig,
+    VectorSimilarity,
 };
+use golem_search::relevance::relevance_from_analysis;
+use golem_search::vector::{insert_embeddings, validate_vector_query};
 use serde_json::{json, Map, Value};
+use std::collections::HashMap;
 
 pub fn doc_to_elasticsearch_document(doc: Doc) -> Result<Value, String> {
     // Validate document ID length (Elasticsearch limit is 512 bytes)
@@ -22,12 +34,14 @@ pub fn doc_to_elasticsearch_document(doc: Doc) -> Result<Value, String> {
     let document = match content {
         Value::Object(mut obj) => {
             obj.insert("id".to_string(), Value::String(doc.id));
+            insert_embeddings(&mut obj, doc.embeddings);
             Value::Object(obj)
         }
         _ => {
             let mut obj = Map::new();
             obj.insert("id".to_string(), Value::String(doc.id));
             obj.insert("content".to_string(), content);
+            insert_embeddings(&mut obj, doc.embeddings);
             Value::Object(obj)
         }
     };
@@ -37,10 +51,18 @@ pub fn doc_to_elasticsearch_document(doc: Doc) -> Result<Value, String> {
 
 pub fn elasticsearch_document_to_doc(id: String, source: Value) -> Doc {
     let content = serde_json::to_string(&source).unwrap_or_else(|_| "{}".to_string());
-    Doc { id, content }
+    Doc {
+        id,
+        content,
+        embeddings: vec![],
+    }
 }
 
-pub fn search_query_to_elasticsearch_query(query: SearchQuery) -> ElasticsearchQuery {
+pub fn search_query_to_elasticsearch_query(
+    query: SearchQuery,
+) -> Result<ElasticsearchQuery, SearchError> {
+    validate_aggregations(&query)?;
+
     let mut es_query = ElasticsearchQuery {
         query: None,
         from: query.offset,
@@ -49,6 +71,7 @@ pub fn search_query_to_elasticsearch_query(query: SearchQuery) -> ElasticsearchQ
         highlight: None,
         aggs: None,
         _source: None,
+        knn: None,
     };
 
     let mut bool_query = json!({
@@ -120,7 +143,36 @@ pub fn search_query_to_elasticsearch_query(query: SearchQuery) -> ElasticsearchQ
         }
     }
 
-    if !bool_query["bool"]["must"].as_array().unwrap().is_empty()
+    if let Some(filter_expr) = &query.filter_expr {
+        let filter = parse_filter_expr(filter_expr)?;
+        bool_query["bool"]["filter"]
+            .as_array_mut()
+            .unwrap()
+            .push(filter_to_query_dsl(&filter));
+    }
+
+    if let Some(vector_query) = &query.vector_query {
+        validate_vector_query(vector_query)?;
+        let mut knn = json!({
+            "field": vector_query.field,
+            "query_vector": vector_query.embedding,
+            "k": vector_query.k,
+            "num_candidates": knn_num_candidates(vector_query.k)
+        });
+        // A top-level query is combined with the nearest neighbours as a disjunction, so the
+        // filters are applied to the neighbours as well
+        if !bool_query["bool"]["filter"].as_array().unwrap().is_empty() {
+            knn["filter"] = bool_query["bool"]["filter"].clone();
+        }
+        let has_text = !bool_query["bool"]["must"].as_array().unwrap().is_empty();
+        if let Some(weight) = vector_query.hybrid_weight.filter(|_| has_text) {
+            knn["boost"] = json!(weight);
+            bool_query["bool"]["boost"] = json!(1.0 - weight);
+            es_query.query = Some(bool_query);
+        }
+        es_query.knn = Some(knn);
+        es_query.size = es_query.size.or(Some(vector_query.k));
+    } else if !bool_query["bool"]["must"].as_array().unwrap().is_empty()
         || !bool_query["bool"]["filter"].as_array().unwrap().is_empty()
     {
         es_query.query = Some(bool_query);
@@ -182,17 +234,20 @@ pub fn search_query_to_elasticsearch_query(query: SearchQuery) -> ElasticsearchQ
         es_query.highlight = Some(highlight);
     }
 
-    if !query.facets.is_empty() {
-        let mut aggs = json!({});
+    if !query.facets.is_empty() || !query.aggregations.is_empty() {
+        let mut aggs = aggregations_to_query_dsl(&query.aggregations);
         for facet in query.facets {
-            aggs[&facet] = json!({
-                "terms": {
-                    "field": format!("{}.keyword", facet),
-                    "size": 10
-                }
-            });
+            aggs.insert(
+                facet.clone(),
+                json!({
+                    "terms": {
+                        "field": format!("{}.keyword", facet),
+                        "size": 10
+                    }
+                }),
+            );
         }
-        es_query.aggs = Some(aggs);
+        es_query.aggs = Some(Value::Object(aggs));
     }
 
     if let Some(config) = query.config {
@@ -219,7 +274,12 @@ pub fn search_query_to_elasticsearch_query(query: SearchQuery) -> ElasticsearchQ
         }
     }
 
-    es_query
+    Ok(es_query)
+}
+
+/// Number of candidates considered per shard by the approximate nearest neighbour search
+fn knn_num_candidates(k: u32) -> u32 {
+    k.saturating_mul(10).clamp(100, 10_000).max(k)
 }
 
 pub fn elasticsearch_response_to_search_results(
@@ -245,7 +305,8 @@ pub fn elasticsearch_response_to_search_results(
         hits,
         facets: response
             .aggregations
-            .map(|aggs| serde_json::to_string(&aggs).unwrap_or_else(|_| "{}".to_string())),
+            .map(|aggs| facet_results_from_aggregations(&aggs))
+            .unwrap_or_default(),
         took_ms: Some(response.took),
     }
 }
@@ -305,6 +366,23 @@ pub fn schema_to_elasticsearch_settings(schema: Schema) -> ElasticsearchSettings
             FieldType::GeoPoint => {
                 field_mapping.insert("type".to_string(), Value::String("geo_point".to_string()));
             }
+            FieldType::Vector => {
+                field_mapping.insert(
+                    "type".to_string(),
+                    Value::String("dense_vector".to_string()),
+                );
+                if let Some(config) = &field.vector {
+                    field_mapping.insert("dims".to_string(), json!(config.dimensions));
+                    field_mapping.insert(
+                        "similarity".to_string(),
+                        json!(match config.similarity {
+                            VectorSimilarity::Cosine => "cosine",
+                            VectorSimilarity::DotProduct => "dot_product",
+                            VectorSimilarity::Euclidean => "l2_norm",
+                        }),
+                    );
+                }
+            }
         }
 
         if !field.index {
@@ -357,9 +435,24 @@ pub fn elasticsearch_mappings_to_schema(mappings: Value, index_name: &str) -> Sc
                         "boolean" => FieldType::Boolean,
                         "date" => FieldType::Date,
                         "geo_point" => FieldType::GeoPoint,
+                        "dense_vector" => FieldType::Vector,
                         _ => FieldType::Text,
                     };
 
+                    let vector = field_def
+                        .get("dims")
+                        .and_then(|dims| dims.as_u64())
+                        .map(|dims| VectorConfig {
+                            dimensions: dims as u32,
+                            similarity: match field_def.get("similarity").and_then(|s| s.as_str()) {
+                                Some("dot_product") | Some("max_inner_product") => {
+                                    VectorSimilarity::DotProduct
+                                }
+                                Some("l2_norm") => VectorSimilarity::Euclidean,
+                                _ => VectorSimilarity::Cosine,
+                            },
+                        });
+
                     let index = field_def
                         .get("index")
                         .and_then(|i| i.as_bool())
@@ -370,8 +463,9 @@ pub fn elasticsearch_mappings_to_schema(mappings: Value, index_name: &str) -> Sc
                         field_type,
                         required: false, // Elasticsearch doesn't have required fields in mappings
                         facet: field_type == FieldType::Keyword, // Keywords can be used for faceting
-                        sort: field_type != FieldType::Text, // Text fields typically can't be sorted directly
+                        sort: field_type != FieldType::Text && field_type != FieldType::Vector, // Text fields typically can't be sorted directly
                         index,
+                        vector,
                     });
                 }
             }
@@ -398,24 +492,23 @@ pub fn create_retry_query(original_query: &SearchQuery, partial_hits: &[SearchHi
 
 pub fn build_bulk_operations(
     index_name: &str,
-    docs: &[Doc],
+    documents: &[Value],
     operation: &str,
 ) -> Result<String, String> {
     let mut bulk_ops = String::new();
 
-    for doc in docs {
+    for document in documents {
         let action = json!({
             operation: {
                 "_index": index_name,
-                "_id": doc.id
+                "_id": document["id"]
             }
         });
         bulk_ops.push_str(&serde_json::to_string(&action).map_err(|e| e.to_string())?);
         bulk_ops.push('\n');
 
         if operation != "delete" {
-            let document = doc_to_elasticsearch_document(doc.clone())?;
-            bulk_ops.push_str(&serde_json::to_string(&document).map_err(|e| e.to_string())?);
+            bulk_ops.push_str(&serde_json::to_string(document).map_err(|e| e.to_string())?);
             bulk_ops.push('\n');
         }
     }
@@ -440,19 +533,204 @@ pub fn build_bulk_delete_operations(index_name: &str, ids: &[String]) -> Result<
     Ok(bulk_ops)
 }
 
+/// Outcome of each operation of a `_bulk` request, in the order of the operations
+pub fn bulk_response_results(response: &ElasticsearchBulkResponse) -> Vec<Result<(), SearchError>> {
+    response
+        .items
+        .iter()
+        .map(|item| {
+            // Each item is keyed by its action, like `{"index": {"_id": .., "status": ..}}`
+            let Some(result) = item.as_object().and_then(|item| item.values().next()) else {
+                return Err(internal_error(format!(
+                    "Unexpected bulk response item: {item}"
+                )));
+            };
+            match result.get("error") {
+                None => Ok(()),
+                Some(error) => {
+                    let status = result.get("status").and_then(Value::as_u64).unwrap_or(500);
+                    Err(bulk_item_error(status as u16, error_reason(error)))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Body of an `_msearch` request, a header naming the index followed by the query of each search
+pub fn build_multi_search_body(
+    searches: &[(String, ElasticsearchQuery)],
+) -> Result<String, String> {
+    let mut body = String::new();
+
+    for (index_name, query) in searches {
+        let header = json!({ "index": index_name });
+        body.push_str(&serde_json::to_string(&header).map_err(|e| e.to_string())?);
+        body.push('\n');
+        body.push_str(&serde_json::to_string(query).map_err(|e| e.to_string())?);
+        body.push('\n');
+    }
+
+    Ok(body)
+}
+
+/// Results of each search of an `_msearch` response, failing with the error of the first search
+/// that failed
+pub fn multi_search_response_results(
+    response: ElasticsearchMultiSearchResponse,
+) -> Result<Vec<SearchResults>, SearchError> {
+    response
+        .responses
+        .into_iter()
+        .map(|item| match item {
+            ElasticsearchMultiSearchItem::Results(response) => {
+                Ok(elasticsearch_response_to_search_results(response))
+            }
+            ElasticsearchMultiSearchItem::Error { error, status } => {
+                Err(bulk_item_error(status, error_reason(&error)))
+            }
+        })
+        .collect()
+}
+
+fn error_reason(error: &Value) -> String {
+    error
+        .get("reason")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+/// Actions of an `_aliases` request pointing `alias` at `index`, removing it from the indices in
+/// `current` so that the alias is moved in one atomic update
+pub fn alias_actions(
+    alias: &str,
+    index: &str,
+    current: &HashMap<String, ElasticsearchIndexAliases>,
+) -> Value {
+    let mut current = current
+        .keys()
+        .filter(|current| current.as_str() != index)
+        .collect::<Vec<_>>();
+    current.sort();
+
+    let mut actions = current
+        .into_iter()
+        .map(|current| json!({"remove": {"index": current, "alias": alias}}))
+        .collect::<Vec<_>>();
+    actions.push(json!({"add": {"index": index, "alias": alias}}));
+    json!({ "actions": actions })
+}
+
+/// Aliases of an `_alias` response, leaving out the hidden indices starting with a dot
+pub fn aliases_from_response(
+    indices: HashMap<String, ElasticsearchIndexAliases>,
+) -> Vec<IndexAlias> {
+    let mut aliases = indices
+        .into_iter()
+        .filter(|(index, _)| !index.starts_with('.'))
+        .flat_map(|(index, aliases)| {
+            aliases.aliases.into_keys().map(move |alias| IndexAlias {
+                alias,
+                index: index.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    aliases.sort_by(|a, b| (&a.alias, &a.index).cmp(&(&b.alias, &b.index)));
+    aliases
+}
+
+/// Body of a `_reindex` request copying the documents of `source` to `target`
+pub fn reindex_request(
+    source: &str,
+    target: &str,
+    transform: Option<&ReindexTransform>,
+) -> Result<Value, SearchError> {
+    let mut source_spec = json!({ "index": source });
+    if let Some(transform) = transform {
+        if let Some(filter) = &transform.filter {
+            let filter = filter_to_query_dsl(&parse_filter_expr(filter)?);
+            source_spec["query"] = json!({"bool": {"filter": [filter]}});
+        }
+        if !transform.fields.is_empty() {
+            source_spec["_source"] = json!(transform.fields);
+        }
+    }
+
+    Ok(json!({
+        "source": source_spec,
+        "dest": { "index": target }
+    }))
+}
+
+/// Result of a reindex task, `None` while the task is still running
+pub fn reindex_task_result(task: &ElasticsearchTask) -> Result<Option<ReindexResult>, SearchError> {
+    if !task.completed {
+        return Ok(None);
+    }
+
+    if let Some(error) = &task.error {
+        let reason = error
+            .get("reason")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| error.to_string());
+        return Err(internal_error(format!("Reindex failed: {reason}")));
+    }
+
+    let response = task
+        .response
+        .as_ref()
+        .ok_or_else(|| internal_error("Reindex task finished without a response"))?;
+
+    if let Some(failure) = response
+        .get("failures")
+        .and_then(Value::as_array)
+        .and_then(|failures| failures.first())
+    {
+        let status = failure.get("status").and_then(Value::as_u64).unwrap_or(500);
+        let reason = failure
+            .pointer("/cause/reason")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| failure.to_string());
+        return Err(bulk_item_error(status as u16, reason));
+    }
+
+    let count = |field: &str| response.get(field).and_then(Value::as_u64).unwrap_or(0);
+    Ok(Some(ReindexResult {
+        copied: Some(count("created") + count("updated")),
+    }))
+}
+
+/// Relevance settings from a `GET _settings` response, which is keyed by the name of the index
+/// even when the settings are requested through an alias
+pub fn relevance_settings_from_response(response: &Value) -> RelevanceSettings {
+    let analysis = response
+        .as_object()
+        .and_then(|indices| indices.values().next())
+        .and_then(|index| index.pointer("/settings/index/analysis"));
+    relevance_from_analysis(analysis)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::client::{
         ElasticsearchHit, ElasticsearchHits, ElasticsearchSearchResponse, ElasticsearchTotal,
     };
-    use golem_search::golem::search::types::{HighlightConfig, SearchConfig};
+    use golem_search::golem::search::types::{
+        FilterExpr, FilterNode, FilterValue, HighlightConfig, RangeFilter, SearchConfig,
+        SynonymSet, VectorQuery,
+    };
+    use golem_search::relevance::relevance_to_index_settings;
+    use golem_search::test_util::conformance_cases;
 
     #[test]
     fn test_doc_to_elasticsearch_document() {
         let doc = Doc {
             id: "test-id".to_string(),
             content: r#"{"title": "Test Document", "content": "This is a test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let es_doc = doc_to_elasticsearch_document(doc).unwrap();
@@ -467,6 +745,7 @@ mod tests {
         let doc = Doc {
             id: long_id,
             content: r#"{"title": "Test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let result = doc_to_elasticsearch_document(doc);
@@ -479,6 +758,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: "invalid json".to_string(),
+            embeddings: vec![],
         };
 
         let result = doc_to_elasticsearch_document(doc);
@@ -506,8 +786,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test query".to_string()),
             filters: vec!["category:electronics".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["price:desc".to_string()],
             facets: vec!["category".to_string()],
+            aggregations: vec![],
             page: None,
             per_page: Some(20),
             offset: Some(10),
@@ -520,7 +803,7 @@ mod tests {
             config: None,
         };
 
-        let es_query = search_query_to_elasticsearch_query(search_query);
+        let es_query = search_query_to_elasticsearch_query(search_query).unwrap();
         assert_eq!(es_query.from, Some(10));
         assert_eq!(es_query.size, Some(20));
         assert!(es_query.query.is_some());
@@ -534,8 +817,11 @@ mod tests {
         let search_query = SearchQuery {
             q: None,
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -543,7 +829,7 @@ mod tests {
             config: None,
         };
 
-        let es_query = search_query_to_elasticsearch_query(search_query);
+        let es_query = search_query_to_elasticsearch_query(search_query).unwrap();
         assert!(es_query.query.is_some());
         // Should have match_all query
         assert_eq!(es_query.query.unwrap()["match_all"], serde_json::json!({}));
@@ -554,8 +840,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -571,7 +860,7 @@ mod tests {
             }),
         };
 
-        let es_query = search_query_to_elasticsearch_query(search_query);
+        let es_query = search_query_to_elasticsearch_query(search_query).unwrap();
         assert!(es_query._source.is_some());
         assert_eq!(
             es_query._source.unwrap(),
@@ -598,7 +887,10 @@ mod tests {
                     highlight: Some(serde_json::json!({"title": ["Test <em>Document</em>"]})),
                 }],
             },
-            aggregations: Some(serde_json::json!({"category": {"buckets": []}})),
+            aggregations: Some(serde_json::json!({
+                "category": {"buckets": [{"key": "books", "doc_count": 1}]},
+                "price_stats": {"count": 1, "min": 10.0, "max": 10.0, "avg": 10.0, "sum": 10.0}
+            })),
         };
 
         let search_results = elasticsearch_response_to_search_results(es_response);
@@ -606,7 +898,16 @@ mod tests {
         assert_eq!(search_results.hits.len(), 1);
         assert_eq!(search_results.hits[0].id, "doc1");
         assert_eq!(search_results.hits[0].score, Some(1.0));
-        assert!(search_results.facets.is_some());
+        assert_eq!(search_results.facets.len(), 2);
+        assert_eq!(search_results.facets[0].name, "category");
+        assert_eq!(search_results.facets[0].buckets[0].count, 1);
+        assert_eq!(
+            search_results.facets[1]
+                .stats
+                .as_ref()
+                .and_then(|stats| stats.avg),
+            Some(10.0)
+        );
         assert_eq!(search_results.took_ms, Some(5));
     }
 
@@ -621,6 +922,7 @@ mod tests {
                     facet: false,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "category".to_string(),
@@ -629,6 +931,7 @@ mod tests {
                     facet: true,
                     sort: true,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "price".to_string(),
@@ -637,6 +940,19 @@ mod tests {
                     facet: false,
                     sort: true,
                     index: false,
+                    vector: None,
+                },
+                SchemaField {
+                    name: "title_vector".to_string(),
+                    field_type: FieldType::Vector,
+                    required: false,
+                    facet: false,
+                    sort: false,
+                    index: true,
+                    vector: Some(VectorConfig {
+                        dimensions: 384,
+                        similarity: VectorSimilarity::DotProduct,
+                    }),
                 },
             ],
             primary_key: Some("id".to_string()),
@@ -650,6 +966,58 @@ mod tests {
         assert!(properties.contains_key("title"));
         assert!(properties.contains_key("category"));
         assert!(properties.contains_key("price"));
+        assert_eq!(
+            properties["title_vector"],
+            serde_json::json!({"type": "dense_vector", "dims": 384, "similarity": "dot_product"})
+        );
+    }
+
+    #[test]
+    fn test_search_query_with_vector_query() {
+        let search_query = SearchQuery {
+            q: Some("space opera".to_string()),
+            filters: vec!["genre:scifi".to_string()],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.5, -0.25],
+                k: 5,
+                hybrid_weight: None,
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let es_query = search_query_to_elasticsearch_query(search_query.clone()).unwrap();
+        assert!(es_query.query.is_none());
+        assert_eq!(es_query.size, Some(5));
+        assert_eq!(
+            es_query.knn.unwrap(),
+            serde_json::json!({
+                "field": "title_vector",
+                "query_vector": [0.5, -0.25],
+                "k": 5,
+                "num_candidates": 100,
+                "filter": [{"term": {"genre": "scifi"}}]
+            })
+        );
+
+        let mut hybrid = search_query;
+        if let Some(vector_query) = hybrid.vector_query.as_mut() {
+            vector_query.hybrid_weight = Some(0.75);
+        }
+        let es_query = search_query_to_elasticsearch_query(hybrid).unwrap();
+        assert_eq!(es_query.knn.unwrap()["boost"], serde_json::json!(0.75));
+        assert_eq!(
+            es_query.query.unwrap()["bool"]["boost"],
+            serde_json::json!(0.25)
+        );
     }
 
     #[test]
@@ -657,8 +1025,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(10),
             offset: Some(0),
@@ -687,18 +1058,12 @@ mod tests {
 
     #[test]
     fn test_build_bulk_operations() {
-        let docs = vec![
-            Doc {
-                id: "doc1".to_string(),
-                content: r#"{"title": "Document 1"}"#.to_string(),
-            },
-            Doc {
-                id: "doc2".to_string(),
-                content: r#"{"title": "Document 2"}"#.to_string(),
-            },
+        let documents = vec![
+            json!({"id": "doc1", "title": "Document 1"}),
+            json!({"id": "doc2", "title": "Document 2"}),
         ];
 
-        let bulk_ops = build_bulk_operations("test-index", &docs, "index").unwrap();
+        let bulk_ops = build_bulk_operations("test-index", &documents, "index").unwrap();
         assert!(bulk_ops.contains("doc1"));
         assert!(bulk_ops.contains("doc2"));
         assert!(bulk_ops.contains("Document 1"));
@@ -714,4 +1079,330 @@ mod tests {
         assert!(bulk_ops.contains("doc2"));
         assert!(bulk_ops.contains("delete"));
     }
+
+    #[test]
+    fn test_bulk_response_results() {
+        let response: ElasticsearchBulkResponse = serde_json::from_value(json!({
+            "took": 12,
+            "errors": true,
+            "items": [
+                {"index": {"_index": "test-index", "_id": "doc1", "status": 201, "result": "created"}},
+                {"index": {
+                    "_index": "test-index",
+                    "_id": "doc2",
+                    "status": 400,
+                    "error": {
+                        "type": "document_parsing_exception",
+                        "reason": "failed to parse field [year] of type [long]"
+                    }
+                }},
+                {"delete": {"_index": "test-index", "_id": "doc3", "status": 404, "result": "not_found"}},
+                {"index": {
+                    "_index": "test-index",
+                    "_id": "doc4",
+                    "status": 429,
+                    "error": {"type": "es_rejected_execution_exception", "reason": "rejected"}
+                }}
+            ]
+        }))
+        .unwrap();
+
+        let results = bulk_response_results(&response);
+        assert_eq!(
+            results,
+            vec![
+                Ok(()),
+                Err(SearchError::InvalidQuery(
+                    "failed to parse field [year] of type [long]".to_string()
+                )),
+                Ok(()),
+                Err(SearchError::RateLimited),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_search() {
+        let query: ElasticsearchQuery =
+            serde_json::from_value(json!({"query": {"match_all": {}}, "size": 5})).unwrap();
+        let body = build_multi_search_body(&[("products".to_string(), query)]).unwrap();
+        let lines = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect::<Vec<Value>>();
+        assert_eq!(
+            lines,
+            vec![
+                json!({"index": "products"}),
+                json!({"query": {"match_all": {}}, "size": 5})
+            ]
+        );
+
+        let response: ElasticsearchMultiSearchResponse = serde_json::from_value(json!({
+            "took": 4,
+            "responses": [
+                {
+                    "took": 3,
+                    "timed_out": false,
+                    "hits": {
+                        "total": {"value": 1, "relation": "eq"},
+                        "max_score": 1.5,
+                        "hits": [{"_index": "products", "_id": "p1", "_score": 1.5, "_source": {"title": "Golem"}}]
+                    },
+                    "status": 200
+                }
+            ]
+        }))
+        .unwrap();
+        let results = multi_search_response_results(response).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].total, Some(1));
+        assert_eq!(results[0].hits[0].id, "p1");
+        assert_eq!(results[0].hits[0].score, Some(1.5));
+
+        let response: ElasticsearchMultiSearchResponse = serde_json::from_value(json!({
+            "took": 1,
+            "responses": [
+                {
+                    "error": {"type": "index_not_found_exception", "reason": "no such index [missing]"},
+                    "status": 404
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            multi_search_response_results(response),
+            Err(SearchError::IndexNotFound)
+        );
+    }
+
+    #[test]
+    fn test_alias_actions() {
+        let current = HashMap::from([
+            (
+                "products_v1".to_string(),
+                ElasticsearchIndexAliases::default(),
+            ),
+            (
+                "products_v2".to_string(),
+                ElasticsearchIndexAliases::default(),
+            ),
+        ]);
+
+        let actions = alias_actions("products", "products_v2", &current);
+        assert_eq!(
+            actions,
+            json!({"actions": [
+                {"remove": {"index": "products_v1", "alias": "products"}},
+                {"add": {"index": "products_v2", "alias": "products"}}
+            ]})
+        );
+
+        let actions = alias_actions("products", "products_v1", &HashMap::new());
+        assert_eq!(
+            actions,
+            json!({"actions": [{"add": {"index": "products_v1", "alias": "products"}}]})
+        );
+    }
+
+    #[test]
+    fn test_aliases_from_response() {
+        let response: HashMap<String, ElasticsearchIndexAliases> = serde_json::from_value(json!({
+            "products_v2": {"aliases": {"products": {}, "catalog": {}}},
+            "orders": {"aliases": {}},
+            ".kibana_1": {"aliases": {".kibana": {}}}
+        }))
+        .unwrap();
+
+        let aliases = aliases_from_response(response);
+        assert_eq!(
+            aliases,
+            vec![
+                IndexAlias {
+                    alias: "catalog".to_string(),
+                    index: "products_v2".to_string(),
+                },
+                IndexAlias {
+                    alias: "products".to_string(),
+                    index: "products_v2".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reindex_request() {
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(2000)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec!["title".to_string(), "year".to_string()],
+        };
+
+        let request = reindex_request("products_v1", "products_v2", Some(&transform)).unwrap();
+        assert_eq!(request["source"]["index"], "products_v1");
+        assert_eq!(request["source"]["_source"], json!(["title", "year"]));
+        assert!(request["source"]["query"]["bool"]["filter"].is_array());
+        assert_eq!(request["dest"]["index"], "products_v2");
+
+        let request = reindex_request("products_v1", "products_v2", None).unwrap();
+        assert_eq!(
+            request,
+            json!({"source": {"index": "products_v1"}, "dest": {"index": "products_v2"}})
+        );
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr { nodes: vec![] }),
+            fields: vec![],
+        };
+        assert!(matches!(
+            reindex_request("products_v1", "products_v2", Some(&transform)),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_reindex_task_result() {
+        let running: ElasticsearchTask =
+            serde_json::from_value(json!({"completed": false, "task": {}})).unwrap();
+        assert_eq!(reindex_task_result(&running), Ok(None));
+
+        let finished: ElasticsearchTask = serde_json::from_value(json!({
+            "completed": true,
+            "response": {"created": 120, "updated": 3, "failures": []}
+        }))
+        .unwrap();
+        assert_eq!(
+            reindex_task_result(&finished),
+            Ok(Some(ReindexResult { copied: Some(123) }))
+        );
+
+        let failed: ElasticsearchTask = serde_json::from_value(json!({
+            "completed": true,
+            "response": {
+                "created": 1,
+                "failures": [{
+                    "status": 400,
+                    "cause": {"reason": "failed to parse field [year] of type [long]"}
+                }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            reindex_task_result(&failed),
+            Err(SearchError::InvalidQuery(
+                "failed to parse field [year] of type [long]".to_string()
+            ))
+        );
+
+        let errored: ElasticsearchTask = serde_json::from_value(json!({
+            "completed": true,
+            "error": {"type": "index_not_found_exception", "reason": "no such index"}
+        }))
+        .unwrap();
+        assert!(matches!(
+            reindex_task_result(&errored),
+            Err(SearchError::Internal(_))
+        ));
+    }
+
+    #[test]
+    fn test_relevance_settings_from_response() {
+        let relevance = RelevanceSettings {
+            synonyms: vec![SynonymSet {
+                synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                root: None,
+            }],
+            stop_words: vec!["the".to_string()],
+            ranking_rules: vec![],
+            provider_settings: None,
+        };
+        let index_settings = relevance_to_index_settings(&relevance).unwrap();
+        let response = json!({
+            "products-v2": {
+                "settings": {
+                    "index": {
+                        "number_of_shards": "1",
+                        "analysis": index_settings["analysis"]
+                    }
+                }
+            }
+        });
+        assert_eq!(relevance_settings_from_response(&response), relevance);
+        assert_eq!(
+            relevance_settings_from_response(&json!({})),
+            RelevanceSettings {
+                synonyms: vec![],
+                stop_words: vec![],
+                ranking_rules: vec![],
+                provider_settings: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        for (name, expr) in conformance_cases() {
+            let query = SearchQuery {
+                q: None,
+                filters: vec![],
+                filter_expr: Some(expr),
+                vector_query: None,
+                sort: vec![],
+                facets: vec![],
+                aggregations: vec![],
+                page: None,
+                per_page: None,
+                offset: None,
+                highlight: None,
+                config: None,
+            };
+            let expected = match name {
+                "eq_text" => json!({"term": {"category": "books"}}),
+                "eq_integer" => json!({"term": {"year": 2020}}),
+                "eq_boolean" => json!({"term": {"in_stock": true}}),
+                "eq_escaped_text" => json!({"term": {"title": "say \"hi\""}}),
+                "ne" => json!({"bool": {"must_not": [{"term": {"category": "books"}}]}}),
+                "range" => json!({"range": {"price": {"gte": 10, "lt": 20.5}}}),
+                "in" => json!({"terms": {"category": ["books", "music"]}}),
+                "exists" => json!({"exists": {"field": "discount"}}),
+                "geo_radius" => json!({
+                    "geo_distance": {"distance": "5000m", "_geo": {"lat": 48.8566, "lon": 2.3522}}
+                }),
+                "and_or" => json!({"bool": {"filter": [
+                    {"term": {"category": "books"}},
+                    {"bool": {
+                        "should": [{"range": {"price": {"lt": 10}}}, {"term": {"in_stock": true}}],
+                        "minimum_should_match": 1
+                    }}
+                ]}}),
+                "not_and" => json!({"bool": {
+                    "should": [
+                        {"bool": {"must_not": [{"term": {"category": "books"}}]}},
+                        {"bool": {"must_not": [{"term": {"in_stock": true}}]}}
+                    ],
+                    "minimum_should_match": 1
+                }}),
+                "not_in" => {
+                    json!({"bool": {"must_not": [{"terms": {"category": ["books", "music"]}}]}})
+                }
+                "not_range" => {
+                    json!({"bool": {"must_not": [{"range": {"price": {"gte": 10, "lt": 20.5}}}]}})
+                }
+                _ => panic!("No expected Elasticsearch filter for case {name}"),
+            };
+            let request = search_query_to_elasticsearch_query(query).unwrap();
+            assert_eq!(
+                request.query.unwrap()["bool"]["filter"],
+                json!([expected]),
+                "filter case {name}"
+            );
+        }
+    }
 }
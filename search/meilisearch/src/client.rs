@@ -89,7 +89,7 @@ pub struct MeilisearchDocumentsResponse {
     pub total: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct MeilisearchDocumentFetchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
@@ -101,6 +101,8 @@ pub struct MeilisearchDocumentFetchRequest {
     pub filter: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ids: Option<Vec<String>>,
+    #[serde(rename = "retrieveVectors", skip_serializing_if = "Option::is_none")]
+    pub retrieve_vectors: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -140,6 +142,17 @@ pub struct MeilisearchSearchRequest {
     pub matching_strategy: Option<String>,
     #[serde(rename = "showRankingScore", skip_serializing_if = "Option::is_none")]
     pub show_ranking_score: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector: Option<Vec<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hybrid: Option<MeilisearchHybrid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeilisearchHybrid {
+    pub embedder: String,
+    #[serde(rename = "semanticRatio")]
+    pub semantic_ratio: f32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -154,6 +167,65 @@ pub struct MeilisearchSearchResponse {
     pub query: String,
     #[serde(rename = "facetDistribution", skip_serializing_if = "Option::is_none")]
     pub facet_distribution: Option<JsonMap<String, JsonValue>>,
+    #[serde(rename = "facetStats", skip_serializing_if = "Option::is_none")]
+    pub facet_stats: Option<JsonMap<String, JsonValue>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeilisearchMultiSearchRequest {
+    pub queries: Vec<MeilisearchMultiSearchQuery>,
+    /// Merges the hits of the queries into one list when set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federation: Option<MeilisearchFederation>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeilisearchMultiSearchQuery {
+    #[serde(rename = "indexUid")]
+    pub index_uid: String,
+    #[serde(flatten)]
+    pub request: MeilisearchSearchRequest,
+    #[serde(rename = "federationOptions", skip_serializing_if = "Option::is_none")]
+    pub federation_options: Option<MeilisearchFederationOptions>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MeilisearchFederationOptions {
+    pub weight: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MeilisearchFederation {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// Response of a multi-search without federation, a response per query
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeilisearchMultiSearchResponse {
+    pub results: Vec<MeilisearchSearchResponse>,
+}
+
+/// Response of a federated multi-search, each hit has a `_federation` field naming its query
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeilisearchFederatedSearchResponse {
+    pub hits: Vec<MeilisearchDocument>,
+    pub offset: u32,
+    pub limit: u32,
+    #[serde(rename = "estimatedTotalHits")]
+    pub estimated_total_hits: u32,
+    #[serde(rename = "processingTimeMs")]
+    pub processing_time_ms: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeilisearchHitFederation {
+    #[serde(rename = "indexUid")]
+    pub index_uid: String,
+    #[serde(rename = "queriesPosition")]
+    pub queries_position: u32,
+    #[serde(rename = "weightedRankingScore")]
+    pub weighted_ranking_score: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -189,6 +261,8 @@ pub struct MeilisearchSettings {
     pub faceting: Option<JsonValue>,
     #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
     pub pagination: Option<JsonValue>,
+    #[serde(rename = "embedders", skip_serializing_if = "Option::is_none")]
+    pub embedders: Option<JsonMap<String, JsonValue>>,
 }
 
 impl MeilisearchApi {
@@ -308,7 +382,7 @@ impl MeilisearchApi {
         parse_response(response)
     }
 
-    pub fn _get_documents(
+    pub fn get_documents(
         &self,
         index_uid: &str,
         request: &MeilisearchDocumentFetchRequest,
@@ -470,6 +544,26 @@ impl MeilisearchApi {
         parse_response(response)
     }
 
+    /// Runs the queries of a multi-search, the response is a
+    /// [`MeilisearchFederatedSearchResponse`] when the request has a federation and a
+    /// [`MeilisearchMultiSearchResponse`] otherwise
+    pub fn multi_search<T: DeserializeOwned + Debug>(
+        &self,
+        request: &MeilisearchMultiSearchRequest,
+    ) -> Result<T, SearchError> {
+        trace!("Running multi-search of {} queries", request.queries.len());
+
+        let url = format!("{}/multi-search", self.base_url);
+
+        let response = self
+            .create_request("POST", &url)
+            .json(request)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to run multi-search: {e}")))?;
+
+        parse_response(response)
+    }
+
     pub fn get_settings(&self, index_uid: &str) -> Result<MeilisearchSettings, SearchError> {
         trace!("Getting settings for index: {index_uid}");
 
@@ -514,6 +608,25 @@ impl MeilisearchApi {
         parse_response(response)
     }
 
+    /// Swaps the documents, settings and task history of each pair of indexes
+    pub fn swap_indexes(&self, pairs: &[(&str, &str)]) -> Result<MeilisearchTask, SearchError> {
+        trace!("Swapping indexes: {pairs:?}");
+
+        let url = format!("{}/swap-indexes", self.base_url);
+        let body = pairs
+            .iter()
+            .map(|(first, second)| serde_json::json!({ "indexes": [first, second] }))
+            .collect::<Vec<_>>();
+
+        let response = self
+            .create_request("POST", &url)
+            .json(&body)
+            .send()
+            .map_err(|e| internal_error(format!("Failed to swap indexes: {e}")))?;
+
+        parse_response(response)
+    }
+
     // Task Management (for checking async operation status)
     pub fn get_task(&self, task_uid: u64) -> Result<MeilisearchTask, SearchError> {
         trace!("Getting task: {task_uid}");
@@ -1,11 +1,34 @@
 use crate::client::{
-    MeilisearchDocument, MeilisearchSearchRequest, MeilisearchSearchResponse, MeilisearchSettings,
+    MeilisearchDocument, MeilisearchDocumentFetchRequest, MeilisearchFederatedSearchResponse,
+    MeilisearchFederation, MeilisearchFederationOptions, MeilisearchHitFederation,
+    MeilisearchHybrid, MeilisearchMultiSearchQuery, MeilisearchMultiSearchRequest,
+    MeilisearchSearchRequest, MeilisearchSearchResponse, MeilisearchSettings, MeilisearchTask,
+};
+use golem_search::aggregation::{facet_fields, field_facet_results, validate_aggregations};
+use golem_search::error::{invalid_query, unsupported};
+use golem_search::federation::{federated_limit, query_weight};
+use golem_search::filter::{
+    format_plain_value, parse_filter_expr, range_bounds, Condition, Filter,
 };
 use golem_search::golem::search::types::{
-    Doc, FieldType, Schema, SchemaField, SearchHit, SearchQuery, SearchResults,
+    AggregationKind, Doc, FederatedHit, FederatedResults, FederationOptions, FieldType,
+    FilterValue, IndexName, ReindexTransform, RelevanceSettings, Schema, SchemaField, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskState, TaskStatus, VectorConfig, VectorSimilarity,
 };
+use golem_search::relevance::{synonym_map, synonym_sets_from_map};
+use golem_search::vector::{insert_embeddings, validate_vector_query};
 use serde_json::{Map as JsonMap, Value as JsonValue};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+/// Ranking rules of a new Meilisearch index, used when the relevance settings have none
+const DEFAULT_RANKING_RULES: [&str; 6] = [
+    "words",
+    "typo",
+    "proximity",
+    "attribute",
+    "sort",
+    "exactness",
+];
 
 pub fn doc_to_meilisearch_document(doc: Doc) -> Result<MeilisearchDocument, String> {
     let mut meilisearch_doc = JsonMap::new();
@@ -18,6 +41,13 @@ pub fn doc_to_meilisearch_document(doc: Doc) -> Result<MeilisearchDocument, Stri
         }
     }
 
+    // User provided embeddings are stored under `_vectors`, keyed by the name of the embedder
+    if !doc.embeddings.is_empty() {
+        let mut vectors = JsonMap::new();
+        insert_embeddings(&mut vectors, doc.embeddings);
+        meilisearch_doc.insert("_vectors".to_string(), JsonValue::Object(vectors));
+    }
+
     Ok(meilisearch_doc)
 }
 
@@ -34,19 +64,37 @@ pub fn meilisearch_document_to_doc(mut doc: MeilisearchDocument) -> Doc {
     let content =
         serde_json::to_string(&JsonValue::Object(doc)).unwrap_or_else(|_| "{}".to_string());
 
-    Doc { id, content }
+    Doc {
+        id,
+        content,
+        embeddings: vec![],
+    }
 }
 
-pub fn search_query_to_meilisearch_request(query: SearchQuery) -> MeilisearchSearchRequest {
+pub fn search_query_to_meilisearch_request(
+    query: SearchQuery,
+) -> Result<MeilisearchSearchRequest, SearchError> {
+    validate_aggregations(&query)?;
+    if query
+        .aggregations
+        .iter()
+        .any(|aggregation| !matches!(aggregation.kind, AggregationKind::Stats))
+    {
+        return Err(unsupported(
+            "Range and date histogram aggregations in Meilisearch",
+        ));
+    }
+    let facets = facet_fields(&query);
+
     let mut request = MeilisearchSearchRequest {
         q: query.q,
         offset: query.offset,
         limit: query.per_page,
         filter: None,
-        facets: if query.facets.is_empty() {
+        facets: if facets.is_empty() {
             None
         } else {
-            Some(query.facets)
+            Some(facets)
         },
         sort: if query.sort.is_empty() {
             None
@@ -72,17 +120,38 @@ pub fn search_query_to_meilisearch_request(query: SearchQuery) -> MeilisearchSea
         show_matches_position: None,
         matching_strategy: None,
         show_ranking_score: None,
+        vector: None,
+        hybrid: None,
     };
 
-    if !query.filters.is_empty() {
-        request.filter = Some(convert_filters_to_meilisearch(query.filters));
+    if let Some(vector_query) = query.vector_query {
+        validate_vector_query(&vector_query)?;
+        // Without a hybrid weight the hits are ranked by the vector similarity alone
+        request.hybrid = Some(MeilisearchHybrid {
+            embedder: vector_query.field,
+            semantic_ratio: vector_query.hybrid_weight.unwrap_or(1.0),
+        });
+        request.vector = Some(vector_query.embedding);
+        request.limit = request.limit.or(Some(vector_query.k));
+    }
+
+    let mut filters = query.filters;
+    if let Some(expr) = &query.filter_expr {
+        let filter = parse_filter_expr(expr)?;
+        filters.push(filter_to_meilisearch_operand(&filter)?);
+    }
+    if !filters.is_empty() {
+        request.filter = Some(convert_filters_to_meilisearch(filters));
     }
 
-    request
+    Ok(request)
 }
 
+/// Converts a search response, `query` is the search query the response belongs to.
+/// Meilisearch only computes the minimum and maximum of numeric facets.
 pub fn meilisearch_response_to_search_results(
     response: MeilisearchSearchResponse,
+    query: &SearchQuery,
 ) -> SearchResults {
     let hits: Vec<SearchHit> = response
         .hits
@@ -103,9 +172,84 @@ pub fn meilisearch_response_to_search_results(
         page: None, // We'd need to calculate this from offset and limit
         per_page: Some(response.limit),
         hits,
-        facets: response
-            .facet_distribution
-            .map(|facets| serde_json::to_string(&facets).unwrap_or_default()),
+        facets: field_facet_results(
+            query,
+            &response.facet_distribution.unwrap_or_default(),
+            &response.facet_stats.unwrap_or_default(),
+        ),
+        took_ms: Some(response.processing_time_ms),
+    }
+}
+
+/// Multi-search request of the queries. With a federation, Meilisearch merges and ranks the hits
+/// itself, so the queries are paged by the federation instead of their own offset and limit.
+pub fn multi_search_request(
+    queries: &[(IndexName, SearchQuery)],
+    federation: Option<&FederationOptions>,
+) -> Result<MeilisearchMultiSearchRequest, SearchError> {
+    let queries = queries
+        .iter()
+        .enumerate()
+        .map(|(position, (index, query))| {
+            let mut request = search_query_to_meilisearch_request(query.clone())?;
+            let federation_options = federation.map(|federation| {
+                request.offset = None;
+                request.limit = None;
+                MeilisearchFederationOptions {
+                    weight: query_weight(federation, position),
+                }
+            });
+            Ok(MeilisearchMultiSearchQuery {
+                index_uid: index.clone(),
+                request,
+                federation_options,
+            })
+        })
+        .collect::<Result<Vec<_>, SearchError>>()?;
+
+    Ok(MeilisearchMultiSearchRequest {
+        queries,
+        federation: federation.map(|federation| MeilisearchFederation {
+            offset: federation.offset.unwrap_or(0),
+            limit: federated_limit(federation),
+        }),
+    })
+}
+
+/// Converts the response of a federated multi-search, the score of a hit is its weighted ranking
+/// score
+pub fn meilisearch_federated_response_to_results(
+    response: MeilisearchFederatedSearchResponse,
+) -> FederatedResults {
+    let hits = response
+        .hits
+        .into_iter()
+        .map(|mut doc| {
+            let federation = doc.remove("_federation").and_then(|federation| {
+                serde_json::from_value::<MeilisearchHitFederation>(federation).ok()
+            });
+            let converted_doc = meilisearch_document_to_doc(doc);
+            FederatedHit {
+                index: federation
+                    .as_ref()
+                    .map(|federation| federation.index_uid.clone())
+                    .unwrap_or_default(),
+                query_position: federation
+                    .as_ref()
+                    .map_or(0, |federation| federation.queries_position),
+                hit: SearchHit {
+                    id: converted_doc.id,
+                    score: federation.and_then(|federation| federation.weighted_ranking_score),
+                    content: Some(converted_doc.content),
+                    highlights: None,
+                },
+            }
+        })
+        .collect();
+
+    FederatedResults {
+        total: Some(response.estimated_total_hits),
+        hits,
         took_ms: Some(response.processing_time_ms),
     }
 }
@@ -117,7 +261,23 @@ pub fn schema_to_meilisearch_settings(schema: Schema) -> MeilisearchSettings {
     let mut filterable_attributes = Vec::new();
     let mut sortable_attributes = Vec::new();
 
+    let mut embedders = JsonMap::new();
+
     for field in schema.fields {
+        // Vector fields are user provided embedders, their embeddings are not document attributes
+        if field.field_type == FieldType::Vector {
+            if let Some(config) = field.vector {
+                embedders.insert(
+                    field.name,
+                    serde_json::json!({
+                        "source": "userProvided",
+                        "dimensions": config.dimensions
+                    }),
+                );
+            }
+            continue;
+        }
+
         if field.index {
             searchable_attributes.push(field.name.clone());
         }
@@ -143,6 +303,10 @@ pub fn schema_to_meilisearch_settings(schema: Schema) -> MeilisearchSettings {
         settings.sortable_attributes = Some(sortable_attributes);
     }
 
+    if !embedders.is_empty() {
+        settings.embedders = Some(embedders);
+    }
+
     settings
 }
 
@@ -172,6 +336,7 @@ pub fn meilisearch_settings_to_schema(settings: MeilisearchSettings) -> Schema {
             facet: false,
             sort: false,
             index: true,
+            vector: None,
         });
     } else {
         for field_name in field_names {
@@ -200,6 +365,25 @@ pub fn meilisearch_settings_to_schema(settings: MeilisearchSettings) -> Schema {
                 facet,
                 sort,
                 index,
+                vector: None,
+            });
+        }
+    }
+
+    for (name, embedder) in settings.embedders.iter().flatten() {
+        if let Some(dimensions) = embedder.get("dimensions").and_then(|d| d.as_u64()) {
+            fields.push(SchemaField {
+                name: name.clone(),
+                field_type: FieldType::Vector,
+                required: false,
+                facet: false,
+                sort: false,
+                index: true,
+                // Meilisearch always compares the embeddings by cosine similarity
+                vector: Some(VectorConfig {
+                    dimensions: dimensions as u32,
+                    similarity: VectorSimilarity::Cosine,
+                }),
             });
         }
     }
@@ -234,33 +418,224 @@ fn convert_filters_to_meilisearch(filters: Vec<String>) -> String {
     filters.join(" AND ")
 }
 
-// for later development :-
-fn _convert_meilisearch_facets_to_golem(
-    facets: JsonMap<String, JsonValue>,
-) -> HashMap<String, HashMap<String, u64>> {
-    let mut result = HashMap::new();
-
-    for (facet_name, facet_value) in facets {
-        if let JsonValue::Object(facet_map) = facet_value {
-            let mut facet_counts = HashMap::new();
-            for (value, count) in facet_map {
-                if let JsonValue::Number(n) = count {
-                    if let Some(count_u64) = n.as_u64() {
-                        facet_counts.insert(value, count_u64);
-                    }
-                }
+/// Compiles a typed filter to the Meilisearch filter syntax
+pub fn filter_to_meilisearch(filter: &Filter) -> Result<String, SearchError> {
+    match filter {
+        Filter::Is(condition) => condition_to_meilisearch(condition, false),
+        Filter::IsNot(condition) => condition_to_meilisearch(condition, true),
+        Filter::And(operands) => join_meilisearch_operands(operands, " AND "),
+        Filter::Or(operands) => join_meilisearch_operands(operands, " OR "),
+    }
+}
+
+/// Compiles a filter to be combined with other expressions, parenthesized if it is compound
+fn filter_to_meilisearch_operand(filter: &Filter) -> Result<String, SearchError> {
+    let compiled = filter_to_meilisearch(filter)?;
+    Ok(match filter {
+        Filter::And(operands) | Filter::Or(operands) if operands.len() > 1 => {
+            format!("({compiled})")
+        }
+        _ => compiled,
+    })
+}
+
+fn join_meilisearch_operands(operands: &[Filter], separator: &str) -> Result<String, SearchError> {
+    Ok(operands
+        .iter()
+        .map(filter_to_meilisearch_operand)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(separator))
+}
+
+fn condition_to_meilisearch(condition: &Condition, negated: bool) -> Result<String, SearchError> {
+    let not = if negated { "NOT " } else { "" };
+    Ok(match condition {
+        Condition::Eq(filter) => {
+            let operator = if negated { "!=" } else { "=" };
+            format!(
+                "{} {operator} {}",
+                filter.field,
+                meilisearch_value(&filter.value)
+            )
+        }
+        Condition::Range(filter) => {
+            let bounds = range_bounds(filter)
+                .into_iter()
+                .map(|(operator, value)| {
+                    format!("{} {operator} {}", filter.field, meilisearch_value(value))
+                })
+                .collect::<Vec<_>>();
+            if negated {
+                format!("NOT ({})", bounds.join(" AND "))
+            } else {
+                bounds.join(" AND ")
+            }
+        }
+        Condition::In(filter) => format!(
+            "{} {not}IN [{}]",
+            filter.field,
+            filter
+                .values
+                .iter()
+                .map(meilisearch_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Condition::Exists(field) => format!("{field} {not}EXISTS"),
+        Condition::GeoRadius(filter) => {
+            if filter.field != "_geo" {
+                return Err(unsupported(format!(
+                    "Meilisearch only supports geo filters on the _geo field, not on {}",
+                    filter.field
+                )));
             }
-            result.insert(facet_name, facet_counts);
+            format!(
+                "{not}_geoRadius({}, {}, {})",
+                filter.latitude, filter.longitude, filter.radius_meters
+            )
         }
+    })
+}
+
+fn meilisearch_value(value: &FilterValue) -> String {
+    match value {
+        FilterValue::Text(text) => {
+            format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+        value => format_plain_value(value).unwrap_or_default(),
     }
+}
 
-    result
+/// Fetch of the documents copied by a reindex, without its page. The `id` primary key is always
+/// fetched, so the documents keep their ids in the target index.
+pub fn reindex_fetch_request(
+    transform: Option<&ReindexTransform>,
+) -> Result<MeilisearchDocumentFetchRequest, SearchError> {
+    let Some(transform) = transform else {
+        return Ok(MeilisearchDocumentFetchRequest::default());
+    };
+
+    let filter = match &transform.filter {
+        Some(expr) => Some(filter_to_meilisearch(&parse_filter_expr(expr)?)?),
+        None => None,
+    };
+
+    let fields = if transform.fields.is_empty() {
+        None
+    } else {
+        let mut fields = vec!["id".to_string()];
+        fields.extend(
+            transform
+                .fields
+                .iter()
+                .filter(|field| field.as_str() != "id")
+                .cloned(),
+        );
+        Some(fields)
+    };
+
+    Ok(MeilisearchDocumentFetchRequest {
+        filter,
+        fields,
+        ..Default::default()
+    })
+}
+
+/// Settings replacing the synonyms, stop words and ranking rules of an index. Meilisearch uses
+/// the same `field:asc` / `field:desc` syntax for its custom ranking rules.
+pub fn relevance_settings_to_meilisearch(
+    settings: RelevanceSettings,
+) -> Result<MeilisearchSettings, SearchError> {
+    if settings.provider_settings.is_some() {
+        return Err(unsupported("Provider relevance settings in Meilisearch"));
+    }
+
+    let ranking_rules = if settings.ranking_rules.is_empty() {
+        DEFAULT_RANKING_RULES.map(str::to_string).to_vec()
+    } else {
+        settings.ranking_rules
+    };
+
+    let synonyms = synonym_map(&settings.synonyms)
+        .into_iter()
+        .map(|(term, synonyms)| (term, JsonValue::from(synonyms)))
+        .collect();
+
+    Ok(MeilisearchSettings {
+        ranking_rules: Some(ranking_rules),
+        stop_words: Some(settings.stop_words),
+        synonyms: Some(synonyms),
+        ..Default::default()
+    })
+}
+
+pub fn meilisearch_settings_to_relevance(settings: MeilisearchSettings) -> RelevanceSettings {
+    let synonyms: BTreeMap<String, Vec<String>> = settings
+        .synonyms
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(term, synonyms)| {
+            let synonyms = synonyms
+                .as_array()
+                .map(|synonyms| {
+                    synonyms
+                        .iter()
+                        .filter_map(|synonym| synonym.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (term, synonyms)
+        })
+        .collect();
+
+    let ranking_rules = settings
+        .ranking_rules
+        .filter(|rules| *rules != DEFAULT_RANKING_RULES)
+        .unwrap_or_default();
+
+    RelevanceSettings {
+        synonyms: synonym_sets_from_map(&synonyms),
+        stop_words: settings.stop_words.unwrap_or_default(),
+        ranking_rules,
+        provider_settings: None,
+    }
+}
+
+/// Meilisearch uid of a task id returned by a write
+pub fn parse_task_uid(id: &str) -> Result<u64, SearchError> {
+    id.parse()
+        .map_err(|_| invalid_query(format!("Unknown task: {id}")))
+}
+
+/// Status of a Meilisearch task, a canceled task did not apply its write so it is reported as
+/// failed
+pub fn meilisearch_task_to_status(task: MeilisearchTask) -> TaskStatus {
+    let (state, error) = match task.status.as_str() {
+        "enqueued" => (TaskState::Enqueued, None),
+        "processing" => (TaskState::Processing, None),
+        "succeeded" => (TaskState::Succeeded, None),
+        "failed" => (TaskState::Failed, task.error.map(|error| error.message)),
+        "canceled" => (TaskState::Failed, Some("Task was canceled".to_string())),
+        status => (
+            TaskState::Failed,
+            Some(format!("Unknown task status: {status}")),
+        ),
+    };
+    TaskStatus {
+        id: task.task_uid.to_string(),
+        state,
+        error,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use golem_search::golem::search::types::{HighlightConfig, SearchConfig};
+    use golem_search::test_util::conformance_cases;
+    use golem_search::golem::search::types::{
+        Aggregation, Embedding, FilterExpr, FilterNode, HighlightConfig, RangeFilter, SearchConfig,
+        SynonymSet, VectorQuery,
+    };
     use serde_json::json;
 
     #[test]
@@ -268,6 +643,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: r#"{"title": "Test Document", "content": "This is a test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let meilisearch_doc = doc_to_meilisearch_document(doc).unwrap();
@@ -281,6 +657,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: "invalid json".to_string(),
+            embeddings: vec![],
         };
 
         let result = doc_to_meilisearch_document(doc);
@@ -341,8 +718,11 @@ mod tests {
                 "category = electronics".to_string(),
                 "price > 100".to_string(),
             ],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["price:desc".to_string()],
             facets: vec!["category".to_string(), "brand".to_string()],
+            aggregations: vec![],
             page: None,
             per_page: Some(20),
             offset: Some(10),
@@ -355,7 +735,7 @@ mod tests {
             config: None,
         };
 
-        let meilisearch_request = search_query_to_meilisearch_request(search_query);
+        let meilisearch_request = search_query_to_meilisearch_request(search_query).unwrap();
         assert_eq!(meilisearch_request.q, Some("test query".to_string()));
         assert_eq!(
             meilisearch_request.filter,
@@ -378,8 +758,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -397,7 +780,7 @@ mod tests {
             }),
         };
 
-        let meilisearch_request = search_query_to_meilisearch_request(search_query);
+        let meilisearch_request = search_query_to_meilisearch_request(search_query).unwrap();
         assert_eq!(
             meilisearch_request.attributes_to_retrieve,
             Some(vec!["title".to_string(), "price".to_string()])
@@ -415,6 +798,7 @@ mod tests {
                     facet: false,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "category".to_string(),
@@ -423,6 +807,7 @@ mod tests {
                     facet: true,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "price".to_string(),
@@ -431,6 +816,7 @@ mod tests {
                     facet: true,
                     sort: true,
                     index: false,
+                    vector: None,
                 },
             ],
             primary_key: Some("id".to_string()),
@@ -510,16 +896,44 @@ mod tests {
             offset: 0,
             processing_time_ms: 5,
             facet_distribution: Some(facet_distribution),
+            facet_stats: json!({"price": {"min": 5.0, "max": 20.0}})
+                .as_object()
+                .cloned(),
             query: "test".to_string(),
         };
+        let query = SearchQuery {
+            q: Some("test".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["category".to_string()],
+            aggregations: vec![Aggregation {
+                name: "price_stats".to_string(),
+                field: "price".to_string(),
+                kind: AggregationKind::Stats,
+            }],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
 
-        let search_results = meilisearch_response_to_search_results(meilisearch_response);
+        let search_results = meilisearch_response_to_search_results(meilisearch_response, &query);
         assert_eq!(search_results.total, Some(2));
         assert_eq!(search_results.per_page, Some(20));
         assert_eq!(search_results.hits.len(), 2);
         assert_eq!(search_results.hits[0].id, "doc1");
         assert_eq!(search_results.hits[1].id, "doc2");
-        assert!(search_results.facets.is_some());
+        assert_eq!(search_results.facets.len(), 2);
+        assert_eq!(search_results.facets[0].buckets.len(), 2);
+        assert_eq!(search_results.facets[0].buckets[0].value, "books");
+        let stats = search_results.facets[1].stats.as_ref().unwrap();
+        assert_eq!(
+            (stats.min, stats.max, stats.avg),
+            (Some(5.0), Some(20.0), None)
+        );
         assert_eq!(search_results.took_ms, Some(5));
     }
 
@@ -528,8 +942,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(10),
             offset: Some(20),
@@ -553,8 +970,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(2),
             offset: Some(10),
@@ -592,23 +1012,387 @@ mod tests {
     }
 
     #[test]
-    fn test_convert_meilisearch_facets_to_golem() {
-        let facets = json!({
-            "category": {
-                "electronics": 5,
-                "books": 3
-            }
-        })
-        .as_object()
-        .unwrap()
-        .clone();
+    fn test_field_facet_results() {
+        let query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["category".to_string()],
+            aggregations: vec![Aggregation {
+                name: "price_stats".to_string(),
+                field: "price".to_string(),
+                kind: AggregationKind::Stats,
+            }],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        let facet_distribution = json!({"category": {"electronics": 5, "books": 3}});
+        let facet_stats = json!({"price": {"min": 2.5, "max": 90.0}});
 
-        let golem_facets = _convert_meilisearch_facets_to_golem(facets);
-        assert_eq!(golem_facets.len(), 1);
+        let facets = field_facet_results(
+            &query,
+            facet_distribution.as_object().unwrap(),
+            facet_stats.as_object().unwrap(),
+        );
+        assert_eq!(facets.len(), 2);
+        assert_eq!(facets[0].name, "category");
+        let buckets = facets[0]
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.value.as_str(), bucket.count))
+            .collect::<Vec<_>>();
+        assert_eq!(buckets, vec![("electronics", 5), ("books", 3)]);
+        assert_eq!(facets[0].stats, None);
+        assert_eq!(facets[1].name, "price_stats");
+        let stats = facets[1].stats.as_ref().unwrap();
+        assert_eq!(
+            (stats.min, stats.max, stats.avg),
+            (Some(2.5), Some(90.0), None)
+        );
+    }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        for (name, expr) in conformance_cases() {
+            let filter = parse_filter_expr(&expr).unwrap();
+            let expected = match name {
+                "eq_text" => r#"category = "books""#,
+                "eq_integer" => "year = 2020",
+                "eq_boolean" => "in_stock = true",
+                "eq_escaped_text" => r#"title = "say \"hi\"""#,
+                "ne" => r#"category != "books""#,
+                "range" => "price >= 10 AND price < 20.5",
+                "in" => r#"category IN ["books", "music"]"#,
+                "exists" => "discount EXISTS",
+                "geo_radius" => "_geoRadius(48.8566, 2.3522, 5000)",
+                "and_or" => r#"category = "books" AND (price < 10 OR in_stock = true)"#,
+                "not_and" => r#"category != "books" OR in_stock != true"#,
+                "not_in" => r#"category NOT IN ["books", "music"]"#,
+                "not_range" => "NOT (price >= 10 AND price < 20.5)",
+                _ => panic!("No expected Meilisearch filter for case {name}"),
+            };
+            assert_eq!(
+                filter_to_meilisearch(&filter).unwrap(),
+                expected,
+                "filter case {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_expr_combined_with_filters() {
+        let (_, expr) = conformance_cases()
+            .into_iter()
+            .find(|(name, _)| *name == "not_and")
+            .unwrap();
+        let search_query = SearchQuery {
+            q: None,
+            filters: vec!["year > 2000".to_string()],
+            filter_expr: Some(expr),
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let meilisearch_request = search_query_to_meilisearch_request(search_query).unwrap();
+        assert_eq!(
+            meilisearch_request.filter,
+            Some(r#"year > 2000 AND (category != "books" OR in_stock != true)"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_search_query_with_vector_query() {
+        let search_query = SearchQuery {
+            q: Some("space opera".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.5, -0.25],
+                k: 5,
+                hybrid_weight: Some(0.75),
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let meilisearch_request = search_query_to_meilisearch_request(search_query).unwrap();
+        assert_eq!(meilisearch_request.vector, Some(vec![0.5, -0.25]));
+        assert_eq!(
+            meilisearch_request.hybrid,
+            Some(MeilisearchHybrid {
+                embedder: "title_vector".to_string(),
+                semantic_ratio: 0.75,
+            })
+        );
+        assert_eq!(meilisearch_request.limit, Some(5));
+    }
+
+    #[test]
+    fn test_doc_with_embeddings_to_meilisearch_document() {
+        let doc = Doc {
+            id: "test-id".to_string(),
+            content: r#"{"title": "Test Document"}"#.to_string(),
+            embeddings: vec![Embedding {
+                field: "title_vector".to_string(),
+                values: vec![0.5, -0.25],
+            }],
+        };
+
+        let meilisearch_doc = doc_to_meilisearch_document(doc).unwrap();
+        assert_eq!(
+            meilisearch_doc.get("_vectors"),
+            Some(&json!({"title_vector": [0.5, -0.25]}))
+        );
+    }
+
+    #[test]
+    fn test_reindex_fetch_request() {
+        assert_eq!(
+            reindex_fetch_request(None).unwrap(),
+            MeilisearchDocumentFetchRequest::default()
+        );
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(2000)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec!["title".to_string(), "year".to_string()],
+        };
+        let request = reindex_fetch_request(Some(&transform)).unwrap();
+        assert_eq!(request.filter, Some("year >= 2000".to_string()));
+        assert_eq!(
+            request.fields,
+            Some(vec![
+                "id".to_string(),
+                "title".to_string(),
+                "year".to_string()
+            ])
+        );
+        assert_eq!(request.offset, None);
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr { nodes: vec![] }),
+            fields: vec![],
+        };
+        assert!(matches!(
+            reindex_fetch_request(Some(&transform)),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_relevance_settings_roundtrip() {
+        let relevance = RelevanceSettings {
+            synonyms: vec![
+                SynonymSet {
+                    synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                    root: None,
+                },
+                SynonymSet {
+                    synonyms: vec!["television".to_string()],
+                    root: Some("tv".to_string()),
+                },
+            ],
+            stop_words: vec!["the".to_string()],
+            ranking_rules: vec![],
+            provider_settings: None,
+        };
+
+        let settings = relevance_settings_to_meilisearch(relevance.clone()).unwrap();
+        assert_eq!(
+            settings.ranking_rules.as_deref().map(|rules| rules.len()),
+            Some(DEFAULT_RANKING_RULES.len())
+        );
+        assert_eq!(
+            settings.synonyms.as_ref().unwrap().get("notebook"),
+            Some(&json!(["laptop"]))
+        );
+        assert_eq!(
+            settings.synonyms.as_ref().unwrap().get("tv"),
+            Some(&json!(["television"]))
+        );
+        assert_eq!(meilisearch_settings_to_relevance(settings), relevance);
+
+        let custom = RelevanceSettings {
+            ranking_rules: vec!["words".to_string(), "year:desc".to_string()],
+            ..relevance.clone()
+        };
+        let settings = relevance_settings_to_meilisearch(custom.clone()).unwrap();
+        assert_eq!(meilisearch_settings_to_relevance(settings), custom);
+
+        let provider = RelevanceSettings {
+            provider_settings: Some("{}".to_string()),
+            ..relevance
+        };
+        assert!(matches!(
+            relevance_settings_to_meilisearch(provider),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
+    #[test]
+    fn test_multi_search_request() {
+        let query = SearchQuery {
+            q: Some("golem".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(30),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        let queries = vec![
+            ("products".to_string(), query.clone()),
+            ("articles".to_string(), query),
+        ];
+
+        let request = multi_search_request(&queries, None).unwrap();
+        assert_eq!(request.federation, None);
+        assert_eq!(request.queries[1].index_uid, "articles");
+        assert_eq!(request.queries[1].request.limit, Some(30));
+        assert_eq!(request.queries[1].federation_options, None);
+
+        let federation = FederationOptions {
+            weights: vec![2.0],
+            offset: Some(10),
+            limit: None,
+        };
+        let request = multi_search_request(&queries, Some(&federation)).unwrap();
+        assert_eq!(
+            request.federation,
+            Some(MeilisearchFederation {
+                offset: 10,
+                limit: 20
+            })
+        );
+        assert_eq!(request.queries[0].request.limit, None);
+        assert_eq!(
+            request.queries[0].federation_options,
+            Some(MeilisearchFederationOptions { weight: 2.0 })
+        );
+        assert_eq!(
+            request.queries[1].federation_options,
+            Some(MeilisearchFederationOptions { weight: 1.0 })
+        );
+    }
+
+    #[test]
+    fn test_meilisearch_federated_response_to_results() {
+        let response: MeilisearchFederatedSearchResponse = serde_json::from_value(json!({
+            "hits": [
+                {
+                    "id": "a1",
+                    "title": "Golem guide",
+                    "_federation": {
+                        "indexUid": "articles",
+                        "queriesPosition": 1,
+                        "weightedRankingScore": 0.9
+                    }
+                },
+                {
+                    "id": 7,
+                    "title": "Golem figure",
+                    "_federation": {
+                        "indexUid": "products",
+                        "queriesPosition": 0,
+                        "weightedRankingScore": 0.4
+                    }
+                }
+            ],
+            "processingTimeMs": 3,
+            "limit": 20,
+            "offset": 0,
+            "estimatedTotalHits": 2
+        }))
+        .unwrap();
+
+        let results = meilisearch_federated_response_to_results(response);
+        assert_eq!(results.total, Some(2));
+        assert_eq!(results.took_ms, Some(3));
+        assert_eq!(results.hits[0].index, "articles");
+        assert_eq!(results.hits[0].query_position, 1);
+        assert_eq!(results.hits[0].hit.id, "a1");
+        assert_eq!(results.hits[0].hit.score, Some(0.9));
         assert_eq!(
-            golem_facets.get("category").unwrap().get("electronics"),
-            Some(&5)
+            results.hits[0].hit.content.as_deref(),
+            Some(r#"{"title":"Golem guide"}"#)
         );
-        assert_eq!(golem_facets.get("category").unwrap().get("books"), Some(&3));
+        assert_eq!(results.hits[1].index, "products");
+        assert_eq!(results.hits[1].hit.id, "7");
+    }
+
+    #[test]
+    fn test_meilisearch_task_to_status() {
+        let task = |status: &str, error: JsonValue| -> MeilisearchTask {
+            serde_json::from_value(json!({
+                "uid": 17,
+                "indexUid": "products",
+                "status": status,
+                "type": "documentAdditionOrUpdate",
+                "enqueuedAt": "2024-08-15T10:20:30Z",
+                "error": error
+            }))
+            .unwrap()
+        };
+
+        let status = meilisearch_task_to_status(task("processing", JsonValue::Null));
+        assert_eq!(status.id, "17");
+        assert_eq!(status.state, TaskState::Processing);
+        assert_eq!(status.error, None);
+
+        let failed = meilisearch_task_to_status(task(
+            "failed",
+            json!({
+                "message": "Document identifier `a b` is invalid.",
+                "code": "invalid_document_id",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#invalid_document_id"
+            }),
+        ));
+        assert_eq!(failed.state, TaskState::Failed);
+        assert_eq!(
+            failed.error.as_deref(),
+            Some("Document identifier `a b` is invalid.")
+        );
+
+        let canceled = meilisearch_task_to_status(task("canceled", JsonValue::Null));
+        assert_eq!(canceled.state, TaskState::Failed);
+        assert!(canceled.error.is_some());
+
+        assert_eq!(parse_task_uid("17").unwrap(), 17);
+        assert!(matches!(
+            parse_task_uid("products:17"),
+            Err(SearchError::InvalidQuery(_))
+        ));
     }
 }
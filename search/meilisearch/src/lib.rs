@@ -1,16 +1,27 @@
-use crate::client::MeilisearchApi;
+use crate::client::{MeilisearchApi, MeilisearchMultiSearchResponse};
 use crate::conversions::{
     create_retry_query, doc_to_meilisearch_document, meilisearch_document_to_doc,
-    meilisearch_response_to_search_results, meilisearch_settings_to_schema,
+    meilisearch_federated_response_to_results, meilisearch_response_to_search_results,
+    meilisearch_settings_to_relevance, meilisearch_settings_to_schema, meilisearch_task_to_status,
+    multi_search_request, parse_task_uid, reindex_fetch_request, relevance_settings_to_meilisearch,
     schema_to_meilisearch_settings, search_query_to_meilisearch_request,
 };
 use golem_rust::wasm_rpc::Pollable;
+use golem_search::bulk::bulk_write;
 use golem_search::config::with_config_keys;
+use golem_search::consistency::{bulk_consistency, waits_for_task, write_consistency};
 use golem_search::durability::{DurableSearch, ExtendedGuest};
+use golem_search::error::{invalid_query, unsupported};
+use golem_search::federation::multi_search_queries;
 use golem_search::golem::search::core::{Guest, GuestSearchStream, SearchStream};
 use golem_search::golem::search::types::{
-    Doc, DocumentId, IndexName, Schema, SearchError, SearchHit, SearchQuery, SearchResults,
+    BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+    MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
 };
+use golem_search::reindex::{run_reindex, ReindexTask};
+use golem_search::relevance::validate_relevance_settings;
+use golem_search::vector::validate_vector_fields;
 use std::cell::{Cell, RefCell};
 
 mod client;
@@ -56,11 +67,14 @@ impl GuestSearchStream for MeilisearchSearchStream {
 
         search_query.offset = Some(current_page * limit);
 
-        let meilisearch_request = search_query_to_meilisearch_request(search_query);
+        let Ok(meilisearch_request) = search_query_to_meilisearch_request(search_query) else {
+            self.finished.set(true);
+            return Some(vec![]);
+        };
 
         match self.client.search(&self.index_name, &meilisearch_request) {
             Ok(response) => {
-                let search_results = meilisearch_response_to_search_results(response);
+                let search_results = meilisearch_response_to_search_results(response, &self.query);
 
                 if search_results.hits.is_empty() {
                     self.finished.set(true);
@@ -105,6 +119,8 @@ struct MeilisearchComponent;
 impl MeilisearchComponent {
     const BASE_URL_ENV_VAR: &'static str = "MEILISEARCH_BASE_URL";
     const API_KEY_ENV_VAR: &'static str = "MEILISEARCH_API_KEY";
+    /// Number of documents copied by one request of a reindex
+    const REINDEX_BATCH_SIZE: u32 = 1000;
 
     fn create_client() -> Result<MeilisearchApi, SearchError> {
         with_config_keys(&[Self::BASE_URL_ENV_VAR], |keys| {
@@ -127,6 +143,9 @@ impl Guest for MeilisearchComponent {
     type SearchStream = MeilisearchSearchStream;
 
     fn create_index(name: IndexName, schema: Option<Schema>) -> Result<(), SearchError> {
+        if let Some(schema) = &schema {
+            validate_vector_fields(schema)?;
+        }
         let client = Self::create_client()?;
 
         let create_request = client::MeilisearchCreateIndexRequest {
@@ -167,49 +186,91 @@ impl Guest for MeilisearchComponent {
             .collect())
     }
 
-    fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+    fn upsert(
+        index: IndexName,
+        doc: Doc,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
         let meilisearch_doc =
             doc_to_meilisearch_document(doc).map_err(SearchError::InvalidQuery)?;
 
         let task = client.add_documents(&index, &[meilisearch_doc])?;
-        client.wait_for_task(task.task_uid)?;
+        if waits_for_task(write_consistency(options.as_ref())) {
+            client.wait_for_task(task.task_uid)?;
+        }
 
-        Ok(())
+        Ok(Some(task.task_uid.to_string()))
     }
 
-    fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+    fn upsert_many(
+        index: IndexName,
+        docs: Vec<Doc>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-        let mut meilisearch_docs = Vec::new();
-
-        for doc in docs {
-            let meilisearch_doc =
-                doc_to_meilisearch_document(doc).map_err(SearchError::InvalidQuery)?;
-            meilisearch_docs.push(meilisearch_doc);
-        }
-
-        let task = client.add_documents(&index, &meilisearch_docs)?;
-        client.wait_for_task(task.task_uid)?;
-
-        Ok(())
+        let wait = waits_for_task(bulk_consistency(options.as_ref()));
+        let mut tasks = Vec::new();
+
+        // A Meilisearch task fails as a whole, so the documents of a chunk share its outcome
+        let mut result = bulk_write(
+            docs,
+            options.as_ref(),
+            |doc| doc.id.clone(),
+            |doc| doc_to_meilisearch_document(doc).map_err(SearchError::InvalidQuery),
+            |meilisearch_docs| {
+                let task = client.add_documents(&index, &meilisearch_docs)?;
+                tasks.push(task.task_uid.to_string());
+                if wait {
+                    client.wait_for_task(task.task_uid)?;
+                }
+                Ok(vec![Ok(()); meilisearch_docs.len()])
+            },
+        )?;
+        result.tasks = tasks;
+        Ok(result)
     }
 
-    fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+    fn delete(
+        index: IndexName,
+        id: DocumentId,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
 
         let task = client.delete_document(&index, &id)?;
-        client.wait_for_task(task.task_uid)?;
+        if waits_for_task(write_consistency(options.as_ref())) {
+            client.wait_for_task(task.task_uid)?;
+        }
 
-        Ok(())
+        Ok(Some(task.task_uid.to_string()))
     }
 
-    fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+    fn delete_many(
+        index: IndexName,
+        ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-
-        let task = client.delete_documents(&index, &ids)?;
-        client.wait_for_task(task.task_uid)?;
-
-        Ok(())
+        let wait = waits_for_task(bulk_consistency(options.as_ref()));
+        let mut tasks = Vec::new();
+
+        let mut result = bulk_write(
+            ids,
+            options.as_ref(),
+            |id| id.clone(),
+            Ok,
+            |ids| {
+                let task = client.delete_documents(&index, &ids)?;
+                tasks.push(task.task_uid.to_string());
+                if wait {
+                    client.wait_for_task(task.task_uid)?;
+                }
+                Ok(vec![Ok(()); ids.len()])
+            },
+        )?;
+        result.tasks = tasks;
+        Ok(result)
     }
 
     fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
@@ -221,16 +282,50 @@ impl Guest for MeilisearchComponent {
         }
     }
 
+    fn get_task_status(id: TaskId) -> Result<TaskStatus, SearchError> {
+        let task_uid = parse_task_uid(&id)?;
+        let client = Self::create_client()?;
+        Ok(meilisearch_task_to_status(client.get_task(task_uid)?))
+    }
+
     fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
         let client = Self::create_client()?;
-        let meilisearch_request = search_query_to_meilisearch_request(query);
+        let meilisearch_request = search_query_to_meilisearch_request(query.clone())?;
 
         let response = client.search(&index, &meilisearch_request)?;
-        Ok(meilisearch_response_to_search_results(response))
+        Ok(meilisearch_response_to_search_results(response, &query))
+    }
+
+    fn multi_search(
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    ) -> Result<MultiSearchResults, SearchError> {
+        let client = Self::create_client()?;
+        let queries = multi_search_queries(queries, federation.as_ref())?;
+        let request = multi_search_request(&queries, federation.as_ref())?;
+
+        if federation.is_some() {
+            let response = client.multi_search(&request)?;
+            return Ok(MultiSearchResults::Merged(
+                meilisearch_federated_response_to_results(response),
+            ));
+        }
+
+        let response: MeilisearchMultiSearchResponse = client.multi_search(&request)?;
+        let results = queries
+            .into_iter()
+            .zip(response.results)
+            .map(|((index, query), response)| {
+                let results = meilisearch_response_to_search_results(response, &query);
+                (index, results)
+            })
+            .collect();
+        Ok(MultiSearchResults::PerIndex(results))
     }
 
     fn stream_search(index: IndexName, query: SearchQuery) -> Result<SearchStream, SearchError> {
         let client = Self::create_client()?;
+        search_query_to_meilisearch_request(query.clone())?;
         let stream = MeilisearchSearchStream::new(client, index, query);
         Ok(SearchStream::new(stream))
     }
@@ -243,6 +338,7 @@ impl Guest for MeilisearchComponent {
     }
 
     fn update_schema(index: IndexName, schema: Schema) -> Result<(), SearchError> {
+        validate_vector_fields(&schema)?;
         let client = Self::create_client()?;
         let settings = schema_to_meilisearch_settings(schema);
 
@@ -250,6 +346,49 @@ impl Guest for MeilisearchComponent {
 
         Ok(())
     }
+
+    fn create_alias(_alias: String, _index: IndexName) -> Result<(), SearchError> {
+        Err(unsupported("Aliases in Meilisearch"))
+    }
+
+    fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        // Meilisearch has no aliases, the index named `alias` swaps its documents and settings
+        // with `index` instead
+        let client = Self::create_client()?;
+        let task = client.swap_indexes(&[(alias.as_str(), index.as_str())])?;
+        client.wait_for_task(task.task_uid)
+    }
+
+    fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+        Err(unsupported("Aliases in Meilisearch"))
+    }
+
+    fn reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexResult, SearchError> {
+        run_reindex::<Self>(source, target, transform)
+    }
+
+    fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+        let client = Self::create_client()?;
+
+        let settings = client.get_settings(&index)?;
+        Ok(meilisearch_settings_to_relevance(settings))
+    }
+
+    fn update_relevance_settings(
+        index: IndexName,
+        settings: RelevanceSettings,
+    ) -> Result<(), SearchError> {
+        validate_relevance_settings(&settings)?;
+        let client = Self::create_client()?;
+        let settings = relevance_settings_to_meilisearch(settings)?;
+
+        let task = client.update_settings(&index, &settings)?;
+        client.wait_for_task(task.task_uid)
+    }
 }
 
 impl ExtendedGuest for MeilisearchComponent {
@@ -267,6 +406,44 @@ impl ExtendedGuest for MeilisearchComponent {
     fn subscribe(stream: &Self::SearchStream) -> Pollable {
         stream.subscribe()
     }
+
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError> {
+        // Meilisearch has no reindex API, so the documents are fetched page by page and added to
+        // the target index before returning
+        let client = Self::create_client()?;
+        let mut request = reindex_fetch_request(transform.as_ref())?;
+        request.limit = Some(Self::REINDEX_BATCH_SIZE);
+        request.retrieve_vectors = Some(true);
+
+        let mut offset = 0;
+        loop {
+            request.offset = Some(offset);
+            let page = client.get_documents(&source, &request)?;
+            if page.results.is_empty() {
+                break;
+            }
+
+            let task = client.add_documents(&target, &page.results)?;
+            client.wait_for_task(task.task_uid)?;
+
+            offset += page.results.len() as u32;
+            if offset >= page.total {
+                break;
+            }
+        }
+
+        Ok(ReindexTask::Finished(ReindexResult {
+            copied: Some(offset as u64),
+        }))
+    }
+
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError> {
+        Err(invalid_query(format!("Unknown reindex task: {task_id}")))
+    }
 }
 
 type DurableMeilisearchComponent = DurableSearch<MeilisearchComponent>;
@@ -0,0 +1,313 @@
+use golem_search::aggregation::{range_key, sort_facet_buckets};
+use golem_search::golem::search::types::{
+    AggregationKind, DateInterval, FacetBucket, FacetResult, FacetStats, RangeBucket, SearchQuery,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+const MINUTE_MS: i64 = 60_000;
+const HOUR_MS: i64 = 60 * MINUTE_MS;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Facet results of the query over the fields of the matching documents: a result per facet
+/// followed by a result per aggregation
+pub fn facet_results(documents: &[&Value], query: &SearchQuery) -> Vec<FacetResult> {
+    let facets = query.facets.iter().map(|facet| {
+        let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+        for document in documents {
+            for value in field_values(document, facet) {
+                let key = match value {
+                    Value::String(value) => value.clone(),
+                    Value::Number(_) | Value::Bool(_) => value.to_string(),
+                    _ => continue,
+                };
+                *counts.entry(key).or_insert(0) += 1;
+            }
+        }
+        let mut buckets = counts
+            .into_iter()
+            .map(|(value, count)| FacetBucket { value, count })
+            .collect::<Vec<_>>();
+        sort_facet_buckets(&mut buckets);
+        let numbers = numeric_values(documents, facet);
+        FacetResult {
+            name: facet.clone(),
+            buckets,
+            stats: (!numbers.is_empty()).then(|| stats(&numbers)),
+        }
+    });
+
+    let aggregations = query.aggregations.iter().map(|aggregation| {
+        let (buckets, stats) = match &aggregation.kind {
+            AggregationKind::Stats => (
+                Vec::new(),
+                Some(stats(&numeric_values(documents, &aggregation.field))),
+            ),
+            AggregationKind::Range(ranges) => {
+                (range_buckets(documents, &aggregation.field, ranges), None)
+            }
+            AggregationKind::DateHistogram(interval) => (
+                date_histogram(documents, &aggregation.field, *interval),
+                None,
+            ),
+        };
+        FacetResult {
+            name: aggregation.name.clone(),
+            buckets,
+            stats,
+        }
+    });
+
+    facets.chain(aggregations).collect()
+}
+
+/// Values of a field, the elements of an array field each count as a value
+fn field_values<'a>(document: &'a Value, field: &str) -> Vec<&'a Value> {
+    match document.get(field) {
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+        None => vec![],
+    }
+}
+
+fn numeric_values(documents: &[&Value], field: &str) -> Vec<f64> {
+    documents
+        .iter()
+        .flat_map(|document| field_values(document, field))
+        .filter_map(Value::as_f64)
+        .collect()
+}
+
+fn stats(values: &[f64]) -> FacetStats {
+    if values.is_empty() {
+        return FacetStats {
+            min: None,
+            max: None,
+            avg: None,
+            sum: None,
+        };
+    }
+    let sum: f64 = values.iter().sum();
+    FacetStats {
+        min: values.iter().copied().reduce(f64::min),
+        max: values.iter().copied().reduce(f64::max),
+        avg: Some(sum / values.len() as f64),
+        sum: Some(sum),
+    }
+}
+
+/// Number of documents with a value in each range, a document counts once per range
+fn range_buckets(documents: &[&Value], field: &str, ranges: &[RangeBucket]) -> Vec<FacetBucket> {
+    ranges
+        .iter()
+        .map(|range| {
+            let count = documents
+                .iter()
+                .filter(|document| {
+                    field_values(document, field)
+                        .into_iter()
+                        .filter_map(Value::as_f64)
+                        .any(|value| {
+                            range.from.is_none_or(|from| value >= from)
+                                && range.to.is_none_or(|to| value < to)
+                        })
+                })
+                .count();
+            FacetBucket {
+                value: range_key(range),
+                count: count as u64,
+            }
+        })
+        .collect()
+}
+
+/// Number of documents in each non-empty interval, in chronological order. The values are
+/// RFC 3339 timestamps or dates, or milliseconds since the epoch, and the intervals are in UTC.
+fn date_histogram(documents: &[&Value], field: &str, interval: DateInterval) -> Vec<FacetBucket> {
+    let mut counts: BTreeMap<i64, u64> = BTreeMap::new();
+    for document in documents {
+        let starts = field_values(document, field)
+            .into_iter()
+            .filter_map(timestamp_millis)
+            .map(|millis| interval_start(millis, interval))
+            .collect::<BTreeSet<_>>();
+        for start in starts {
+            *counts.entry(start).or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|(start, count)| FacetBucket {
+            value: format_timestamp(start),
+            count,
+        })
+        .collect()
+}
+
+/// Milliseconds since the epoch of a date value
+fn timestamp_millis(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(number) => number.as_i64(),
+        Value::String(text) => parse_timestamp(text),
+        _ => None,
+    }
+}
+
+/// Parses `YYYY-MM-DD`, optionally followed by `THH:MM[:SS[.fff]]` and a `Z` or `±HH:MM` offset
+fn parse_timestamp(text: &str) -> Option<i64> {
+    let number = |part: &str| -> Option<i64> {
+        if part.bytes().all(|b| b.is_ascii_digit()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+    let mut date_parts = date.splitn(3, '-');
+    let year = number(date_parts.next()?)?;
+    let month = number(date_parts.next()?)?;
+    let day = number(date_parts.next()?)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut millis = days_from_civil(year, month, day) * DAY_MS;
+
+    if let Some(time) = time {
+        let (time, offset_ms) = if let Some(time) = time.strip_suffix('Z') {
+            (time, 0)
+        } else if let Some(position) = time.rfind(['+', '-']) {
+            let (hours, minutes) = time[position + 1..].split_once(':')?;
+            let offset = number(hours)? * HOUR_MS + number(minutes)? * MINUTE_MS;
+            let sign = if time.as_bytes()[position] == b'-' {
+                -1
+            } else {
+                1
+            };
+            (&time[..position], sign * offset)
+        } else {
+            (time, 0)
+        };
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+        let mut time_parts = time.splitn(3, ':');
+        let hours = number(time_parts.next()?)?;
+        let minutes = number(time_parts.next()?)?;
+        let seconds = time_parts.next().map(number).unwrap_or(Some(0))?;
+        let fraction_ms = match fraction {
+            Some(fraction) => {
+                let digits = &fraction[..fraction.len().min(3)];
+                number(digits)? * 10_i64.pow(3 - digits.len() as u32)
+            }
+            None => 0,
+        };
+        millis += hours * HOUR_MS + minutes * MINUTE_MS + seconds * 1000 + fraction_ms;
+        millis -= offset_ms;
+    }
+    Some(millis)
+}
+
+/// Start of the calendar interval containing a timestamp, weeks start on Monday
+fn interval_start(millis: i64, interval: DateInterval) -> i64 {
+    let days = millis.div_euclid(DAY_MS);
+    let (year, month, _) = civil_from_days(days);
+    let start_day = match interval {
+        DateInterval::Minute => return millis - millis.rem_euclid(MINUTE_MS),
+        DateInterval::Hour => return millis - millis.rem_euclid(HOUR_MS),
+        DateInterval::Day => days,
+        // The epoch was a Thursday
+        DateInterval::Week => days - (days + 3).rem_euclid(7),
+        DateInterval::Month => days_from_civil(year, month, 1),
+        DateInterval::Quarter => days_from_civil(year, (month - 1) / 3 * 3 + 1, 1),
+        DateInterval::Year => days_from_civil(year, 1, 1),
+    };
+    start_day * DAY_MS
+}
+
+/// Formats a timestamp like the `key_as_string` of an Elasticsearch date histogram
+fn format_timestamp(millis: i64) -> String {
+    let (year, month, day) = civil_from_days(millis.div_euclid(DAY_MS));
+    let time = millis.rem_euclid(DAY_MS);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        time / HOUR_MS,
+        time % HOUR_MS / MINUTE_MS,
+        time % MINUTE_MS / 1000,
+        time % 1000
+    )
+}
+
+/// Days since the epoch of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of the proleptic Gregorian calendar of a number of days since the epoch
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01"), Some(0));
+        assert_eq!(
+            parse_timestamp("2024-02-29T12:30:15.250Z"),
+            Some(1_709_209_815_250)
+        );
+        assert_eq!(
+            parse_timestamp("2024-02-29T14:30:15.25+02:00"),
+            Some(1_709_209_815_250)
+        );
+        assert_eq!(parse_timestamp("1969-12-31T23:59"), Some(-MINUTE_MS));
+        assert_eq!(parse_timestamp("not a date"), None);
+        assert_eq!(parse_timestamp("2024-13-01"), None);
+
+        assert_eq!(
+            format_timestamp(1_709_209_815_250),
+            "2024-02-29T12:30:15.250Z"
+        );
+        assert_eq!(format_timestamp(-MINUTE_MS), "1969-12-31T23:59:00.000Z");
+    }
+
+    #[test]
+    fn test_interval_start() {
+        let millis = parse_timestamp("2024-08-15T10:20:30Z").unwrap();
+        let start = |interval| format_timestamp(interval_start(millis, interval));
+
+        assert_eq!(start(DateInterval::Minute), "2024-08-15T10:20:00.000Z");
+        assert_eq!(start(DateInterval::Hour), "2024-08-15T10:00:00.000Z");
+        assert_eq!(start(DateInterval::Day), "2024-08-15T00:00:00.000Z");
+        assert_eq!(start(DateInterval::Week), "2024-08-12T00:00:00.000Z");
+        assert_eq!(start(DateInterval::Month), "2024-08-01T00:00:00.000Z");
+        assert_eq!(start(DateInterval::Quarter), "2024-07-01T00:00:00.000Z");
+        assert_eq!(start(DateInterval::Year), "2024-01-01T00:00:00.000Z");
+    }
+}
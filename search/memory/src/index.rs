@@ -0,0 +1,240 @@
+use golem_search::error::{index_not_found, invalid_query};
+use golem_search::golem::search::types::{
+    Doc, DocumentId, Embedding, FieldType, IndexName, RelevanceSettings, Schema, SchemaField,
+    SearchError, VectorConfig, VectorSimilarity,
+};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// A document as it is held in memory, the content is kept as it was written and parsed once into
+/// a JSON object
+pub struct StoredDocument {
+    pub content: String,
+    pub fields: Value,
+    pub embeddings: Vec<Embedding>,
+}
+
+impl StoredDocument {
+    pub fn to_doc(&self, id: &str) -> Doc {
+        Doc {
+            id: id.to_string(),
+            content: self.content.clone(),
+            embeddings: self.embeddings.clone(),
+        }
+    }
+
+    pub fn embedding(&self, field: &str) -> Option<&[f32]> {
+        self.embeddings
+            .iter()
+            .find(|embedding| embedding.field == field)
+            .map(|embedding| embedding.values.as_slice())
+    }
+}
+
+/// An index held in the worker memory. The documents are ordered by their id, so the results do
+/// not depend on the insertion order and are the same when the writes are replayed.
+#[derive(Default)]
+pub struct MemoryIndex {
+    pub schema: Option<Schema>,
+    pub documents: BTreeMap<DocumentId, StoredDocument>,
+    pub relevance: Option<RelevanceSettings>,
+}
+
+impl MemoryIndex {
+    pub fn new(schema: Option<Schema>) -> Self {
+        Self {
+            schema,
+            documents: BTreeMap::new(),
+            relevance: None,
+        }
+    }
+
+    /// Parses and validates a document against the schema of the index
+    pub fn prepare(&self, doc: Doc) -> Result<(DocumentId, StoredDocument), SearchError> {
+        let fields = match serde_json::from_str::<Value>(&doc.content) {
+            Ok(fields) if fields.is_object() => fields,
+            Ok(_) => {
+                return Err(invalid_query(format!(
+                    "Content of document {} is not a JSON object",
+                    doc.id
+                )))
+            }
+            Err(e) => {
+                return Err(invalid_query(format!(
+                    "Failed to parse content of document {} as JSON: {e}",
+                    doc.id
+                )))
+            }
+        };
+
+        if let Some(schema) = &self.schema {
+            for field in schema.fields.iter().filter(|field| field.required) {
+                if fields.get(&field.name).is_none_or(Value::is_null) {
+                    return Err(invalid_query(format!(
+                        "Document {} is missing the required field {}",
+                        doc.id, field.name
+                    )));
+                }
+            }
+        }
+        for embedding in &doc.embeddings {
+            if let Some(config) = self.vector_config(&embedding.field) {
+                if embedding.values.len() != config.dimensions as usize {
+                    return Err(invalid_query(format!(
+                        "Embedding {} of document {} has {} dimensions instead of {}",
+                        embedding.field,
+                        doc.id,
+                        embedding.values.len(),
+                        config.dimensions
+                    )));
+                }
+            }
+        }
+
+        Ok((
+            doc.id,
+            StoredDocument {
+                content: doc.content,
+                fields,
+                embeddings: doc.embeddings,
+            },
+        ))
+    }
+
+    /// The configuration of a vector field, if the schema defines it
+    pub fn vector_config(&self, field: &str) -> Option<&VectorConfig> {
+        self.schema
+            .as_ref()?
+            .fields
+            .iter()
+            .find(|schema_field| {
+                schema_field.name == field && schema_field.field_type == FieldType::Vector
+            })?
+            .vector
+            .as_ref()
+    }
+
+    /// The fields searched by full-text queries, `None` if every text field of the documents is
+    /// searched because the index has no schema
+    pub fn searchable_fields(&self) -> Option<Vec<String>> {
+        self.schema.as_ref().map(|schema| {
+            schema
+                .fields
+                .iter()
+                .filter(|field| {
+                    field.index && matches!(field.field_type, FieldType::Text | FieldType::Keyword)
+                })
+                .map(|field| field.name.clone())
+                .collect()
+        })
+    }
+
+    /// The schema of the index, inferred from the documents if it was not given
+    pub fn schema(&self) -> Schema {
+        if let Some(schema) = &self.schema {
+            return schema.clone();
+        }
+
+        let mut fields: Vec<SchemaField> = Vec::new();
+        for document in self.documents.values() {
+            for (name, value) in document.fields.as_object().into_iter().flatten() {
+                if fields.iter().any(|field| &field.name == name) {
+                    continue;
+                }
+                if let Some(field_type) = infer_field_type(value) {
+                    fields.push(schema_field(name, field_type, None));
+                }
+            }
+            for embedding in &document.embeddings {
+                if fields.iter().any(|field| field.name == embedding.field) {
+                    continue;
+                }
+                let config = VectorConfig {
+                    dimensions: embedding.values.len() as u32,
+                    similarity: VectorSimilarity::Cosine,
+                };
+                fields.push(schema_field(
+                    &embedding.field,
+                    FieldType::Vector,
+                    Some(config),
+                ));
+            }
+        }
+
+        Schema {
+            fields,
+            primary_key: Some("id".to_string()),
+        }
+    }
+}
+
+fn infer_field_type(value: &Value) -> Option<FieldType> {
+    match value {
+        Value::String(_) => Some(FieldType::Text),
+        Value::Number(number) if number.is_i64() || number.is_u64() => Some(FieldType::Integer),
+        Value::Number(_) => Some(FieldType::Float),
+        Value::Bool(_) => Some(FieldType::Boolean),
+        Value::Object(object)
+            if object.contains_key("lat")
+                && (object.contains_key("lng") || object.contains_key("lon")) =>
+        {
+            Some(FieldType::GeoPoint)
+        }
+        Value::Array(values) => values.iter().find_map(infer_field_type),
+        _ => None,
+    }
+}
+
+fn schema_field(name: &str, field_type: FieldType, vector: Option<VectorConfig>) -> SchemaField {
+    SchemaField {
+        name: name.to_string(),
+        field_type,
+        required: false,
+        facet: false,
+        sort: field_type != FieldType::Text && field_type != FieldType::Vector,
+        index: true,
+        vector,
+    }
+}
+
+thread_local! {
+    static INDEXES: RefCell<BTreeMap<IndexName, MemoryIndex>> = const { RefCell::new(BTreeMap::new()) };
+    static ALIASES: RefCell<BTreeMap<String, IndexName>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Runs `f` on the indexes of the worker
+pub fn with_indexes<T>(f: impl FnOnce(&mut BTreeMap<IndexName, MemoryIndex>) -> T) -> T {
+    INDEXES.with_borrow_mut(f)
+}
+
+/// Runs `f` on the aliases of the worker, keyed by the alias name
+pub fn with_aliases<T>(f: impl FnOnce(&mut BTreeMap<String, IndexName>) -> T) -> T {
+    ALIASES.with_borrow_mut(f)
+}
+
+/// Name of the index an index name or alias refers to
+fn resolve(name: &str) -> IndexName {
+    with_aliases(|aliases| aliases.get(name).cloned()).unwrap_or_else(|| name.to_string())
+}
+
+/// Runs `f` on an existing index, which can be referred to by an alias
+pub fn with_index<T>(
+    name: &str,
+    f: impl FnOnce(&mut MemoryIndex) -> Result<T, SearchError>,
+) -> Result<T, SearchError> {
+    let name = resolve(name);
+    with_indexes(|indexes| match indexes.get_mut(&name) {
+        Some(index) => f(index),
+        None => Err(index_not_found()),
+    })
+}
+
+/// Runs `f` on an index, which is created without a schema if it does not exist yet
+pub fn with_index_or_create<T>(
+    name: &str,
+    f: impl FnOnce(&mut MemoryIndex) -> Result<T, SearchError>,
+) -> Result<T, SearchError> {
+    let name = resolve(name);
+    with_indexes(|indexes| f(indexes.entry(name).or_default()))
+}
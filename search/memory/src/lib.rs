@@ -0,0 +1,364 @@
+use crate::index::{with_aliases, with_index, with_index_or_create, with_indexes, MemoryIndex};
+use crate::query::{reindex_documents, search_index, DEFAULT_PER_PAGE};
+use golem_rust::wasm_rpc::Pollable;
+use golem_search::bulk::bulk_write;
+use golem_search::durability::{DurableSearch, ExtendedGuest};
+use golem_search::error::{index_not_found, invalid_query, unsupported};
+use golem_search::federation::run_multi_search;
+use golem_search::golem::search::core::{Guest, GuestSearchStream, SearchStream};
+use golem_search::golem::search::types::{
+    BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+    MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
+};
+use golem_search::reindex::{run_reindex, ReindexTask};
+use golem_search::relevance::{parse_ranking_rule, validate_relevance_settings, RankingRule};
+use golem_search::vector::validate_vector_fields;
+use std::cell::Cell;
+
+mod facets;
+mod index;
+mod query;
+mod scoring;
+
+/// Search stream paging through the results of the query, each page is searched when it is
+/// requested so the stream sees the writes made in the meantime
+struct MemorySearchStream {
+    index_name: String,
+    query: SearchQuery,
+    offset: Cell<u32>,
+    finished: Cell<bool>,
+}
+
+impl MemorySearchStream {
+    pub fn new(index_name: String, query: SearchQuery) -> Self {
+        Self {
+            index_name,
+            offset: Cell::new(query.offset.unwrap_or(0)),
+            query,
+            finished: Cell::new(false),
+        }
+    }
+
+    pub fn subscribe(&self) -> Pollable {
+        golem_rust::bindings::wasi::clocks::monotonic_clock::subscribe_duration(0)
+    }
+}
+
+impl GuestSearchStream for MemorySearchStream {
+    fn get_next(&self) -> Option<Vec<SearchHit>> {
+        if self.finished.get() {
+            return Some(vec![]);
+        }
+
+        let per_page = self.query.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let mut search_query = self.query.clone();
+        search_query.offset = Some(self.offset.get());
+        search_query.per_page = Some(per_page);
+
+        match with_index(&self.index_name, |index| search_index(index, &search_query)) {
+            Ok(results) => {
+                if (results.hits.len() as u32) < per_page {
+                    self.finished.set(true);
+                }
+                self.offset
+                    .set(self.offset.get() + results.hits.len() as u32);
+                Some(results.hits)
+            }
+            Err(_) => {
+                self.finished.set(true);
+                Some(vec![])
+            }
+        }
+    }
+
+    fn blocking_get_next(&self) -> Vec<SearchHit> {
+        self.get_next().unwrap_or_default()
+    }
+}
+
+struct MemoryComponent;
+
+impl MemoryComponent {
+    /// Checks that `alias` can point to `index`, which has to be an index and not another alias
+    fn validate_alias(alias: &str, index: &str) -> Result<(), SearchError> {
+        with_indexes(|indexes| {
+            if !indexes.contains_key(index) {
+                Err(index_not_found())
+            } else if indexes.contains_key(alias) {
+                Err(invalid_query(format!(
+                    "Alias {alias} is the name of an index"
+                )))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+impl Guest for MemoryComponent {
+    type SearchStream = MemorySearchStream;
+
+    fn create_index(name: IndexName, schema: Option<Schema>) -> Result<(), SearchError> {
+        if let Some(schema) = &schema {
+            validate_vector_fields(schema)?;
+        }
+
+        if with_aliases(|aliases| aliases.contains_key(&name)) {
+            return Err(invalid_query(format!(
+                "Index {name} is the name of an alias"
+            )));
+        }
+
+        with_indexes(|indexes| {
+            if indexes.contains_key(&name) {
+                return Err(invalid_query(format!("Index {name} already exists")));
+            }
+            indexes.insert(name, MemoryIndex::new(schema));
+            Ok(())
+        })
+    }
+
+    fn delete_index(name: IndexName) -> Result<(), SearchError> {
+        with_indexes(|indexes| match indexes.remove(&name) {
+            Some(_) => Ok(()),
+            None => Err(index_not_found()),
+        })?;
+        with_aliases(|aliases| aliases.retain(|_, index| *index != name));
+        Ok(())
+    }
+
+    fn list_indexes() -> Result<Vec<IndexName>, SearchError> {
+        Ok(with_indexes(|indexes| indexes.keys().cloned().collect()))
+    }
+
+    /// Writes are applied immediately, so every consistency behaves the same
+    fn upsert(
+        index: IndexName,
+        doc: Doc,
+        _options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
+        // The index is created on the first write, like Elasticsearch and Meilisearch do
+        with_index_or_create(&index, |index| {
+            let (id, document) = index.prepare(doc)?;
+            index.documents.insert(id, document);
+            Ok(None)
+        })
+    }
+
+    fn upsert_many(
+        index: IndexName,
+        docs: Vec<Doc>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
+        with_index_or_create(&index, |index| {
+            bulk_write(
+                docs,
+                options.as_ref(),
+                |doc| doc.id.clone(),
+                Ok,
+                |docs| {
+                    Ok(docs
+                        .into_iter()
+                        .map(|doc| {
+                            let (id, document) = index.prepare(doc)?;
+                            index.documents.insert(id, document);
+                            Ok(())
+                        })
+                        .collect())
+                },
+            )
+        })
+    }
+
+    fn delete(
+        index: IndexName,
+        id: DocumentId,
+        _options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
+        with_index(&index, |index| {
+            index.documents.remove(&id);
+            Ok(None)
+        })
+    }
+
+    fn delete_many(
+        index: IndexName,
+        ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
+        with_index(&index, |index| {
+            bulk_write(
+                ids,
+                options.as_ref(),
+                |id| id.clone(),
+                Ok,
+                |ids| {
+                    Ok(ids
+                        .iter()
+                        .map(|id| {
+                            index.documents.remove(id);
+                            Ok(())
+                        })
+                        .collect())
+                },
+            )
+        })
+    }
+
+    fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
+        with_index(&index, |index| {
+            Ok(index
+                .documents
+                .get(&id)
+                .map(|document| document.to_doc(&id)))
+        })
+    }
+
+    fn get_task_status(_id: TaskId) -> Result<TaskStatus, SearchError> {
+        Err(unsupported("Write tasks in memory"))
+    }
+
+    fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
+        with_index(&index, |index| search_index(index, &query))
+    }
+
+    fn stream_search(index: IndexName, query: SearchQuery) -> Result<SearchStream, SearchError> {
+        with_index(&index, |memory_index| search_index(memory_index, &query))?;
+        Ok(SearchStream::new(MemorySearchStream::new(index, query)))
+    }
+
+    fn multi_search(
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    ) -> Result<MultiSearchResults, SearchError> {
+        run_multi_search(queries, federation, |index, query| {
+            with_index(index, |index| search_index(index, &query))
+        })
+    }
+
+    fn get_schema(index: IndexName) -> Result<Schema, SearchError> {
+        with_index(&index, |index| Ok(index.schema()))
+    }
+
+    fn update_schema(index: IndexName, schema: Schema) -> Result<(), SearchError> {
+        validate_vector_fields(&schema)?;
+        with_index_or_create(&index, |index| {
+            index.schema = Some(schema);
+            Ok(())
+        })
+    }
+
+    fn create_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        Self::validate_alias(&alias, &index)?;
+        with_aliases(|aliases| {
+            if aliases.contains_key(&alias) {
+                return Err(invalid_query(format!("Alias {alias} already exists")));
+            }
+            aliases.insert(alias, index);
+            Ok(())
+        })
+    }
+
+    fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        Self::validate_alias(&alias, &index)?;
+        with_aliases(|aliases| aliases.insert(alias, index));
+        Ok(())
+    }
+
+    fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+        Ok(with_aliases(|aliases| {
+            aliases
+                .iter()
+                .map(|(alias, index)| IndexAlias {
+                    alias: alias.clone(),
+                    index: index.clone(),
+                })
+                .collect()
+        }))
+    }
+
+    fn reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexResult, SearchError> {
+        run_reindex::<Self>(source, target, transform)
+    }
+
+    fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+        with_index(&index, |index| {
+            Ok(index.relevance.clone().unwrap_or(RelevanceSettings {
+                synonyms: vec![],
+                stop_words: vec![],
+                ranking_rules: vec![],
+                provider_settings: None,
+            }))
+        })
+    }
+
+    fn update_relevance_settings(
+        index: IndexName,
+        settings: RelevanceSettings,
+    ) -> Result<(), SearchError> {
+        validate_relevance_settings(&settings)?;
+        if settings.provider_settings.is_some() {
+            return Err(unsupported("Provider relevance settings in memory"));
+        }
+        // Only the field rules are applied, as tie-breakers of the text score
+        for rule in &settings.ranking_rules {
+            if let RankingRule::Builtin(_) = parse_ranking_rule(rule)? {
+                return Err(unsupported("Built-in ranking rules in memory"));
+            }
+        }
+
+        with_index(&index, |index| {
+            index.relevance = Some(settings);
+            Ok(())
+        })
+    }
+}
+
+impl ExtendedGuest for MemoryComponent {
+    // The index only lives in the worker memory, it is rebuilt by replaying the writes
+    const REAPPLY_REPLAYED_WRITES: bool = true;
+
+    fn unwrapped_stream(index: IndexName, query: SearchQuery) -> Self::SearchStream {
+        MemorySearchStream::new(index, query)
+    }
+
+    fn subscribe(stream: &Self::SearchStream) -> Pollable {
+        stream.subscribe()
+    }
+
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError> {
+        let docs = with_index(&source, |index| {
+            reindex_documents(index, transform.as_ref())
+        })?;
+        let copied = docs.len() as u64;
+
+        with_index_or_create(&target, |index| {
+            for doc in docs {
+                let (id, document) = index.prepare(doc)?;
+                index.documents.insert(id, document);
+            }
+            Ok(())
+        })?;
+
+        Ok(ReindexTask::Finished(ReindexResult {
+            copied: Some(copied),
+        }))
+    }
+
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError> {
+        Err(invalid_query(format!("Unknown reindex task: {task_id}")))
+    }
+}
+
+type DurableMemoryComponent = DurableSearch<MemoryComponent>;
+
+golem_search::export_search!(DurableMemoryComponent with_types_in golem_search);
@@ -0,0 +1,919 @@
+use crate::facets::facet_results;
+use crate::index::{MemoryIndex, StoredDocument};
+use crate::scoring::{bm25_scores, field_text, token_spans, tokenize, vector_score};
+use golem_search::aggregation::validate_aggregations;
+use golem_search::error::invalid_query;
+use golem_search::filter::{matches_filter, parse_filter_expr, Condition, Filter};
+use golem_search::golem::search::types::{
+    Doc, FieldFilter, FilterValue, HighlightConfig, RangeFilter, ReindexTransform, SearchError,
+    SearchHit, SearchQuery, SearchResults, VectorQuery, VectorSimilarity,
+};
+use golem_search::relevance::{parse_ranking_rule, synonym_map, RankingRule};
+use golem_search::vector::validate_vector_query;
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::time::Instant;
+
+pub const DEFAULT_PER_PAGE: u32 = 20;
+
+/// A document matching the query with its score
+struct Match<'a> {
+    id: &'a str,
+    document: &'a StoredDocument,
+    score: Option<f64>,
+}
+
+/// Runs a search query on an index
+pub fn search_index(
+    index: &MemoryIndex,
+    query: &SearchQuery,
+) -> Result<SearchResults, SearchError> {
+    let started = Instant::now();
+
+    validate_aggregations(query)?;
+    let filter = query_filter(query)?;
+    let terms = query
+        .q
+        .as_deref()
+        .filter(|q| q.trim() != "*")
+        .map(|q| query_terms(index, q))
+        .unwrap_or_default();
+
+    let ids = index.documents.keys().collect::<Vec<_>>();
+    let documents = index.documents.values().collect::<Vec<_>>();
+    let text_scores = if terms.is_empty() {
+        None
+    } else {
+        let contents = documents
+            .iter()
+            .map(|document| &document.fields)
+            .collect::<Vec<_>>();
+        Some(bm25_scores(
+            &terms,
+            &contents,
+            &searched_fields(index, query),
+        ))
+    };
+
+    let mut matches = ids
+        .into_iter()
+        .zip(documents)
+        .enumerate()
+        .filter(|(_, (_, document))| {
+            filter
+                .as_ref()
+                .is_none_or(|filter| matches_filter(filter, &document.fields))
+        })
+        .map(|(position, (id, document))| Match {
+            id,
+            document,
+            score: text_scores.as_ref().map(|scores| scores[position]),
+        })
+        .collect::<Vec<_>>();
+
+    match &query.vector_query {
+        Some(vector_query) => {
+            validate_vector_query(vector_query)?;
+            matches = rank_by_vector(index, matches, vector_query, text_scores.is_some())?;
+        }
+        None => matches.retain(|m| m.score.is_none_or(|score| score > 0.0)),
+    }
+
+    sort_matches(&mut matches, &query.sort, &ranking_criteria(index));
+
+    let documents = matches
+        .iter()
+        .map(|m| &m.document.fields)
+        .collect::<Vec<_>>();
+    let facets = facet_results(&documents, query);
+    let total = matches.len() as u32;
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+    let offset = query.offset.unwrap_or_else(|| {
+        query
+            .page
+            .unwrap_or(1)
+            .saturating_sub(1)
+            .saturating_mul(per_page)
+    });
+
+    let term_set = terms.into_iter().collect::<HashSet<_>>();
+    let hits = matches
+        .into_iter()
+        .skip(offset as usize)
+        .take(per_page as usize)
+        .map(|m| to_search_hit(m, query, &term_set))
+        .collect();
+
+    Ok(SearchResults {
+        total: Some(total),
+        page: Some(offset / per_page + 1),
+        per_page: Some(per_page),
+        hits,
+        facets,
+        took_ms: Some(started.elapsed().as_millis() as u32),
+    })
+}
+
+/// Documents of an index copied by a reindex, the ones matching the filter of the transform and
+/// with only its fields kept
+pub fn reindex_documents(
+    index: &MemoryIndex,
+    transform: Option<&ReindexTransform>,
+) -> Result<Vec<Doc>, SearchError> {
+    let filter = match transform.and_then(|transform| transform.filter.as_ref()) {
+        Some(expr) => Some(parse_filter_expr(expr)?),
+        None => None,
+    };
+    let fields = transform
+        .map(|transform| transform.fields.as_slice())
+        .unwrap_or_default();
+
+    Ok(index
+        .documents
+        .iter()
+        .filter(|(_, document)| {
+            filter
+                .as_ref()
+                .is_none_or(|filter| matches_filter(filter, &document.fields))
+        })
+        .map(|(id, document)| {
+            let mut doc = document.to_doc(id);
+            if !fields.is_empty() {
+                let content = fields
+                    .iter()
+                    .filter_map(|field| {
+                        let value = document.fields.get(field)?;
+                        Some((field.clone(), value.clone()))
+                    })
+                    .collect::<Map<_, _>>();
+                doc.content = Value::Object(content).to_string();
+                doc.embeddings
+                    .retain(|embedding| fields.contains(&embedding.field));
+            }
+            doc
+        })
+        .collect())
+}
+
+/// Combines the provider specific `filters` strings and the typed filter expression with AND
+fn query_filter(query: &SearchQuery) -> Result<Option<Filter>, SearchError> {
+    let mut filters = query
+        .filters
+        .iter()
+        .map(String::as_str)
+        .map(parse_filter_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(expr) = &query.filter_expr {
+        filters.push(parse_filter_expr(expr)?);
+    }
+    Ok(match filters.len() {
+        0 => None,
+        1 => filters.pop(),
+        _ => Some(Filter::And(filters)),
+    })
+}
+
+/// Parses a filter string in the `field:value` syntax, the value can be prefixed by one of the
+/// `=`, `!=`, `>`, `>=`, `<` and `<=` operators
+pub fn parse_filter_string(filter: &str) -> Result<Filter, SearchError> {
+    let Some((field, value)) = filter.split_once(':') else {
+        return Err(invalid_query(format!(
+            "Filter {filter} is not in the field:value syntax"
+        )));
+    };
+    let field = field.trim().to_string();
+    if field.is_empty() {
+        return Err(invalid_query(format!("Filter {filter} has no field")));
+    }
+
+    let value = value.trim();
+    let (operator, value) = ["!=", ">=", "<=", "=", ">", "<"]
+        .into_iter()
+        .find_map(|operator| value.strip_prefix(operator).map(|value| (operator, value)))
+        .unwrap_or(("=", value));
+    let value = parse_filter_value(value.trim());
+
+    let range = |gt, gte, lt, lte| {
+        Filter::Is(Condition::Range(RangeFilter {
+            field: field.clone(),
+            gt,
+            gte,
+            lt,
+            lte,
+        }))
+    };
+    Ok(match operator {
+        ">" => range(Some(value), None, None, None),
+        ">=" => range(None, Some(value), None, None),
+        "<" => range(None, None, Some(value), None),
+        "<=" => range(None, None, None, Some(value)),
+        "!=" => Filter::IsNot(Condition::Eq(FieldFilter {
+            field: field.clone(),
+            value,
+        })),
+        _ => Filter::Is(Condition::Eq(FieldFilter {
+            field: field.clone(),
+            value,
+        })),
+    })
+}
+
+fn parse_filter_value(value: &str) -> FilterValue {
+    if let Ok(value) = value.parse::<i64>() {
+        FilterValue::Integer(value)
+    } else if let Ok(value) = value.parse::<f64>() {
+        FilterValue::Float(value)
+    } else if let Ok(value) = value.parse::<bool>() {
+        FilterValue::Boolean(value)
+    } else {
+        FilterValue::Text(value.trim_matches('"').trim_matches('\'').to_string())
+    }
+}
+
+/// The fields searched by the full-text query with their boosts
+fn searched_fields(index: &MemoryIndex, query: &SearchQuery) -> Vec<(String, f64)> {
+    let names = index.searchable_fields().unwrap_or_else(|| {
+        let mut names = Vec::new();
+        for document in index.documents.values() {
+            for (name, value) in document.fields.as_object().into_iter().flatten() {
+                if field_text(value).is_some() && !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    });
+
+    let boosts = query
+        .config
+        .as_ref()
+        .map(|config| config.boost_fields.as_slice())
+        .unwrap_or_default();
+    names
+        .into_iter()
+        .map(|name| {
+            let boost = boosts
+                .iter()
+                .find(|(field, _)| *field == name)
+                .map(|(_, boost)| *boost as f64)
+                .unwrap_or(1.0);
+            (name, boost)
+        })
+        .collect()
+}
+
+/// Keeps the `k` nearest neighbours of the documents matching the filters. With a hybrid weight
+/// and a full-text query, the neighbours and the full-text matches are combined by weighting the
+/// vector score against the full-text score normalized to the best match.
+fn rank_by_vector<'a>(
+    index: &MemoryIndex,
+    matches: Vec<Match<'a>>,
+    vector_query: &VectorQuery,
+    has_text: bool,
+) -> Result<Vec<Match<'a>>, SearchError> {
+    let similarity = index
+        .vector_config(&vector_query.field)
+        .map(|config| config.similarity)
+        .unwrap_or(VectorSimilarity::Cosine);
+
+    let mut neighbours = Vec::new();
+    let mut text_matches = Vec::new();
+    for m in matches {
+        let vector = match m.document.embedding(&vector_query.field) {
+            Some(embedding) if embedding.len() != vector_query.embedding.len() => {
+                return Err(invalid_query(format!(
+                    "Vector query on {} has {} dimensions instead of {}",
+                    vector_query.field,
+                    vector_query.embedding.len(),
+                    embedding.len()
+                )))
+            }
+            Some(embedding) => Some(vector_score(similarity, &vector_query.embedding, embedding)),
+            None => None,
+        };
+        match vector {
+            Some(vector) => neighbours.push((m, vector)),
+            None => text_matches.push((m, 0.0)),
+        }
+    }
+    neighbours.sort_by(|(a, a_score), (b, b_score)| {
+        b_score.total_cmp(a_score).then_with(|| a.id.cmp(b.id))
+    });
+    let mut others = neighbours.split_off(neighbours.len().min(vector_query.k as usize));
+
+    let weight = vector_query.hybrid_weight.filter(|_| has_text);
+    let Some(weight) = weight.map(f64::from) else {
+        return Ok(neighbours
+            .into_iter()
+            .map(|(m, vector)| Match {
+                score: Some(vector),
+                ..m
+            })
+            .collect());
+    };
+
+    // Documents outside of the neighbours only contribute with their full-text score
+    others.iter_mut().for_each(|(_, vector)| *vector = 0.0);
+    let mut candidates = neighbours;
+    candidates.extend(others);
+    candidates.extend(text_matches);
+    let best_text = candidates
+        .iter()
+        .filter_map(|(m, _)| m.score)
+        .fold(0.0, f64::max);
+
+    Ok(candidates
+        .into_iter()
+        .filter(|(m, vector)| *vector > 0.0 || m.score.is_some_and(|score| score > 0.0))
+        .map(|(m, vector)| {
+            let text = match m.score {
+                Some(score) if best_text > 0.0 => score / best_text,
+                _ => 0.0,
+            };
+            Match {
+                score: Some(weight * vector + (1.0 - weight) * text),
+                ..m
+            }
+        })
+        .collect())
+}
+
+/// Terms of the query text without the stop words of the index, followed by the synonyms of the
+/// remaining terms
+fn query_terms(index: &MemoryIndex, q: &str) -> Vec<String> {
+    let mut terms = tokenize(q);
+    let Some(relevance) = &index.relevance else {
+        return terms;
+    };
+
+    let stop_words = relevance
+        .stop_words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<HashSet<_>>();
+    terms.retain(|term| !stop_words.contains(term));
+
+    let synonyms = synonym_map(&relevance.synonyms);
+    let mut expanded = terms.clone();
+    for term in &terms {
+        let term_synonyms = synonyms
+            .iter()
+            .filter(|(synonym_term, _)| synonym_term.to_lowercase() == *term)
+            .flat_map(|(_, synonyms)| synonyms.iter().flat_map(|synonym| tokenize(synonym)));
+        for synonym in term_synonyms {
+            if !expanded.contains(&synonym) {
+                expanded.push(synonym);
+            }
+        }
+    }
+    expanded
+}
+
+/// The `field:asc` / `field:desc` ranking rules of the index, which order the matches with the
+/// same score
+fn ranking_criteria(index: &MemoryIndex) -> Vec<(String, bool)> {
+    index
+        .relevance
+        .iter()
+        .flat_map(|relevance| &relevance.ranking_rules)
+        .filter_map(|rule| match parse_ranking_rule(rule) {
+            Ok(RankingRule::Field { field, descending }) => Some((field.to_string(), descending)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Sorts the matches by the `field`, `field:asc`, `field:desc` or `-field` sort criteria, then by
+/// their score and the ranking criteria of the index. The documents without a sort field come
+/// last, ties are ordered by id.
+fn sort_matches(matches: &mut [Match], sort: &[String], ranking: &[(String, bool)]) {
+    let criteria = sort
+        .iter()
+        .map(|criterion| {
+            if let Some((field, direction)) = criterion.split_once(':') {
+                (field.to_string(), direction.trim() == "desc")
+            } else if let Some(field) = criterion.strip_prefix('-') {
+                (field.to_string(), true)
+            } else {
+                (criterion.clone(), false)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|a, b| {
+        let by_fields = |criteria: &[(String, bool)]| {
+            criteria
+                .iter()
+                .fold(Ordering::Equal, |ordering, (field, desc)| {
+                    ordering.then_with(|| {
+                        compare_values(
+                            a.document.fields.get(field),
+                            b.document.fields.get(field),
+                            *desc,
+                        )
+                    })
+                })
+        };
+        let by_score = || {
+            let score = |m: &Match| m.score.unwrap_or(0.0);
+            score(b).total_cmp(&score(a))
+        };
+        by_fields(&criteria)
+            .then_with(by_score)
+            .then_with(|| by_fields(ranking))
+            .then_with(|| a.id.cmp(b.id))
+    });
+}
+
+fn compare_values(a: Option<&Value>, b: Option<&Value>, desc: bool) -> Ordering {
+    let ordering = match (a, b) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .unwrap_or_default()
+            .total_cmp(&b.as_f64().unwrap_or_default()),
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+        (Some(a), None) if !a.is_null() => return Ordering::Less,
+        (None, Some(b)) if !b.is_null() => return Ordering::Greater,
+        _ => Ordering::Equal,
+    };
+    if desc {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+fn to_search_hit(m: Match, query: &SearchQuery, terms: &HashSet<String>) -> SearchHit {
+    let attributes = query
+        .config
+        .as_ref()
+        .map(|config| config.attributes_to_retrieve.as_slice())
+        .unwrap_or_default();
+    let content = if attributes.is_empty() {
+        m.document.content.clone()
+    } else {
+        let fields = m
+            .document
+            .fields
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(name, _)| attributes.contains(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect::<Map<_, _>>();
+        Value::Object(fields).to_string()
+    };
+
+    let highlights = query
+        .highlight
+        .as_ref()
+        .and_then(|config| highlights(&m.document.fields, config, terms));
+
+    SearchHit {
+        id: m.id.to_string(),
+        score: m.score,
+        content: Some(content),
+        highlights,
+    }
+}
+
+/// Highlights the query terms in the configured fields, in the `{field: [fragment]}` format of
+/// the Elasticsearch highlights. Only the fields containing a term are highlighted.
+fn highlights(fields: &Value, config: &HighlightConfig, terms: &HashSet<String>) -> Option<String> {
+    let pre_tag = config.pre_tag.as_deref().unwrap_or("<em>");
+    let post_tag = config.post_tag.as_deref().unwrap_or("</em>");
+
+    let mut highlights = Map::new();
+    for field in &config.fields {
+        let Some(text) = fields.get(field).and_then(field_text) else {
+            continue;
+        };
+        if let Some(fragment) = highlight_text(&text, terms, pre_tag, post_tag, config.max_length) {
+            highlights.insert(field.clone(), json!([fragment]));
+        }
+    }
+    (!highlights.is_empty()).then(|| Value::Object(highlights).to_string())
+}
+
+/// Wraps the tokens of the text matching a term in the tags. With a maximum length the fragment
+/// starts a quarter of the length before the first match.
+pub fn highlight_text(
+    text: &str,
+    terms: &HashSet<String>,
+    pre_tag: &str,
+    post_tag: &str,
+    max_length: Option<u32>,
+) -> Option<String> {
+    let spans = token_spans(text)
+        .into_iter()
+        .filter(|(start, end)| terms.contains(&text[*start..*end].to_lowercase()))
+        .collect::<Vec<_>>();
+    let (first_start, _) = spans.first()?;
+
+    let (start, end) = match max_length {
+        Some(max_length) => {
+            let boundaries = text
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain([text.len()])
+                .collect::<Vec<_>>();
+            let first_char = boundaries.partition_point(|index| index < first_start);
+            let start_char = first_char.saturating_sub(max_length as usize / 4);
+            let end_char = (start_char + max_length as usize).min(boundaries.len() - 1);
+            (boundaries[start_char], boundaries[end_char])
+        }
+        None => (0, text.len()),
+    };
+
+    let mut fragment = String::new();
+    let mut position = start;
+    for (span_start, span_end) in spans
+        .into_iter()
+        .filter(|(span_start, span_end)| *span_start >= start && *span_end <= end)
+    {
+        fragment.push_str(&text[position..span_start]);
+        fragment.push_str(pre_tag);
+        fragment.push_str(&text[span_start..span_end]);
+        fragment.push_str(post_tag);
+        position = span_end;
+    }
+    fragment.push_str(&text[position..end]);
+    Some(fragment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_search::golem::search::types::{
+        Aggregation, AggregationKind, Embedding, FacetBucket, FacetResult, FacetStats, FieldType,
+        FilterExpr, FilterNode, RangeBucket, RelevanceSettings, Schema, SchemaField, SynonymSet,
+        VectorConfig,
+    };
+    use golem_search::test_util::conformance_cases;
+
+    fn create_index(documents: Vec<(&str, Value)>) -> MemoryIndex {
+        let mut index = MemoryIndex::default();
+        for (id, content) in documents {
+            let (id, document) = index
+                .prepare(Doc {
+                    id: id.to_string(),
+                    content: content.to_string(),
+                    embeddings: vec![],
+                })
+                .unwrap();
+            index.documents.insert(id, document);
+        }
+        index
+    }
+
+    fn books() -> MemoryIndex {
+        create_index(vec![
+            (
+                "doc1",
+                json!({"title": "The Great Gatsby", "genre": "fiction", "year": 1925}),
+            ),
+            (
+                "doc2",
+                json!({"title": "To Kill a Mockingbird", "genre": "fiction", "year": 1960}),
+            ),
+            (
+                "doc3",
+                json!({"title": "1984", "genre": "dystopian", "year": 1949}),
+            ),
+            (
+                "doc4",
+                json!({"title": "The Catcher in the Rye", "genre": "fiction", "year": 1951}),
+            ),
+        ])
+    }
+
+    fn query(q: Option<&str>) -> SearchQuery {
+        SearchQuery {
+            q: q.map(str::to_string),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        }
+    }
+
+    fn hit_ids(results: &SearchResults) -> Vec<&str> {
+        results.hits.iter().map(|hit| hit.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_bm25_ranking() {
+        let index = create_index(vec![
+            ("doc1", json!({"title": "rust programming language"})),
+            ("doc2", json!({"title": "rust rust rust"})),
+            ("doc3", json!({"title": "python programming"})),
+        ]);
+
+        let results = search_index(&index, &query(Some("Rust"))).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc2", "doc1"]);
+        assert_eq!(results.total, Some(2));
+        let scores = results
+            .hits
+            .iter()
+            .map(|hit| hit.score.unwrap())
+            .collect::<Vec<_>>();
+        assert!(scores[0] > scores[1] && scores[1] > 0.0);
+    }
+
+    #[test]
+    fn test_relevance_settings() {
+        let mut index = books();
+        index.relevance = Some(RelevanceSettings {
+            synonyms: vec![SynonymSet {
+                synonyms: vec!["fiction".to_string()],
+                root: Some("novel".to_string()),
+            }],
+            stop_words: vec!["The".to_string()],
+            ranking_rules: vec!["year:desc".to_string()],
+            provider_settings: None,
+        });
+
+        // The synonym matches the genre, the ties are ordered by the ranking rule
+        let results = search_index(&index, &query(Some("the novel"))).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc2", "doc4", "doc1"]);
+
+        // Only stop words left, so every document matches
+        let results = search_index(&index, &query(Some("the"))).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc2", "doc4", "doc3", "doc1"]);
+    }
+
+    #[test]
+    fn test_filters_and_facets() {
+        let mut search_query = query(None);
+        search_query.filters = vec!["genre:fiction".to_string(), "year:>=1950".to_string()];
+        search_query.facets = vec!["genre".to_string()];
+
+        let results = search_index(&books(), &search_query).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc2", "doc4"]);
+        assert_eq!(
+            results.facets,
+            vec![FacetResult {
+                name: "genre".to_string(),
+                buckets: vec![FacetBucket {
+                    value: "fiction".to_string(),
+                    count: 2
+                }],
+                stats: None
+            }]
+        );
+
+        search_query.filters = vec!["genre".to_string()];
+        assert!(matches!(
+            search_index(&books(), &search_query),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_aggregations() {
+        let mut search_query = query(None);
+        search_query.facets = vec!["genre".to_string()];
+        search_query.aggregations = vec![
+            Aggregation {
+                name: "year_stats".to_string(),
+                field: "year".to_string(),
+                kind: AggregationKind::Stats,
+            },
+            Aggregation {
+                name: "eras".to_string(),
+                field: "year".to_string(),
+                kind: AggregationKind::Range(vec![
+                    RangeBucket {
+                        key: None,
+                        from: None,
+                        to: Some(1950.0),
+                    },
+                    RangeBucket {
+                        key: Some("modern".to_string()),
+                        from: Some(1950.0),
+                        to: None,
+                    },
+                ]),
+            },
+        ];
+
+        let results = search_index(&books(), &search_query).unwrap();
+        let names = results
+            .facets
+            .iter()
+            .map(|facet| facet.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["genre", "year_stats", "eras"]);
+        assert_eq!(results.facets[0].buckets[0].value, "fiction");
+        assert_eq!(results.facets[0].buckets[0].count, 3);
+        assert_eq!(
+            results.facets[1].stats,
+            Some(FacetStats {
+                min: Some(1925.0),
+                max: Some(1960.0),
+                avg: Some(1946.25),
+                sum: Some(7785.0)
+            })
+        );
+        let eras = results.facets[2]
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.value.as_str(), bucket.count))
+            .collect::<Vec<_>>();
+        assert_eq!(eras, vec![("*-1950", 2), ("modern", 2)]);
+
+        search_query.aggregations = vec![Aggregation {
+            name: "genre".to_string(),
+            field: "year".to_string(),
+            kind: AggregationKind::Stats,
+        }];
+        assert!(matches!(
+            search_index(&books(), &search_query),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_sort_and_pagination() {
+        let mut search_query = query(None);
+        search_query.sort = vec!["year:desc".to_string()];
+        search_query.per_page = Some(2);
+        search_query.page = Some(2);
+
+        let results = search_index(&books(), &search_query).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc3", "doc1"]);
+        assert_eq!(results.total, Some(4));
+        assert_eq!(results.page, Some(2));
+        assert_eq!(results.per_page, Some(2));
+
+        search_query.offset = Some(1);
+        let results = search_index(&books(), &search_query).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc4", "doc3"]);
+    }
+
+    #[test]
+    fn test_highlights() {
+        let mut search_query = query(Some("gatsby"));
+        search_query.highlight = Some(HighlightConfig {
+            fields: vec!["title".to_string(), "genre".to_string()],
+            pre_tag: Some("<b>".to_string()),
+            post_tag: Some("</b>".to_string()),
+            max_length: None,
+        });
+
+        let results = search_index(&books(), &search_query).unwrap();
+        assert_eq!(
+            results.hits[0].highlights,
+            Some(r#"{"title":["The Great <b>Gatsby</b>"]}"#.to_string())
+        );
+
+        let terms = HashSet::from(["fox".to_string()]);
+        assert_eq!(
+            highlight_text(
+                "The quick brown fox jumps over the lazy dog",
+                &terms,
+                "<em>",
+                "</em>",
+                Some(12)
+            ),
+            Some("wn <em>fox</em> jumps".to_string())
+        );
+    }
+
+    #[test]
+    fn test_vector_query() {
+        let mut index = books();
+        index.schema = Some(Schema {
+            fields: vec![
+                SchemaField {
+                    name: "title".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    facet: false,
+                    sort: false,
+                    index: true,
+                    vector: None,
+                },
+                SchemaField {
+                    name: "title_vector".to_string(),
+                    field_type: FieldType::Vector,
+                    required: false,
+                    facet: false,
+                    sort: false,
+                    index: true,
+                    vector: Some(VectorConfig {
+                        dimensions: 2,
+                        similarity: VectorSimilarity::Cosine,
+                    }),
+                },
+            ],
+            primary_key: None,
+        });
+        for (id, values) in [("doc1", [1.0, 0.0]), ("doc3", [0.0, 1.0])] {
+            index.documents.get_mut(id).unwrap().embeddings = vec![Embedding {
+                field: "title_vector".to_string(),
+                values: values.to_vec(),
+            }];
+        }
+
+        let mut search_query = query(None);
+        search_query.vector_query = Some(VectorQuery {
+            field: "title_vector".to_string(),
+            embedding: vec![0.25, 1.0],
+            k: 1,
+            hybrid_weight: None,
+        });
+        let results = search_index(&index, &search_query).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc3"]);
+
+        // The full-text match of doc1 outweighs its lower vector score
+        search_query.q = Some("gatsby".to_string());
+        if let Some(vector_query) = search_query.vector_query.as_mut() {
+            vector_query.hybrid_weight = Some(0.25);
+        }
+        let results = search_index(&index, &search_query).unwrap();
+        assert_eq!(hit_ids(&results), vec!["doc1", "doc3"]);
+
+        if let Some(vector_query) = search_query.vector_query.as_mut() {
+            vector_query.embedding = vec![1.0, 0.0, 0.0];
+        }
+        assert!(matches!(
+            search_index(&index, &search_query),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        let index = create_index(vec![
+            (
+                "1",
+                json!({"category": "books", "year": 2020, "price": 10, "in_stock": true,
+                       "_geo": {"lat": 48.86, "lng": 2.35}}),
+            ),
+            (
+                "2",
+                json!({"category": "music", "year": 2019, "price": 20.5, "in_stock": false,
+                       "discount": 5, "title": "say \"hi\""}),
+            ),
+        ]);
+
+        for (name, expr) in conformance_cases() {
+            let mut search_query = query(None);
+            search_query.filter_expr = Some(expr);
+            assert!(
+                search_index(&index, &search_query).is_ok(),
+                "filter case {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reindex_documents() {
+        let mut index = books();
+        index.documents.get_mut("doc2").unwrap().embeddings = vec![Embedding {
+            field: "plot".to_string(),
+            values: vec![0.1, 0.2],
+        }];
+
+        let all = reindex_documents(&index, None).unwrap();
+        assert_eq!(all.len(), index.documents.len());
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(1950)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec!["title".to_string(), "plot".to_string()],
+        };
+        let copied = reindex_documents(&index, Some(&transform)).unwrap();
+        let ids = copied.iter().map(|doc| doc.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["doc2", "doc4"]);
+        assert_eq!(
+            serde_json::from_str::<Value>(&copied[0].content).unwrap(),
+            json!({"title": "To Kill a Mockingbird"})
+        );
+        assert_eq!(copied[0].embeddings.len(), 1);
+
+        let transform = ReindexTransform {
+            filter: None,
+            fields: vec!["title".to_string()],
+        };
+        let copied = reindex_documents(&index, Some(&transform)).unwrap();
+        assert!(copied.iter().all(|doc| doc.embeddings.is_empty()));
+    }
+}
@@ -0,0 +1,143 @@
+use golem_search::golem::search::types::VectorSimilarity;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Term frequency saturation of BM25
+const K1: f64 = 1.2;
+/// Document length normalization of BM25
+const B: f64 = 0.75;
+
+/// Byte ranges of the tokens of a text, which are the runs of alphanumeric characters
+pub fn token_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(token_start)) => {
+                spans.push((token_start, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(token_start) = start {
+        spans.push((token_start, text.len()));
+    }
+    spans
+}
+
+/// Lowercased tokens of a text
+pub fn tokenize(text: &str) -> Vec<String> {
+    token_spans(text)
+        .into_iter()
+        .map(|(start, end)| text[start..end].to_lowercase())
+        .collect()
+}
+
+/// The text of a field, the strings of an array field are joined
+pub fn field_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Array(values) => {
+            let texts = values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .collect::<Vec<_>>();
+            (!texts.is_empty()).then(|| texts.join(" "))
+        }
+        _ => None,
+    }
+}
+
+/// Term frequencies and length of a field of a document
+struct FieldTerms {
+    frequencies: HashMap<String, u32>,
+    length: usize,
+}
+
+impl FieldTerms {
+    fn new(text: &str) -> Self {
+        let tokens = tokenize(text);
+        let mut frequencies = HashMap::new();
+        for token in &tokens {
+            *frequencies.entry(token.clone()).or_insert(0) += 1;
+        }
+        Self {
+            frequencies,
+            length: tokens.len(),
+        }
+    }
+}
+
+/// Scores the documents against the query terms with BM25, summed over the searched fields and
+/// multiplied by their boosts. The statistics of a field are computed over all the documents, a
+/// document without any of the terms scores 0.
+pub fn bm25_scores(terms: &[String], documents: &[&Value], fields: &[(String, f64)]) -> Vec<f64> {
+    let mut scores = vec![0.0; documents.len()];
+    let count = documents.len() as f64;
+
+    for (field, boost) in fields {
+        let field_terms = documents
+            .iter()
+            .map(|document| {
+                document
+                    .get(field)
+                    .and_then(field_text)
+                    .map(|text| FieldTerms::new(&text))
+            })
+            .collect::<Vec<_>>();
+        let with_field = field_terms.iter().flatten().count().max(1) as f64;
+        let total_length: usize = field_terms.iter().flatten().map(|terms| terms.length).sum();
+        let average_length = (total_length as f64 / with_field).max(1.0);
+
+        for term in terms {
+            let document_frequency = field_terms
+                .iter()
+                .flatten()
+                .filter(|terms| terms.frequencies.contains_key(term))
+                .count() as f64;
+            if document_frequency == 0.0 {
+                continue;
+            }
+            let idf = (1.0 + (count - document_frequency + 0.5) / (document_frequency + 0.5)).ln();
+
+            for (score, terms) in scores.iter_mut().zip(&field_terms) {
+                let Some(terms) = terms else {
+                    continue;
+                };
+                let Some(frequency) = terms.frequencies.get(term) else {
+                    continue;
+                };
+                let frequency = *frequency as f64;
+                let normalization = 1.0 - B + B * terms.length as f64 / average_length;
+                *score += boost * idf * frequency * (K1 + 1.0) / (frequency + K1 * normalization);
+            }
+        }
+    }
+
+    scores
+}
+
+/// Similarity of two embeddings mapped to a positive score, higher is more similar, the same way
+/// as Elasticsearch scores its `knn` search
+pub fn vector_score(similarity: VectorSimilarity, a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(a, b)| *a as f64 * *b as f64).sum();
+    match similarity {
+        VectorSimilarity::Cosine => {
+            let norm = |v: &[f32]| v.iter().map(|x| *x as f64 * *x as f64).sum::<f64>().sqrt();
+            let norms = norm(a) * norm(b);
+            let cosine = if norms == 0.0 { 0.0 } else { dot / norms };
+            (1.0 + cosine) / 2.0
+        }
+        VectorSimilarity::DotProduct => (1.0 + dot) / 2.0,
+        VectorSimilarity::Euclidean => {
+            let squared: f64 = a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+                .sum();
+            1.0 / (1.0 + squared)
+        }
+    }
+}
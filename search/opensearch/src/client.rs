@@ -6,6 +6,7 @@ use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::time::Duration;
 
@@ -115,6 +116,19 @@ pub struct OpenSearchBulkAction {
     pub id: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct OpenSearchMultiSearchResponse {
+    pub responses: Vec<OpenSearchMultiSearchItem>,
+}
+
+/// Response of one search of an `_msearch` request, which fails on its own
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum OpenSearchMultiSearchItem {
+    Error { error: Value, status: u16 },
+    Results(OpenSearchSearchResponse),
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct OpenSearchBulkResponse {
@@ -142,6 +156,24 @@ pub struct OpenSearchIndexInfo {
     pub pri_store_size: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct OpenSearchIndexAliases {
+    #[serde(default)]
+    pub aliases: HashMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenSearchTaskStarted {
+    pub task: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenSearchTask {
+    pub completed: bool,
+    pub response: Option<Value>,
+    pub error: Option<Value>,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct OpenSearchErrorResponse {
@@ -387,10 +419,14 @@ impl OpenSearchApi {
         index_name: &str,
         id: &str,
         document: &Value,
+        refresh: &str,
     ) -> Result<(), SearchError> {
         trace!("Indexing document {id} in index: {index_name}");
 
-        let url = format!("{}/{}/_doc/{}", self.base_url, index_name, id);
+        let url = format!(
+            "{}/{}/_doc/{}?refresh={}",
+            self.base_url, index_name, id, refresh
+        );
 
         let response = self.execute_with_retry_sync(|| {
             self.create_request(Method::PUT, &url).json(document).send()
@@ -403,10 +439,14 @@ impl OpenSearchApi {
         }
     }
 
-    pub fn bulk_index(&self, operations: &str) -> Result<OpenSearchBulkResponse, SearchError> {
+    pub fn bulk_index(
+        &self,
+        operations: &str,
+        refresh: &str,
+    ) -> Result<OpenSearchBulkResponse, SearchError> {
         trace!("Performing bulk index operation");
 
-        let url = format!("{}/_bulk", self.base_url);
+        let url = format!("{}/_bulk?refresh={}", self.base_url, refresh);
 
         let response = self.execute_with_retry_sync(|| {
             self.create_request_with_content_type(Method::POST, &url, "application/x-ndjson")
@@ -417,10 +457,18 @@ impl OpenSearchApi {
         parse_response(response)
     }
 
-    pub fn delete_document(&self, index_name: &str, id: &str) -> Result<(), SearchError> {
+    pub fn delete_document(
+        &self,
+        index_name: &str,
+        id: &str,
+        refresh: &str,
+    ) -> Result<(), SearchError> {
         trace!("Deleting document {id} from index: {index_name}");
 
-        let url = format!("{}/{}/_doc/{}", self.base_url, index_name, id);
+        let url = format!(
+            "{}/{}/_doc/{}?refresh={}",
+            self.base_url, index_name, id, refresh
+        );
 
         let response =
             self.execute_with_retry_sync(|| self.create_request(Method::DELETE, &url).send())?;
@@ -470,6 +518,23 @@ impl OpenSearchApi {
         parse_response(response)
     }
 
+    pub fn multi_search(
+        &self,
+        searches: &str,
+    ) -> Result<OpenSearchMultiSearchResponse, SearchError> {
+        trace!("Performing multi-search");
+
+        let url = format!("{}/_msearch", self.base_url);
+
+        let response = self.execute_with_retry_sync(|| {
+            self.create_request_with_content_type(Method::POST, &url, "application/x-ndjson")
+                .body(searches.to_string())
+                .send()
+        })?;
+
+        parse_response(response)
+    }
+
     pub fn search_with_scroll(
         &self,
         index_name: &str,
@@ -564,6 +629,116 @@ impl OpenSearchApi {
             Err(search_error_from_status(response.status()))
         }
     }
+
+    pub fn get_settings(&self, index_name: &str) -> Result<Value, SearchError> {
+        trace!("Getting settings for index: {index_name}");
+
+        let url = format!("{}/{}/_settings", self.base_url, index_name);
+
+        let response =
+            self.execute_with_retry_sync(|| self.create_request(Method::GET, &url).send())?;
+
+        parse_response(response)
+    }
+
+    pub fn put_settings(&self, index_name: &str, settings: &Value) -> Result<(), SearchError> {
+        trace!("Putting settings for index: {index_name}");
+
+        let url = format!("{}/{}/_settings", self.base_url, index_name);
+
+        let response = self.execute_with_retry_sync(|| {
+            self.create_request(Method::PUT, &url).json(settings).send()
+        })?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(search_error_from_status(response.status()))
+        }
+    }
+
+    /// Closes the index, or opens it again when `open` is set
+    pub fn set_index_open(&self, index_name: &str, open: bool) -> Result<(), SearchError> {
+        let action = if open { "_open" } else { "_close" };
+        trace!("Running {action} on index: {index_name}");
+
+        let url = format!("{}/{}/{}", self.base_url, index_name, action);
+
+        let response =
+            self.execute_with_retry_sync(|| self.create_request(Method::POST, &url).send())?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(search_error_from_status(response.status()))
+        }
+    }
+
+    pub fn update_aliases(&self, actions: &Value) -> Result<(), SearchError> {
+        trace!("Updating aliases: {actions}");
+
+        let url = format!("{}/_aliases", self.base_url);
+
+        let response = self.execute_with_retry_sync(|| {
+            self.create_request(Method::POST, &url).json(actions).send()
+        })?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(search_error_from_status(response.status()))
+        }
+    }
+
+    /// Aliases of the indices, keyed by index name. Only the aliases named `alias` are returned
+    /// if it is given, and none if it does not exist.
+    pub fn get_aliases(
+        &self,
+        alias: Option<&str>,
+    ) -> Result<HashMap<String, OpenSearchIndexAliases>, SearchError> {
+        trace!("Getting aliases: {alias:?}");
+
+        let url = match alias {
+            Some(alias) => format!("{}/_alias/{}", self.base_url, alias),
+            None => format!("{}/_alias", self.base_url),
+        };
+
+        let response =
+            self.execute_with_retry_sync(|| self.create_request(Method::GET, &url).send())?;
+
+        if alias.is_some() && response.status() == 404 {
+            Ok(HashMap::new())
+        } else {
+            parse_response(response)
+        }
+    }
+
+    /// Starts a reindex task on the cluster without waiting for it to complete
+    pub fn start_reindex(&self, request: &Value) -> Result<OpenSearchTaskStarted, SearchError> {
+        trace!("Starting reindex: {request}");
+
+        let url = format!(
+            "{}/_reindex?refresh=true&wait_for_completion=false",
+            self.base_url
+        );
+
+        let response = self.execute_with_retry_sync(|| {
+            self.create_request(Method::POST, &url).json(request).send()
+        })?;
+
+        parse_response(response)
+    }
+
+    pub fn get_task(&self, task_id: &str) -> Result<OpenSearchTask, SearchError> {
+        trace!("Getting task: {task_id}");
+
+        let url = format!("{}/_tasks/{}", self.base_url, task_id);
+
+        let response =
+            self.execute_with_retry_sync(|| self.create_request(Method::GET, &url).send())?;
+
+        parse_response(response)
+    }
 }
 
 fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, SearchError> {
@@ -1,10 +1,21 @@
 use crate::client::{
-    OpenSearchMappings, OpenSearchQuery, OpenSearchScrollResponse, OpenSearchSearchResponse,
-    OpenSearchSettings,
+    OpenSearchBulkResponse, OpenSearchIndexAliases, OpenSearchMappings, OpenSearchMultiSearchItem,
+    OpenSearchMultiSearchResponse, OpenSearchQuery, OpenSearchScrollResponse,
+    OpenSearchSearchResponse, OpenSearchSettings, OpenSearchTask,
 };
+use golem_search::aggregation::{
+    aggregations_to_query_dsl, facet_results_from_aggregations, validate_aggregations,
+};
+use golem_search::bulk::bulk_item_error;
+use golem_search::error::internal_error;
+use golem_search::filter::{filter_to_query_dsl, parse_filter_expr};
 use golem_search::golem::search::types::{
-    Doc, FieldType, Schema, SchemaField, SearchHit, SearchQuery, SearchResults,
+    Doc, FieldType, IndexAlias, ReindexResult, ReindexTransform, RelevanceSettings, Schema,
+    SchemaField, SearchError, SearchHit, SearchQuery, SearchResults, VectorConfig, VectorQuery,
+    VectorSimilarity,
 };
+use golem_search::relevance::relevance_from_analysis;
+use golem_search::vector::{insert_embeddings, validate_vector_query};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 
@@ -26,6 +37,7 @@ pub fn doc_to_opensearch_document(doc: Doc) -> Result<Value, String> {
             opensearch_doc.insert("content".to_string(), Value::String(doc.content));
         }
     }
+    insert_embeddings(&mut opensearch_doc, doc.embeddings);
 
     Ok(Value::Object(opensearch_doc))
 }
@@ -48,10 +60,18 @@ pub fn opensearch_document_to_doc(document: Value) -> Doc {
     let content =
         serde_json::to_string(&Value::Object(doc_map)).unwrap_or_else(|_| "{}".to_string());
 
-    Doc { id, content }
+    Doc {
+        id,
+        content,
+        embeddings: vec![],
+    }
 }
 
-pub fn search_query_to_opensearch_request(query: SearchQuery) -> OpenSearchQuery {
+pub fn search_query_to_opensearch_request(
+    query: SearchQuery,
+) -> Result<OpenSearchQuery, SearchError> {
+    validate_aggregations(&query)?;
+
     let mut opensearch_query = OpenSearchQuery {
         query: None,
         from: query.offset,
@@ -82,37 +102,40 @@ pub fn search_query_to_opensearch_request(query: SearchQuery) -> OpenSearchQuery
         }));
     }
 
-    if !query.filters.is_empty() {
-        let mut bool_query = serde_json::json!({
+    let mut filters = Vec::new();
+    for filter in query.filters {
+        if let Some((field, value)) = filter.split_once(':') {
+            filters.push(serde_json::json!({
+                "term": {
+                    field: value
+                }
+            }));
+        } else {
+            filters.push(serde_json::json!({
+                "query_string": {
+                    "query": filter
+                }
+            }));
+        }
+    }
+
+    if let Some(filter_expr) = &query.filter_expr {
+        let filter = parse_filter_expr(filter_expr)?;
+        filters.push(filter_to_query_dsl(&filter));
+    }
+
+    if let Some(vector_query) = &query.vector_query {
+        validate_vector_query(vector_query)?;
+        let text_query = opensearch_query.query.take();
+        opensearch_query.query = Some(knn_query(vector_query, text_query, filters));
+        opensearch_query.size = opensearch_query.size.or(Some(vector_query.k));
+    } else if !filters.is_empty() {
+        opensearch_query.query = Some(serde_json::json!({
             "bool": {
                 "must": opensearch_query.query.take(),
-                "filter": []
-            }
-        });
-
-        for filter in query.filters {
-            if let Some((field, value)) = filter.split_once(':') {
-                bool_query["bool"]["filter"]
-                    .as_array_mut()
-                    .unwrap()
-                    .push(serde_json::json!({
-                        "term": {
-                            field: value
-                        }
-                    }));
-            } else {
-                bool_query["bool"]["filter"]
-                    .as_array_mut()
-                    .unwrap()
-                    .push(serde_json::json!({
-                        "query_string": {
-                            "query": filter
-                        }
-                    }));
+                "filter": filters
             }
-        }
-
-        opensearch_query.query = Some(bool_query);
+        }));
     }
 
     if !query.sort.is_empty() {
@@ -168,8 +191,8 @@ pub fn search_query_to_opensearch_request(query: SearchQuery) -> OpenSearchQuery
         opensearch_query.highlight = Some(highlight);
     }
 
-    if !query.facets.is_empty() {
-        let mut aggs = Map::new();
+    if !query.facets.is_empty() || !query.aggregations.is_empty() {
+        let mut aggs = aggregations_to_query_dsl(&query.aggregations);
         for facet in query.facets {
             let field_name = if facet == "year" {
                 facet.clone()
@@ -178,7 +201,7 @@ pub fn search_query_to_opensearch_request(query: SearchQuery) -> OpenSearchQuery
             };
 
             aggs.insert(
-                format!("{facet}_terms"),
+                facet,
                 serde_json::json!({
                     "terms": {
                         "field": field_name,
@@ -190,7 +213,44 @@ pub fn search_query_to_opensearch_request(query: SearchQuery) -> OpenSearchQuery
         opensearch_query.aggs = Some(Value::Object(aggs));
     }
 
-    opensearch_query
+    Ok(opensearch_query)
+}
+
+/// Builds the k-NN query of a vector search. The filters are applied while searching the
+/// neighbours, and in a hybrid search the boosted full-text and k-NN scores are summed.
+fn knn_query(vector_query: &VectorQuery, text_query: Option<Value>, filters: Vec<Value>) -> Value {
+    let mut knn = serde_json::json!({
+        "vector": vector_query.embedding,
+        "k": vector_query.k
+    });
+    if !filters.is_empty() {
+        knn["filter"] = serde_json::json!({
+            "bool": {
+                "filter": filters.clone()
+            }
+        });
+    }
+
+    let text_query = text_query.filter(|query| query.get("multi_match").is_some());
+    match (vector_query.hybrid_weight, text_query) {
+        (Some(weight), Some(mut text_query)) => {
+            knn["boost"] = serde_json::json!(weight);
+            text_query["multi_match"]["boost"] = serde_json::json!(1.0 - weight);
+            serde_json::json!({
+                "bool": {
+                    "should": [
+                        text_query,
+                        { "knn": { &vector_query.field: knn } }
+                    ],
+                    "minimum_should_match": 1,
+                    "filter": filters
+                }
+            })
+        }
+        _ => serde_json::json!({
+            "knn": { &vector_query.field: knn }
+        }),
+    }
 }
 
 pub fn opensearch_response_to_search_results(response: OpenSearchSearchResponse) -> SearchResults {
@@ -232,30 +292,7 @@ pub fn opensearch_response_to_search_results(response: OpenSearchSearchResponse)
 
     let facets = response
         .aggregations
-        .map(|aggs| {
-            let mut facet_map = HashMap::new();
-            if let Value::Object(aggs_map) = aggs {
-                for (key, value) in aggs_map {
-                    if key.ends_with("_terms") {
-                        let facet_name = key.strip_suffix("_terms").unwrap_or(&key);
-                        if let Some(Value::Array(buckets_array)) = value.get("buckets") {
-                            let facet_values: Vec<String> = buckets_array
-                                .iter()
-                                .filter_map(|bucket| {
-                                    bucket
-                                        .get("key")
-                                        .and_then(|k| k.as_str().map(|s| s.to_string()))
-                                })
-                                .collect();
-                            if !facet_values.is_empty() {
-                                facet_map.insert(facet_name.to_string(), facet_values);
-                            }
-                        }
-                    }
-                }
-            }
-            facet_map
-        })
+        .map(|aggs| facet_results_from_aggregations(&aggs))
         .unwrap_or_default();
 
     SearchResults {
@@ -263,7 +300,7 @@ pub fn opensearch_response_to_search_results(response: OpenSearchSearchResponse)
         page: None,     // OpenSearch uses offset/size, not page numbers
         per_page: None, // We'll calculate this from the request
         hits,
-        facets: Some(serde_json::to_string(&facets).unwrap_or_else(|_| "{}".to_string())),
+        facets,
         took_ms: Some(response.took),
     }
 }
@@ -284,6 +321,7 @@ pub fn opensearch_scroll_response_to_search_results(
 
 pub fn schema_to_opensearch_settings(schema: Schema) -> OpenSearchSettings {
     let mut properties = Map::new();
+    let mut has_vector_fields = false;
 
     for field in schema.fields {
         let mut field_mapping = Map::new();
@@ -296,12 +334,37 @@ pub fn schema_to_opensearch_settings(schema: Schema) -> OpenSearchSettings {
             FieldType::Boolean => "boolean",
             FieldType::Date => "date",
             FieldType::GeoPoint => "geo_point",
+            FieldType::Vector => "knn_vector",
         };
         field_mapping.insert(
             "type".to_string(),
             Value::String(opensearch_type.to_string()),
         );
 
+        if let Some(config) = field
+            .vector
+            .as_ref()
+            .filter(|_| field.field_type == FieldType::Vector)
+        {
+            has_vector_fields = true;
+            field_mapping.insert(
+                "dimension".to_string(),
+                serde_json::json!(config.dimensions),
+            );
+            field_mapping.insert(
+                "method".to_string(),
+                serde_json::json!({
+                    "name": "hnsw",
+                    "engine": "lucene",
+                    "space_type": match config.similarity {
+                        VectorSimilarity::Cosine => "cosinesimil",
+                        VectorSimilarity::DotProduct => "innerproduct",
+                        VectorSimilarity::Euclidean => "l2",
+                    }
+                }),
+            );
+        }
+
         if field.field_type == FieldType::Text {
             field_mapping.insert(
                 "analyzer".to_string(),
@@ -326,6 +389,9 @@ pub fn schema_to_opensearch_settings(schema: Schema) -> OpenSearchSettings {
         "number_of_replicas".to_string(),
         Value::Number(serde_json::Number::from(0)),
     );
+    if has_vector_fields {
+        index_settings.insert("index.knn".to_string(), Value::Bool(true));
+    }
 
     OpenSearchSettings {
         mappings: Some(mappings),
@@ -358,17 +424,35 @@ pub fn opensearch_mappings_to_schema(
                                     "boolean" => FieldType::Boolean,
                                     "date" => FieldType::Date,
                                     "geo_point" => FieldType::GeoPoint,
+                                    "knn_vector" => FieldType::Vector,
                                     _ => FieldType::Text,
                                 })
                                 .unwrap_or(FieldType::Text);
 
+                            let vector = field_map
+                                .get("dimension")
+                                .and_then(|dimension| dimension.as_u64())
+                                .map(|dimension| VectorConfig {
+                                    dimensions: dimension as u32,
+                                    similarity: match field_map
+                                        .get("method")
+                                        .and_then(|method| method.get("space_type"))
+                                        .and_then(|space_type| space_type.as_str())
+                                    {
+                                        Some("innerproduct") => VectorSimilarity::DotProduct,
+                                        Some("l2") => VectorSimilarity::Euclidean,
+                                        _ => VectorSimilarity::Cosine,
+                                    },
+                                });
+
                             fields.push(SchemaField {
                                 name: field_name.clone(),
                                 field_type,
                                 required: false,
                                 facet: field_type == FieldType::Keyword,
-                                sort: true,
+                                sort: field_type != FieldType::Vector,
                                 index: true,
+                                vector,
                             });
                         }
                     }
@@ -393,16 +477,197 @@ pub fn create_retry_query(original_query: &SearchQuery, partial_hits: &[SearchHi
     retry_query
 }
 
+/// Outcome of each operation of a `_bulk` request, in the order of the operations
+pub fn bulk_response_results(response: &OpenSearchBulkResponse) -> Vec<Result<(), SearchError>> {
+    response
+        .items
+        .iter()
+        .map(|item| {
+            // Each item is keyed by its action, like `{"index": {"_id": .., "status": ..}}`
+            let Some(result) = item.as_object().and_then(|item| item.values().next()) else {
+                return Err(internal_error(format!(
+                    "Unexpected bulk response item: {item}"
+                )));
+            };
+            match result.get("error") {
+                None => Ok(()),
+                Some(error) => {
+                    let status = result.get("status").and_then(Value::as_u64).unwrap_or(500);
+                    Err(bulk_item_error(status as u16, error_reason(error)))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Body of an `_msearch` request, a header naming the index followed by the query of each search
+pub fn build_multi_search_body(searches: &[(String, OpenSearchQuery)]) -> Result<String, String> {
+    let mut body = String::new();
+
+    for (index_name, query) in searches {
+        let header = serde_json::json!({ "index": index_name });
+        body.push_str(&serde_json::to_string(&header).map_err(|e| e.to_string())?);
+        body.push('\n');
+        body.push_str(&serde_json::to_string(query).map_err(|e| e.to_string())?);
+        body.push('\n');
+    }
+
+    Ok(body)
+}
+
+/// Results of each search of an `_msearch` response, failing with the error of the first search
+/// that failed
+pub fn multi_search_response_results(
+    response: OpenSearchMultiSearchResponse,
+) -> Result<Vec<SearchResults>, SearchError> {
+    response
+        .responses
+        .into_iter()
+        .map(|item| match item {
+            OpenSearchMultiSearchItem::Results(response) => {
+                Ok(opensearch_response_to_search_results(response))
+            }
+            OpenSearchMultiSearchItem::Error { error, status } => {
+                Err(bulk_item_error(status, error_reason(&error)))
+            }
+        })
+        .collect()
+}
+
+fn error_reason(error: &Value) -> String {
+    error
+        .get("reason")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+/// Actions of an `_aliases` request pointing `alias` at `index`, removing it from the indices in
+/// `current` so that the alias is moved in one atomic update
+pub fn alias_actions(
+    alias: &str,
+    index: &str,
+    current: &HashMap<String, OpenSearchIndexAliases>,
+) -> Value {
+    let mut current = current
+        .keys()
+        .filter(|current| current.as_str() != index)
+        .collect::<Vec<_>>();
+    current.sort();
+
+    let mut actions = current
+        .into_iter()
+        .map(|current| serde_json::json!({"remove": {"index": current, "alias": alias}}))
+        .collect::<Vec<_>>();
+    actions.push(serde_json::json!({"add": {"index": index, "alias": alias}}));
+    serde_json::json!({ "actions": actions })
+}
+
+/// Aliases of an `_alias` response, leaving out the hidden indices starting with a dot
+pub fn aliases_from_response(indices: HashMap<String, OpenSearchIndexAliases>) -> Vec<IndexAlias> {
+    let mut aliases = indices
+        .into_iter()
+        .filter(|(index, _)| !index.starts_with('.'))
+        .flat_map(|(index, aliases)| {
+            aliases.aliases.into_keys().map(move |alias| IndexAlias {
+                alias,
+                index: index.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    aliases.sort_by(|a, b| (&a.alias, &a.index).cmp(&(&b.alias, &b.index)));
+    aliases
+}
+
+/// Body of a `_reindex` request copying the documents of `source` to `target`
+pub fn reindex_request(
+    source: &str,
+    target: &str,
+    transform: Option<&ReindexTransform>,
+) -> Result<Value, SearchError> {
+    let mut source_spec = serde_json::json!({ "index": source });
+    if let Some(transform) = transform {
+        if let Some(filter) = &transform.filter {
+            let filter = filter_to_query_dsl(&parse_filter_expr(filter)?);
+            source_spec["query"] = serde_json::json!({"bool": {"filter": [filter]}});
+        }
+        if !transform.fields.is_empty() {
+            source_spec["_source"] = serde_json::json!(transform.fields);
+        }
+    }
+
+    Ok(serde_json::json!({
+        "source": source_spec,
+        "dest": { "index": target }
+    }))
+}
+
+/// Result of a reindex task, `None` while the task is still running
+pub fn reindex_task_result(task: &OpenSearchTask) -> Result<Option<ReindexResult>, SearchError> {
+    if !task.completed {
+        return Ok(None);
+    }
+
+    if let Some(error) = &task.error {
+        let reason = error
+            .get("reason")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| error.to_string());
+        return Err(internal_error(format!("Reindex failed: {reason}")));
+    }
+
+    let response = task
+        .response
+        .as_ref()
+        .ok_or_else(|| internal_error("Reindex task finished without a response"))?;
+
+    if let Some(failure) = response
+        .get("failures")
+        .and_then(Value::as_array)
+        .and_then(|failures| failures.first())
+    {
+        let status = failure.get("status").and_then(Value::as_u64).unwrap_or(500);
+        let reason = failure
+            .pointer("/cause/reason")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| failure.to_string());
+        return Err(bulk_item_error(status as u16, reason));
+    }
+
+    let count = |field: &str| response.get(field).and_then(Value::as_u64).unwrap_or(0);
+    Ok(Some(ReindexResult {
+        copied: Some(count("created") + count("updated")),
+    }))
+}
+
+/// Relevance settings from a `GET _settings` response, which is keyed by the name of the index
+/// even when the settings are requested through an alias
+pub fn relevance_settings_from_response(response: &Value) -> RelevanceSettings {
+    let analysis = response
+        .as_object()
+        .and_then(|indices| indices.values().next())
+        .and_then(|index| index.pointer("/settings/index/analysis"));
+    relevance_from_analysis(analysis)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use golem_search::golem::search::types::HighlightConfig;
+    use golem_search::golem::search::types::{
+        Embedding, FilterExpr, FilterNode, FilterValue, HighlightConfig, RangeFilter, SynonymSet,
+    };
+    use golem_search::relevance::relevance_to_index_settings;
+    use golem_search::test_util::conformance_cases;
+    use serde_json::json;
 
     #[test]
     fn test_doc_to_opensearch_document() {
         let doc = Doc {
             id: "test-id".to_string(),
             content: r#"{"title": "Test Document", "content": "This is a test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let opensearch_doc = doc_to_opensearch_document(doc).unwrap();
@@ -416,6 +681,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: "invalid json".to_string(),
+            embeddings: vec![],
         };
 
         let opensearch_doc = doc_to_opensearch_document(doc).unwrap();
@@ -423,6 +689,24 @@ mod tests {
         assert_eq!(opensearch_doc.get("content").unwrap(), "invalid json");
     }
 
+    #[test]
+    fn test_doc_to_opensearch_document_with_embeddings() {
+        let doc = Doc {
+            id: "test-id".to_string(),
+            content: r#"{"title": "Test Document"}"#.to_string(),
+            embeddings: vec![Embedding {
+                field: "title_vector".to_string(),
+                values: vec![0.5, -0.25],
+            }],
+        };
+
+        let opensearch_doc = doc_to_opensearch_document(doc).unwrap();
+        assert_eq!(
+            opensearch_doc.get("title_vector").unwrap(),
+            &serde_json::json!([0.5, -0.25])
+        );
+    }
+
     #[test]
     fn test_opensearch_document_to_doc() {
         let opensearch_doc = serde_json::json!({
@@ -453,8 +737,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test query".to_string()),
             filters: vec!["category:electronics".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["price:desc".to_string()],
             facets: vec!["category".to_string()],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(20),
             offset: Some(10),
@@ -467,7 +754,7 @@ mod tests {
             config: None,
         };
 
-        let opensearch_query = search_query_to_opensearch_request(search_query);
+        let opensearch_query = search_query_to_opensearch_request(search_query).unwrap();
         assert!(opensearch_query.query.is_some());
         assert!(opensearch_query.sort.is_some());
         assert!(opensearch_query.aggs.is_some());
@@ -479,8 +766,11 @@ mod tests {
         let original_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(10),
             offset: Some(20),
@@ -498,4 +788,388 @@ mod tests {
         let retry_query = create_retry_query(&original_query, &partial_hits);
         assert_eq!(retry_query.offset, Some(21)); // 20 + 1 hit received
     }
+
+    #[test]
+    fn test_search_query_with_vector_query() {
+        let search_query = SearchQuery {
+            q: Some("space opera".to_string()),
+            filters: vec!["genre:scifi".to_string()],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.5, -0.25],
+                k: 5,
+                hybrid_weight: Some(0.75),
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let opensearch_query = search_query_to_opensearch_request(search_query.clone()).unwrap();
+        let filter = serde_json::json!({"term": {"genre": "scifi"}});
+        assert_eq!(opensearch_query.size, Some(5));
+        assert_eq!(
+            opensearch_query.query.unwrap(),
+            serde_json::json!({
+                "bool": {
+                    "should": [
+                        {
+                            "multi_match": {
+                                "query": "space opera",
+                                "type": "best_fields",
+                                "fields": ["*"],
+                                "boost": 0.25
+                            }
+                        },
+                        {
+                            "knn": {
+                                "title_vector": {
+                                    "vector": [0.5, -0.25],
+                                    "k": 5,
+                                    "filter": {"bool": {"filter": [filter.clone()]}},
+                                    "boost": 0.75
+                                }
+                            }
+                        }
+                    ],
+                    "minimum_should_match": 1,
+                    "filter": [filter]
+                }
+            })
+        );
+
+        let mut vector_only = search_query;
+        vector_only.filters = vec![];
+        if let Some(vector_query) = vector_only.vector_query.as_mut() {
+            vector_query.hybrid_weight = None;
+        }
+        let opensearch_query = search_query_to_opensearch_request(vector_only).unwrap();
+        assert_eq!(
+            opensearch_query.query.unwrap(),
+            serde_json::json!({
+                "knn": {"title_vector": {"vector": [0.5, -0.25], "k": 5}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_bulk_response_results() {
+        let response: OpenSearchBulkResponse = serde_json::from_value(serde_json::json!({
+            "took": 7,
+            "errors": true,
+            "items": [
+                {"index": {"_index": "test-index", "_id": "doc1", "status": 201, "result": "created"}},
+                {"index": {
+                    "_index": "test-index",
+                    "_id": "doc2",
+                    "status": 400,
+                    "error": {
+                        "type": "mapper_parsing_exception",
+                        "reason": "failed to parse field [year] of type [long]"
+                    }
+                }},
+                {"delete": {"_index": "test-index", "_id": "doc3", "status": 404, "result": "not_found"}},
+                {"delete": {
+                    "_index": "test-index",
+                    "_id": "doc4",
+                    "status": 503,
+                    "error": {"type": "unavailable_shards_exception", "reason": "primary shard is not active"}
+                }}
+            ]
+        }))
+        .unwrap();
+
+        let results = bulk_response_results(&response);
+        assert_eq!(
+            results,
+            vec![
+                Ok(()),
+                Err(SearchError::InvalidQuery(
+                    "failed to parse field [year] of type [long]".to_string()
+                )),
+                Ok(()),
+                Err(SearchError::Internal(
+                    "primary shard is not active".to_string()
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_search() {
+        let query: OpenSearchQuery =
+            serde_json::from_value(serde_json::json!({"query": {"match_all": {}}, "size": 5}))
+                .unwrap();
+        let body = build_multi_search_body(&[("products".to_string(), query)]).unwrap();
+        let lines = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect::<Vec<Value>>();
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({"index": "products"}),
+                serde_json::json!({"query": {"match_all": {}}, "size": 5})
+            ]
+        );
+
+        let response: OpenSearchMultiSearchResponse = serde_json::from_value(serde_json::json!({
+            "took": 4,
+            "responses": [
+                {
+                    "took": 3,
+                    "timed_out": false,
+                    "hits": {
+                        "total": {"value": 1, "relation": "eq"},
+                        "max_score": 1.5,
+                        "hits": [{"_index": "products", "_id": "p1", "_score": 1.5, "_source": {"title": "Golem"}}]
+                    },
+                    "status": 200
+                },
+                {
+                    "error": {"type": "search_phase_execution_exception", "reason": "all shards failed"},
+                    "status": 400
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            multi_search_response_results(response),
+            Err(SearchError::InvalidQuery("all shards failed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_alias_actions() {
+        let current = HashMap::from([
+            ("products_v1".to_string(), OpenSearchIndexAliases::default()),
+            ("products_v2".to_string(), OpenSearchIndexAliases::default()),
+        ]);
+
+        let actions = alias_actions("products", "products_v2", &current);
+        assert_eq!(
+            actions,
+            serde_json::json!({"actions": [
+                {"remove": {"index": "products_v1", "alias": "products"}},
+                {"add": {"index": "products_v2", "alias": "products"}}
+            ]})
+        );
+
+        let actions = alias_actions("products", "products_v1", &HashMap::new());
+        assert_eq!(
+            actions,
+            serde_json::json!({"actions": [{"add": {"index": "products_v1", "alias": "products"}}]})
+        );
+    }
+
+    #[test]
+    fn test_aliases_from_response() {
+        let response: HashMap<String, OpenSearchIndexAliases> =
+            serde_json::from_value(serde_json::json!({
+                "products_v2": {"aliases": {"products": {}, "catalog": {}}},
+                "orders": {"aliases": {}},
+                ".kibana_1": {"aliases": {".kibana": {}}}
+            }))
+            .unwrap();
+
+        let aliases = aliases_from_response(response);
+        assert_eq!(
+            aliases,
+            vec![
+                IndexAlias {
+                    alias: "catalog".to_string(),
+                    index: "products_v2".to_string(),
+                },
+                IndexAlias {
+                    alias: "products".to_string(),
+                    index: "products_v2".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reindex_request() {
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(2000)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec!["title".to_string(), "year".to_string()],
+        };
+
+        let request = reindex_request("products_v1", "products_v2", Some(&transform)).unwrap();
+        assert_eq!(request["source"]["index"], "products_v1");
+        assert_eq!(
+            request["source"]["_source"],
+            serde_json::json!(["title", "year"])
+        );
+        assert!(request["source"]["query"]["bool"]["filter"].is_array());
+        assert_eq!(request["dest"]["index"], "products_v2");
+
+        let request = reindex_request("products_v1", "products_v2", None).unwrap();
+        assert_eq!(
+            request,
+            serde_json::json!({"source": {"index": "products_v1"}, "dest": {"index": "products_v2"}})
+        );
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr { nodes: vec![] }),
+            fields: vec![],
+        };
+        assert!(matches!(
+            reindex_request("products_v1", "products_v2", Some(&transform)),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_reindex_task_result() {
+        let running: OpenSearchTask =
+            serde_json::from_value(serde_json::json!({"completed": false, "task": {}})).unwrap();
+        assert_eq!(reindex_task_result(&running), Ok(None));
+
+        let finished: OpenSearchTask = serde_json::from_value(serde_json::json!({
+            "completed": true,
+            "response": {"created": 120, "updated": 3, "failures": []}
+        }))
+        .unwrap();
+        assert_eq!(
+            reindex_task_result(&finished),
+            Ok(Some(ReindexResult { copied: Some(123) }))
+        );
+
+        let failed: OpenSearchTask = serde_json::from_value(serde_json::json!({
+            "completed": true,
+            "response": {
+                "created": 1,
+                "failures": [{
+                    "status": 400,
+                    "cause": {"reason": "failed to parse field [year] of type [long]"}
+                }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            reindex_task_result(&failed),
+            Err(SearchError::InvalidQuery(
+                "failed to parse field [year] of type [long]".to_string()
+            ))
+        );
+
+        let errored: OpenSearchTask = serde_json::from_value(serde_json::json!({
+            "completed": true,
+            "error": {"type": "index_not_found_exception", "reason": "no such index"}
+        }))
+        .unwrap();
+        assert!(matches!(
+            reindex_task_result(&errored),
+            Err(SearchError::Internal(_))
+        ));
+    }
+
+    #[test]
+    fn test_relevance_settings_from_response() {
+        let relevance = RelevanceSettings {
+            synonyms: vec![SynonymSet {
+                synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                root: None,
+            }],
+            stop_words: vec!["the".to_string()],
+            ranking_rules: vec![],
+            provider_settings: None,
+        };
+        let index_settings = relevance_to_index_settings(&relevance).unwrap();
+        let response = serde_json::json!({
+            "products-v2": {
+                "settings": {
+                    "index": {
+                        "number_of_shards": "1",
+                        "analysis": index_settings["analysis"]
+                    }
+                }
+            }
+        });
+        assert_eq!(relevance_settings_from_response(&response), relevance);
+        assert_eq!(
+            relevance_settings_from_response(&serde_json::json!({})),
+            RelevanceSettings {
+                synonyms: vec![],
+                stop_words: vec![],
+                ranking_rules: vec![],
+                provider_settings: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        for (name, expr) in conformance_cases() {
+            let query = SearchQuery {
+                q: None,
+                filters: vec![],
+                filter_expr: Some(expr),
+                vector_query: None,
+                sort: vec![],
+                facets: vec![],
+                aggregations: vec![],
+                page: None,
+                per_page: None,
+                offset: None,
+                highlight: None,
+                config: None,
+            };
+            let expected = match name {
+                "eq_text" => json!({"term": {"category": "books"}}),
+                "eq_integer" => json!({"term": {"year": 2020}}),
+                "eq_boolean" => json!({"term": {"in_stock": true}}),
+                "eq_escaped_text" => json!({"term": {"title": "say \"hi\""}}),
+                "ne" => json!({"bool": {"must_not": [{"term": {"category": "books"}}]}}),
+                "range" => json!({"range": {"price": {"gte": 10, "lt": 20.5}}}),
+                "in" => json!({"terms": {"category": ["books", "music"]}}),
+                "exists" => json!({"exists": {"field": "discount"}}),
+                "geo_radius" => json!({
+                    "geo_distance": {"distance": "5000m", "_geo": {"lat": 48.8566, "lon": 2.3522}}
+                }),
+                "and_or" => json!({"bool": {"filter": [
+                    {"term": {"category": "books"}},
+                    {"bool": {
+                        "should": [{"range": {"price": {"lt": 10}}}, {"term": {"in_stock": true}}],
+                        "minimum_should_match": 1
+                    }}
+                ]}}),
+                "not_and" => json!({"bool": {
+                    "should": [
+                        {"bool": {"must_not": [{"term": {"category": "books"}}]}},
+                        {"bool": {"must_not": [{"term": {"in_stock": true}}]}}
+                    ],
+                    "minimum_should_match": 1
+                }}),
+                "not_in" => {
+                    json!({"bool": {"must_not": [{"terms": {"category": ["books", "music"]}}]}})
+                }
+                "not_range" => {
+                    json!({"bool": {"must_not": [{"range": {"price": {"gte": 10, "lt": 20.5}}}]}})
+                }
+                _ => panic!("No expected OpenSearch filter for case {name}"),
+            };
+            let request = search_query_to_opensearch_request(query).unwrap();
+            assert_eq!(
+                request.query.unwrap()["bool"]["filter"],
+                json!([expected]),
+                "filter case {name}"
+            );
+        }
+    }
 }
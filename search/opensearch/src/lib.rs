@@ -1,19 +1,33 @@
 use crate::client::OpenSearchApi;
 use crate::conversions::{
-    create_retry_query, doc_to_opensearch_document, opensearch_document_to_doc,
-    opensearch_mappings_to_schema, opensearch_response_to_search_results,
-    opensearch_scroll_response_to_search_results, schema_to_opensearch_settings,
-    search_query_to_opensearch_request,
+    alias_actions, aliases_from_response, build_multi_search_body, bulk_response_results,
+    create_retry_query, doc_to_opensearch_document, multi_search_response_results,
+    opensearch_document_to_doc, opensearch_mappings_to_schema,
+    opensearch_response_to_search_results, opensearch_scroll_response_to_search_results,
+    reindex_request, reindex_task_result, relevance_settings_from_response,
+    schema_to_opensearch_settings, search_query_to_opensearch_request,
 };
 use golem_rust::wasm_rpc::Pollable;
+use golem_search::bulk::bulk_write;
 use golem_search::config::with_config_keys;
+use golem_search::consistency::{bulk_consistency, refresh_parameter, write_consistency};
 use golem_search::durability::{DurableSearch, ExtendedGuest};
+use golem_search::error::{invalid_query, unsupported};
+use golem_search::federation::{multi_search_queries, multi_search_results};
 use golem_search::golem::search::core::{Guest, GuestSearchStream, SearchStream};
 use golem_search::golem::search::types::{
-    Doc, DocumentId, IndexName, Schema, SearchError, SearchHit, SearchQuery, SearchResults,
+    BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+    MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
 };
-use log::trace;
+use golem_search::reindex::{run_reindex, ReindexTask};
+use golem_search::relevance::{
+    analysis_changed, relevance_to_index_settings, validate_relevance_settings,
+};
+use golem_search::vector::validate_vector_fields;
+use log::{error, trace};
 use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 
 mod client;
 mod conversions;
@@ -52,7 +66,10 @@ impl OpenSearchSearchStream {
 impl OpenSearchSearchStream {
     fn try_scroll_next(&self) -> Option<Option<Vec<SearchHit>>> {
         if self.scroll_id.borrow().is_none() {
-            let mut os_query = search_query_to_opensearch_request(self.query.clone());
+            let Ok(mut os_query) = search_query_to_opensearch_request(self.query.clone()) else {
+                self.finished.set(true);
+                return Some(Some(vec![]));
+            };
             os_query.from = Some(0);
             os_query.size = Some(self.query.per_page.unwrap_or(100)); // Larger page size for scroll
 
@@ -101,7 +118,10 @@ impl OpenSearchSearchStream {
     }
 
     fn try_pagination_next(&self) -> Option<Vec<SearchHit>> {
-        let mut os_query = search_query_to_opensearch_request(self.query.clone());
+        let Ok(mut os_query) = search_query_to_opensearch_request(self.query.clone()) else {
+            self.finished.set(true);
+            return Some(vec![]);
+        };
         os_query.from = Some(self.current_offset.get());
         os_query.size = Some(self.query.per_page.unwrap_or(10));
 
@@ -190,6 +210,9 @@ impl Guest for OpenSearchComponent {
     type SearchStream = OpenSearchSearchStream;
 
     fn create_index(name: IndexName, schema: Option<Schema>) -> Result<(), SearchError> {
+        if let Some(schema) = &schema {
+            validate_vector_fields(schema)?;
+        }
         let client = Self::create_client()?;
 
         let settings = schema.map(schema_to_opensearch_settings);
@@ -211,7 +234,11 @@ impl Guest for OpenSearchComponent {
         Ok(indices.into_iter().map(|idx| idx.index).collect())
     }
 
-    fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+    fn upsert(
+        index: IndexName,
+        doc: Doc,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
         let opensearch_doc = doc_to_opensearch_document(doc).map_err(SearchError::InvalidQuery)?;
 
@@ -221,75 +248,101 @@ impl Guest for OpenSearchComponent {
             .unwrap_or("unknown")
             .to_string();
 
-        client.index_document(&index, &doc_id, &opensearch_doc)?;
+        client.index_document(
+            &index,
+            &doc_id,
+            &opensearch_doc,
+            refresh_parameter(write_consistency(options.as_ref())),
+        )?;
 
-        Ok(())
+        // OpenSearch writes synchronously, there is no task to track
+        Ok(None)
     }
 
-    fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+    fn upsert_many(
+        index: IndexName,
+        docs: Vec<Doc>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-
-        if docs.is_empty() {
-            return Ok(());
-        }
-
-        let mut bulk_operations = Vec::new();
-        for doc in docs {
-            let opensearch_doc =
-                doc_to_opensearch_document(doc).map_err(SearchError::InvalidQuery)?;
-
-            let doc_id = opensearch_doc
-                .get("id")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown")
-                .to_string();
-
-            let action = serde_json::json!({
-                "index": {
-                    "_index": index,
-                    "_id": doc_id
+        let refresh = refresh_parameter(bulk_consistency(options.as_ref()));
+
+        bulk_write(
+            docs,
+            options.as_ref(),
+            |doc| doc.id.clone(),
+            |doc| doc_to_opensearch_document(doc).map_err(SearchError::InvalidQuery),
+            |opensearch_docs| {
+                let mut bulk_operations = Vec::new();
+                for opensearch_doc in opensearch_docs {
+                    let doc_id = opensearch_doc
+                        .get("id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("unknown")
+                        .to_string();
+
+                    let action = serde_json::json!({
+                        "index": {
+                            "_index": index,
+                            "_id": doc_id
+                        }
+                    });
+                    bulk_operations.push(serde_json::to_string(&action).unwrap());
+                    bulk_operations.push(serde_json::to_string(&opensearch_doc).unwrap());
                 }
-            });
-            bulk_operations.push(serde_json::to_string(&action).unwrap());
-            bulk_operations.push(serde_json::to_string(&opensearch_doc).unwrap());
-        }
-
-        let bulk_body = bulk_operations.join("\n") + "\n";
 
-        let _result = client.bulk_index(&bulk_body)?;
-
-        Ok(())
+                let bulk_body = bulk_operations.join("\n") + "\n";
+                let response = client.bulk_index(&bulk_body, refresh)?;
+                Ok(bulk_response_results(&response))
+            },
+        )
     }
 
-    fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+    fn delete(
+        index: IndexName,
+        id: DocumentId,
+        options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
-        client.delete_document(&index, &id)?;
+        client.delete_document(
+            &index,
+            &id,
+            refresh_parameter(write_consistency(options.as_ref())),
+        )?;
 
-        Ok(())
+        Ok(None)
     }
 
-    fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+    fn delete_many(
+        index: IndexName,
+        ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-
-        if ids.is_empty() {
-            return Ok(());
-        }
-
-        let mut bulk_operations = Vec::new();
-        for id in ids {
-            let action = serde_json::json!({
-                "delete": {
-                    "_index": index,
-                    "_id": id
+        let refresh = refresh_parameter(bulk_consistency(options.as_ref()));
+
+        bulk_write(
+            ids,
+            options.as_ref(),
+            |id| id.clone(),
+            Ok,
+            |ids| {
+                let mut bulk_operations = Vec::new();
+                for id in ids {
+                    let action = serde_json::json!({
+                        "delete": {
+                            "_index": index,
+                            "_id": id
+                        }
+                    });
+                    bulk_operations.push(serde_json::to_string(&action).unwrap());
                 }
-            });
-            bulk_operations.push(serde_json::to_string(&action).unwrap());
-        }
-
-        let bulk_body = bulk_operations.join("\n") + "\n";
-        client.bulk_index(&bulk_body)?;
 
-        Ok(())
+                let bulk_body = bulk_operations.join("\n") + "\n";
+                let response = client.bulk_index(&bulk_body, refresh)?;
+                Ok(bulk_response_results(&response))
+            },
+        )
     }
 
     fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
@@ -301,9 +354,13 @@ impl Guest for OpenSearchComponent {
         }
     }
 
+    fn get_task_status(_id: TaskId) -> Result<TaskStatus, SearchError> {
+        Err(unsupported("Write tasks in OpenSearch"))
+    }
+
     fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
         let client = Self::create_client()?;
-        let opensearch_request = search_query_to_opensearch_request(query);
+        let opensearch_request = search_query_to_opensearch_request(query)?;
 
         let response = client.search(&index, &opensearch_request)?;
         Ok(opensearch_response_to_search_results(response))
@@ -311,10 +368,31 @@ impl Guest for OpenSearchComponent {
 
     fn stream_search(index: IndexName, query: SearchQuery) -> Result<SearchStream, SearchError> {
         let client = Self::create_client()?;
+        search_query_to_opensearch_request(query.clone())?;
         let stream = OpenSearchSearchStream::new(client, index, query);
         Ok(SearchStream::new(stream))
     }
 
+    fn multi_search(
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    ) -> Result<MultiSearchResults, SearchError> {
+        let client = Self::create_client()?;
+        let queries = multi_search_queries(queries, federation.as_ref())?;
+        let searches = queries
+            .into_iter()
+            .map(|(index, query)| Ok((index, search_query_to_opensearch_request(query)?)))
+            .collect::<Result<Vec<_>, SearchError>>()?;
+        let body = build_multi_search_body(&searches).map_err(SearchError::InvalidQuery)?;
+
+        let results = multi_search_response_results(client.multi_search(&body)?)?;
+        let indexes = searches.into_iter().map(|(index, _)| index);
+        Ok(multi_search_results(
+            indexes.zip(results).collect(),
+            federation.as_ref(),
+        ))
+    }
+
     fn get_schema(index: IndexName) -> Result<Schema, SearchError> {
         let client = Self::create_client()?;
 
@@ -326,6 +404,7 @@ impl Guest for OpenSearchComponent {
     }
 
     fn update_schema(index: IndexName, schema: Schema) -> Result<(), SearchError> {
+        validate_vector_fields(&schema)?;
         let client = Self::create_client()?;
         let settings = schema_to_opensearch_settings(schema);
 
@@ -335,6 +414,63 @@ impl Guest for OpenSearchComponent {
 
         Ok(())
     }
+
+    fn create_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        let client = Self::create_client()?;
+        if !client.get_aliases(Some(&alias))?.is_empty() {
+            return Err(invalid_query(format!("Alias {alias} already exists")));
+        }
+        client.update_aliases(&alias_actions(&alias, &index, &HashMap::new()))
+    }
+
+    fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        let client = Self::create_client()?;
+        let current = client.get_aliases(Some(&alias))?;
+        client.update_aliases(&alias_actions(&alias, &index, &current))
+    }
+
+    fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+        let client = Self::create_client()?;
+        Ok(aliases_from_response(client.get_aliases(None)?))
+    }
+
+    fn reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexResult, SearchError> {
+        run_reindex::<Self>(source, target, transform)
+    }
+
+    fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+        let client = Self::create_client()?;
+        let response = client.get_settings(&index)?;
+        Ok(relevance_settings_from_response(&response))
+    }
+
+    fn update_relevance_settings(
+        index: IndexName,
+        settings: RelevanceSettings,
+    ) -> Result<(), SearchError> {
+        validate_relevance_settings(&settings)?;
+        let index_settings = relevance_to_index_settings(&settings)?;
+        let client = Self::create_client()?;
+
+        let current = relevance_settings_from_response(&client.get_settings(&index)?);
+        if !analysis_changed(&current, &settings) {
+            return Ok(());
+        }
+
+        // Analysis settings can only be changed while the index is closed. It is reopened even
+        // when the update fails, so a rejected update does not leave it unavailable.
+        client.set_index_open(&index, false)?;
+        let result = client.put_settings(&index, &index_settings);
+        let reopened = client.set_index_open(&index, true);
+        if let Err(err) = &reopened {
+            error!("Failed to reopen index {index} after updating its settings: {err:?}");
+        }
+        result.and(reopened)
+    }
 }
 
 impl ExtendedGuest for OpenSearchComponent {
@@ -353,6 +489,22 @@ impl ExtendedGuest for OpenSearchComponent {
     fn subscribe(stream: &Self::SearchStream) -> Pollable {
         stream.subscribe()
     }
+
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError> {
+        let client = Self::create_client()?;
+        let request = reindex_request(&source, &target, transform.as_ref())?;
+        let started = client.start_reindex(&request)?;
+        Ok(ReindexTask::Running(started.task))
+    }
+
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError> {
+        let client = Self::create_client()?;
+        reindex_task_result(&client.get_task(task_id)?)
+    }
 }
 
 impl Drop for OpenSearchSearchStream {
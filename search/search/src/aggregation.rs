@@ -0,0 +1,463 @@
+use crate::error::invalid_query;
+use crate::golem::search::types::{
+    Aggregation, AggregationKind, DateInterval, FacetBucket, FacetResult, FacetStats, RangeBucket,
+    SearchError, SearchQuery,
+};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+/// Value of a range bucket in the results, `from-to` with `*` for an open end unless a key is set
+pub fn range_key(bucket: &RangeBucket) -> String {
+    match &bucket.key {
+        Some(key) => key.clone(),
+        None => {
+            let bound =
+                |value: Option<f64>| value.map_or("*".to_string(), |value| value.to_string());
+            format!("{}-{}", bound(bucket.from), bound(bucket.to))
+        }
+    }
+}
+
+/// Calendar interval name shared by Elasticsearch and OpenSearch
+pub fn date_interval_name(interval: DateInterval) -> &'static str {
+    match interval {
+        DateInterval::Minute => "minute",
+        DateInterval::Hour => "hour",
+        DateInterval::Day => "day",
+        DateInterval::Week => "week",
+        DateInterval::Month => "month",
+        DateInterval::Quarter => "quarter",
+        DateInterval::Year => "year",
+    }
+}
+
+/// Checks that every facet result of the query gets a distinct name and that the ranges are valid
+pub fn validate_aggregations(query: &SearchQuery) -> Result<(), SearchError> {
+    let mut names: HashSet<&str> = query.facets.iter().map(String::as_str).collect();
+    for aggregation in &query.aggregations {
+        if aggregation.name.is_empty() || aggregation.field.is_empty() {
+            return Err(invalid_query(
+                "Aggregations need a non-empty name and field",
+            ));
+        }
+        if !names.insert(&aggregation.name) {
+            return Err(invalid_query(format!(
+                "Duplicate facet or aggregation name '{}'",
+                aggregation.name
+            )));
+        }
+        if let AggregationKind::Range(ranges) = &aggregation.kind {
+            if ranges.is_empty() {
+                return Err(invalid_query(format!(
+                    "Range aggregation '{}' has no ranges",
+                    aggregation.name
+                )));
+            }
+            for range in ranges {
+                if let (Some(from), Some(to)) = (range.from, range.to) {
+                    if from >= to {
+                        return Err(invalid_query(format!(
+                            "Range '{}' of aggregation '{}' is empty",
+                            range_key(range),
+                            aggregation.name
+                        )));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Aggregations in the query DSL of Elasticsearch and OpenSearch, keyed by the aggregation name
+pub fn aggregations_to_query_dsl(aggregations: &[Aggregation]) -> Map<String, Value> {
+    aggregations
+        .iter()
+        .map(|aggregation| {
+            let field = &aggregation.field;
+            let dsl = match &aggregation.kind {
+                AggregationKind::Stats => json!({ "stats": { "field": field } }),
+                AggregationKind::Range(ranges) => {
+                    let ranges: Vec<Value> = ranges
+                        .iter()
+                        .map(|range| {
+                            let mut dsl = json!({ "key": range_key(range) });
+                            if let Some(from) = range.from {
+                                dsl["from"] = json!(from);
+                            }
+                            if let Some(to) = range.to {
+                                dsl["to"] = json!(to);
+                            }
+                            dsl
+                        })
+                        .collect();
+                    json!({ "range": { "field": field, "ranges": ranges } })
+                }
+                AggregationKind::DateHistogram(interval) => json!({
+                    "date_histogram": {
+                        "field": field,
+                        "calendar_interval": date_interval_name(*interval),
+                        "min_doc_count": 1
+                    }
+                }),
+            };
+            (aggregation.name.clone(), dsl)
+        })
+        .collect()
+}
+
+/// Facet results of the `aggregations` of an Elasticsearch or OpenSearch response.
+/// Bucket aggregations keep the order of the provider, metric aggregations become stats.
+pub fn facet_results_from_aggregations(aggregations: &Value) -> Vec<FacetResult> {
+    let Some(aggregations) = aggregations.as_object() else {
+        return Vec::new();
+    };
+
+    aggregations
+        .iter()
+        .filter_map(|(name, aggregation)| {
+            if let Some(buckets) = aggregation.get("buckets") {
+                let buckets = match buckets {
+                    Value::Array(buckets) => buckets.iter().filter_map(bucket_from_dsl).collect(),
+                    Value::Object(buckets) => buckets
+                        .iter()
+                        .map(|(key, bucket)| FacetBucket {
+                            value: key.clone(),
+                            count: bucket["doc_count"].as_u64().unwrap_or(0),
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                Some(FacetResult {
+                    name: name.clone(),
+                    buckets,
+                    stats: None,
+                })
+            } else if aggregation.get("count").is_some() {
+                Some(FacetResult {
+                    name: name.clone(),
+                    buckets: Vec::new(),
+                    stats: Some(FacetStats {
+                        min: aggregation["min"].as_f64(),
+                        max: aggregation["max"].as_f64(),
+                        avg: aggregation["avg"].as_f64(),
+                        sum: aggregation["sum"].as_f64(),
+                    }),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn bucket_from_dsl(bucket: &Value) -> Option<FacetBucket> {
+    let value = match (bucket.get("key_as_string"), bucket.get("key")) {
+        (Some(Value::String(key)), _) | (_, Some(Value::String(key))) => key.clone(),
+        (_, Some(key @ (Value::Number(_) | Value::Bool(_)))) => key.to_string(),
+        _ => return None,
+    };
+    Some(FacetBucket {
+        value,
+        count: bucket["doc_count"].as_u64().unwrap_or(0),
+    })
+}
+
+/// Orders the buckets of a value facet by descending count, then by value
+pub fn sort_facet_buckets(buckets: &mut [FacetBucket]) {
+    buckets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+}
+
+/// Fields the providers computing stats as part of their facets have to facet on: the facets
+/// of the query followed by the fields of its `stats` aggregations
+pub fn facet_fields(query: &SearchQuery) -> Vec<String> {
+    let mut fields = query.facets.clone();
+    for aggregation in &query.aggregations {
+        if matches!(aggregation.kind, AggregationKind::Stats)
+            && !fields.contains(&aggregation.field)
+        {
+            fields.push(aggregation.field.clone());
+        }
+    }
+    fields
+}
+
+/// Facet results of the providers returning value counts and stats per field, `counts` maps
+/// each field to its values and their counts and `stats` each field to its statistics.
+/// Returns a result per facet of the query followed by a result per `stats` aggregation.
+pub fn field_facet_results(
+    query: &SearchQuery,
+    counts: &Map<String, Value>,
+    stats: &Map<String, Value>,
+) -> Vec<FacetResult> {
+    let field_stats = |field: &str| {
+        stats.get(field).map(|stats| FacetStats {
+            min: stats["min"].as_f64(),
+            max: stats["max"].as_f64(),
+            avg: stats["avg"].as_f64(),
+            sum: stats["sum"].as_f64(),
+        })
+    };
+
+    let facets = query.facets.iter().map(|facet| {
+        let mut buckets: Vec<FacetBucket> = counts
+            .get(facet)
+            .and_then(Value::as_object)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|(value, count)| FacetBucket {
+                        value: value.clone(),
+                        count: count.as_u64().unwrap_or(0),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        sort_facet_buckets(&mut buckets);
+        FacetResult {
+            name: facet.clone(),
+            buckets,
+            stats: field_stats(facet),
+        }
+    });
+    let aggregations = query
+        .aggregations
+        .iter()
+        .filter(|aggregation| matches!(aggregation.kind, AggregationKind::Stats))
+        .map(|aggregation| FacetResult {
+            name: aggregation.name.clone(),
+            buckets: Vec::new(),
+            stats: Some(field_stats(&aggregation.field).unwrap_or(FacetStats {
+                min: None,
+                max: None,
+                avg: None,
+                sum: None,
+            })),
+        });
+    facets.chain(aggregations).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(facets: Vec<&str>, aggregations: Vec<Aggregation>) -> SearchQuery {
+        SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: facets.into_iter().map(String::from).collect(),
+            aggregations,
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        }
+    }
+
+    fn aggregation(name: &str, kind: AggregationKind) -> Aggregation {
+        Aggregation {
+            name: name.to_string(),
+            field: "price".to_string(),
+            kind,
+        }
+    }
+
+    fn range(key: Option<&str>, from: Option<f64>, to: Option<f64>) -> RangeBucket {
+        RangeBucket {
+            key: key.map(String::from),
+            from,
+            to,
+        }
+    }
+
+    #[test]
+    fn test_range_key() {
+        assert_eq!(range_key(&range(None, Some(10.0), Some(20.5))), "10-20.5");
+        assert_eq!(range_key(&range(None, None, Some(10.0))), "*-10");
+        assert_eq!(range_key(&range(None, Some(20.0), None)), "20-*");
+        assert_eq!(range_key(&range(Some("cheap"), None, Some(10.0))), "cheap");
+    }
+
+    #[test]
+    fn test_validate_aggregations() {
+        let stats = aggregation("price_stats", AggregationKind::Stats);
+        assert!(validate_aggregations(&query(vec!["category"], vec![stats.clone()])).is_ok());
+        assert!(matches!(
+            validate_aggregations(&query(vec!["price_stats"], vec![stats.clone()])),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            validate_aggregations(&query(vec![], vec![stats.clone(), stats])),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            validate_aggregations(&query(
+                vec![],
+                vec![aggregation("ranges", AggregationKind::Range(vec![]))]
+            )),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            validate_aggregations(&query(
+                vec![],
+                vec![aggregation(
+                    "ranges",
+                    AggregationKind::Range(vec![range(None, Some(20.0), Some(10.0))])
+                )]
+            )),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_aggregations_to_query_dsl() {
+        let dsl = aggregations_to_query_dsl(&[
+            aggregation("price_stats", AggregationKind::Stats),
+            aggregation(
+                "price_ranges",
+                AggregationKind::Range(vec![
+                    range(None, None, Some(10.0)),
+                    range(Some("expensive"), Some(10.0), None),
+                ]),
+            ),
+            Aggregation {
+                name: "per_month".to_string(),
+                field: "created_at".to_string(),
+                kind: AggregationKind::DateHistogram(DateInterval::Month),
+            },
+        ]);
+
+        assert_eq!(dsl["price_stats"], json!({ "stats": { "field": "price" } }));
+        assert_eq!(
+            dsl["price_ranges"],
+            json!({
+                "range": {
+                    "field": "price",
+                    "ranges": [
+                        { "key": "*-10", "to": 10.0 },
+                        { "key": "expensive", "from": 10.0 }
+                    ]
+                }
+            })
+        );
+        assert_eq!(
+            dsl["per_month"],
+            json!({
+                "date_histogram": {
+                    "field": "created_at",
+                    "calendar_interval": "month",
+                    "min_doc_count": 1
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_facet_results_from_aggregations() {
+        let aggregations = json!({
+            "category": {
+                "doc_count_error_upper_bound": 0,
+                "sum_other_doc_count": 0,
+                "buckets": [
+                    { "key": "books", "doc_count": 3 },
+                    { "key": "music", "doc_count": 1 }
+                ]
+            },
+            "price_stats": { "count": 4, "min": 1.0, "max": 9.0, "avg": 4.5, "sum": 18.0 },
+            "empty_stats": { "count": 0, "min": null, "max": null, "avg": null, "sum": 0.0 },
+            "per_month": {
+                "buckets": [
+                    { "key_as_string": "2024-01-01T00:00:00.000Z", "key": 1704067200000_i64, "doc_count": 2 }
+                ]
+            },
+            "year": { "buckets": [{ "key": 1999, "doc_count": 5 }] }
+        });
+
+        let results = facet_results_from_aggregations(&aggregations);
+        let result = |name: &str| results.iter().find(|result| result.name == name).unwrap();
+
+        assert_eq!(results.len(), 5);
+        assert_eq!(
+            result("category").buckets,
+            vec![
+                FacetBucket {
+                    value: "books".to_string(),
+                    count: 3
+                },
+                FacetBucket {
+                    value: "music".to_string(),
+                    count: 1
+                }
+            ]
+        );
+        assert_eq!(
+            result("price_stats").stats,
+            Some(FacetStats {
+                min: Some(1.0),
+                max: Some(9.0),
+                avg: Some(4.5),
+                sum: Some(18.0)
+            })
+        );
+        assert_eq!(result("empty_stats").stats.as_ref().unwrap().min, None);
+        assert_eq!(
+            result("per_month").buckets[0].value,
+            "2024-01-01T00:00:00.000Z"
+        );
+        assert_eq!(result("year").buckets[0].value, "1999");
+    }
+
+    #[test]
+    fn test_field_facet_results() {
+        let query = query(
+            vec!["category"],
+            vec![aggregation("price_stats", AggregationKind::Stats)],
+        );
+        assert_eq!(facet_fields(&query), vec!["category", "price"]);
+
+        let counts = json!({
+            "category": { "music": 1, "books": 3 },
+            "price": { "1": 1, "9": 1 }
+        });
+        let stats = json!({ "price": { "min": 1.0, "max": 9.0 } });
+        let results = field_facet_results(
+            &query,
+            counts.as_object().unwrap(),
+            stats.as_object().unwrap(),
+        );
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "category");
+        assert_eq!(results[0].buckets[0].value, "books");
+        assert_eq!(results[0].stats, None);
+        assert_eq!(results[1].name, "price_stats");
+        assert!(results[1].buckets.is_empty());
+        assert_eq!(
+            results[1].stats,
+            Some(FacetStats {
+                min: Some(1.0),
+                max: Some(9.0),
+                avg: None,
+                sum: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_sort_facet_buckets() {
+        let bucket = |value: &str, count| FacetBucket {
+            value: value.to_string(),
+            count,
+        };
+        let mut buckets = vec![bucket("b", 1), bucket("c", 2), bucket("a", 1)];
+        sort_facet_buckets(&mut buckets);
+        assert_eq!(
+            buckets,
+            vec![bucket("c", 2), bucket("a", 1), bucket("b", 1)]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::error::{internal_error, invalid_query};
+use crate::golem::search::types::{BulkItem, BulkOptions, BulkResult, DocumentId, SearchError};
+
+/// Number of items written by one request of a bulk write
+pub fn chunk_size(options: Option<&BulkOptions>, len: usize) -> Result<usize, SearchError> {
+    match options.and_then(|options| options.chunk_size) {
+        Some(0) => Err(invalid_query("Bulk chunk size has to be at least 1")),
+        Some(chunk_size) => Ok(chunk_size as usize),
+        None => Ok(len.max(1)),
+    }
+}
+
+/// Runs a bulk write over `items`, one request per chunk of `chunk-size` items.
+///
+/// `prepare` converts an item to the form sent to the provider, the items it rejects are reported
+/// as failed without being sent. `send` writes the prepared items of a chunk and returns the
+/// outcome of each of them, in the order they were given. If the request of a chunk fails as a
+/// whole, its error is reported for every item of the chunk and the next chunks are still sent.
+/// The result has no tasks, providers writing asynchronously add the tasks of the chunks to it.
+pub fn bulk_write<T, P>(
+    items: Vec<T>,
+    options: Option<&BulkOptions>,
+    id: impl Fn(&T) -> DocumentId,
+    mut prepare: impl FnMut(T) -> Result<P, SearchError>,
+    mut send: impl FnMut(Vec<P>) -> Result<Vec<Result<(), SearchError>>, SearchError>,
+) -> Result<BulkResult, SearchError> {
+    let chunk_size = chunk_size(options, items.len())?;
+    let mut results = Vec::with_capacity(items.len());
+
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        let chunk = items.by_ref().take(chunk_size).collect::<Vec<_>>();
+        let ids = chunk.iter().map(&id).collect::<Vec<_>>();
+        let mut errors: Vec<Option<SearchError>> = vec![None; chunk.len()];
+
+        let mut prepared = Vec::new();
+        let mut positions = Vec::new();
+        for (position, item) in chunk.into_iter().enumerate() {
+            match prepare(item) {
+                Ok(item) => {
+                    prepared.push(item);
+                    positions.push(position);
+                }
+                Err(error) => errors[position] = Some(error),
+            }
+        }
+
+        if !prepared.is_empty() {
+            match send(prepared) {
+                Ok(outcomes) => {
+                    let mut outcomes = outcomes.into_iter();
+                    for position in positions {
+                        errors[position] = match outcomes.next() {
+                            Some(outcome) => outcome.err(),
+                            None => Some(internal_error(
+                                "Bulk response is missing the result of a document",
+                            )),
+                        };
+                    }
+                }
+                Err(error) => {
+                    for position in positions {
+                        errors[position] = Some(error.clone());
+                    }
+                }
+            }
+        }
+
+        results.extend(
+            ids.into_iter()
+                .zip(errors)
+                .map(|(id, error)| BulkItem { id, error }),
+        );
+    }
+
+    Ok(BulkResult {
+        items: results,
+        tasks: Vec::new(),
+    })
+}
+
+/// Error of a single item of a bulk request, from the HTTP status reported for the item
+pub fn bulk_item_error(status: u16, reason: impl AsRef<str>) -> SearchError {
+    match status {
+        404 => SearchError::IndexNotFound,
+        429 => SearchError::RateLimited,
+        400..=499 => invalid_query(reason),
+        _ => internal_error(reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bulk::{bulk_item_error, bulk_write, chunk_size};
+    use crate::error::invalid_query;
+    use crate::golem::search::types::{BulkOptions, SearchError};
+
+    #[test]
+    fn chunk_size_defaults_to_a_single_chunk() {
+        assert_eq!(chunk_size(None, 1000).unwrap(), 1000);
+        assert_eq!(chunk_size(None, 0).unwrap(), 1);
+        let options = BulkOptions {
+            chunk_size: Some(100),
+            consistency: None,
+        };
+        assert_eq!(chunk_size(Some(&options), 1000).unwrap(), 100);
+        let options = BulkOptions {
+            chunk_size: Some(0),
+            consistency: None,
+        };
+        assert!(matches!(
+            chunk_size(Some(&options), 1000),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn bulk_write_reports_each_item_in_order() {
+        let items = (1..=7).collect::<Vec<u32>>();
+        let options = BulkOptions {
+            chunk_size: Some(3),
+            consistency: None,
+        };
+        let mut chunks = Vec::new();
+
+        let result = bulk_write(
+            items,
+            Some(&options),
+            |item| item.to_string(),
+            |item| {
+                if item == 2 {
+                    Err(invalid_query("invalid document"))
+                } else {
+                    Ok(item * 10)
+                }
+            },
+            |prepared| {
+                chunks.push(prepared.clone());
+                if prepared.contains(&70) {
+                    return Err(SearchError::Timeout);
+                }
+                Ok(prepared
+                    .into_iter()
+                    .map(|item| {
+                        if item == 50 {
+                            Err(invalid_query("mapping"))
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .collect())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chunks, vec![vec![10, 30], vec![40, 50, 60], vec![70]]);
+        let ids = result
+            .items
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["1", "2", "3", "4", "5", "6", "7"]);
+        let errors = result
+            .items
+            .iter()
+            .map(|item| item.error.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                None,
+                Some(SearchError::InvalidQuery("invalid document".to_string())),
+                None,
+                None,
+                Some(SearchError::InvalidQuery("mapping".to_string())),
+                None,
+                Some(SearchError::Timeout),
+            ]
+        );
+    }
+
+    #[test]
+    fn bulk_write_reports_missing_results() {
+        let result = bulk_write(
+            vec!["a", "b"],
+            None,
+            |item| item.to_string(),
+            Ok,
+            |_| Ok(vec![Ok(())]),
+        )
+        .unwrap();
+
+        assert_eq!(result.items[0].error, None);
+        assert!(matches!(
+            result.items[1].error,
+            Some(SearchError::Internal(_))
+        ));
+    }
+
+    #[test]
+    fn bulk_item_error_from_status() {
+        assert_eq!(
+            bulk_item_error(400, "mapper_parsing_exception"),
+            SearchError::InvalidQuery("mapper_parsing_exception".to_string())
+        );
+        assert_eq!(
+            bulk_item_error(404, "no such index"),
+            SearchError::IndexNotFound
+        );
+        assert_eq!(bulk_item_error(429, "rejected"), SearchError::RateLimited);
+        assert_eq!(
+            bulk_item_error(500, "shard failure"),
+            SearchError::Internal("shard failure".to_string())
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::golem::search::types::{BulkOptions, WriteConsistency, WriteOptions};
+
+/// Consistency of a single document write, `async` when not set
+pub fn write_consistency(options: Option<&WriteOptions>) -> WriteConsistency {
+    options
+        .and_then(|options| options.consistency)
+        .unwrap_or(WriteConsistency::Async)
+}
+
+/// Consistency of a bulk write, `async` when not set
+pub fn bulk_consistency(options: Option<&BulkOptions>) -> WriteConsistency {
+    options
+        .and_then(|options| options.consistency)
+        .unwrap_or(WriteConsistency::Async)
+}
+
+/// Whether a provider processing writes in tasks waits for the task of a write before returning.
+/// Such providers make a write searchable as soon as its task is processed, so `refresh` waits
+/// just like `wait-for-indexing`.
+pub fn waits_for_task(consistency: WriteConsistency) -> bool {
+    consistency != WriteConsistency::Async
+}
+
+/// Value of the `refresh` parameter of an Elasticsearch or OpenSearch write
+pub fn refresh_parameter(consistency: WriteConsistency) -> &'static str {
+    match consistency {
+        WriteConsistency::Async => "false",
+        WriteConsistency::WaitForIndexing => "wait_for",
+        WriteConsistency::Refresh => "true",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consistency::{
+        bulk_consistency, refresh_parameter, waits_for_task, write_consistency,
+    };
+    use crate::golem::search::types::{BulkOptions, WriteConsistency, WriteOptions};
+
+    #[test]
+    fn consistency_defaults_to_async() {
+        assert_eq!(write_consistency(None), WriteConsistency::Async);
+        assert_eq!(
+            write_consistency(Some(&WriteOptions { consistency: None })),
+            WriteConsistency::Async
+        );
+        assert_eq!(
+            write_consistency(Some(&WriteOptions {
+                consistency: Some(WriteConsistency::WaitForIndexing),
+            })),
+            WriteConsistency::WaitForIndexing
+        );
+        assert_eq!(bulk_consistency(None), WriteConsistency::Async);
+        assert_eq!(
+            bulk_consistency(Some(&BulkOptions {
+                chunk_size: Some(10),
+                consistency: Some(WriteConsistency::Refresh),
+            })),
+            WriteConsistency::Refresh
+        );
+    }
+
+    #[test]
+    fn consistency_of_providers() {
+        assert!(!waits_for_task(WriteConsistency::Async));
+        assert!(waits_for_task(WriteConsistency::WaitForIndexing));
+        assert!(waits_for_task(WriteConsistency::Refresh));
+
+        assert_eq!(refresh_parameter(WriteConsistency::Async), "false");
+        assert_eq!(
+            refresh_parameter(WriteConsistency::WaitForIndexing),
+            "wait_for"
+        );
+        assert_eq!(refresh_parameter(WriteConsistency::Refresh), "true");
+    }
+}
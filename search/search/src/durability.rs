@@ -1,5 +1,8 @@
 use crate::golem::search::core::Guest;
-use crate::golem::search::types::{IndexName, SearchHit, SearchQuery};
+use crate::golem::search::types::{
+    IndexName, ReindexResult, ReindexTransform, SearchError, SearchHit, SearchQuery,
+};
+use crate::reindex::ReindexTask;
 use golem_rust::wasm_rpc::Pollable;
 use std::marker::PhantomData;
 
@@ -8,6 +11,10 @@ pub struct DurableSearch<Impl> {
 }
 
 pub trait ExtendedGuest: Guest + 'static {
+    /// Providers keeping their index in the worker memory apply the writes again when they are
+    /// replayed from the oplog, which restores the index after the worker is restarted
+    const REAPPLY_REPLAYED_WRITES: bool = false;
+
     fn unwrapped_stream(index: IndexName, query: SearchQuery) -> Self::SearchStream;
 
     /// Creates the retry query with the original query and any partial results received.
@@ -28,6 +35,17 @@ pub trait ExtendedGuest: Guest + 'static {
     }
 
     fn subscribe(stream: &Self::SearchStream) -> Pollable;
+
+    /// Starts copying the documents of `source` into `target`, used by `reindex` to poll the
+    /// task durably
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError>;
+
+    /// Checks a reindex task started by `start_reindex`, `None` while it is still running
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError>;
 }
 
 /// When the durability feature flag is off, wrapping with `DurableSearch` is just a passthrough
@@ -36,7 +54,9 @@ mod passthrough_impl {
     use crate::durability::{DurableSearch, ExtendedGuest};
     use crate::golem::search::core::{Guest, SearchStream};
     use crate::golem::search::types::{
-        Doc, DocumentId, IndexName, Schema, SearchError, SearchQuery, SearchResults,
+        BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+        MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema,
+        SearchError, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
     };
     use crate::init_logging;
 
@@ -58,24 +78,40 @@ mod passthrough_impl {
             Impl::list_indexes()
         }
 
-        fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+        fn upsert(
+            index: IndexName,
+            doc: Doc,
+            options: Option<WriteOptions>,
+        ) -> Result<Option<TaskId>, SearchError> {
             init_logging();
-            Impl::upsert(index, doc)
+            Impl::upsert(index, doc, options)
         }
 
-        fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+        fn upsert_many(
+            index: IndexName,
+            docs: Vec<Doc>,
+            options: Option<BulkOptions>,
+        ) -> Result<BulkResult, SearchError> {
             init_logging();
-            Impl::upsert_many(index, docs)
+            Impl::upsert_many(index, docs, options)
         }
 
-        fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+        fn delete(
+            index: IndexName,
+            id: DocumentId,
+            options: Option<WriteOptions>,
+        ) -> Result<Option<TaskId>, SearchError> {
             init_logging();
-            Impl::delete(index, id)
+            Impl::delete(index, id, options)
         }
 
-        fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+        fn delete_many(
+            index: IndexName,
+            ids: Vec<DocumentId>,
+            options: Option<BulkOptions>,
+        ) -> Result<BulkResult, SearchError> {
             init_logging();
-            Impl::delete_many(index, ids)
+            Impl::delete_many(index, ids, options)
         }
 
         fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
@@ -83,6 +119,11 @@ mod passthrough_impl {
             Impl::get(index, id)
         }
 
+        fn get_task_status(id: TaskId) -> Result<TaskStatus, SearchError> {
+            init_logging();
+            Impl::get_task_status(id)
+        }
+
         fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
             init_logging();
             Impl::search(index, query)
@@ -96,6 +137,14 @@ mod passthrough_impl {
             Impl::stream_search(index, query)
         }
 
+        fn multi_search(
+            queries: Vec<(IndexName, SearchQuery)>,
+            federation: Option<FederationOptions>,
+        ) -> Result<MultiSearchResults, SearchError> {
+            init_logging();
+            Impl::multi_search(queries, federation)
+        }
+
         fn get_schema(index: IndexName) -> Result<Schema, SearchError> {
             init_logging();
             Impl::get_schema(index)
@@ -105,17 +154,59 @@ mod passthrough_impl {
             init_logging();
             Impl::update_schema(index, schema)
         }
+
+        fn create_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+            init_logging();
+            Impl::create_alias(alias, index)
+        }
+
+        fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+            init_logging();
+            Impl::swap_alias(alias, index)
+        }
+
+        fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+            init_logging();
+            Impl::list_aliases()
+        }
+
+        fn reindex(
+            source: IndexName,
+            target: IndexName,
+            transform: Option<ReindexTransform>,
+        ) -> Result<ReindexResult, SearchError> {
+            init_logging();
+            Impl::reindex(source, target, transform)
+        }
+
+        fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+            init_logging();
+            Impl::get_relevance_settings(index)
+        }
+
+        fn update_relevance_settings(
+            index: IndexName,
+            settings: RelevanceSettings,
+        ) -> Result<(), SearchError> {
+            init_logging();
+            Impl::update_relevance_settings(index, settings)
+        }
     }
 }
 
 #[cfg(feature = "durability")]
 mod durable_impl {
     use crate::durability::{DurableSearch, ExtendedGuest};
+    use crate::filter::parse_filter_expr;
     use crate::golem::search::core::{Guest, GuestSearchStream, SearchStream};
     use crate::golem::search::types::{
-        Doc, DocumentId, IndexName, Schema, SearchError, SearchHit, SearchQuery, SearchResults,
+        BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+        MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema,
+        SearchError, SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
     };
     use crate::init_logging;
+    use crate::reindex::{wait_for_reindex, ReindexTask};
+    use crate::vector::validate_vector_query;
     use golem_rust::bindings::golem::durability::durability::{
         DurableFunctionType, LazyInitializedPollable,
     };
@@ -140,24 +231,28 @@ mod durable_impl {
     struct UpsertInput {
         index: IndexName,
         doc: Doc,
+        options: Option<WriteOptions>,
     }
 
     #[derive(Debug, Clone, IntoValue)]
     struct UpsertManyInput {
         index: IndexName,
         docs: Vec<Doc>,
+        options: Option<BulkOptions>,
     }
 
     #[derive(Debug, Clone, IntoValue)]
     struct DeleteInput {
         index: IndexName,
         id: DocumentId,
+        options: Option<WriteOptions>,
     }
 
     #[derive(Debug, Clone, IntoValue)]
     struct DeleteManyInput {
         index: IndexName,
         ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
     }
 
     #[derive(Debug, Clone, IntoValue)]
@@ -166,6 +261,11 @@ mod durable_impl {
         id: DocumentId,
     }
 
+    #[derive(Debug, Clone, IntoValue)]
+    struct GetTaskStatusInput {
+        id: TaskId,
+    }
+
     #[derive(Debug, Clone, IntoValue)]
     struct SearchInput {
         index: IndexName,
@@ -178,6 +278,12 @@ mod durable_impl {
         query: SearchQuery,
     }
 
+    #[derive(Debug, Clone, IntoValue)]
+    struct MultiSearchInput {
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    }
+
     #[derive(Debug, Clone, IntoValue)]
     struct GetSchemaInput {
         index: IndexName,
@@ -189,6 +295,35 @@ mod durable_impl {
         schema: Schema,
     }
 
+    #[derive(Debug, Clone, IntoValue)]
+    struct AliasInput {
+        alias: String,
+        index: IndexName,
+    }
+
+    #[derive(Debug, Clone, IntoValue)]
+    struct StartReindexInput {
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    }
+
+    #[derive(Debug, Clone, IntoValue)]
+    struct PollReindexInput {
+        task_id: String,
+    }
+
+    #[derive(Debug, Clone, IntoValue)]
+    struct GetRelevanceSettingsInput {
+        index: IndexName,
+    }
+
+    #[derive(Debug, Clone, IntoValue)]
+    struct UpdateRelevanceSettingsInput {
+        index: IndexName,
+        settings: RelevanceSettings,
+    }
+
     #[derive(Debug, IntoValue)]
     struct NoInput;
 
@@ -209,21 +344,61 @@ mod durable_impl {
         names: Vec<IndexName>,
     }
 
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct WriteOutput {
+        task: Option<TaskId>,
+    }
+
     #[derive(Debug, Clone, FromValueAndType, IntoValue)]
     struct GetDocOutput {
         doc: Option<Doc>,
     }
 
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct GetTaskStatusOutput {
+        status: TaskStatus,
+    }
+
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct BulkOutput {
+        result: BulkResult,
+    }
+
     #[derive(Debug, Clone, FromValueAndType, IntoValue)]
     struct SearchOutput {
         results: SearchResults,
     }
 
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct MultiSearchOutput {
+        results: MultiSearchResults,
+    }
+
     #[derive(Debug, Clone, FromValueAndType, IntoValue)]
     struct GetSchemaOutput {
         schema: Schema,
     }
 
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct ListAliasesOutput {
+        aliases: Vec<IndexAlias>,
+    }
+
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct StartReindexOutput {
+        task: ReindexTask,
+    }
+
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct PollReindexOutput {
+        result: Option<ReindexResult>,
+    }
+
+    #[derive(Debug, Clone, FromValueAndType, IntoValue)]
+    struct GetRelevanceSettingsOutput {
+        settings: RelevanceSettings,
+    }
+
     impl<Impl: ExtendedGuest> Guest for DurableSearch<Impl> {
         type SearchStream = DurableSearchStream<Impl>;
 
@@ -243,7 +418,9 @@ mod durable_impl {
                     .persist(CreateIndexInput { name, schema }, result)
                     .map(|_: NoOutput| ())
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || Impl::create_index(name, schema));
+                result
             }
         }
 
@@ -263,7 +440,9 @@ mod durable_impl {
                     .persist(DeleteIndexInput { name }, result)
                     .map(|_: NoOutput| ())
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || Impl::delete_index(name));
+                result
             }
         }
 
@@ -289,83 +468,136 @@ mod durable_impl {
             }
         }
 
-        fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+        fn upsert(
+            index: IndexName,
+            doc: Doc,
+            options: Option<WriteOptions>,
+        ) -> Result<Option<TaskId>, SearchError> {
             init_logging();
 
-            let durability = Durability::<NoOutput, SearchError>::new(
+            let durability = Durability::<WriteOutput, SearchError>::new(
                 "golem_search",
                 "upsert",
                 DurableFunctionType::WriteRemote,
             );
             if durability.is_live() {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
-                    Impl::upsert(index.clone(), doc.clone()).map(|()| NoOutput)
+                    Impl::upsert(index.clone(), doc.clone(), options)
+                        .map(|task| WriteOutput { task })
                 });
                 durability
-                    .persist(UpsertInput { index, doc }, result)
-                    .map(|_: NoOutput| ())
+                    .persist(
+                        UpsertInput {
+                            index,
+                            doc,
+                            options,
+                        },
+                        result,
+                    )
+                    .map(|output| output.task)
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|output: WriteOutput| output.task);
+                reapply_replayed_write::<Impl, _>(&result, || Impl::upsert(index, doc, options));
+                result
             }
         }
 
-        fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+        fn upsert_many(
+            index: IndexName,
+            docs: Vec<Doc>,
+            options: Option<BulkOptions>,
+        ) -> Result<BulkResult, SearchError> {
             init_logging();
 
-            let durability = Durability::<NoOutput, SearchError>::new(
+            let durability = Durability::<BulkOutput, SearchError>::new(
                 "golem_search",
                 "upsert_many",
                 DurableFunctionType::WriteRemote,
             );
             if durability.is_live() {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
-                    Impl::upsert_many(index.clone(), docs.clone()).map(|_| NoOutput)
+                    Impl::upsert_many(index.clone(), docs.clone(), options)
+                        .map(|result| BulkOutput { result })
                 });
                 durability
-                    .persist(UpsertManyInput { index, docs }, result)
-                    .map(|_: NoOutput| ())
+                    .persist(
+                        UpsertManyInput {
+                            index,
+                            docs,
+                            options,
+                        },
+                        result,
+                    )
+                    .map(|output| output.result)
             } else {
-                durability.replay().map(|_: NoOutput| {})
+                let result = durability.replay().map(|output: BulkOutput| output.result);
+                reapply_replayed_write::<Impl, _>(&result, || {
+                    Impl::upsert_many(index, docs, options)
+                });
+                result
             }
         }
 
-        fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+        fn delete(
+            index: IndexName,
+            id: DocumentId,
+            options: Option<WriteOptions>,
+        ) -> Result<Option<TaskId>, SearchError> {
             init_logging();
 
-            let durability = Durability::<NoOutput, SearchError>::new(
+            let durability = Durability::<WriteOutput, SearchError>::new(
                 "golem_search",
                 "delete",
                 DurableFunctionType::WriteRemote,
             );
             if durability.is_live() {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
-                    Impl::delete(index.clone(), id.clone()).map(|()| NoOutput)
+                    Impl::delete(index.clone(), id.clone(), options)
+                        .map(|task| WriteOutput { task })
                 });
                 durability
-                    .persist(DeleteInput { index, id }, result)
-                    .map(|_: NoOutput| ())
+                    .persist(DeleteInput { index, id, options }, result)
+                    .map(|output| output.task)
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|output: WriteOutput| output.task);
+                reapply_replayed_write::<Impl, _>(&result, || Impl::delete(index, id, options));
+                result
             }
         }
 
-        fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+        fn delete_many(
+            index: IndexName,
+            ids: Vec<DocumentId>,
+            options: Option<BulkOptions>,
+        ) -> Result<BulkResult, SearchError> {
             init_logging();
 
-            let durability = Durability::<NoOutput, SearchError>::new(
+            let durability = Durability::<BulkOutput, SearchError>::new(
                 "golem_search",
                 "delete_many",
                 DurableFunctionType::WriteRemote,
             );
             if durability.is_live() {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
-                    Impl::delete_many(index.clone(), ids.clone()).map(|_| NoOutput)
+                    Impl::delete_many(index.clone(), ids.clone(), options)
+                        .map(|result| BulkOutput { result })
                 });
                 durability
-                    .persist(DeleteManyInput { index, ids }, result)
-                    .map(|_: NoOutput| ())
+                    .persist(
+                        DeleteManyInput {
+                            index,
+                            ids,
+                            options,
+                        },
+                        result,
+                    )
+                    .map(|output| output.result)
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|output: BulkOutput| output.result);
+                reapply_replayed_write::<Impl, _>(&result, || {
+                    Impl::delete_many(index, ids, options)
+                });
+                result
             }
         }
 
@@ -389,6 +621,28 @@ mod durable_impl {
             }
         }
 
+        fn get_task_status(id: TaskId) -> Result<TaskStatus, SearchError> {
+            init_logging();
+
+            let durability = Durability::<GetTaskStatusOutput, SearchError>::new(
+                "golem_search",
+                "get_task_status",
+                DurableFunctionType::ReadRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::get_task_status(id.clone()).map(|status| GetTaskStatusOutput { status })
+                });
+                durability
+                    .persist(GetTaskStatusInput { id }, result)
+                    .map(|output| output.status)
+            } else {
+                durability
+                    .replay()
+                    .map(|output: GetTaskStatusOutput| output.status)
+            }
+        }
+
         fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
             init_logging();
 
@@ -412,12 +666,52 @@ mod durable_impl {
             }
         }
 
+        fn multi_search(
+            queries: Vec<(IndexName, SearchQuery)>,
+            federation: Option<FederationOptions>,
+        ) -> Result<MultiSearchResults, SearchError> {
+            init_logging();
+
+            let durability = Durability::<MultiSearchOutput, SearchError>::new(
+                "golem_search",
+                "multi_search",
+                DurableFunctionType::ReadRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::multi_search(queries.clone(), federation.clone())
+                        .map(|results| MultiSearchOutput { results })
+                });
+                durability
+                    .persist(
+                        MultiSearchInput {
+                            queries,
+                            federation,
+                        },
+                        result,
+                    )
+                    .map(|output| output.results)
+            } else {
+                durability
+                    .replay()
+                    .map(|output: MultiSearchOutput| output.results)
+            }
+        }
+
         fn stream_search(
             index: IndexName,
             query: SearchQuery,
         ) -> Result<SearchStream, SearchError> {
             init_logging();
 
+            // The stream is created without the provider, so the query is validated upfront
+            if let Some(filter_expr) = &query.filter_expr {
+                parse_filter_expr(filter_expr)?;
+            }
+            if let Some(vector_query) = &query.vector_query {
+                validate_vector_query(vector_query)?;
+            }
+
             let durability = Durability::<NoOutput, UnusedError>::new(
                 "golem_search",
                 "stream_search",
@@ -478,11 +772,206 @@ mod durable_impl {
                     .persist(UpdateSchemaInput { index, schema }, result)
                     .map(|_: NoOutput| ())
             } else {
-                durability.replay().map(|_: NoOutput| ())
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || Impl::update_schema(index, schema));
+                result
+            }
+        }
+
+        fn create_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+            init_logging();
+
+            let durability = Durability::<NoOutput, SearchError>::new(
+                "golem_search",
+                "create_alias",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::create_alias(alias.clone(), index.clone()).map(|()| NoOutput)
+                });
+                durability
+                    .persist(AliasInput { alias, index }, result)
+                    .map(|_: NoOutput| ())
+            } else {
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || Impl::create_alias(alias, index));
+                result
+            }
+        }
+
+        fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+            init_logging();
+
+            let durability = Durability::<NoOutput, SearchError>::new(
+                "golem_search",
+                "swap_alias",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::swap_alias(alias.clone(), index.clone()).map(|()| NoOutput)
+                });
+                durability
+                    .persist(AliasInput { alias, index }, result)
+                    .map(|_: NoOutput| ())
+            } else {
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || Impl::swap_alias(alias, index));
+                result
+            }
+        }
+
+        fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+            init_logging();
+
+            let durability = Durability::<ListAliasesOutput, SearchError>::new(
+                "golem_search",
+                "list_aliases",
+                DurableFunctionType::ReadRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::list_aliases().map(|aliases| ListAliasesOutput { aliases })
+                });
+                durability
+                    .persist(NoInput, result)
+                    .map(|output| output.aliases)
+            } else {
+                durability
+                    .replay()
+                    .map(|output: ListAliasesOutput| output.aliases)
+            }
+        }
+
+        /// The reindex is started and each check of its task is persisted separately, so a
+        /// replayed worker continues polling the same task instead of starting a new copy
+        fn reindex(
+            source: IndexName,
+            target: IndexName,
+            transform: Option<ReindexTransform>,
+        ) -> Result<ReindexResult, SearchError> {
+            init_logging();
+
+            let durability = Durability::<StartReindexOutput, SearchError>::new(
+                "golem_search",
+                "start_reindex",
+                DurableFunctionType::WriteRemote,
+            );
+            let task = if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::start_reindex(source.clone(), target.clone(), transform.clone())
+                        .map(|task| StartReindexOutput { task })
+                });
+                durability
+                    .persist(
+                        StartReindexInput {
+                            source,
+                            target,
+                            transform,
+                        },
+                        result,
+                    )
+                    .map(|output| output.task)
+            } else {
+                let result = durability
+                    .replay()
+                    .map(|output: StartReindexOutput| output.task);
+                reapply_replayed_write::<Impl, _>(&result, || {
+                    Impl::start_reindex(source, target, transform)
+                });
+                result
+            }?;
+
+            wait_for_reindex(task, |task_id, delay| {
+                let durability = Durability::<PollReindexOutput, SearchError>::new(
+                    "golem_search",
+                    "poll_reindex",
+                    DurableFunctionType::ReadRemote,
+                );
+                if durability.is_live() {
+                    std::thread::sleep(delay);
+                    let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                        Impl::poll_reindex(task_id).map(|result| PollReindexOutput { result })
+                    });
+                    durability
+                        .persist(
+                            PollReindexInput {
+                                task_id: task_id.to_string(),
+                            },
+                            result,
+                        )
+                        .map(|output| output.result)
+                } else {
+                    durability
+                        .replay()
+                        .map(|output: PollReindexOutput| output.result)
+                }
+            })
+        }
+
+        fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+            init_logging();
+
+            let durability = Durability::<GetRelevanceSettingsOutput, SearchError>::new(
+                "golem_search",
+                "get_relevance_settings",
+                DurableFunctionType::ReadRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::get_relevance_settings(index.clone())
+                        .map(|settings| GetRelevanceSettingsOutput { settings })
+                });
+                durability
+                    .persist(GetRelevanceSettingsInput { index }, result)
+                    .map(|output| output.settings)
+            } else {
+                durability
+                    .replay()
+                    .map(|output: GetRelevanceSettingsOutput| output.settings)
+            }
+        }
+
+        fn update_relevance_settings(
+            index: IndexName,
+            settings: RelevanceSettings,
+        ) -> Result<(), SearchError> {
+            init_logging();
+
+            let durability = Durability::<NoOutput, SearchError>::new(
+                "golem_search",
+                "update_relevance_settings",
+                DurableFunctionType::WriteRemote,
+            );
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::update_relevance_settings(index.clone(), settings.clone())
+                        .map(|()| NoOutput)
+                });
+                durability
+                    .persist(UpdateRelevanceSettingsInput { index, settings }, result)
+                    .map(|_: NoOutput| ())
+            } else {
+                let result = durability.replay().map(|_: NoOutput| ());
+                reapply_replayed_write::<Impl, _>(&result, || {
+                    Impl::update_relevance_settings(index, settings)
+                });
+                result
             }
         }
     }
 
+    /// Applies a successful replayed write again if the provider keeps its index in memory
+    fn reapply_replayed_write<Impl: ExtendedGuest, T>(
+        result: &Result<T, SearchError>,
+        write: impl FnOnce() -> Result<T, SearchError>,
+    ) {
+        if Impl::REAPPLY_REPLAYED_WRITES && result.is_ok() {
+            let _ = write();
+        }
+    }
+
     /// Represents the durable search stream's state
     ///
     /// In live mode it directly calls the underlying Search stream which is implemented on
@@ -722,6 +1211,10 @@ mod durable_impl {
                 content:
                     r#"{"title": "Test Document", "author": "John Doe", "tags": ["rust", "wasm"]}"#
                         .to_string(),
+                embeddings: vec![Embedding {
+                    field: "title_vector".to_string(),
+                    values: vec![0.25, -0.5, 1.0],
+                }],
             };
             roundtrip_test(doc);
         }
@@ -777,8 +1270,65 @@ mod durable_impl {
             let query = SearchQuery {
                 q: Some("rust programming language".to_string()),
                 filters: vec!["category:programming".to_string(), "lang:en".to_string()],
+                filter_expr: Some(FilterExpr {
+                    nodes: vec![
+                        FilterNode::Eq(FieldFilter {
+                            field: "lang".to_string(),
+                            value: FilterValue::Text("en".to_string()),
+                        }),
+                        FilterNode::Range(RangeFilter {
+                            field: "year".to_string(),
+                            gt: None,
+                            gte: Some(FilterValue::Integer(2015)),
+                            lt: None,
+                            lte: Some(FilterValue::Float(2024.5)),
+                        }),
+                        FilterNode::GeoRadius(GeoRadiusFilter {
+                            field: "location".to_string(),
+                            latitude: 47.4979,
+                            longitude: 19.0402,
+                            radius_meters: 1500.0,
+                        }),
+                        FilterNode::Not(2),
+                        FilterNode::And(vec![0, 1, 3]),
+                    ],
+                }),
+                vector_query: Some(VectorQuery {
+                    field: "title_vector".to_string(),
+                    embedding: vec![0.1, 0.2, 0.3],
+                    k: 10,
+                    hybrid_weight: Some(0.7),
+                }),
                 sort: vec!["score:desc".to_string(), "date:asc".to_string()],
                 facets: vec!["category".to_string(), "author".to_string()],
+                aggregations: vec![
+                    Aggregation {
+                        name: "price_stats".to_string(),
+                        field: "price".to_string(),
+                        kind: AggregationKind::Stats,
+                    },
+                    Aggregation {
+                        name: "price_ranges".to_string(),
+                        field: "price".to_string(),
+                        kind: AggregationKind::Range(vec![
+                            RangeBucket {
+                                key: None,
+                                from: None,
+                                to: Some(10.0),
+                            },
+                            RangeBucket {
+                                key: Some("expensive".to_string()),
+                                from: Some(10.0),
+                                to: None,
+                            },
+                        ]),
+                    },
+                    Aggregation {
+                        name: "per_month".to_string(),
+                        field: "published_at".to_string(),
+                        kind: AggregationKind::DateHistogram(DateInterval::Month),
+                    },
+                ],
                 page: Some(2),
                 per_page: Some(20),
                 offset: Some(40),
@@ -804,8 +1354,11 @@ mod durable_impl {
             let minimal_query = SearchQuery {
                 q: None,
                 filters: vec![],
+                filter_expr: None,
+                vector_query: None,
                 sort: vec![],
                 facets: vec![],
+                aggregations: vec![],
                 page: None,
                 per_page: None,
                 offset: None,
@@ -837,6 +1390,120 @@ mod durable_impl {
             roundtrip_test(minimal_hit);
         }
 
+        #[test]
+        fn bulk_result_roundtrip() {
+            roundtrip_test(BulkOptions {
+                chunk_size: Some(500),
+                consistency: Some(WriteConsistency::Async),
+            });
+            roundtrip_test(BulkResult {
+                items: vec![
+                    BulkItem {
+                        id: "doc-1".to_string(),
+                        error: None,
+                    },
+                    BulkItem {
+                        id: "doc-2".to_string(),
+                        error: Some(SearchError::InvalidQuery(
+                            "failed to parse field [year]".to_string(),
+                        )),
+                    },
+                ],
+                tasks: vec!["products:42".to_string()],
+            });
+        }
+
+        #[test]
+        fn multi_search_roundtrip() {
+            roundtrip_test(FederationOptions {
+                weights: vec![1.0, 0.5],
+                offset: Some(20),
+                limit: None,
+            });
+            let hit = SearchHit {
+                id: "faq-7".to_string(),
+                score: Some(0.75),
+                content: Some(r#"{"question":"How do I reset my password?"}"#.to_string()),
+                highlights: None,
+            };
+            roundtrip_test(MultiSearchResults::PerIndex(vec![(
+                "faqs".to_string(),
+                SearchResults {
+                    total: Some(1),
+                    page: None,
+                    per_page: Some(10),
+                    hits: vec![hit.clone()],
+                    facets: vec![],
+                    took_ms: Some(3),
+                },
+            )]));
+            roundtrip_test(MultiSearchResults::Merged(FederatedResults {
+                total: Some(12),
+                hits: vec![FederatedHit {
+                    index: "faqs".to_string(),
+                    query_position: 2,
+                    hit,
+                }],
+                took_ms: None,
+            }));
+        }
+
+        #[test]
+        fn write_options_and_task_status_roundtrip() {
+            roundtrip_test(WriteOptions {
+                consistency: Some(WriteConsistency::Refresh),
+            });
+            roundtrip_test(WriteOptions { consistency: None });
+            roundtrip_test(TaskStatus {
+                id: "17".to_string(),
+                state: TaskState::Failed,
+                error: Some("Document identifier is invalid".to_string()),
+            });
+            roundtrip_test(TaskStatus {
+                id: "18".to_string(),
+                state: TaskState::Enqueued,
+                error: None,
+            });
+        }
+
+        #[test]
+        fn alias_and_reindex_roundtrip() {
+            roundtrip_test(IndexAlias {
+                alias: "products".to_string(),
+                index: "products-v2".to_string(),
+            });
+            roundtrip_test(ReindexTransform {
+                filter: Some(FilterExpr {
+                    nodes: vec![FilterNode::Exists("title".to_string())],
+                }),
+                fields: vec!["title".to_string(), "year".to_string()],
+            });
+            roundtrip_test(ReindexTask::Running(
+                "oTUltX4IQMOUUVeiohTt8A:12345".to_string(),
+            ));
+            roundtrip_test(ReindexTask::Finished(ReindexResult { copied: Some(42) }));
+            roundtrip_test(ReindexTask::Finished(ReindexResult { copied: None }));
+        }
+
+        #[test]
+        fn relevance_settings_roundtrip() {
+            roundtrip_test(RelevanceSettings {
+                synonyms: vec![
+                    SynonymSet {
+                        synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                        root: None,
+                    },
+                    SynonymSet {
+                        synonyms: vec!["rust".to_string()],
+                        root: Some("rustlang".to_string()),
+                    },
+                ],
+                stop_words: vec!["the".to_string(), "a".to_string()],
+                ranking_rules: vec!["words".to_string(), "year:desc".to_string()],
+                provider_settings: Some(r#"{"overrides": []}"#.to_string()),
+            });
+        }
+
         #[test]
         fn search_results_roundtrip() {
             let results = SearchResults {
@@ -857,7 +1524,32 @@ mod durable_impl {
                         highlights: Some(r#"{"title": ["<em>Second</em> Document"]}"#.to_string()),
                     },
                 ],
-                facets: Some(r#"{"category": {"programming": 50, "tutorial": 25}}"#.to_string()),
+                facets: vec![
+                    FacetResult {
+                        name: "category".to_string(),
+                        buckets: vec![
+                            FacetBucket {
+                                value: "programming".to_string(),
+                                count: 50,
+                            },
+                            FacetBucket {
+                                value: "tutorial".to_string(),
+                                count: 25,
+                            },
+                        ],
+                        stats: None,
+                    },
+                    FacetResult {
+                        name: "price_stats".to_string(),
+                        buckets: vec![],
+                        stats: Some(FacetStats {
+                            min: Some(5.0),
+                            max: Some(120.0),
+                            avg: Some(42.5),
+                            sum: None,
+                        }),
+                    },
+                ],
                 took_ms: Some(15),
             };
             roundtrip_test(results);
@@ -868,7 +1560,7 @@ mod durable_impl {
                 page: None,
                 per_page: None,
                 hits: vec![],
-                facets: None,
+                facets: vec![],
                 took_ms: Some(5),
             };
             roundtrip_test(empty_results);
@@ -883,6 +1575,7 @@ mod durable_impl {
             roundtrip_test(FieldType::Boolean);
             roundtrip_test(FieldType::Date);
             roundtrip_test(FieldType::GeoPoint);
+            roundtrip_test(FieldType::Vector);
         }
 
         #[test]
@@ -894,6 +1587,7 @@ mod durable_impl {
                 facet: false,
                 sort: true,
                 index: true,
+                vector: None,
             };
             roundtrip_test(field);
 
@@ -905,6 +1599,7 @@ mod durable_impl {
                 facet: true,
                 sort: false,
                 index: true,
+                vector: None,
             };
             roundtrip_test(keyword_field);
 
@@ -916,8 +1611,24 @@ mod durable_impl {
                 facet: true,
                 sort: true,
                 index: false,
+                vector: None,
             };
             roundtrip_test(numeric_field);
+
+            // Test vector field
+            let vector_field = SchemaField {
+                name: "title_vector".to_string(),
+                field_type: FieldType::Vector,
+                required: false,
+                facet: false,
+                sort: false,
+                index: true,
+                vector: Some(VectorConfig {
+                    dimensions: 384,
+                    similarity: VectorSimilarity::DotProduct,
+                }),
+            };
+            roundtrip_test(vector_field);
         }
 
         #[test]
@@ -931,6 +1642,7 @@ mod durable_impl {
                         facet: false,
                         sort: false,
                         index: true,
+                        vector: None,
                     },
                     SchemaField {
                         name: "title".to_string(),
@@ -939,6 +1651,7 @@ mod durable_impl {
                         facet: false,
                         sort: true,
                         index: true,
+                        vector: None,
                     },
                     SchemaField {
                         name: "category".to_string(),
@@ -947,6 +1660,7 @@ mod durable_impl {
                         facet: true,
                         sort: false,
                         index: true,
+                        vector: None,
                     },
                     SchemaField {
                         name: "price".to_string(),
@@ -955,6 +1669,7 @@ mod durable_impl {
                         facet: true,
                         sort: true,
                         index: false,
+                        vector: None,
                     },
                     SchemaField {
                         name: "published_date".to_string(),
@@ -963,6 +1678,7 @@ mod durable_impl {
                         facet: false,
                         sort: true,
                         index: true,
+                        vector: None,
                     },
                 ],
                 primary_key: Some("id".to_string()),
@@ -1000,8 +1716,11 @@ mod durable_impl {
             let original_query = SearchQuery {
                 q: Some("test".to_string()),
                 filters: vec![],
+                filter_expr: None,
+                vector_query: None,
                 sort: vec![],
                 facets: vec![],
+                aggregations: vec![],
                 page: Some(1),
                 per_page: Some(10),
                 offset: Some(0),
@@ -1060,6 +1779,8 @@ mod durable_impl {
                     "price:[100 TO 500]".to_string(),
                     "availability:true".to_string(),
                 ],
+                filter_expr: None,
+                vector_query: None,
                 sort: vec![
                     "price:asc".to_string(),
                     "_score:desc".to_string(),
@@ -1070,6 +1791,7 @@ mod durable_impl {
                     "brand".to_string(),
                     "color".to_string(),
                 ],
+                aggregations: vec![],
                 page: Some(5),
                 per_page: Some(50),
                 offset: Some(200),
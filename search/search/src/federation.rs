@@ -0,0 +1,354 @@
+use crate::error::invalid_query;
+use crate::golem::search::types::{
+    FederatedHit, FederatedResults, FederationOptions, IndexName, MultiSearchResults, SearchError,
+    SearchQuery, SearchResults,
+};
+
+/// Number of merged hits returned when the federation options have no limit
+pub const DEFAULT_FEDERATED_LIMIT: u32 = 20;
+
+/// Validates a multi-search and returns the queries to send. When the hits are merged, each query
+/// fetches the first `offset + limit` hits of its index, which contain every hit of the merged page.
+pub fn multi_search_queries(
+    queries: Vec<(IndexName, SearchQuery)>,
+    federation: Option<&FederationOptions>,
+) -> Result<Vec<(IndexName, SearchQuery)>, SearchError> {
+    let Some(federation) = federation else {
+        return Ok(queries);
+    };
+
+    if federation.weights.len() > queries.len() {
+        return Err(invalid_query("More federation weights than queries"));
+    }
+    if let Some(weight) = federation
+        .weights
+        .iter()
+        .find(|weight| !weight.is_finite() || **weight < 0.0)
+    {
+        return Err(invalid_query(format!(
+            "Invalid federation weight: {weight}"
+        )));
+    }
+
+    let window = federation
+        .offset
+        .unwrap_or(0)
+        .saturating_add(federated_limit(federation));
+    queries
+        .into_iter()
+        .map(|(index, query)| {
+            if !query.facets.is_empty() || !query.aggregations.is_empty() {
+                return Err(invalid_query(
+                    "Merged multi-search queries cannot have facets or aggregations",
+                ));
+            }
+            let query = SearchQuery {
+                page: None,
+                offset: None,
+                per_page: Some(window),
+                ..query
+            };
+            Ok((index, query))
+        })
+        .collect()
+}
+
+/// Number of merged hits returned
+pub fn federated_limit(federation: &FederationOptions) -> u32 {
+    federation.limit.unwrap_or(DEFAULT_FEDERATED_LIMIT)
+}
+
+/// Weight of the query at `position` of a multi-search
+pub fn query_weight(federation: &FederationOptions, position: usize) -> f32 {
+    federation.weights.get(position).copied().unwrap_or(1.0)
+}
+
+/// Results of a multi-search from the results of its queries, in the order of the queries
+pub fn multi_search_results(
+    results: Vec<(IndexName, SearchResults)>,
+    federation: Option<&FederationOptions>,
+) -> MultiSearchResults {
+    match federation {
+        Some(federation) => MultiSearchResults::Merged(merge_results(results, federation)),
+        None => MultiSearchResults::PerIndex(results),
+    }
+}
+
+/// Merges the hits of the queries into one list ranked by weighted score.
+///
+/// Scores of different indexes are not comparable, so the scores of each query are divided by its
+/// best score first. The hits of a query without scores are scored by their rank instead, the
+/// first hit having a score of 1. Hits with equal scores keep the order of the queries.
+pub fn merge_results(
+    results: Vec<(IndexName, SearchResults)>,
+    federation: &FederationOptions,
+) -> FederatedResults {
+    let total = results.iter().map(|(_, results)| results.total).sum();
+    let took_ms = results.iter().map(|(_, results)| results.took_ms).sum();
+
+    let mut hits = Vec::new();
+    for (position, (index, results)) in results.into_iter().enumerate() {
+        let weight = f64::from(query_weight(federation, position));
+        let best_score = results
+            .hits
+            .iter()
+            .map(|hit| hit.score)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|scores| scores.into_iter().reduce(f64::max))
+            .filter(|best_score| *best_score > 0.0);
+
+        for (rank, mut hit) in results.hits.into_iter().enumerate() {
+            let score = match (best_score, hit.score) {
+                (Some(best_score), Some(score)) => score / best_score,
+                _ => 1.0 / (rank as f64 + 1.0),
+            };
+            hit.score = Some(score * weight);
+            hits.push(FederatedHit {
+                index: index.clone(),
+                query_position: position as u32,
+                hit,
+            });
+        }
+    }
+    hits.sort_by(|a, b| {
+        let score = |hit: &FederatedHit| hit.hit.score.unwrap_or(0.0);
+        score(b).total_cmp(&score(a))
+    });
+
+    FederatedResults {
+        total,
+        hits: hits
+            .into_iter()
+            .skip(federation.offset.unwrap_or(0) as usize)
+            .take(federated_limit(federation) as usize)
+            .collect(),
+        took_ms,
+    }
+}
+
+/// Runs a multi-search one query at a time, for providers without a multi-search request
+pub fn run_multi_search(
+    queries: Vec<(IndexName, SearchQuery)>,
+    federation: Option<FederationOptions>,
+    mut search: impl FnMut(&IndexName, SearchQuery) -> Result<SearchResults, SearchError>,
+) -> Result<MultiSearchResults, SearchError> {
+    let queries = multi_search_queries(queries, federation.as_ref())?;
+    let results = queries
+        .into_iter()
+        .map(|(index, query)| {
+            let results = search(&index, query)?;
+            Ok((index, results))
+        })
+        .collect::<Result<Vec<_>, SearchError>>()?;
+    Ok(multi_search_results(results, federation.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::federation::{merge_results, multi_search_queries, run_multi_search};
+    use crate::golem::search::types::{
+        Aggregation, AggregationKind, FederationOptions, MultiSearchResults, SearchError,
+        SearchHit, SearchQuery, SearchResults,
+    };
+
+    fn query() -> SearchQuery {
+        SearchQuery {
+            q: Some("golem".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: Some(3),
+            per_page: Some(5),
+            offset: None,
+            highlight: None,
+            config: None,
+        }
+    }
+
+    fn results(total: Option<u32>, hits: &[(&str, Option<f64>)]) -> SearchResults {
+        SearchResults {
+            total,
+            page: None,
+            per_page: None,
+            hits: hits
+                .iter()
+                .map(|(id, score)| SearchHit {
+                    id: id.to_string(),
+                    score: *score,
+                    content: None,
+                    highlights: None,
+                })
+                .collect(),
+            facets: vec![],
+            took_ms: Some(2),
+        }
+    }
+
+    fn federation(weights: Vec<f32>, offset: Option<u32>, limit: Option<u32>) -> FederationOptions {
+        FederationOptions {
+            weights,
+            offset,
+            limit,
+        }
+    }
+
+    #[test]
+    fn merged_queries_fetch_the_merged_page() {
+        let queries = vec![
+            ("products".to_string(), query()),
+            ("articles".to_string(), query()),
+        ];
+
+        let unchanged = multi_search_queries(queries.clone(), None).unwrap();
+        assert_eq!(unchanged, queries);
+
+        let merged =
+            multi_search_queries(queries.clone(), Some(&federation(vec![], Some(10), None)))
+                .unwrap();
+        for (_, query) in &merged {
+            assert_eq!(query.page, None);
+            assert_eq!(query.offset, None);
+            assert_eq!(query.per_page, Some(30));
+        }
+
+        assert!(matches!(
+            multi_search_queries(
+                queries.clone(),
+                Some(&federation(vec![1.0, 1.0, 1.0], None, None))
+            ),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            multi_search_queries(queries.clone(), Some(&federation(vec![-1.0], None, None))),
+            Err(SearchError::InvalidQuery(_))
+        ));
+
+        let faceted = vec![(
+            "products".to_string(),
+            SearchQuery {
+                aggregations: vec![Aggregation {
+                    name: "price_stats".to_string(),
+                    field: "price".to_string(),
+                    kind: AggregationKind::Stats,
+                }],
+                ..query()
+            },
+        )];
+        assert!(multi_search_queries(faceted.clone(), None).is_ok());
+        assert!(matches!(
+            multi_search_queries(faceted, Some(&federation(vec![], None, None))),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn merge_ranks_normalized_weighted_scores() {
+        let merged = merge_results(
+            vec![
+                (
+                    "products".to_string(),
+                    results(Some(3), &[("p1", Some(8.0)), ("p2", Some(2.0))]),
+                ),
+                (
+                    "articles".to_string(),
+                    results(Some(10), &[("a1", Some(0.9)), ("a2", Some(0.6))]),
+                ),
+                (
+                    "faqs".to_string(),
+                    results(None, &[("f1", None), ("f2", None)]),
+                ),
+            ],
+            &federation(vec![1.0, 0.5], None, None),
+        );
+
+        let ranked = merged
+            .hits
+            .iter()
+            .map(|hit| (hit.index.as_str(), hit.hit.id.as_str(), hit.hit.score))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                ("products", "p1", Some(1.0)),
+                ("faqs", "f1", Some(1.0)),
+                ("articles", "a1", Some(0.5)),
+                ("faqs", "f2", Some(0.5)),
+                ("articles", "a2", Some(0.6 / 0.9 * 0.5)),
+                ("products", "p2", Some(0.25)),
+            ]
+        );
+        assert_eq!(merged.hits[2].query_position, 1);
+        assert_eq!(merged.total, None);
+        assert_eq!(merged.took_ms, Some(6));
+    }
+
+    #[test]
+    fn merge_pages_the_merged_hits() {
+        let merged = merge_results(
+            vec![
+                (
+                    "products".to_string(),
+                    results(Some(3), &[("p1", Some(3.0)), ("p2", Some(2.0))]),
+                ),
+                (
+                    "articles".to_string(),
+                    results(Some(1), &[("a1", Some(1.0))]),
+                ),
+            ],
+            &federation(vec![], Some(1), Some(1)),
+        );
+
+        assert_eq!(merged.total, Some(4));
+        let ids = merged
+            .hits
+            .iter()
+            .map(|hit| hit.hit.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["a1"]);
+    }
+
+    #[test]
+    fn run_multi_search_keeps_the_order_of_the_queries() {
+        let queries = vec![
+            ("products".to_string(), query()),
+            ("articles".to_string(), query()),
+        ];
+        let mut searched = Vec::new();
+
+        let results = run_multi_search(queries, None, |index, query| {
+            searched.push(index.clone());
+            assert_eq!(query.page, Some(3));
+            Ok(results(Some(1), &[(index.as_str(), None)]))
+        })
+        .unwrap();
+
+        assert_eq!(searched, vec!["products", "articles"]);
+        match results {
+            MultiSearchResults::PerIndex(results) => {
+                let ids = results
+                    .iter()
+                    .map(|(index, results)| (index.as_str(), results.hits[0].id.as_str()))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    ids,
+                    vec![("products", "products"), ("articles", "articles")]
+                );
+            }
+            MultiSearchResults::Merged(_) => panic!("Expected the results of each query"),
+        }
+
+        let failed = run_multi_search(
+            vec![("missing".to_string(), query())],
+            Some(FederationOptions {
+                weights: vec![],
+                offset: None,
+                limit: None,
+            }),
+            |_, _| Err(SearchError::IndexNotFound),
+        );
+        assert_eq!(failed, Err(SearchError::IndexNotFound));
+    }
+}
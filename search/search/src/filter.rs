@@ -0,0 +1,461 @@
+use crate::error::invalid_query;
+use crate::golem::search::types::{
+    FieldFilter, FilterExpr, FilterNode, FilterValue, GeoRadiusFilter, InFilter, RangeFilter,
+    SearchError,
+};
+use serde_json::{json, Value};
+
+/// A filter expression in negation normal form. The negations of `not` nodes are pushed down to
+/// the conditions with De Morgan's laws, so providers without a generic NOT operator can compile
+/// every expression the same way.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Is(Condition),
+    IsNot(Condition),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+}
+
+/// A condition on a single field. A negated condition also matches the documents without the field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Eq(FieldFilter),
+    Range(RangeFilter),
+    In(InFilter),
+    Exists(String),
+    GeoRadius(GeoRadiusFilter),
+}
+
+impl Condition {
+    pub fn field(&self) -> &str {
+        match self {
+            Condition::Eq(filter) => &filter.field,
+            Condition::Range(filter) => &filter.field,
+            Condition::In(filter) => &filter.field,
+            Condition::Exists(field) => field,
+            Condition::GeoRadius(filter) => &filter.field,
+        }
+    }
+}
+
+/// Parses and validates a filter expression. Every node except the root has to be the operand of
+/// exactly one node following it, so the nodes form a single tree.
+pub fn parse_filter_expr(expr: &FilterExpr) -> Result<Filter, SearchError> {
+    if expr.nodes.is_empty() {
+        return Err(invalid_query("Filter expression has no nodes"));
+    }
+
+    let mut referenced = vec![false; expr.nodes.len()];
+    for (index, node) in expr.nodes.iter().enumerate() {
+        let operands = match node {
+            FilterNode::And(operands) | FilterNode::Or(operands) => operands.clone(),
+            FilterNode::Not(operand) => vec![*operand],
+            _ => vec![],
+        };
+        for operand in operands {
+            let operand = operand as usize;
+            if operand >= index {
+                return Err(invalid_query(format!(
+                    "Filter node {index} refers to node {operand}, operands have to precede the node"
+                )));
+            }
+            if referenced[operand] {
+                return Err(invalid_query(format!(
+                    "Filter node {operand} is the operand of more than one node"
+                )));
+            }
+            referenced[operand] = true;
+        }
+    }
+    if let Some(index) = referenced[..expr.nodes.len() - 1]
+        .iter()
+        .position(|referenced| !referenced)
+    {
+        return Err(invalid_query(format!(
+            "Filter node {index} is not part of the expression"
+        )));
+    }
+
+    build_filter(&expr.nodes, expr.nodes.len() - 1, false)
+}
+
+fn build_filter(nodes: &[FilterNode], index: usize, negated: bool) -> Result<Filter, SearchError> {
+    let condition = |condition: Condition, is: bool| {
+        if is != negated {
+            Filter::Is(condition)
+        } else {
+            Filter::IsNot(condition)
+        }
+    };
+
+    match &nodes[index] {
+        FilterNode::Eq(filter) => {
+            validate_field(&filter.field)?;
+            Ok(condition(Condition::Eq(filter.clone()), true))
+        }
+        FilterNode::Ne(filter) => {
+            validate_field(&filter.field)?;
+            Ok(condition(Condition::Eq(filter.clone()), false))
+        }
+        FilterNode::Range(filter) => {
+            validate_field(&filter.field)?;
+            if filter.gt.is_none()
+                && filter.gte.is_none()
+                && filter.lt.is_none()
+                && filter.lte.is_none()
+            {
+                return Err(invalid_query(format!(
+                    "Range filter on {} has no bounds",
+                    filter.field
+                )));
+            }
+            if (filter.gt.is_some() && filter.gte.is_some())
+                || (filter.lt.is_some() && filter.lte.is_some())
+            {
+                return Err(invalid_query(format!(
+                    "Range filter on {} has both an exclusive and an inclusive bound on the same side",
+                    filter.field
+                )));
+            }
+            Ok(condition(Condition::Range(filter.clone()), true))
+        }
+        FilterNode::In(filter) => {
+            validate_field(&filter.field)?;
+            if filter.values.is_empty() {
+                return Err(invalid_query(format!(
+                    "In filter on {} has no values",
+                    filter.field
+                )));
+            }
+            Ok(condition(Condition::In(filter.clone()), true))
+        }
+        FilterNode::Exists(field) => {
+            validate_field(field)?;
+            Ok(condition(Condition::Exists(field.clone()), true))
+        }
+        FilterNode::GeoRadius(filter) => {
+            validate_field(&filter.field)?;
+            if !(-90.0..=90.0).contains(&filter.latitude)
+                || !(-180.0..=180.0).contains(&filter.longitude)
+                || filter.radius_meters.is_nan()
+                || filter.radius_meters <= 0.0
+            {
+                return Err(invalid_query(format!(
+                    "Geo radius filter on {} has an invalid location or radius",
+                    filter.field
+                )));
+            }
+            Ok(condition(Condition::GeoRadius(filter.clone()), true))
+        }
+        FilterNode::And(operands) | FilterNode::Or(operands) => {
+            if operands.is_empty() {
+                return Err(invalid_query(format!(
+                    "Filter node {index} has no operands"
+                )));
+            }
+            let operands = operands
+                .iter()
+                .map(|operand| build_filter(nodes, *operand as usize, negated))
+                .collect::<Result<Vec<_>, _>>()?;
+            // not(a and b) is (not a) or (not b), and the other way around
+            let is_and = matches!(nodes[index], FilterNode::And(_)) != negated;
+            Ok(if is_and {
+                Filter::And(operands)
+            } else {
+                Filter::Or(operands)
+            })
+        }
+        FilterNode::Not(operand) => build_filter(nodes, *operand as usize, !negated),
+    }
+}
+
+fn validate_field(field: &str) -> Result<(), SearchError> {
+    if field.trim().is_empty() {
+        Err(invalid_query("Filter field name is empty"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Formats a numeric or boolean filter value for the string based filter syntaxes, `None` for text
+pub fn format_plain_value(value: &FilterValue) -> Option<String> {
+    match value {
+        FilterValue::Text(_) => None,
+        FilterValue::Integer(value) => Some(value.to_string()),
+        FilterValue::Float(value) => Some(value.to_string()),
+        FilterValue::Boolean(value) => Some(value.to_string()),
+    }
+}
+
+/// The bounds of a range filter as comparison operators and values, lower bound first
+pub fn range_bounds(filter: &RangeFilter) -> Vec<(&'static str, &FilterValue)> {
+    [
+        (">", &filter.gt),
+        (">=", &filter.gte),
+        ("<", &filter.lt),
+        ("<=", &filter.lte),
+    ]
+    .into_iter()
+    .filter_map(|(operator, value)| value.as_ref().map(|value| (operator, value)))
+    .collect()
+}
+
+/// Compiles the filter to the Query DSL shared by Elasticsearch and OpenSearch, to be used in
+/// the `filter` clause of a bool query
+pub fn filter_to_query_dsl(filter: &Filter) -> Value {
+    match filter {
+        Filter::Is(condition) => condition_to_query_dsl(condition),
+        Filter::IsNot(condition) => json!({
+            "bool": {
+                "must_not": [condition_to_query_dsl(condition)]
+            }
+        }),
+        Filter::And(operands) => json!({
+            "bool": {
+                "filter": operands.iter().map(filter_to_query_dsl).collect::<Vec<_>>()
+            }
+        }),
+        Filter::Or(operands) => json!({
+            "bool": {
+                "should": operands.iter().map(filter_to_query_dsl).collect::<Vec<_>>(),
+                "minimum_should_match": 1
+            }
+        }),
+    }
+}
+
+fn condition_to_query_dsl(condition: &Condition) -> Value {
+    match condition {
+        Condition::Eq(filter) => json!({
+            "term": { &filter.field: value_to_json(&filter.value) }
+        }),
+        Condition::Range(filter) => {
+            let mut bounds = serde_json::Map::new();
+            for (name, value) in [
+                ("gt", &filter.gt),
+                ("gte", &filter.gte),
+                ("lt", &filter.lt),
+                ("lte", &filter.lte),
+            ] {
+                if let Some(value) = value {
+                    bounds.insert(name.to_string(), value_to_json(value));
+                }
+            }
+            json!({
+                "range": { &filter.field: bounds }
+            })
+        }
+        Condition::In(filter) => json!({
+            "terms": {
+                &filter.field: filter.values.iter().map(value_to_json).collect::<Vec<_>>()
+            }
+        }),
+        Condition::Exists(field) => json!({
+            "exists": { "field": field }
+        }),
+        Condition::GeoRadius(filter) => json!({
+            "geo_distance": {
+                "distance": format!("{}m", filter.radius_meters),
+                &filter.field: {
+                    "lat": filter.latitude,
+                    "lon": filter.longitude
+                }
+            }
+        }),
+    }
+}
+
+fn value_to_json(value: &FilterValue) -> Value {
+    match value {
+        FilterValue::Text(value) => json!(value),
+        FilterValue::Integer(value) => json!(value),
+        FilterValue::Float(value) => json!(value),
+        FilterValue::Boolean(value) => json!(value),
+    }
+}
+
+/// Reference semantics of the filters, which the compiled filters of the providers have to match.
+/// Conditions on an array field match if any of its elements matches.
+pub fn matches_filter(filter: &Filter, document: &Value) -> bool {
+    match filter {
+        Filter::Is(condition) => condition_matches(condition, document),
+        Filter::IsNot(condition) => !condition_matches(condition, document),
+        Filter::And(operands) => operands
+            .iter()
+            .all(|operand| matches_filter(operand, document)),
+        Filter::Or(operands) => operands
+            .iter()
+            .any(|operand| matches_filter(operand, document)),
+    }
+}
+
+fn condition_matches(condition: &Condition, document: &Value) -> bool {
+    match document.get(condition.field()) {
+        None | Some(Value::Null) => false,
+        Some(Value::Array(values)) => values.iter().any(|value| value_matches(condition, value)),
+        Some(value) => value_matches(condition, value),
+    }
+}
+
+fn value_matches(condition: &Condition, field: &Value) -> bool {
+    let as_f64 = |value: &FilterValue| match value {
+        FilterValue::Integer(value) => Some(*value as f64),
+        FilterValue::Float(value) => Some(*value),
+        _ => None,
+    };
+    let equals = |value: &FilterValue| match value {
+        FilterValue::Text(value) => field.as_str() == Some(value),
+        FilterValue::Boolean(value) => field.as_bool() == Some(*value),
+        number => field.as_f64().is_some() && field.as_f64() == as_f64(number),
+    };
+
+    match condition {
+        Condition::Eq(filter) => equals(&filter.value),
+        Condition::In(filter) => filter.values.iter().any(equals),
+        Condition::Exists(_) => !field.is_null(),
+        Condition::Range(filter) => {
+            let Some(field) = field.as_f64() else {
+                return false;
+            };
+            let bound = |value: &Option<FilterValue>, check: fn(f64, f64) -> bool| {
+                value
+                    .as_ref()
+                    .and_then(as_f64)
+                    .is_none_or(|value| check(field, value))
+            };
+            bound(&filter.gt, |a, b| a > b)
+                && bound(&filter.gte, |a, b| a >= b)
+                && bound(&filter.lt, |a, b| a < b)
+                && bound(&filter.lte, |a, b| a <= b)
+        }
+        Condition::GeoRadius(filter) => {
+            let longitude = field["lng"].as_f64().or_else(|| field["lon"].as_f64());
+            let (Some(latitude), Some(longitude)) = (field["lat"].as_f64(), longitude) else {
+                return false;
+            };
+            haversine_meters(latitude, longitude, filter.latitude, filter.longitude)
+                <= filter.radius_meters
+        }
+    }
+}
+
+/// Great-circle distance of two points in meters
+fn haversine_meters(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * 6_371_000.0 * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::filter::{
+        filter_to_query_dsl, matches_filter, parse_filter_expr, Condition, Filter,
+    };
+    use crate::golem::search::types::{
+        FieldFilter, FilterExpr, FilterNode, FilterValue, SearchError,
+    };
+    use crate::test_util::conformance_cases;
+    use serde_json::{json, Value};
+
+    fn documents() -> Vec<(&'static str, Value)> {
+        vec![
+            (
+                "1",
+                json!({"category": "books", "year": 2020, "price": 10, "in_stock": true,
+                       "_geo": {"lat": 48.86, "lng": 2.35}}),
+            ),
+            (
+                "2",
+                json!({"category": "music", "year": 2019, "price": 20.5, "in_stock": false,
+                       "discount": 5, "title": "say \"hi\""}),
+            ),
+            (
+                "3",
+                json!({"category": "books", "price": 5, "in_stock": false,
+                       "_geo": {"lat": 47.5, "lng": 19.04}}),
+            ),
+            ("4", json!({"category": "games", "price": 15})),
+        ]
+    }
+
+    #[test]
+    fn conformance_cases_match_expected_documents() {
+        for (name, expr) in conformance_cases() {
+            let filter = parse_filter_expr(&expr).unwrap();
+            let matching = documents()
+                .into_iter()
+                .filter(|(_, document)| matches_filter(&filter, document))
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            let expected: Vec<&str> = match name {
+                "eq_text" => vec!["1", "3"],
+                "eq_integer" => vec!["1"],
+                "eq_boolean" => vec!["1"],
+                "eq_escaped_text" => vec!["2"],
+                "ne" => vec!["2", "4"],
+                "range" => vec!["1", "4"],
+                "in" => vec!["1", "2", "3"],
+                "exists" => vec!["2"],
+                "geo_radius" => vec!["1"],
+                "and_or" => vec!["1", "3"],
+                "not_and" => vec!["2", "3", "4"],
+                "not_in" => vec!["4"],
+                "not_range" => vec!["2", "3"],
+                _ => panic!("No expected documents for filter case {name}"),
+            };
+            assert_eq!(matching, expected, "filter case {name}");
+        }
+    }
+
+    #[test]
+    fn pushes_negations_to_conditions() {
+        let (_, expr) = conformance_cases()
+            .into_iter()
+            .find(|(name, _)| *name == "not_and")
+            .unwrap();
+        let filter = parse_filter_expr(&expr).unwrap();
+        assert!(matches!(
+            filter,
+            Filter::Or(ref operands) if operands.iter().all(|operand| matches!(operand, Filter::IsNot(Condition::Eq(_))))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        let eq = FilterNode::Eq(FieldFilter {
+            field: "category".to_string(),
+            value: FilterValue::Text("books".to_string()),
+        });
+        let invalid = |nodes: Vec<FilterNode>| {
+            matches!(
+                parse_filter_expr(&FilterExpr { nodes }),
+                Err(SearchError::InvalidQuery(_))
+            )
+        };
+
+        assert!(invalid(vec![]));
+        assert!(invalid(vec![FilterNode::Not(0)]));
+        assert!(invalid(vec![eq.clone(), FilterNode::And(vec![0, 0])]));
+        assert!(invalid(vec![eq.clone(), eq.clone()]));
+        assert!(invalid(vec![eq, FilterNode::Or(vec![])]));
+    }
+
+    #[test]
+    fn compiles_to_query_dsl() {
+        let (_, expr) = conformance_cases()
+            .into_iter()
+            .find(|(name, _)| *name == "not_range")
+            .unwrap();
+        let filter = parse_filter_expr(&expr).unwrap();
+        assert_eq!(
+            filter_to_query_dsl(&filter),
+            json!({
+                "bool": {
+                    "must_not": [{"range": {"price": {"gte": 10, "lt": 20.5}}}]
+                }
+            })
+        );
+    }
+}
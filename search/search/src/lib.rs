@@ -1,6 +1,16 @@
+pub mod aggregation;
+pub mod bulk;
 pub mod config;
+pub mod consistency;
 pub mod durability;
 pub mod error;
+pub mod federation;
+pub mod filter;
+pub mod reindex;
+pub mod relevance;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod vector;
 
 wit_bindgen::generate!({
     path: "../wit",
@@ -0,0 +1,99 @@
+use crate::durability::ExtendedGuest;
+use crate::golem::search::types::{IndexName, ReindexResult, ReindexTransform, SearchError};
+use golem_rust::{FromValueAndType, IntoValue};
+use std::time::Duration;
+
+/// Delay before the first check of a running reindex task
+const INITIAL_POLL_DELAY: Duration = Duration::from_millis(500);
+/// Longest delay between two checks of a running reindex task
+const MAX_POLL_DELAY: Duration = Duration::from_secs(10);
+
+/// A reindex started by a provider
+#[derive(Debug, Clone, PartialEq, FromValueAndType, IntoValue)]
+pub enum ReindexTask {
+    /// Task running on the server, identified by its id and checked until it finishes
+    Running(String),
+    /// Reindex done before returning, by providers copying the documents themselves
+    Finished(ReindexResult),
+}
+
+/// Waits until a reindex task finishes. `poll` is called with the id of the task and the delay to
+/// wait before checking it, and returns `None` while the task is still running. The delay doubles
+/// after every check, up to `MAX_POLL_DELAY`.
+pub fn wait_for_reindex(
+    task: ReindexTask,
+    mut poll: impl FnMut(&str, Duration) -> Result<Option<ReindexResult>, SearchError>,
+) -> Result<ReindexResult, SearchError> {
+    let task_id = match task {
+        ReindexTask::Running(task_id) => task_id,
+        ReindexTask::Finished(result) => return Ok(result),
+    };
+
+    let mut delay = INITIAL_POLL_DELAY;
+    loop {
+        if let Some(result) = poll(&task_id, delay)? {
+            return Ok(result);
+        }
+        delay = (delay * 2).min(MAX_POLL_DELAY);
+    }
+}
+
+/// Runs a reindex of a provider until it finishes, sleeping between the checks of its task
+pub fn run_reindex<Impl: ExtendedGuest>(
+    source: IndexName,
+    target: IndexName,
+    transform: Option<ReindexTransform>,
+) -> Result<ReindexResult, SearchError> {
+    let task = Impl::start_reindex(source, target, transform)?;
+    wait_for_reindex(task, |task_id, delay| {
+        std::thread::sleep(delay);
+        Impl::poll_reindex(task_id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golem::search::types::{ReindexResult, SearchError};
+    use crate::reindex::{wait_for_reindex, ReindexTask, MAX_POLL_DELAY};
+    use std::time::Duration;
+
+    #[test]
+    fn finished_task_is_not_polled() {
+        let result = wait_for_reindex(
+            ReindexTask::Finished(ReindexResult { copied: Some(3) }),
+            |_, _| panic!("A finished task must not be polled"),
+        );
+        assert_eq!(result, Ok(ReindexResult { copied: Some(3) }));
+    }
+
+    #[test]
+    fn running_task_is_polled_with_backoff() {
+        let mut delays = Vec::new();
+        let result = wait_for_reindex(ReindexTask::Running("node:42".to_string()), |id, delay| {
+            assert_eq!(id, "node:42");
+            delays.push(delay);
+            if delays.len() < 8 {
+                Ok(None)
+            } else {
+                Ok(Some(ReindexResult { copied: Some(1000) }))
+            }
+        });
+
+        assert_eq!(result, Ok(ReindexResult { copied: Some(1000) }));
+        assert_eq!(delays[0], Duration::from_millis(500));
+        assert_eq!(delays[1], Duration::from_secs(1));
+        assert!(delays.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(delays.last(), Some(&MAX_POLL_DELAY));
+    }
+
+    #[test]
+    fn failed_poll_stops_waiting() {
+        let result = wait_for_reindex(ReindexTask::Running("node:42".to_string()), |_, _| {
+            Err(SearchError::Internal("version conflict".to_string()))
+        });
+        assert_eq!(
+            result,
+            Err(SearchError::Internal("version conflict".to_string()))
+        );
+    }
+}
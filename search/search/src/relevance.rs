@@ -0,0 +1,538 @@
+use crate::error::{invalid_query, unsupported};
+use crate::golem::search::types::{RelevanceSettings, SearchError, SynonymSet};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::iter::once;
+
+/// Token filter holding the synonyms on Elasticsearch and OpenSearch
+const SYNONYM_FILTER: &str = "golem_synonyms";
+/// Token filter holding the stop words on Elasticsearch and OpenSearch
+const STOP_WORDS_FILTER: &str = "golem_stop_words";
+/// Analyzer applied to the query text of every field on Elasticsearch and OpenSearch
+const SEARCH_ANALYZER: &str = "default_search";
+
+/// A parsed entry of `relevance-settings.ranking-rules`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RankingRule<'a> {
+    /// `field:asc` or `field:desc`
+    Field { field: &'a str, descending: bool },
+    /// Built-in rule of the provider, passed through by name
+    Builtin(&'a str),
+}
+
+pub fn parse_ranking_rule(rule: &str) -> Result<RankingRule<'_>, SearchError> {
+    match rule.rsplit_once(':') {
+        Some((field, "asc")) if !field.is_empty() => Ok(RankingRule::Field {
+            field,
+            descending: false,
+        }),
+        Some((field, "desc")) if !field.is_empty() => Ok(RankingRule::Field {
+            field,
+            descending: true,
+        }),
+        Some(_) => Err(invalid_query(format!(
+            "Invalid ranking rule '{rule}', expected 'field:asc' or 'field:desc'"
+        ))),
+        None if rule.trim().is_empty() => Err(invalid_query("Ranking rules cannot be empty")),
+        None => Ok(RankingRule::Builtin(rule)),
+    }
+}
+
+/// Checks the parts of the settings every provider interprets the same way
+pub fn validate_relevance_settings(settings: &RelevanceSettings) -> Result<(), SearchError> {
+    for set in &settings.synonyms {
+        let terms = set.root.iter().chain(&set.synonyms);
+        if terms.clone().any(|term| term.trim().is_empty()) {
+            return Err(invalid_query("Synonyms cannot be empty"));
+        }
+        let required = if set.root.is_some() { 1 } else { 2 };
+        if set.synonyms.len() < required {
+            return Err(invalid_query(format!(
+                "A synonym set needs at least {required} synonyms"
+            )));
+        }
+    }
+    if settings
+        .stop_words
+        .iter()
+        .any(|word| word.trim().is_empty())
+    {
+        return Err(invalid_query("Stop words cannot be empty"));
+    }
+    for rule in &settings.ranking_rules {
+        parse_ranking_rule(rule)?;
+    }
+    parse_provider_settings(settings)?;
+    Ok(())
+}
+
+/// The provider settings as a JSON object
+pub fn parse_provider_settings(
+    settings: &RelevanceSettings,
+) -> Result<Option<Map<String, Value>>, SearchError> {
+    match &settings.provider_settings {
+        Some(provider_settings) => match serde_json::from_str(provider_settings) {
+            Ok(Value::Object(object)) => Ok(Some(object)),
+            Ok(_) => Err(invalid_query("Provider settings must be a JSON object")),
+            Err(e) => Err(invalid_query(format!("Invalid provider settings: {e}"))),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Synonyms keyed by the term they expand, the way Meilisearch stores them. A multi-way set maps
+/// each of its terms to the others, a one-way set only maps its root.
+pub fn synonym_map(sets: &[SynonymSet]) -> BTreeMap<String, Vec<String>> {
+    let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut add = |term: &String, synonym: &String| {
+        let synonyms = map.entry(term.clone()).or_default();
+        if synonym != term && !synonyms.contains(synonym) {
+            synonyms.push(synonym.clone());
+        }
+    };
+    for set in sets {
+        match &set.root {
+            Some(root) => set.synonyms.iter().for_each(|synonym| add(root, synonym)),
+            None => {
+                for term in &set.synonyms {
+                    set.synonyms.iter().for_each(|synonym| add(term, synonym));
+                }
+            }
+        }
+    }
+    map
+}
+
+/// Inverse of `synonym_map`. Terms mapping to each other are grouped back into multi-way sets,
+/// every other entry becomes a one-way set.
+pub fn synonym_sets_from_map(map: &BTreeMap<String, Vec<String>>) -> Vec<SynonymSet> {
+    let group_of = |term: &String| -> Option<BTreeSet<String>> {
+        map.get(term)
+            .map(|synonyms| once(term).chain(synonyms).cloned().collect())
+    };
+
+    let mut grouped = BTreeSet::new();
+    let mut sets = Vec::new();
+    for (term, synonyms) in map {
+        if synonyms.is_empty() || grouped.contains(term) {
+            continue;
+        }
+        let group = group_of(term);
+        if synonyms.iter().all(|synonym| group_of(synonym) == group) {
+            grouped.extend(group.unwrap_or_default());
+            sets.push(SynonymSet {
+                synonyms: once(term).chain(synonyms).cloned().collect(),
+                root: None,
+            });
+        } else {
+            sets.push(SynonymSet {
+                synonyms: synonyms.clone(),
+                root: Some(term.clone()),
+            });
+        }
+    }
+    sets
+}
+
+/// Synonym rules in the Solr format of the Elasticsearch and OpenSearch synonym filters
+pub fn synonym_rules(sets: &[SynonymSet]) -> Result<Vec<String>, SearchError> {
+    let terms = sets
+        .iter()
+        .flat_map(|set| set.root.iter().chain(&set.synonyms));
+    for term in terms {
+        if term.contains(',') || term.contains("=>") {
+            return Err(invalid_query(format!(
+                "Synonym '{term}' cannot contain ',' or '=>'"
+            )));
+        }
+    }
+
+    Ok(sets
+        .iter()
+        .map(|set| match &set.root {
+            Some(root) => format!("{root} => {root}, {}", set.synonyms.join(", ")),
+            None => set.synonyms.join(", "),
+        })
+        .collect())
+}
+
+/// Parses Solr synonym rules, a rule with several inputs gives a one-way set for each of them
+pub fn synonym_sets_from_rules(rules: &[String]) -> Vec<SynonymSet> {
+    let split_terms = |terms: &str| -> Vec<String> {
+        terms
+            .split(',')
+            .map(str::trim)
+            .filter(|term| !term.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+
+    rules
+        .iter()
+        .flat_map(|rule| match rule.split_once("=>") {
+            Some((inputs, outputs)) => {
+                let outputs = split_terms(outputs);
+                split_terms(inputs)
+                    .into_iter()
+                    .map(|input| SynonymSet {
+                        synonyms: outputs
+                            .iter()
+                            .filter(|output| **output != input)
+                            .cloned()
+                            .collect(),
+                        root: Some(input),
+                    })
+                    .collect()
+            }
+            None => vec![SynonymSet {
+                synonyms: split_terms(rule),
+                root: None,
+            }],
+        })
+        .filter(|set| !set.synonyms.is_empty())
+        .collect()
+}
+
+/// Index settings applying the relevance settings on Elasticsearch and OpenSearch. Stop words and
+/// synonyms become token filters of the `default_search` analyzer, and the provider settings may
+/// add other `analysis` settings. Ranking rules have no index level equivalent.
+pub fn relevance_to_index_settings(settings: &RelevanceSettings) -> Result<Value, SearchError> {
+    if !settings.ranking_rules.is_empty() {
+        return Err(unsupported("Ranking rules"));
+    }
+
+    let mut analysis = match parse_provider_settings(settings)? {
+        Some(mut provider_settings) => {
+            if provider_settings.keys().any(|key| key != "analysis") {
+                return Err(invalid_query(
+                    "Only 'analysis' is supported in the provider settings",
+                ));
+            }
+            match provider_settings.remove("analysis") {
+                Some(Value::Object(analysis)) => analysis,
+                Some(_) => return Err(invalid_query("'analysis' must be a JSON object")),
+                None => Map::new(),
+            }
+        }
+        None => Map::new(),
+    };
+
+    let mut filters = vec![json!("lowercase")];
+    if !settings.stop_words.is_empty() {
+        object_entry(&mut analysis, "filter")?.insert(
+            STOP_WORDS_FILTER.to_string(),
+            json!({"type": "stop", "stopwords": settings.stop_words, "ignore_case": true}),
+        );
+        filters.push(json!(STOP_WORDS_FILTER));
+    }
+    if !settings.synonyms.is_empty() {
+        object_entry(&mut analysis, "filter")?.insert(
+            SYNONYM_FILTER.to_string(),
+            json!({
+                "type": "synonym_graph",
+                "synonyms": synonym_rules(&settings.synonyms)?,
+                "lenient": true
+            }),
+        );
+        filters.push(json!(SYNONYM_FILTER));
+    }
+    object_entry(&mut analysis, "analyzer")?.insert(
+        SEARCH_ANALYZER.to_string(),
+        json!({"type": "custom", "tokenizer": "standard", "filter": filters}),
+    );
+
+    Ok(json!({ "analysis": analysis }))
+}
+
+fn object_entry<'a>(
+    object: &'a mut Map<String, Value>,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>, SearchError> {
+    object
+        .entry(key)
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .ok_or_else(|| invalid_query(format!("'analysis.{key}' must be a JSON object")))
+}
+
+/// Reads back the relevance settings written by `relevance_to_index_settings` from the `analysis`
+/// index settings. The filters are only reported while the search analyzer uses them, the other
+/// analysis settings are returned as provider settings.
+pub fn relevance_from_analysis(analysis: Option<&Value>) -> RelevanceSettings {
+    let mut analysis = analysis
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    let strings = |value: Option<&Value>| -> Vec<String> {
+        value
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|value| value.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let analysis_value = Value::Object(analysis.clone());
+    let search_filters =
+        strings(analysis_value.pointer(&format!("/analyzer/{SEARCH_ANALYZER}/filter")));
+    let uses = |filter: &str| search_filters.iter().any(|name| name == filter);
+
+    let synonyms = if uses(SYNONYM_FILTER) {
+        synonym_sets_from_rules(&strings(
+            analysis_value.pointer(&format!("/filter/{SYNONYM_FILTER}/synonyms")),
+        ))
+    } else {
+        vec![]
+    };
+    let stop_words = if uses(STOP_WORDS_FILTER) {
+        strings(analysis_value.pointer(&format!("/filter/{STOP_WORDS_FILTER}/stopwords")))
+    } else {
+        vec![]
+    };
+
+    for (key, name) in [
+        ("filter", SYNONYM_FILTER),
+        ("filter", STOP_WORDS_FILTER),
+        ("analyzer", SEARCH_ANALYZER),
+    ] {
+        if let Some(entries) = analysis.get_mut(key).and_then(Value::as_object_mut) {
+            entries.remove(name);
+        }
+    }
+    analysis.retain(|_, value| !value.as_object().is_some_and(Map::is_empty));
+
+    RelevanceSettings {
+        synonyms,
+        stop_words,
+        ranking_rules: vec![],
+        provider_settings: (!analysis.is_empty())
+            .then(|| json!({ "analysis": analysis }).to_string()),
+    }
+}
+
+/// Whether applying `settings` changes the analysis settings of an index with the `current`
+/// relevance settings read by `relevance_from_analysis`. Elasticsearch and OpenSearch only change
+/// analysis settings while the index is closed, so it is only closed when this is the case.
+/// Provider settings are always applied, as the provider returns their values reformatted.
+pub fn analysis_changed(current: &RelevanceSettings, settings: &RelevanceSettings) -> bool {
+    settings.provider_settings.is_some()
+        || current.stop_words != settings.stop_words
+        || synonym_rules(&current.synonyms).ok() != synonym_rules(&settings.synonyms).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golem::search::types::{RelevanceSettings, SearchError, SynonymSet};
+    use crate::relevance::{
+        analysis_changed, parse_ranking_rule, relevance_from_analysis, relevance_to_index_settings,
+        synonym_map, synonym_rules, synonym_sets_from_map, synonym_sets_from_rules,
+        validate_relevance_settings, RankingRule,
+    };
+    use serde_json::{json, Value};
+
+    fn multi_way(terms: &[&str]) -> SynonymSet {
+        SynonymSet {
+            synonyms: terms.iter().map(|term| term.to_string()).collect(),
+            root: None,
+        }
+    }
+
+    fn one_way(root: &str, terms: &[&str]) -> SynonymSet {
+        SynonymSet {
+            synonyms: terms.iter().map(|term| term.to_string()).collect(),
+            root: Some(root.to_string()),
+        }
+    }
+
+    fn settings(synonyms: Vec<SynonymSet>, stop_words: &[&str]) -> RelevanceSettings {
+        RelevanceSettings {
+            synonyms,
+            stop_words: stop_words.iter().map(|word| word.to_string()).collect(),
+            ranking_rules: vec![],
+            provider_settings: None,
+        }
+    }
+
+    #[test]
+    fn parses_ranking_rules() {
+        assert_eq!(
+            parse_ranking_rule("year:desc").unwrap(),
+            RankingRule::Field {
+                field: "year",
+                descending: true
+            }
+        );
+        assert_eq!(
+            parse_ranking_rule("price:asc").unwrap(),
+            RankingRule::Field {
+                field: "price",
+                descending: false
+            }
+        );
+        assert_eq!(
+            parse_ranking_rule("typo").unwrap(),
+            RankingRule::Builtin("typo")
+        );
+        assert!(matches!(
+            parse_ranking_rule("year:down"),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            parse_ranking_rule(" "),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn validates_synonym_sets() {
+        assert!(validate_relevance_settings(&settings(
+            vec![
+                multi_way(&["laptop", "notebook"]),
+                one_way("tv", &["television"])
+            ],
+            &["the"],
+        ))
+        .is_ok());
+        assert!(validate_relevance_settings(&settings(vec![multi_way(&["laptop"])], &[])).is_err());
+        assert!(validate_relevance_settings(&settings(vec![one_way("tv", &[])], &[])).is_err());
+        assert!(validate_relevance_settings(&settings(vec![], &[""])).is_err());
+
+        let mut provider_settings = settings(vec![], &[]);
+        provider_settings.provider_settings = Some("[]".to_string());
+        assert!(validate_relevance_settings(&provider_settings).is_err());
+    }
+
+    #[test]
+    fn synonym_map_roundtrip() {
+        let sets = vec![
+            multi_way(&["laptop", "notebook", "portable"]),
+            one_way("tv", &["television", "screen"]),
+        ];
+        let map = synonym_map(&sets);
+        assert_eq!(
+            map.get("notebook").unwrap(),
+            &vec!["laptop".to_string(), "portable".to_string()]
+        );
+        assert_eq!(
+            map.get("tv").unwrap(),
+            &vec!["television".to_string(), "screen".to_string()]
+        );
+        assert!(!map.contains_key("television"));
+
+        assert_eq!(
+            synonym_sets_from_map(&map),
+            vec![
+                multi_way(&["laptop", "notebook", "portable"]),
+                one_way("tv", &["television", "screen"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn synonym_rules_roundtrip() {
+        let sets = vec![
+            multi_way(&["laptop", "notebook"]),
+            one_way("tv", &["television"]),
+        ];
+        let rules = synonym_rules(&sets).unwrap();
+        assert_eq!(rules, vec!["laptop, notebook", "tv => tv, television"]);
+        assert_eq!(synonym_sets_from_rules(&rules), sets);
+
+        assert_eq!(
+            synonym_sets_from_rules(&["usa, us => united states".to_string()]),
+            vec![
+                one_way("usa", &["united states"]),
+                one_way("us", &["united states"]),
+            ]
+        );
+        assert!(synonym_rules(&[multi_way(&["a,b", "c"])]).is_err());
+    }
+
+    #[test]
+    fn index_settings_roundtrip() {
+        let mut relevance = settings(vec![multi_way(&["laptop", "notebook"])], &["the", "a"]);
+        relevance.provider_settings = Some(
+            json!({"analysis": {"filter": {"my_stemmer": {"type": "stemmer", "language": "english"}}}})
+                .to_string(),
+        );
+
+        let index_settings = relevance_to_index_settings(&relevance).unwrap();
+        assert_eq!(
+            index_settings["analysis"]["analyzer"]["default_search"]["filter"],
+            json!(["lowercase", "golem_stop_words", "golem_synonyms"])
+        );
+        assert_eq!(
+            index_settings["analysis"]["filter"]["golem_synonyms"]["synonyms"],
+            json!(["laptop, notebook"])
+        );
+        assert_eq!(
+            index_settings["analysis"]["filter"]["my_stemmer"]["type"],
+            json!("stemmer")
+        );
+
+        let read = relevance_from_analysis(index_settings.get("analysis"));
+        assert_eq!(read.synonyms, relevance.synonyms);
+        assert_eq!(read.stop_words, relevance.stop_words);
+        assert_eq!(
+            read.provider_settings
+                .map(
+                    |provider_settings| serde_json::from_str::<Value>(&provider_settings).unwrap()
+                ),
+            relevance
+                .provider_settings
+                .map(
+                    |provider_settings| serde_json::from_str::<Value>(&provider_settings).unwrap()
+                )
+        );
+    }
+
+    #[test]
+    fn cleared_settings_ignore_leftover_filters() {
+        let previous = relevance_to_index_settings(&settings(
+            vec![multi_way(&["laptop", "notebook"])],
+            &["the"],
+        ))
+        .unwrap();
+        let cleared = relevance_to_index_settings(&settings(vec![], &[])).unwrap();
+
+        // The index keeps the filters of the previous update, only the analyzer is replaced
+        let mut analysis = previous["analysis"].clone();
+        analysis["analyzer"] = cleared["analysis"]["analyzer"].clone();
+        let read = relevance_from_analysis(Some(&analysis));
+        assert!(read.synonyms.is_empty());
+        assert!(read.stop_words.is_empty());
+        assert_eq!(read.provider_settings, None);
+    }
+
+    #[test]
+    fn index_settings_reject_ranking_rules() {
+        let mut relevance = settings(vec![], &[]);
+        relevance.ranking_rules = vec!["year:desc".to_string()];
+        assert_eq!(
+            relevance_to_index_settings(&relevance),
+            Err(SearchError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn analysis_changes_with_synonyms_and_stop_words() {
+        let current = settings(vec![multi_way(&["laptop", "notebook"])], &["the"]);
+        let read = relevance_from_analysis(Some(
+            &relevance_to_index_settings(&current).unwrap()["analysis"],
+        ));
+        assert!(!analysis_changed(&read, &current));
+
+        assert!(analysis_changed(&read, &settings(vec![], &["the"])));
+        assert!(analysis_changed(
+            &read,
+            &settings(vec![multi_way(&["laptop", "notebook"])], &["a"])
+        ));
+
+        let mut with_provider_settings = current.clone();
+        with_provider_settings.provider_settings = Some(json!({"analysis": {}}).to_string());
+        assert!(analysis_changed(&read, &with_provider_settings));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::golem::search::types::{
+    FieldFilter, FilterExpr, FilterNode, FilterValue, GeoRadiusFilter, InFilter, RangeFilter,
+};
+
+/// Filter expressions covering every node type and the negations, which the filter compilers of
+/// all the providers are tested against, so they stay equivalent
+pub fn conformance_cases() -> Vec<(&'static str, FilterExpr)> {
+    let text = |value: &str| FilterValue::Text(value.to_string());
+    let eq = |field: &str, value: FilterValue| {
+        FilterNode::Eq(FieldFilter {
+            field: field.to_string(),
+            value,
+        })
+    };
+    let price_range = || {
+        FilterNode::Range(RangeFilter {
+            field: "price".to_string(),
+            gt: None,
+            gte: Some(FilterValue::Integer(10)),
+            lt: Some(FilterValue::Float(20.5)),
+            lte: None,
+        })
+    };
+    let categories = || {
+        FilterNode::In(InFilter {
+            field: "category".to_string(),
+            values: vec![text("books"), text("music")],
+        })
+    };
+    let expr = |nodes: Vec<FilterNode>| FilterExpr { nodes };
+
+    vec![
+        ("eq_text", expr(vec![eq("category", text("books"))])),
+        (
+            "eq_integer",
+            expr(vec![eq("year", FilterValue::Integer(2020))]),
+        ),
+        (
+            "eq_boolean",
+            expr(vec![eq("in_stock", FilterValue::Boolean(true))]),
+        ),
+        (
+            "eq_escaped_text",
+            expr(vec![eq("title", text("say \"hi\""))]),
+        ),
+        (
+            "ne",
+            expr(vec![FilterNode::Ne(FieldFilter {
+                field: "category".to_string(),
+                value: text("books"),
+            })]),
+        ),
+        ("range", expr(vec![price_range()])),
+        ("in", expr(vec![categories()])),
+        (
+            "exists",
+            expr(vec![FilterNode::Exists("discount".to_string())]),
+        ),
+        (
+            "geo_radius",
+            expr(vec![FilterNode::GeoRadius(GeoRadiusFilter {
+                field: "_geo".to_string(),
+                latitude: 48.8566,
+                longitude: 2.3522,
+                radius_meters: 5000.0,
+            })]),
+        ),
+        (
+            "and_or",
+            expr(vec![
+                eq("category", text("books")),
+                FilterNode::Range(RangeFilter {
+                    field: "price".to_string(),
+                    gt: None,
+                    gte: None,
+                    lt: Some(FilterValue::Integer(10)),
+                    lte: None,
+                }),
+                eq("in_stock", FilterValue::Boolean(true)),
+                FilterNode::Or(vec![1, 2]),
+                FilterNode::And(vec![0, 3]),
+            ]),
+        ),
+        (
+            "not_and",
+            expr(vec![
+                eq("category", text("books")),
+                eq("in_stock", FilterValue::Boolean(true)),
+                FilterNode::And(vec![0, 1]),
+                FilterNode::Not(2),
+            ]),
+        ),
+        ("not_in", expr(vec![categories(), FilterNode::Not(0)])),
+        ("not_range", expr(vec![price_range(), FilterNode::Not(0)])),
+    ]
+}
@@ -0,0 +1,189 @@
+use crate::error::invalid_query;
+use crate::golem::search::types::{Embedding, FieldType, Schema, SearchError, VectorQuery};
+use serde_json::{Map, Value};
+
+/// Validates a vector query before it is compiled for a provider
+pub fn validate_vector_query(query: &VectorQuery) -> Result<(), SearchError> {
+    if query.field.trim().is_empty() {
+        return Err(invalid_query("Vector query field name is empty"));
+    }
+    if query.embedding.is_empty() {
+        return Err(invalid_query(format!(
+            "Vector query on {} has an empty embedding",
+            query.field
+        )));
+    }
+    if query.embedding.iter().any(|value| !value.is_finite()) {
+        return Err(invalid_query(format!(
+            "Vector query on {} has a non-finite embedding value",
+            query.field
+        )));
+    }
+    if query.k == 0 {
+        return Err(invalid_query(format!(
+            "Vector query on {} has to retrieve at least one neighbour",
+            query.field
+        )));
+    }
+    if let Some(weight) = query.hybrid_weight {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(invalid_query(format!(
+                "Hybrid weight of the vector query on {} has to be between 0 and 1",
+                query.field
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Validates that every vector field of the schema has a configuration with dimensions
+pub fn validate_vector_fields(schema: &Schema) -> Result<(), SearchError> {
+    for field in &schema.fields {
+        let configured = field
+            .vector
+            .as_ref()
+            .is_some_and(|config| config.dimensions > 0);
+        if field.field_type == FieldType::Vector && !configured {
+            return Err(invalid_query(format!(
+                "Vector field {} has no dimensions configured",
+                field.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Writes the embeddings of a document into the fields of a JSON object, keyed by their field
+pub fn insert_embeddings(object: &mut Map<String, Value>, embeddings: Vec<Embedding>) {
+    for embedding in embeddings {
+        object.insert(embedding.field, Value::from(embedding.values));
+    }
+}
+
+/// Removes the vector fields `fields` from the fields of a JSON object and returns them as
+/// embeddings, the inverse of `insert_embeddings`. Fields which are missing or do not hold a list
+/// of numbers are left in the object.
+pub fn take_embeddings(object: &mut Map<String, Value>, fields: &[String]) -> Vec<Embedding> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let values = object
+                .get(field)?
+                .as_array()?
+                .iter()
+                .map(|value| value.as_f64().map(|value| value as f32))
+                .collect::<Option<Vec<_>>>()?;
+            object.remove(field);
+            Some(Embedding {
+                field: field.clone(),
+                values,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golem::search::types::{
+        Embedding, FieldType, Schema, SchemaField, SearchError, VectorQuery,
+    };
+    use crate::vector::{
+        insert_embeddings, take_embeddings, validate_vector_fields, validate_vector_query,
+    };
+    use serde_json::{json, Map};
+
+    #[test]
+    fn rejects_invalid_vector_queries() {
+        let query = VectorQuery {
+            field: "embedding".to_string(),
+            embedding: vec![0.5, 0.25],
+            k: 5,
+            hybrid_weight: Some(0.3),
+        };
+        assert!(validate_vector_query(&query).is_ok());
+
+        let invalid = |query: VectorQuery| {
+            matches!(
+                validate_vector_query(&query),
+                Err(SearchError::InvalidQuery(_))
+            )
+        };
+        assert!(invalid(VectorQuery {
+            embedding: vec![],
+            ..query.clone()
+        }));
+        assert!(invalid(VectorQuery {
+            embedding: vec![f32::NAN],
+            ..query.clone()
+        }));
+        assert!(invalid(VectorQuery {
+            k: 0,
+            ..query.clone()
+        }));
+        assert!(invalid(VectorQuery {
+            hybrid_weight: Some(1.5),
+            ..query
+        }));
+    }
+
+    #[test]
+    fn requires_configuration_of_vector_fields() {
+        let schema = Schema {
+            fields: vec![SchemaField {
+                name: "embedding".to_string(),
+                field_type: FieldType::Vector,
+                required: false,
+                facet: false,
+                sort: false,
+                index: true,
+                vector: None,
+            }],
+            primary_key: None,
+        };
+        assert!(matches!(
+            validate_vector_fields(&schema),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn inserts_embeddings_into_fields() {
+        let mut object = Map::new();
+        object.insert("title".to_string(), json!("Dune"));
+        insert_embeddings(
+            &mut object,
+            vec![Embedding {
+                field: "embedding".to_string(),
+                values: vec![0.5, -1.0],
+            }],
+        );
+        assert_eq!(
+            json!(object),
+            json!({"title": "Dune", "embedding": [0.5, -1.0]})
+        );
+    }
+
+    #[test]
+    fn takes_embeddings_from_fields() {
+        let mut object = Map::new();
+        object.insert("title".to_string(), json!("Dune"));
+        object.insert("embedding".to_string(), json!([0.5, -1.0]));
+        object.insert("tags".to_string(), json!(["scifi"]));
+        let embeddings = take_embeddings(
+            &mut object,
+            &[
+                "embedding".to_string(),
+                "tags".to_string(),
+                "missing".to_string(),
+            ],
+        );
+        assert_eq!(
+            embeddings,
+            vec![Embedding {
+                field: "embedding".to_string(),
+                values: vec![0.5, -1.0],
+            }]
+        );
+        assert_eq!(json!(object), json!({"title": "Dune", "tags": ["scifi"]}));
+    }
+}
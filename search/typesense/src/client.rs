@@ -1,3 +1,4 @@
+use golem_search::bulk::bulk_item_error;
 use golem_search::error::{from_reqwest_error, internal_error, search_error_from_status};
 use golem_search::golem::search::types::SearchError;
 use log::trace;
@@ -115,7 +116,7 @@ impl TypesenseSearchApi {
         );
 
         let url = format!(
-            "{}/collections/{}/documents/import",
+            "{}/collections/{}/documents/import?action=upsert",
             self.base_url, collection_name
         );
 
@@ -203,6 +204,12 @@ impl TypesenseSearchApi {
     ) -> Result<SearchResponse, SearchError> {
         trace!("Searching collection: {collection_name}");
 
+        // Typesense limits the query string to 4000 characters, which an embedding easily
+        // exceeds, so vector queries are sent in the body of a multi-search instead
+        if query.vector_query.is_some() {
+            return self.search_with_body(collection_name, query);
+        }
+
         let url = format!(
             "{}/collections/{}/documents/search",
             self.base_url, collection_name
@@ -250,7 +257,245 @@ impl TypesenseSearchApi {
         Ok(params.join("&"))
     }
 
-    pub fn _multi_search(
+    /// Searches the collection with a multi-search of a single search, which takes its
+    /// parameters in the request body
+    fn search_with_body(
+        &self,
+        collection_name: &str,
+        query: &SearchQuery,
+    ) -> Result<SearchResponse, SearchError> {
+        let searches = MultiSearchQuery {
+            searches: vec![MultiSearchRequest {
+                collection: collection_name.to_string(),
+                query: query.clone(),
+            }],
+        };
+
+        match self.multi_search(&searches)?.results.into_iter().next() {
+            Some(MultiSearchResult::Results(response)) => Ok(response),
+            Some(MultiSearchResult::Error { code, error }) => Err(bulk_item_error(code, error)),
+            None => Err(internal_error(
+                "Typesense returned no results for the search",
+            )),
+        }
+    }
+
+    /// Documents of a collection matching `query`, read from its NDJSON export
+    pub fn export_documents(
+        &self,
+        collection_name: &str,
+        query: &ExportDocumentsQuery,
+    ) -> Result<Vec<TypesenseDocument>, SearchError> {
+        trace!("Exporting documents from collection: {collection_name}");
+
+        let mut params = Vec::new();
+        if let Some(ref filter_by) = query.filter_by {
+            params.push(format!("filter_by={}", urlencoding::encode(filter_by)));
+        }
+        if let Some(ref include_fields) = query.include_fields {
+            params.push(format!(
+                "include_fields={}",
+                urlencoding::encode(include_fields)
+            ));
+        }
+
+        let url = format!(
+            "{}/collections/{}/documents/export",
+            self.base_url, collection_name
+        );
+        let full_url = if params.is_empty() {
+            url
+        } else {
+            format!("{url}?{}", params.join("&"))
+        };
+
+        let response = self
+            .create_request(Method::GET, &full_url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(search_error_from_status(status));
+        }
+
+        let body = response
+            .text()
+            .map_err(|err| from_reqwest_error("Failed to read response", err))?;
+
+        body.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|e| internal_error(format!("Failed to parse exported document: {e}")))
+            })
+            .collect()
+    }
+
+    /// Points an alias to a collection, creating the alias if it does not exist
+    pub fn upsert_alias(
+        &self,
+        alias: &str,
+        collection_name: &str,
+    ) -> Result<CollectionAlias, SearchError> {
+        trace!("Pointing alias {alias} to collection: {collection_name}");
+
+        let url = format!("{}/aliases/{}", self.base_url, alias);
+
+        let response = self
+            .create_request(Method::PUT, &url)
+            .json(&serde_json::json!({ "collection_name": collection_name }))
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn get_alias(&self, alias: &str) -> Result<Option<CollectionAlias>, SearchError> {
+        trace!("Getting alias: {alias}");
+
+        let url = format!("{}/aliases/{}", self.base_url, alias);
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        if response.status() == 404 {
+            Ok(None)
+        } else {
+            parse_response(response).map(Some)
+        }
+    }
+
+    pub fn list_aliases(&self) -> Result<ListAliasesResponse, SearchError> {
+        trace!("Listing aliases");
+
+        let url = format!("{}/aliases", self.base_url);
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn list_synonyms(
+        &self,
+        collection_name: &str,
+    ) -> Result<ListSynonymsResponse, SearchError> {
+        trace!("Listing synonyms for collection: {collection_name}");
+
+        let url = format!("{}/collections/{}/synonyms", self.base_url, collection_name);
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn upsert_synonym(
+        &self,
+        collection_name: &str,
+        synonym: &SearchSynonym,
+    ) -> Result<SearchSynonym, SearchError> {
+        trace!(
+            "Upserting synonym {} for collection: {collection_name}",
+            synonym.id
+        );
+
+        let url = format!(
+            "{}/collections/{}/synonyms/{}",
+            self.base_url, collection_name, synonym.id
+        );
+
+        let response = self
+            .create_request(Method::PUT, &url)
+            .json(synonym)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response(response)
+    }
+
+    pub fn delete_synonym(&self, collection_name: &str, id: &str) -> Result<(), SearchError> {
+        trace!("Deleting synonym {id} for collection: {collection_name}");
+
+        let url = format!(
+            "{}/collections/{}/synonyms/{}",
+            self.base_url, collection_name, id
+        );
+
+        let response = self
+            .create_request(Method::DELETE, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response::<serde_json::Value>(response).map(|_| ())
+    }
+
+    pub fn list_overrides(
+        &self,
+        collection_name: &str,
+    ) -> Result<ListOverridesResponse, SearchError> {
+        trace!("Listing overrides for collection: {collection_name}");
+
+        let url = format!(
+            "{}/collections/{}/overrides",
+            self.base_url, collection_name
+        );
+
+        let response = self
+            .create_request(Method::GET, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response(response)
+    }
+
+    /// Creates or replaces a curation override, `rule` is the override without its id
+    pub fn upsert_override(
+        &self,
+        collection_name: &str,
+        id: &str,
+        rule: &serde_json::Value,
+    ) -> Result<(), SearchError> {
+        trace!("Upserting override {id} for collection: {collection_name}");
+
+        let url = format!(
+            "{}/collections/{}/overrides/{}",
+            self.base_url, collection_name, id
+        );
+
+        let response = self
+            .create_request(Method::PUT, &url)
+            .json(rule)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response::<serde_json::Value>(response).map(|_| ())
+    }
+
+    pub fn delete_override(&self, collection_name: &str, id: &str) -> Result<(), SearchError> {
+        trace!("Deleting override {id} for collection: {collection_name}");
+
+        let url = format!(
+            "{}/collections/{}/overrides/{}",
+            self.base_url, collection_name, id
+        );
+
+        let response = self
+            .create_request(Method::DELETE, &url)
+            .send()
+            .map_err(|e| internal_error(format!("HTTP request failed: {e}")))?;
+
+        parse_response::<serde_json::Value>(response).map(|_| ())
+    }
+
+    pub fn multi_search(
         &self,
         searches: &MultiSearchQuery,
     ) -> Result<MultiSearchResponse, SearchError> {
@@ -303,22 +548,26 @@ fn parse_bulk_import_response(response: Response) -> Result<IndexDocumentsRespon
         let lines: Vec<&str> = body_str.trim().split('\n').collect();
         let mut success_count = 0;
         let mut total_processed = 0;
+        let mut results = Vec::new();
 
+        // The response has one line per imported document, in the order of the request
         for line in lines {
             if !line.trim().is_empty() {
                 total_processed += 1;
-                match serde_json::from_str::<serde_json::Value>(line) {
-                    Ok(json) => {
-                        if json
-                            .get("success")
-                            .and_then(|v| v.as_bool())
-                            .unwrap_or(false)
-                        {
+                match serde_json::from_str::<ImportDocumentResult>(line) {
+                    Ok(result) => {
+                        if result.success {
                             success_count += 1;
                         }
+                        results.push(result);
                     }
                     Err(e) => {
-                        println!("[Typesense] Failed to parse NDJSON line: {e} | line: {line}");
+                        trace!("Failed to parse NDJSON line: {e} | line: {line}");
+                        results.push(ImportDocumentResult {
+                            success: false,
+                            code: None,
+                            error: Some(format!("Failed to parse import result: {e}")),
+                        });
                     }
                 }
             }
@@ -327,6 +576,7 @@ fn parse_bulk_import_response(response: Response) -> Result<IndexDocumentsRespon
         let response = IndexDocumentsResponse {
             success: success_count == total_processed && total_processed > 0,
             num_imported: Some(success_count),
+            results,
         };
 
         Ok(response)
@@ -378,6 +628,10 @@ pub struct CollectionField {
     pub sort: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub optional: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_dim: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vec_dist: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -457,6 +711,8 @@ pub struct SearchQuery {
     pub prioritize_token_position: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_candidates: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_query: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -528,7 +784,15 @@ pub struct RequestParams {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiSearchResponse {
-    pub results: Vec<SearchResponse>,
+    pub results: Vec<MultiSearchResult>,
+}
+
+/// Response of one search of a multi-search, which fails on its own
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MultiSearchResult {
+    Error { code: u16, error: String },
+    Results(SearchResponse),
 }
 
 // Response types
@@ -562,6 +826,15 @@ pub struct IndexDocumentResponse {
 pub struct IndexDocumentsResponse {
     pub success: bool,
     pub num_imported: Option<u32>,
+    pub results: Vec<ImportDocumentResult>,
+}
+
+/// Result of a single document of an import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportDocumentResult {
+    pub success: bool,
+    pub code: Option<u16>,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -578,3 +851,43 @@ pub struct DeleteDocumentResponse {
 pub struct DeleteDocumentsResponse {
     pub num_deleted: u32,
 }
+
+/// Parameters of a document export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportDocumentsQuery {
+    pub filter_by: Option<String>,
+    pub include_fields: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionAlias {
+    pub name: String,
+    pub collection_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAliasesResponse {
+    pub aliases: Vec<CollectionAlias>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchSynonym {
+    /// Part of the URL when a synonym is written
+    #[serde(skip_serializing)]
+    pub id: String,
+    pub synonyms: Vec<String>,
+    /// One-way synonyms are expanded from the root only, Typesense returns an empty root for
+    /// multi-way synonyms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListSynonymsResponse {
+    pub synonyms: Vec<SearchSynonym>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListOverridesResponse {
+    pub overrides: Vec<serde_json::Value>,
+}
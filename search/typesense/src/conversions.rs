@@ -1,9 +1,23 @@
 use crate::client::{
-    CollectionField, CollectionSchema, SearchHit as TypesenseSearchHit,
-    SearchQuery as TypesenseSearchQuery, SearchResponse, TypesenseDocument,
+    CollectionAlias, CollectionField, CollectionSchema, ExportDocumentsQuery,
+    IndexDocumentsResponse, MultiSearchResponse, MultiSearchResult,
+    SearchHit as TypesenseSearchHit, SearchQuery as TypesenseSearchQuery, SearchResponse,
+    SearchSynonym, TypesenseDocument,
+};
+use golem_search::aggregation::{field_facet_results, range_key, validate_aggregations};
+use golem_search::bulk::bulk_item_error;
+use golem_search::error::{invalid_query, unsupported};
+use golem_search::filter::{
+    format_plain_value, parse_filter_expr, range_bounds, Condition, Filter,
 };
 use golem_search::golem::search::types::{
-    Doc, FieldType, Schema, SchemaField, SearchHit, SearchQuery, SearchResults,
+    AggregationKind, Doc, FacetBucket, FacetResult, FieldType, FilterValue, IndexAlias,
+    RangeBucket, ReindexTransform, RelevanceSettings, Schema, SchemaField, SearchError, SearchHit,
+    SearchQuery, SearchResults, SynonymSet, VectorConfig, VectorQuery, VectorSimilarity,
+};
+use golem_search::relevance::parse_provider_settings;
+use golem_search::vector::{
+    insert_embeddings, take_embeddings, validate_vector_fields, validate_vector_query,
 };
 use serde_json::{Map, Value};
 
@@ -12,6 +26,7 @@ pub fn doc_to_typesense_document(doc: Doc) -> Result<TypesenseDocument, String>
         .map_err(|e| format!("Failed to parse document content as JSON: {e}"))?;
 
     fields.insert("id".to_string(), Value::String(doc.id));
+    insert_embeddings(&mut fields, doc.embeddings);
 
     Ok(TypesenseDocument { fields })
 }
@@ -26,10 +41,43 @@ pub fn _typesense_document_to_doc(doc: TypesenseDocument) -> Doc {
 
     let content = serde_json::to_string(&fields).unwrap_or_else(|_| "{}".to_string());
 
-    Doc { id, content }
+    Doc {
+        id,
+        content,
+        embeddings: vec![],
+    }
+}
+
+/// Converts the hit of a document lookup, the values of the vector fields `vector_fields` are
+/// moved from its content to its embeddings
+pub fn search_hit_to_doc(hit: SearchHit, vector_fields: &[String]) -> Doc {
+    let mut fields: Map<String, Value> = hit
+        .content
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    let embeddings = take_embeddings(&mut fields, vector_fields);
+
+    Doc {
+        id: hit.id,
+        content: Value::Object(fields).to_string(),
+        embeddings,
+    }
 }
 
-pub fn search_query_to_typesense_query(query: SearchQuery) -> TypesenseSearchQuery {
+/// Names of the vector fields of a collection
+pub fn vector_field_names(fields: &[CollectionField]) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| field.num_dim.is_some())
+        .map(|field| field.name.clone())
+        .collect()
+}
+
+pub fn search_query_to_typesense_query(
+    query: SearchQuery,
+) -> Result<TypesenseSearchQuery, SearchError> {
+    let facet_by = typesense_facet_by(&query)?;
+
     let mut typesense_query = TypesenseSearchQuery {
         q: query.q.unwrap_or_else(|| "*".to_string()),
         query_by: Some("title,author,description,genre".to_string()),
@@ -66,18 +114,33 @@ pub fn search_query_to_typesense_query(query: SearchQuery) -> TypesenseSearchQue
         prioritize_exact_match: None,
         prioritize_token_position: None,
         max_candidates: None,
+        vector_query: None,
     };
 
-    if !query.filters.is_empty() {
-        typesense_query.filter_by = Some(query.filters.join(" && "));
+    if let Some(vector_query) = &query.vector_query {
+        validate_vector_query(vector_query)?;
+        // Without a hybrid weight the keyword search is skipped and only the neighbours are ranked
+        if vector_query.hybrid_weight.is_none() {
+            typesense_query.q = "*".to_string();
+        }
+        typesense_query.vector_query = Some(typesense_vector_query(vector_query));
+    }
+
+    let mut filters = query.filters;
+    if let Some(expr) = &query.filter_expr {
+        let filter = parse_filter_expr(expr)?;
+        filters.push(filter_to_typesense_operand(&filter)?);
+    }
+    if !filters.is_empty() {
+        typesense_query.filter_by = Some(filters.join(" && "));
     }
 
     if !query.sort.is_empty() {
         typesense_query.sort_by = Some(query.sort.join(","));
     }
 
-    if !query.facets.is_empty() {
-        typesense_query.facet_by = Some(query.facets.join(","));
+    if !facet_by.is_empty() {
+        typesense_query.facet_by = Some(facet_by.join(","));
     }
 
     if let Some(highlight) = query.highlight {
@@ -220,35 +283,225 @@ pub fn search_query_to_typesense_query(query: SearchQuery) -> TypesenseSearchQue
         }
     }
 
-    typesense_query
+    Ok(typesense_query)
 }
 
-pub fn typesense_response_to_search_results(response: SearchResponse) -> SearchResults {
+/// Formats a vector query for the `vector_query` parameter, the hybrid weight is passed as
+/// `alpha`, the weight of the vector search rank against the keyword search rank
+fn typesense_vector_query(vector_query: &VectorQuery) -> String {
+    let embedding = vector_query
+        .embedding
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    match vector_query.hybrid_weight {
+        Some(weight) => format!(
+            "{}:([{embedding}], k:{}, alpha:{weight})",
+            vector_query.field, vector_query.k
+        ),
+        None => format!(
+            "{}:([{embedding}], k:{})",
+            vector_query.field, vector_query.k
+        ),
+    }
+}
+
+/// Compiles a typed filter to the Typesense `filter_by` syntax. Typesense has no operator for
+/// missing fields and cannot negate ranges or geo filters, these filters are not supported.
+pub fn filter_to_typesense(filter: &Filter) -> Result<String, SearchError> {
+    match filter {
+        Filter::Is(condition) => condition_to_typesense(condition, false),
+        Filter::IsNot(condition) => condition_to_typesense(condition, true),
+        Filter::And(operands) => join_typesense_operands(operands, " && "),
+        Filter::Or(operands) => join_typesense_operands(operands, " || "),
+    }
+}
+
+/// Compiles a filter to be combined with other expressions, parenthesized if it is compound
+fn filter_to_typesense_operand(filter: &Filter) -> Result<String, SearchError> {
+    let compiled = filter_to_typesense(filter)?;
+    Ok(match filter {
+        Filter::And(operands) | Filter::Or(operands) if operands.len() > 1 => {
+            format!("({compiled})")
+        }
+        _ => compiled,
+    })
+}
+
+fn join_typesense_operands(operands: &[Filter], separator: &str) -> Result<String, SearchError> {
+    Ok(operands
+        .iter()
+        .map(filter_to_typesense_operand)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(separator))
+}
+
+fn condition_to_typesense(condition: &Condition, negated: bool) -> Result<String, SearchError> {
+    let operator = if negated { ":!=" } else { ":=" };
+    match condition {
+        Condition::Eq(filter) => Ok(format!(
+            "{}{operator}{}",
+            filter.field,
+            typesense_value(&filter.value)?
+        )),
+        Condition::In(filter) => Ok(format!(
+            "{}{operator}[{}]",
+            filter.field,
+            filter
+                .values
+                .iter()
+                .map(typesense_value)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        )),
+        Condition::Range(filter) if !negated => Ok(range_bounds(filter)
+            .into_iter()
+            .map(|(operator, value)| {
+                Ok(format!(
+                    "{}:{operator}{}",
+                    filter.field,
+                    typesense_value(value)?
+                ))
+            })
+            .collect::<Result<Vec<_>, SearchError>>()?
+            .join(" && ")),
+        Condition::GeoRadius(filter) if !negated => Ok(format!(
+            "{}:({}, {}, {} km)",
+            filter.field,
+            filter.latitude,
+            filter.longitude,
+            filter.radius_meters / 1000.0
+        )),
+        Condition::Range(_) => Err(unsupported("Negated range filters in Typesense")),
+        Condition::GeoRadius(_) => Err(unsupported("Negated geo filters in Typesense")),
+        Condition::Exists(_) => Err(unsupported("Exists filters in Typesense")),
+    }
+}
+
+fn typesense_value(value: &FilterValue) -> Result<String, SearchError> {
+    match value {
+        FilterValue::Text(text) if text.contains('`') => Err(invalid_query(format!(
+            "Typesense filter values cannot contain backticks: {text}"
+        ))),
+        FilterValue::Text(text) => Ok(format!("`{text}`")),
+        value => Ok(format_plain_value(value).unwrap_or_default()),
+    }
+}
+
+/// Entries of `facet_by`: the facets of the query, a range facet per range aggregation and the
+/// fields of the stats aggregations. Typesense returns the counts of a field only once, so a
+/// field cannot be both a facet and a range aggregation, and date histograms are not supported.
+fn typesense_facet_by(query: &SearchQuery) -> Result<Vec<String>, SearchError> {
+    validate_aggregations(query)?;
+
+    let mut facet_by = query.facets.clone();
+    let mut stats_fields = Vec::new();
+    for aggregation in &query.aggregations {
+        match &aggregation.kind {
+            AggregationKind::Stats => stats_fields.push(aggregation.field.clone()),
+            AggregationKind::Range(ranges) => {
+                if facet_by.iter().any(|facet| {
+                    facet == &aggregation.field
+                        || facet.starts_with(&format!("{}(", aggregation.field))
+                }) {
+                    return Err(unsupported(
+                        "Several facets or range aggregations on the same field in Typesense",
+                    ));
+                }
+                let ranges = ranges
+                    .iter()
+                    .map(typesense_range)
+                    .collect::<Result<Vec<_>, _>>()?;
+                facet_by.push(format!("{}({})", aggregation.field, ranges.join(", ")));
+            }
+            AggregationKind::DateHistogram(_) => {
+                return Err(unsupported("Date histogram aggregations in Typesense"));
+            }
+        }
+    }
+    for field in stats_fields {
+        let faceted = facet_by
+            .iter()
+            .any(|facet| facet == &field || facet.starts_with(&format!("{field}(")));
+        if !faceted {
+            facet_by.push(field);
+        }
+    }
+    Ok(facet_by)
+}
+
+/// A range of a range facet, `label:[from, to]`. Typesense needs both bounds and labels
+/// without the characters of the range syntax.
+fn typesense_range(range: &RangeBucket) -> Result<String, SearchError> {
+    let label = range_key(range);
+    if label.is_empty() || label.contains([':', ',', '(', ')', '[', ']', ' ']) {
+        return Err(invalid_query(format!(
+            "Invalid range key '{label}' for Typesense"
+        )));
+    }
+    match (range.from, range.to) {
+        (Some(from), Some(to)) => Ok(format!("{label}:[{from}, {to}]")),
+        _ => Err(unsupported("Open-ended ranges in Typesense")),
+    }
+}
+
+/// Converts a search response, `query` is the search query the response belongs to
+pub fn typesense_response_to_search_results(
+    response: SearchResponse,
+    query: &SearchQuery,
+) -> SearchResults {
     let hits = response
         .hits
         .into_iter()
         .map(typesense_hit_to_search_hit)
         .collect();
 
-    let facets = response.facet_counts.map(|facet_counts| {
-        let facets_map: Map<String, Value> = facet_counts
+    let mut counts = Map::new();
+    let mut stats = Map::new();
+    for facet_count in response.facet_counts.unwrap_or_default() {
+        let values: Map<String, Value> = facet_count
+            .counts
             .into_iter()
-            .map(|facet_count| {
-                let values: Map<String, Value> = facet_count
-                    .counts
-                    .into_iter()
-                    .map(|facet_value| {
-                        (
-                            facet_value.value.as_str().unwrap_or("unknown").to_string(),
-                            Value::Number(serde_json::Number::from(facet_value.count)),
-                        )
-                    })
-                    .collect();
-                (facet_count.field_name, Value::Object(values))
+            .map(|facet_value| {
+                let value = match facet_value.value {
+                    Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                (value, Value::from(facet_value.count))
             })
             .collect();
-        serde_json::to_string(&facets_map).unwrap_or_default()
-    });
+        if let Some(facet_stats) = facet_count.stats {
+            stats.insert(
+                facet_count.field_name.clone(),
+                serde_json::to_value(facet_stats).unwrap_or_default(),
+            );
+        }
+        counts.insert(facet_count.field_name, Value::Object(values));
+    }
+
+    let mut facets = field_facet_results(query, &counts, &stats);
+    for aggregation in &query.aggregations {
+        if let AggregationKind::Range(ranges) = &aggregation.kind {
+            let buckets = ranges
+                .iter()
+                .map(|range| {
+                    let value = range_key(range);
+                    let count = counts
+                        .get(&aggregation.field)
+                        .and_then(|values| values.get(&value))
+                        .and_then(Value::as_u64)
+                        .unwrap_or(0);
+                    FacetBucket { value, count }
+                })
+                .collect();
+            facets.push(FacetResult {
+                name: aggregation.name.clone(),
+                buckets,
+                stats: None,
+            });
+        }
+    }
 
     SearchResults {
         total: Some(response.found),
@@ -260,6 +513,25 @@ pub fn typesense_response_to_search_results(response: SearchResponse) -> SearchR
     }
 }
 
+/// Converts the responses of a multi-search, `queries` are the search queries in the order of the
+/// searches. Fails with the error of the first search that failed.
+pub fn multi_search_response_results(
+    response: MultiSearchResponse,
+    queries: &[SearchQuery],
+) -> Result<Vec<SearchResults>, SearchError> {
+    response
+        .results
+        .into_iter()
+        .zip(queries)
+        .map(|(result, query)| match result {
+            MultiSearchResult::Results(response) => {
+                Ok(typesense_response_to_search_results(response, query))
+            }
+            MultiSearchResult::Error { code, error } => Err(bulk_item_error(code, error)),
+        })
+        .collect()
+}
+
 pub fn typesense_hit_to_search_hit(hit: TypesenseSearchHit) -> SearchHit {
     let mut document = hit.document;
 
@@ -321,6 +593,22 @@ pub fn typesense_hit_to_search_hit(hit: TypesenseSearchHit) -> SearchHit {
     }
 }
 
+/// Validates that the schema can be created as a collection, Typesense only compares vectors by
+/// cosine similarity or inner product
+pub fn validate_typesense_schema(schema: &Schema) -> Result<(), SearchError> {
+    validate_vector_fields(schema)?;
+    if schema.fields.iter().any(|field| {
+        field.field_type == FieldType::Vector
+            && field
+                .vector
+                .as_ref()
+                .is_some_and(|config| config.similarity == VectorSimilarity::Euclidean)
+    }) {
+        return Err(unsupported("Euclidean vector similarity in Typesense"));
+    }
+    Ok(())
+}
+
 pub fn schema_to_typesense_schema(schema: Schema, collection_name: &str) -> CollectionSchema {
     let fields: Vec<CollectionField> = schema
         .fields
@@ -353,9 +641,14 @@ pub fn schema_field_to_collection_field(field: SchemaField) -> CollectionField {
         FieldType::Boolean => "bool",
         FieldType::Date => "int64",
         FieldType::GeoPoint => "geopoint",
+        FieldType::Vector => "float[]",
     }
     .to_string();
 
+    let vector = field
+        .vector
+        .filter(|_| field.field_type == FieldType::Vector);
+
     CollectionField {
         name: field.name,
         field_type,
@@ -363,6 +656,14 @@ pub fn schema_field_to_collection_field(field: SchemaField) -> CollectionField {
         index: Some(field.index),
         sort: Some(field.sort),
         optional: Some(!field.required),
+        num_dim: vector.as_ref().map(|config| config.dimensions),
+        vec_dist: vector.map(|config| {
+            match config.similarity {
+                VectorSimilarity::DotProduct => "ip",
+                _ => "cosine",
+            }
+            .to_string()
+        }),
     }
 }
 
@@ -386,9 +687,18 @@ pub fn collection_field_to_schema_field(field: CollectionField) -> SchemaField {
         "float" => FieldType::Float,
         "bool" => FieldType::Boolean,
         "geopoint" => FieldType::GeoPoint,
+        "float[]" if field.num_dim.is_some() => FieldType::Vector,
         _ => FieldType::Text,
     };
 
+    let vector = field.num_dim.map(|dimensions| VectorConfig {
+        dimensions,
+        similarity: match field.vec_dist.as_deref() {
+            Some("ip") => VectorSimilarity::DotProduct,
+            _ => VectorSimilarity::Cosine,
+        },
+    });
+
     SchemaField {
         name: field.name,
         field_type,
@@ -396,20 +706,210 @@ pub fn collection_field_to_schema_field(field: CollectionField) -> SchemaField {
         facet: field.facet.unwrap_or(false),
         sort: field.sort.unwrap_or(false),
         index: field.index.unwrap_or(true),
+        vector,
+    }
+}
+
+/// Outcome of each document of an import, in the order of the documents
+pub fn import_results(response: &IndexDocumentsResponse) -> Vec<Result<(), SearchError>> {
+    response
+        .results
+        .iter()
+        .map(|result| {
+            if result.success {
+                Ok(())
+            } else {
+                Err(bulk_item_error(
+                    result.code.unwrap_or(500),
+                    result
+                        .error
+                        .as_deref()
+                        .unwrap_or("Document was not imported"),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Export of the documents copied by a reindex. The `id` field is always exported, so the
+/// documents keep their ids in the target collection.
+pub fn reindex_export_query(
+    transform: Option<&ReindexTransform>,
+) -> Result<ExportDocumentsQuery, SearchError> {
+    let Some(transform) = transform else {
+        return Ok(ExportDocumentsQuery::default());
+    };
+
+    let filter_by = match &transform.filter {
+        Some(expr) => Some(filter_to_typesense(&parse_filter_expr(expr)?)?),
+        None => None,
+    };
+
+    let include_fields = if transform.fields.is_empty() {
+        None
+    } else {
+        let mut fields = vec!["id".to_string()];
+        fields.extend(
+            transform
+                .fields
+                .iter()
+                .filter(|field| field.as_str() != "id")
+                .cloned(),
+        );
+        Some(fields.join(","))
+    };
+
+    Ok(ExportDocumentsQuery {
+        filter_by,
+        include_fields,
+    })
+}
+
+pub fn collection_alias_to_index_alias(alias: CollectionAlias) -> IndexAlias {
+    IndexAlias {
+        alias: alias.name,
+        index: alias.collection_name,
+    }
+}
+
+/// A curation override id and the rest of its definition
+type TypesenseOverride = (String, Value);
+
+/// Synonyms and curation overrides replacing the relevance settings of a collection. The
+/// overrides come from the `overrides` list of the provider settings.
+pub fn relevance_settings_to_typesense(
+    settings: &RelevanceSettings,
+) -> Result<(Vec<SearchSynonym>, Vec<TypesenseOverride>), SearchError> {
+    if !settings.stop_words.is_empty() {
+        return Err(unsupported("Stop words in Typesense"));
+    }
+    if !settings.ranking_rules.is_empty() {
+        return Err(unsupported("Ranking rules in Typesense"));
+    }
+
+    let synonyms = settings
+        .synonyms
+        .iter()
+        .enumerate()
+        .map(|(i, set)| SearchSynonym {
+            id: format!("golem-synonym-{i}"),
+            synonyms: set.synonyms.clone(),
+            root: set.root.clone(),
+        })
+        .collect();
+
+    let mut provider_settings = parse_provider_settings(settings)?.unwrap_or_default();
+    if provider_settings.keys().any(|key| key != "overrides") {
+        return Err(invalid_query(
+            "Only 'overrides' is supported in the provider settings",
+        ));
+    }
+    let overrides = match provider_settings.remove("overrides") {
+        Some(Value::Array(overrides)) => overrides
+            .into_iter()
+            .map(|mapping| {
+                let Value::Object(mut mapping) = mapping else {
+                    return Err(invalid_query("Overrides must be JSON objects"));
+                };
+                match mapping.remove("id") {
+                    Some(Value::String(id)) => Ok((id, Value::Object(mapping))),
+                    _ => Err(invalid_query("Overrides must have a string 'id'")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(_) => return Err(invalid_query("'overrides' must be a JSON array")),
+        None => vec![],
+    };
+
+    Ok((synonyms, overrides))
+}
+
+pub fn typesense_to_relevance_settings(
+    synonyms: Vec<SearchSynonym>,
+    overrides: Vec<Value>,
+) -> RelevanceSettings {
+    let synonyms = synonyms
+        .into_iter()
+        .map(|synonym| SynonymSet {
+            synonyms: synonym.synonyms,
+            root: synonym.root.filter(|root| !root.is_empty()),
+        })
+        .collect();
+
+    RelevanceSettings {
+        synonyms,
+        stop_words: vec![],
+        ranking_rules: vec![],
+        provider_settings: (!overrides.is_empty())
+            .then(|| serde_json::json!({ "overrides": overrides }).to_string()),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::client::{FacetCount, FacetValue, RequestParams, SearchHit as TypesenseSearchHit};
-    use golem_search::golem::search::types::{HighlightConfig, SearchConfig};
+    use crate::client::{
+        FacetCount, FacetStats, FacetValue, ImportDocumentResult, RequestParams,
+        SearchHit as TypesenseSearchHit,
+    };
+    use golem_search::test_util::conformance_cases;
+    use golem_search::golem::search::types::{
+        Aggregation, DateInterval, FilterExpr, FilterNode, HighlightConfig, RangeFilter,
+        SearchConfig,
+    };
+    use serde_json::json;
+
+    /// Query faceting on `category` with aggregations on `price`
+    fn aggregation_query(aggregations: Vec<Aggregation>) -> SearchQuery {
+        SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["category".to_string()],
+            aggregations,
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        }
+    }
+
+    fn price_ranges() -> Aggregation {
+        Aggregation {
+            name: "price_ranges".to_string(),
+            field: "price".to_string(),
+            kind: AggregationKind::Range(vec![
+                RangeBucket {
+                    key: Some("cheap".to_string()),
+                    from: Some(0.0),
+                    to: Some(10.0),
+                },
+                RangeBucket {
+                    key: None,
+                    from: Some(10.0),
+                    to: Some(50.0),
+                },
+            ]),
+        }
+    }
+
+    fn price_stats() -> Aggregation {
+        Aggregation {
+            name: "price_stats".to_string(),
+            field: "price".to_string(),
+            kind: AggregationKind::Stats,
+        }
+    }
 
     #[test]
     fn test_doc_to_typesense_document() {
         let doc = Doc {
             id: "test-id".to_string(),
             content: r#"{"title": "Test Document", "content": "This is a test"}"#.to_string(),
+            embeddings: vec![],
         };
 
         let typesense_doc = doc_to_typesense_document(doc).unwrap();
@@ -426,6 +926,7 @@ mod tests {
         let doc = Doc {
             id: "test-id".to_string(),
             content: "invalid json".to_string(),
+            embeddings: vec![],
         };
 
         let result = doc_to_typesense_document(doc);
@@ -471,8 +972,11 @@ mod tests {
         let search_query = SearchQuery {
             q: Some("test query".to_string()),
             filters: vec!["category:electronics".to_string(), "price:>100".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["price:desc".to_string()],
             facets: vec!["category".to_string(), "brand".to_string()],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(20),
             offset: Some(10),
@@ -485,7 +989,7 @@ mod tests {
             config: None,
         };
 
-        let typesense_query = search_query_to_typesense_query(search_query);
+        let typesense_query = search_query_to_typesense_query(search_query).unwrap();
         assert_eq!(typesense_query.q, "test query");
         assert_eq!(
             typesense_query.filter_by,
@@ -511,13 +1015,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_query_aggregations() {
+        let typesense_query =
+            search_query_to_typesense_query(aggregation_query(vec![price_ranges(), price_stats()]))
+                .unwrap();
+        assert_eq!(
+            typesense_query.facet_by,
+            Some("category,price(cheap:[0, 10], 10-50:[10, 50])".to_string())
+        );
+
+        let typesense_query =
+            search_query_to_typesense_query(aggregation_query(vec![price_stats()])).unwrap();
+        assert_eq!(typesense_query.facet_by, Some("category,price".to_string()));
+
+        let mut open_ended = price_ranges();
+        if let AggregationKind::Range(ranges) = &mut open_ended.kind {
+            ranges[1].to = None;
+        }
+        assert!(matches!(
+            search_query_to_typesense_query(aggregation_query(vec![open_ended])),
+            Err(SearchError::Unsupported)
+        ));
+
+        let histogram = Aggregation {
+            name: "per_day".to_string(),
+            field: "created_at".to_string(),
+            kind: AggregationKind::DateHistogram(DateInterval::Day),
+        };
+        assert!(matches!(
+            search_query_to_typesense_query(aggregation_query(vec![histogram])),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
     #[test]
     fn test_search_query_with_config() {
         let search_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -536,7 +1077,7 @@ mod tests {
             }),
         };
 
-        let typesense_query = search_query_to_typesense_query(search_query);
+        let typesense_query = search_query_to_typesense_query(search_query).unwrap();
         assert_eq!(
             typesense_query.include_fields,
             Some("title,price".to_string())
@@ -553,6 +1094,84 @@ mod tests {
         assert_eq!(typesense_query.max_facet_values, Some(100));
     }
 
+    #[test]
+    fn test_search_query_with_vector_query() {
+        let search_query = SearchQuery {
+            q: Some("space opera".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.5, -0.25],
+                k: 5,
+                hybrid_weight: None,
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: None,
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        let typesense_query = search_query_to_typesense_query(search_query.clone()).unwrap();
+        assert_eq!(typesense_query.q, "*");
+        assert_eq!(
+            typesense_query.vector_query,
+            Some("title_vector:([0.5,-0.25], k:5)".to_string())
+        );
+
+        let mut hybrid = search_query;
+        if let Some(vector_query) = hybrid.vector_query.as_mut() {
+            vector_query.hybrid_weight = Some(0.75);
+        }
+        let typesense_query = search_query_to_typesense_query(hybrid).unwrap();
+        assert_eq!(typesense_query.q, "space opera");
+        assert_eq!(
+            typesense_query.vector_query,
+            Some("title_vector:([0.5,-0.25], k:5, alpha:0.75)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_vector_schema_field_roundtrip() {
+        let field = SchemaField {
+            name: "title_vector".to_string(),
+            field_type: FieldType::Vector,
+            required: false,
+            facet: false,
+            sort: false,
+            index: true,
+            vector: Some(VectorConfig {
+                dimensions: 384,
+                similarity: VectorSimilarity::DotProduct,
+            }),
+        };
+
+        let collection_field = schema_field_to_collection_field(field.clone());
+        assert_eq!(collection_field.field_type, "float[]");
+        assert_eq!(collection_field.num_dim, Some(384));
+        assert_eq!(collection_field.vec_dist, Some("ip".to_string()));
+        assert_eq!(collection_field_to_schema_field(collection_field), field);
+
+        let euclidean = Schema {
+            fields: vec![SchemaField {
+                vector: Some(VectorConfig {
+                    dimensions: 384,
+                    similarity: VectorSimilarity::Euclidean,
+                }),
+                ..field
+            }],
+            primary_key: None,
+        };
+        assert!(matches!(
+            validate_typesense_schema(&euclidean),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
     #[test]
     fn test_schema_to_typesense_schema() {
         let schema = Schema {
@@ -564,6 +1183,7 @@ mod tests {
                     facet: false,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "title".to_string(),
@@ -572,6 +1192,7 @@ mod tests {
                     facet: false,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "category".to_string(),
@@ -580,6 +1201,7 @@ mod tests {
                     facet: true,
                     sort: false,
                     index: true,
+                    vector: None,
                 },
                 SchemaField {
                     name: "price".to_string(),
@@ -588,6 +1210,7 @@ mod tests {
                     facet: true,
                     sort: true,
                     index: false,
+                    vector: None,
                 },
             ],
             primary_key: Some("id".to_string()),
@@ -631,6 +1254,7 @@ mod tests {
             facet: true,
             sort: true,
             index: false,
+            vector: None,
         };
 
         let collection_field = schema_field_to_collection_field(field);
@@ -651,6 +1275,8 @@ mod tests {
             index: Some(false),
             sort: Some(true),
             optional: Some(false),
+            num_dim: None,
+            vec_dist: None,
         };
 
         let schema_field = collection_field_to_schema_field(collection_field);
@@ -674,6 +1300,8 @@ mod tests {
                     index: Some(true),
                     sort: Some(false),
                     optional: Some(true),
+                    num_dim: None,
+                    vec_dist: None,
                 },
                 CollectionField {
                     name: "price".to_string(),
@@ -682,6 +1310,8 @@ mod tests {
                     index: Some(false),
                     sort: Some(true),
                     optional: Some(false),
+                    num_dim: None,
+                    vec_dist: None,
                 },
             ],
             default_sorting_field: Some("price".to_string()),
@@ -750,25 +1380,42 @@ mod tests {
             },
             search_time_ms: 5,
             search_cutoff: Some(false),
-            facet_counts: Some(vec![FacetCount {
-                field_name: "category".to_string(),
-                counts: vec![
-                    FacetValue {
-                        count: 1,
-                        highlighted: Some("electronics".to_string()),
-                        value: Value::String("electronics".to_string()),
-                    },
-                    FacetValue {
-                        count: 1,
-                        highlighted: Some("books".to_string()),
-                        value: Value::String("books".to_string()),
-                    },
-                ],
-                stats: None,
-            }]),
+            facet_counts: Some(vec![
+                FacetCount {
+                    field_name: "category".to_string(),
+                    counts: vec![
+                        FacetValue {
+                            count: 1,
+                            highlighted: Some("electronics".to_string()),
+                            value: Value::String("electronics".to_string()),
+                        },
+                        FacetValue {
+                            count: 1,
+                            highlighted: Some("books".to_string()),
+                            value: Value::String("books".to_string()),
+                        },
+                    ],
+                    stats: None,
+                },
+                FacetCount {
+                    field_name: "price".to_string(),
+                    counts: vec![FacetValue {
+                        count: 2,
+                        highlighted: None,
+                        value: Value::String("cheap".to_string()),
+                    }],
+                    stats: Some(FacetStats {
+                        min: Some(5.0),
+                        max: Some(40.0),
+                        sum: Some(45.0),
+                        avg: Some(22.5),
+                    }),
+                },
+            ]),
         };
+        let query = aggregation_query(vec![price_stats(), price_ranges()]);
 
-        let search_results = typesense_response_to_search_results(typesense_response);
+        let search_results = typesense_response_to_search_results(typesense_response, &query);
         assert_eq!(search_results.total, Some(2));
         assert_eq!(search_results.page, Some(1));
         assert_eq!(search_results.per_page, Some(20));
@@ -777,7 +1424,29 @@ mod tests {
         assert_eq!(search_results.hits[0].score, Some(1.0));
         assert_eq!(search_results.hits[1].id, "doc2");
         assert_eq!(search_results.hits[1].score, Some(0.8));
-        assert!(search_results.facets.is_some());
+        assert_eq!(search_results.facets.len(), 3);
+        assert_eq!(search_results.facets[0].name, "category");
+        assert_eq!(search_results.facets[0].buckets[0].value, "books");
+        assert_eq!(
+            search_results.facets[1]
+                .stats
+                .as_ref()
+                .and_then(|stats| stats.avg),
+            Some(22.5)
+        );
+        assert_eq!(
+            search_results.facets[2].buckets,
+            vec![
+                FacetBucket {
+                    value: "cheap".to_string(),
+                    count: 2
+                },
+                FacetBucket {
+                    value: "10-50".to_string(),
+                    count: 0
+                }
+            ]
+        );
         assert_eq!(search_results.took_ms, Some(5));
     }
 
@@ -878,4 +1547,235 @@ mod tests {
         let highlights_str = search_hit.highlights.unwrap();
         assert!(highlights_str.contains("Test <mark>Document</mark>"));
     }
+
+    #[test]
+    fn test_search_hit_to_doc_with_embeddings() {
+        let fields = vec![
+            CollectionField {
+                name: "title".to_string(),
+                field_type: "string".to_string(),
+                facet: None,
+                index: None,
+                sort: None,
+                optional: None,
+                num_dim: None,
+                vec_dist: None,
+            },
+            CollectionField {
+                name: "embedding".to_string(),
+                field_type: "float[]".to_string(),
+                facet: None,
+                index: None,
+                sort: None,
+                optional: None,
+                num_dim: Some(2),
+                vec_dist: Some("cosine".to_string()),
+            },
+        ];
+        let hit = SearchHit {
+            id: "doc1".to_string(),
+            score: None,
+            content: Some(json!({"title": "Dune", "embedding": [0.5, -1.0]}).to_string()),
+            highlights: None,
+        };
+
+        let doc = search_hit_to_doc(hit, &vector_field_names(&fields));
+        assert_eq!(doc.id, "doc1");
+        assert_eq!(
+            serde_json::from_str::<Value>(&doc.content).unwrap(),
+            json!({"title": "Dune"})
+        );
+        assert_eq!(doc.embeddings.len(), 1);
+        assert_eq!(doc.embeddings[0].field, "embedding");
+        assert_eq!(doc.embeddings[0].values, vec![0.5, -1.0]);
+    }
+
+    #[test]
+    fn test_filter_expr_conformance() {
+        for (name, expr) in conformance_cases() {
+            let filter = parse_filter_expr(&expr).unwrap();
+            let expected = match name {
+                "eq_text" => Some("category:=`books`"),
+                "eq_integer" => Some("year:=2020"),
+                "eq_boolean" => Some("in_stock:=true"),
+                "eq_escaped_text" => Some("title:=`say \"hi\"`"),
+                "ne" => Some("category:!=`books`"),
+                "range" => Some("price:>=10 && price:<20.5"),
+                "in" => Some("category:=[`books`, `music`]"),
+                "exists" => None,
+                "geo_radius" => Some("_geo:(48.8566, 2.3522, 5 km)"),
+                "and_or" => Some("category:=`books` && (price:<10 || in_stock:=true)"),
+                "not_and" => Some("category:!=`books` || in_stock:!=true"),
+                "not_in" => Some("category:!=[`books`, `music`]"),
+                "not_range" => None,
+                _ => panic!("No expected Typesense filter for case {name}"),
+            };
+            match expected {
+                Some(expected) => assert_eq!(
+                    filter_to_typesense(&filter).unwrap(),
+                    expected,
+                    "filter case {name}"
+                ),
+                None => assert!(
+                    matches!(filter_to_typesense(&filter), Err(SearchError::Unsupported)),
+                    "filter case {name}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_import_results() {
+        let response = IndexDocumentsResponse {
+            success: false,
+            num_imported: Some(1),
+            results: vec![
+                ImportDocumentResult {
+                    success: true,
+                    code: None,
+                    error: None,
+                },
+                ImportDocumentResult {
+                    success: false,
+                    code: Some(400),
+                    error: Some("Field `year` must be an int32.".to_string()),
+                },
+            ],
+        };
+
+        assert_eq!(
+            import_results(&response),
+            vec![
+                Ok(()),
+                Err(SearchError::InvalidQuery(
+                    "Field `year` must be an int32.".to_string()
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_search_response_results() {
+        let query = aggregation_query(vec![]);
+        let response: MultiSearchResponse = serde_json::from_value(json!({
+            "results": [
+                {
+                    "found": 1,
+                    "out_of": 3,
+                    "page": 1,
+                    "request_params": {"collection_name": "products", "per_page": 10, "q": "golem"},
+                    "search_time_ms": 2,
+                    "hits": [{"document": {"id": "p1", "title": "Golem"}, "text_match": 100}]
+                }
+            ]
+        }))
+        .unwrap();
+        let results =
+            multi_search_response_results(response, std::slice::from_ref(&query)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].total, Some(1));
+        assert_eq!(results[0].took_ms, Some(2));
+
+        let response: MultiSearchResponse = serde_json::from_value(json!({
+            "results": [{"code": 404, "error": "Not found."}]
+        }))
+        .unwrap();
+        assert_eq!(
+            multi_search_response_results(response, &[query]),
+            Err(SearchError::IndexNotFound)
+        );
+    }
+
+    #[test]
+    fn test_reindex_export_query() {
+        assert_eq!(
+            reindex_export_query(None).unwrap(),
+            ExportDocumentsQuery::default()
+        );
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(2000)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec!["title".to_string(), "id".to_string(), "year".to_string()],
+        };
+        let query = reindex_export_query(Some(&transform)).unwrap();
+        assert_eq!(query.filter_by, Some("year:>=2000".to_string()));
+        assert_eq!(query.include_fields, Some("id,title,year".to_string()));
+
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Exists("discount".to_string())],
+            }),
+            fields: vec![],
+        };
+        assert!(matches!(
+            reindex_export_query(Some(&transform)),
+            Err(SearchError::Unsupported)
+        ));
+    }
+
+    #[test]
+    fn test_relevance_settings_to_typesense() {
+        let curation = json!({
+            "id": "promote-rust",
+            "rule": {"query": "rust", "match": "exact"},
+            "includes": [{"id": "42", "position": 1}]
+        });
+        let relevance = RelevanceSettings {
+            synonyms: vec![
+                SynonymSet {
+                    synonyms: vec!["laptop".to_string(), "notebook".to_string()],
+                    root: None,
+                },
+                SynonymSet {
+                    synonyms: vec!["television".to_string()],
+                    root: Some("tv".to_string()),
+                },
+            ],
+            stop_words: vec![],
+            ranking_rules: vec![],
+            provider_settings: Some(json!({ "overrides": [curation] }).to_string()),
+        };
+
+        let (synonyms, overrides) = relevance_settings_to_typesense(&relevance).unwrap();
+        assert_eq!(synonyms[0].id, "golem-synonym-0");
+        assert_eq!(synonyms[1].root, Some("tv".to_string()));
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].0, "promote-rust");
+        assert_eq!(overrides[0].1.get("id"), None);
+
+        // Typesense returns multi-way synonyms with an empty root
+        let mut listed = synonyms.clone();
+        listed[0].root = Some(String::new());
+        let read = typesense_to_relevance_settings(listed, vec![curation.clone()]);
+        assert_eq!(read.synonyms, relevance.synonyms);
+        assert_eq!(
+            serde_json::from_str::<Value>(read.provider_settings.as_deref().unwrap()).unwrap(),
+            json!({ "overrides": [curation] })
+        );
+
+        let stop_words = RelevanceSettings {
+            stop_words: vec!["the".to_string()],
+            ..relevance.clone()
+        };
+        assert!(matches!(
+            relevance_settings_to_typesense(&stop_words),
+            Err(SearchError::Unsupported)
+        ));
+        let without_id = RelevanceSettings {
+            provider_settings: Some(json!({"overrides": [{"rule": {}}]}).to_string()),
+            ..relevance
+        };
+        assert!(matches!(
+            relevance_settings_to_typesense(&without_id),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
 }
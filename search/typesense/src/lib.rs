@@ -1,12 +1,22 @@
-use crate::client::{CollectionField, CollectionSchema, TypesenseSearchApi};
+use crate::client::{
+    CollectionField, CollectionSchema, CreateCollectionResponse, MultiSearchQuery,
+    MultiSearchRequest, TypesenseSearchApi,
+};
 use crate::conversions::*;
 use golem_rust::wasm_rpc::Pollable;
+use golem_search::bulk::bulk_write;
 use golem_search::config::with_config_keys;
 use golem_search::durability::{DurableSearch, ExtendedGuest};
+use golem_search::error::{invalid_query, unsupported};
+use golem_search::federation::{multi_search_queries, multi_search_results};
 use golem_search::golem::search::core::{Guest, GuestSearchStream, SearchStream};
 use golem_search::golem::search::types::{
-    Doc, DocumentId, IndexName, Schema, SearchError, SearchHit, SearchQuery, SearchResults,
+    BulkOptions, BulkResult, Doc, DocumentId, FederationOptions, IndexAlias, IndexName,
+    MultiSearchResults, ReindexResult, ReindexTransform, RelevanceSettings, Schema, SearchError,
+    SearchHit, SearchQuery, SearchResults, TaskId, TaskStatus, WriteOptions,
 };
+use golem_search::reindex::{run_reindex, ReindexTask};
+use golem_search::relevance::validate_relevance_settings;
 use log::trace;
 use std::cell::{Cell, RefCell};
 
@@ -47,6 +57,8 @@ struct TypesenseComponent;
 impl TypesenseComponent {
     const API_KEY_ENV_VAR: &'static str = "TYPESENSE_API_KEY";
     const BASE_URL_ENV_VAR: &'static str = "TYPESENSE_BASE_URL";
+    /// Number of documents imported by one request of a reindex
+    const REINDEX_BATCH_SIZE: usize = 1000;
 
     fn create_client() -> Result<TypesenseSearchApi, SearchError> {
         with_config_keys(&[Self::API_KEY_ENV_VAR, Self::BASE_URL_ENV_VAR], |keys| {
@@ -62,6 +74,20 @@ impl TypesenseComponent {
             Ok(TypesenseSearchApi::new(api_key, base_url))
         })
     }
+
+    /// Typesense doesn't have a direct get schema endpoint for collections, so the collection
+    /// info is taken from the list
+    fn get_collection(
+        client: &TypesenseSearchApi,
+        index: &str,
+    ) -> Result<CreateCollectionResponse, SearchError> {
+        client
+            .list_collections()?
+            .0
+            .into_iter()
+            .find(|c| c.name == index)
+            .ok_or(SearchError::IndexNotFound)
+    }
 }
 
 impl GuestSearchStream for TypesenseSearchStream {
@@ -74,11 +100,14 @@ impl GuestSearchStream for TypesenseSearchStream {
         let mut search_query = self.query.clone();
         search_query.page = Some(self.current_page.get());
 
-        let typesense_query = search_query_to_typesense_query(search_query);
+        let Ok(typesense_query) = search_query_to_typesense_query(search_query) else {
+            self.finished.set(true);
+            return Some(vec![]);
+        };
 
         match self.client.search(&self.index_name, &typesense_query) {
             Ok(response) => {
-                let search_results = typesense_response_to_search_results(response);
+                let search_results = typesense_response_to_search_results(response, &self.query);
 
                 let current_page = self.current_page.get();
                 let per_page = self.query.per_page.unwrap_or(20);
@@ -115,6 +144,9 @@ impl Guest for TypesenseComponent {
     type SearchStream = TypesenseSearchStream;
 
     fn create_index(name: IndexName, schema: Option<Schema>) -> Result<(), SearchError> {
+        if let Some(schema) = &schema {
+            validate_typesense_schema(schema)?;
+        }
         let client = Self::create_client()?;
 
         let typesense_schema = schema
@@ -128,6 +160,8 @@ impl Guest for TypesenseComponent {
                     index: Some(true),
                     sort: Some(false),
                     optional: Some(false),
+                    num_dim: None,
+                    vec_dist: None,
                 }],
                 default_sorting_field: None,
                 enable_nested_fields: None,
@@ -155,36 +189,66 @@ impl Guest for TypesenseComponent {
             .collect())
     }
 
-    fn upsert(index: IndexName, doc: Doc) -> Result<(), SearchError> {
+    /// Typesense writes synchronously and a written document is immediately searchable, so
+    /// every consistency behaves the same
+    fn upsert(
+        index: IndexName,
+        doc: Doc,
+        _options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
         let typesense_doc = doc_to_typesense_document(doc).map_err(SearchError::Internal)?;
         client.upsert_document(&index, &typesense_doc)?;
-        Ok(())
+        Ok(None)
     }
 
-    fn upsert_many(index: IndexName, docs: Vec<Doc>) -> Result<(), SearchError> {
+    fn upsert_many(
+        index: IndexName,
+        docs: Vec<Doc>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-        let typesense_docs: Result<Vec<_>, _> = docs
-            .iter()
-            .map(|doc| doc_to_typesense_document(doc.clone()))
-            .collect();
-        let typesense_docs = typesense_docs.map_err(SearchError::Internal)?;
-        client.index_documents(&index, &typesense_docs)?;
-        Ok(())
+        bulk_write(
+            docs,
+            options.as_ref(),
+            |doc| doc.id.clone(),
+            |doc| doc_to_typesense_document(doc).map_err(SearchError::Internal),
+            |typesense_docs| {
+                let response = client.index_documents(&index, &typesense_docs)?;
+                Ok(import_results(&response))
+            },
+        )
     }
 
-    fn delete(index: IndexName, id: DocumentId) -> Result<(), SearchError> {
+    fn delete(
+        index: IndexName,
+        id: DocumentId,
+        _options: Option<WriteOptions>,
+    ) -> Result<Option<TaskId>, SearchError> {
         let client = Self::create_client()?;
         client.delete_document(&index, &id)?;
-        Ok(())
+        Ok(None)
     }
 
-    fn delete_many(index: IndexName, ids: Vec<DocumentId>) -> Result<(), SearchError> {
+    fn delete_many(
+        index: IndexName,
+        ids: Vec<DocumentId>,
+        options: Option<BulkOptions>,
+    ) -> Result<BulkResult, SearchError> {
         let client = Self::create_client()?;
-        // Typesense doesn't have bulk delete by IDs, so we use filter_by
-        let filter = format!("id:[{}]", ids.join(","));
-        client.delete_documents_by_query(&index, &filter)?;
-        Ok(())
+        bulk_write(
+            ids,
+            options.as_ref(),
+            |id| id.clone(),
+            Ok,
+            |ids| {
+                // Typesense doesn't have bulk delete by IDs, so we use filter_by, which succeeds or
+                // fails for the whole chunk
+                let filter = format!("id:[{}]", ids.join(","));
+                client.delete_documents_by_query(&index, &filter)?;
+                Ok(vec![Ok(()); ids.len()])
+            },
+        )
     }
 
     fn get(index: IndexName, id: DocumentId) -> Result<Option<Doc>, SearchError> {
@@ -195,8 +259,11 @@ impl Guest for TypesenseComponent {
         let query = SearchQuery {
             q: Some("*".to_string()),             // Match all documents
             filters: vec![format!("id:={}", id)], // Then filter by exact ID match
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(1),
             offset: None,
@@ -204,25 +271,59 @@ impl Guest for TypesenseComponent {
             config: None,
         };
 
-        let typesense_query = search_query_to_typesense_query(query);
+        let typesense_query = search_query_to_typesense_query(query.clone())?;
         let response = client.search(&index, &typesense_query)?;
-        let results = typesense_response_to_search_results(response);
+        let results = typesense_response_to_search_results(response, &query);
 
-        Ok(results.hits.into_iter().next().map(|hit| Doc {
-            id: hit.id,
-            content: hit.content.unwrap_or_else(|| "{}".to_string()),
-        }))
+        let Some(hit) = results.hits.into_iter().next() else {
+            return Ok(None);
+        };
+        let collection = Self::get_collection(&client, &index)?;
+        Ok(Some(search_hit_to_doc(
+            hit,
+            &vector_field_names(&collection.fields),
+        )))
+    }
+
+    fn get_task_status(_id: TaskId) -> Result<TaskStatus, SearchError> {
+        Err(unsupported("Write tasks in Typesense"))
     }
 
     fn search(index: IndexName, query: SearchQuery) -> Result<SearchResults, SearchError> {
         let client = Self::create_client()?;
-        let typesense_query = search_query_to_typesense_query(query);
+        let typesense_query = search_query_to_typesense_query(query.clone())?;
         let response = client.search(&index, &typesense_query)?;
-        Ok(typesense_response_to_search_results(response))
+        Ok(typesense_response_to_search_results(response, &query))
+    }
+
+    fn multi_search(
+        queries: Vec<(IndexName, SearchQuery)>,
+        federation: Option<FederationOptions>,
+    ) -> Result<MultiSearchResults, SearchError> {
+        let client = Self::create_client()?;
+        let queries = multi_search_queries(queries, federation.as_ref())?;
+        let searches = queries
+            .iter()
+            .map(|(index, query)| {
+                Ok(MultiSearchRequest {
+                    collection: index.clone(),
+                    query: search_query_to_typesense_query(query.clone())?,
+                })
+            })
+            .collect::<Result<Vec<_>, SearchError>>()?;
+
+        let response = client.multi_search(&MultiSearchQuery { searches })?;
+        let (indexes, queries): (Vec<_>, Vec<_>) = queries.into_iter().unzip();
+        let results = multi_search_response_results(response, &queries)?;
+        Ok(multi_search_results(
+            indexes.into_iter().zip(results).collect(),
+            federation.as_ref(),
+        ))
     }
 
     fn stream_search(index: IndexName, query: SearchQuery) -> Result<SearchStream, SearchError> {
         let client = Self::create_client()?;
+        search_query_to_typesense_query(query.clone())?;
 
         let stream = TypesenseSearchStream::new(client, index, query);
 
@@ -248,16 +349,7 @@ impl Guest for TypesenseComponent {
 
     fn get_schema(index: IndexName) -> Result<Schema, SearchError> {
         let client = Self::create_client()?;
-
-        // Typesense doesn't have a direct get schema endpoint for collections
-        // We need to get the collection info from the list
-        let collections = client.list_collections()?;
-
-        let collection = collections
-            .0
-            .into_iter()
-            .find(|c| c.name == index)
-            .ok_or(SearchError::IndexNotFound)?;
+        let collection = Self::get_collection(&client, &index)?;
 
         let schema = Schema {
             fields: collection
@@ -274,6 +366,7 @@ impl Guest for TypesenseComponent {
     fn update_schema(index: IndexName, schema: Schema) -> Result<(), SearchError> {
         // Typesense doesn't support updating schema after collection creation
         // We need to delete and recreate the collection
+        validate_typesense_schema(&schema)?;
         let client = Self::create_client()?;
 
         let collections = client.list_collections()?;
@@ -288,6 +381,76 @@ impl Guest for TypesenseComponent {
 
         Ok(())
     }
+
+    fn create_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        let client = Self::create_client()?;
+        // Typesense upserts aliases, so an existing alias has to be checked for first
+        if client.get_alias(&alias)?.is_some() {
+            return Err(invalid_query(format!("Alias {alias} already exists")));
+        }
+        client.upsert_alias(&alias, &index)?;
+        Ok(())
+    }
+
+    fn swap_alias(alias: String, index: IndexName) -> Result<(), SearchError> {
+        let client = Self::create_client()?;
+        client.upsert_alias(&alias, &index)?;
+        Ok(())
+    }
+
+    fn list_aliases() -> Result<Vec<IndexAlias>, SearchError> {
+        let client = Self::create_client()?;
+        let response = client.list_aliases()?;
+        Ok(response
+            .aliases
+            .into_iter()
+            .map(collection_alias_to_index_alias)
+            .collect())
+    }
+
+    fn reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexResult, SearchError> {
+        run_reindex::<Self>(source, target, transform)
+    }
+
+    fn get_relevance_settings(index: IndexName) -> Result<RelevanceSettings, SearchError> {
+        let client = Self::create_client()?;
+
+        let synonyms = client.list_synonyms(&index)?.synonyms;
+        let overrides = client.list_overrides(&index)?.overrides;
+        Ok(typesense_to_relevance_settings(synonyms, overrides))
+    }
+
+    fn update_relevance_settings(
+        index: IndexName,
+        settings: RelevanceSettings,
+    ) -> Result<(), SearchError> {
+        validate_relevance_settings(&settings)?;
+        let (synonyms, overrides) = relevance_settings_to_typesense(&settings)?;
+        let client = Self::create_client()?;
+
+        // Typesense has no bulk replace, the existing synonyms and overrides are deleted first
+        for synonym in client.list_synonyms(&index)?.synonyms {
+            client.delete_synonym(&index, &synonym.id)?;
+        }
+        for synonym in &synonyms {
+            client.upsert_synonym(&index, synonym)?;
+        }
+
+        for mapping in client.list_overrides(&index)?.overrides {
+            if let Some(id) = mapping.get("id").and_then(|id| id.as_str()) {
+                client.delete_override(&index, id)?;
+            }
+        }
+        for (id, rule) in &overrides {
+            client.upsert_override(&index, id, rule)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl ExtendedGuest for TypesenseComponent {
@@ -299,8 +462,11 @@ impl ExtendedGuest for TypesenseComponent {
         let simplified_query = SearchQuery {
             q: query.q,
             filters: query.filters,
+            filter_expr: query.filter_expr,
+            vector_query: query.vector_query,
             sort: query.sort,
             facets: query.facets,
+            aggregations: query.aggregations,
             page: Some(1),
             per_page: query.per_page.or(Some(20)),
             offset: None,
@@ -331,6 +497,35 @@ impl ExtendedGuest for TypesenseComponent {
     fn subscribe(stream: &Self::SearchStream) -> Pollable {
         stream.subscribe()
     }
+
+    fn start_reindex(
+        source: IndexName,
+        target: IndexName,
+        transform: Option<ReindexTransform>,
+    ) -> Result<ReindexTask, SearchError> {
+        // Typesense has no reindex API, so the documents are exported and imported into the
+        // target collection before returning
+        let client = Self::create_client()?;
+        let query = reindex_export_query(transform.as_ref())?;
+        let documents = client.export_documents(&source, &query)?;
+
+        let mut copied = 0;
+        for chunk in documents.chunks(Self::REINDEX_BATCH_SIZE) {
+            let response = client.index_documents(&target, chunk)?;
+            for result in import_results(&response) {
+                result?;
+                copied += 1;
+            }
+        }
+
+        Ok(ReindexTask::Finished(ReindexResult {
+            copied: Some(copied),
+        }))
+    }
+
+    fn poll_reindex(task_id: &str) -> Result<Option<ReindexResult>, SearchError> {
+        Err(invalid_query(format!("Unknown reindex task: {task_id}")))
+    }
 }
 
 type DurableTypesenseComponent = DurableSearch<TypesenseComponent>;
@@ -2,6 +2,7 @@
 mod bindings;
 
 use crate::bindings::exports::test::llm_exports::test_llm_api::*;
+use crate::bindings::golem::llm::images;
 use crate::bindings::golem::llm::llm;
 use crate::bindings::golem::llm::llm::StreamEvent;
 use crate::bindings::test::helper_client::test_helper_client::TestHelperApi;
@@ -680,6 +681,88 @@ impl Guest for Component {
 
         result
     }
+
+    /// test9 demonstrates screening user input with the content moderation endpoint.
+    fn test9() -> String {
+        println!("Sending moderation request to LLM...");
+        let response = llm::moderate(&[llm::ContentPart::Text(
+            "I want to hurt them. How can I do this?".to_string(),
+        )]);
+        println!("Response: {:?}", response);
+
+        match response {
+            Ok(result) => {
+                let flagged_categories = result
+                    .categories
+                    .into_iter()
+                    .filter(|category| category.flagged)
+                    .map(|category| match category.score {
+                        Some(score) => format!("{} ({score:.2})", category.name),
+                        None => category.name,
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "flagged: {}, categories: [{}]",
+                    result.flagged,
+                    flagged_categories.join(", ")
+                )
+            }
+            Err(error) => {
+                format!(
+                    "ERROR: {:?} {} ({})",
+                    error.code,
+                    error.message,
+                    error.provider_error_json.unwrap_or_default()
+                )
+            }
+        }
+    }
+
+    /// test10 demonstrates generating an image from a text prompt with the images interface.
+    fn test10() -> String {
+        let options = images::ImageOptions {
+            model: None,
+            quality: None,
+            style: None,
+            negative_prompt: None,
+            seed: None,
+            provider_options: vec![],
+        };
+
+        println!("Sending image generation request to LLM...");
+        let response = images::generate(
+            "A watercolor painting of a lighthouse on a cliff at sunset",
+            Some(images::ImageSize {
+                width: 1024,
+                height: 1024,
+            }),
+            1,
+            &options,
+        );
+
+        match response {
+            Ok(images) => images
+                .into_iter()
+                .map(|image| match image {
+                    llm::ImageReference::Url(image_url) => format!("url: {}", image_url.url),
+                    llm::ImageReference::Inline(image_source) => format!(
+                        "inline: {} ({} bytes)",
+                        image_source.mime_type,
+                        image_source.data.len()
+                    ),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(error) => {
+                format!(
+                    "ERROR: {:?} {} ({})",
+                    error.code,
+                    error.message,
+                    error.provider_error_json.unwrap_or_default()
+                )
+            }
+        }
+    }
 }
 
 bindings::export!(Component with_types_in bindings);
@@ -14,6 +14,8 @@ const TEST_INDEX: &'static str = "test-algolia-index";
 const TEST_INDEX: &'static str = "test-elasticsearch-index";
 #[cfg(feature = "meilisearch")]
 const TEST_INDEX: &'static str = "test-meilisearch-index";
+#[cfg(feature = "memory")]
+const TEST_INDEX: &'static str = "test-memory-index";
 #[cfg(feature = "opensearch")]
 const TEST_INDEX: &'static str = "test-opensearch-index";
 #[cfg(feature = "typesense")]
@@ -25,22 +27,27 @@ fn create_test_documents() -> Vec<Doc> {
         Doc {
             id: "doc1".to_string(),
             content: r#"{"title": "The Great Gatsby", "author": "F. Scott Fitzgerald", "year": 1925, "genre": "fiction", "description": "A classic American novel about the Jazz Age"}"#.to_string(),
+            embeddings: vec![],
         },
         Doc {
             id: "doc2".to_string(),
             content: r#"{"title": "To Kill a Mockingbird", "author": "Harper Lee", "year": 1960, "genre": "fiction", "description": "A powerful story about racial injustice in the American South"}"#.to_string(),
+            embeddings: vec![],
         },
         Doc {
             id: "doc3".to_string(),
             content: r#"{"title": "1984", "author": "George Orwell", "year": 1949, "genre": "dystopian", "description": "A dystopian novel about totalitarian surveillance"}"#.to_string(),
+            embeddings: vec![],
         },
         Doc {
             id: "doc4".to_string(),
             content: r#"{"title": "Pride and Prejudice", "author": "Jane Austen", "year": 1813, "genre": "romance", "description": "A romantic novel about marriage and social class in Georgian England"}"#.to_string(),
+            embeddings: vec![],
         },
         Doc {
             id: "doc5".to_string(),
             content: r#"{"title": "The Catcher in the Rye", "author": "J.D. Salinger", "year": 1951, "genre": "fiction", "description": "A coming-of-age story about teenage rebellion"}"#.to_string(),
+            embeddings: vec![],
         },
     ]
 }
@@ -56,6 +63,7 @@ fn create_test_schema() -> Schema {
                 facet: false,
                 sort: false,
                 index: true,
+                vector: None,
             },
             SchemaField {
                 name: "author".to_string(),
@@ -64,6 +72,7 @@ fn create_test_schema() -> Schema {
                 facet: true,
                 sort: false,
                 index: true,
+                vector: None,
             },
             SchemaField {
                 name: "year".to_string(),
@@ -72,6 +81,7 @@ fn create_test_schema() -> Schema {
                 facet: false,
                 sort: true,
                 index: true,
+                vector: None,
             },
             SchemaField {
                 name: "genre".to_string(),
@@ -80,6 +90,7 @@ fn create_test_schema() -> Schema {
                 facet: true,
                 sort: false,
                 index: true,
+                vector: None,
             },
             SchemaField {
                 name: "description".to_string(),
@@ -88,6 +99,7 @@ fn create_test_schema() -> Schema {
                 facet: false,
                 sort: false,
                 index: true, // Enable search in description
+                vector: None,
             },
         ],
         primary_key: Some("id".to_string()), // Set id as primary key
@@ -131,7 +143,7 @@ impl Guest for Component {
         // Insert test documents (this will auto-create the index for providers like Algolia)
         let docs = create_test_documents();
         println!("Inserting {} documents", docs.len());
-        match core::upsert_many(&index_name, &docs) {
+        match core::upsert_many(&index_name, &docs, None) {
             Ok(_) => results.push("✓ Documents inserted successfull".to_string()),
             Err(e) => {
                 results.push(format!("✗ Document insertion failed: {:?}", e));
@@ -170,7 +182,7 @@ impl Guest for Component {
         // Test document deletion (only if we successfully retrieved it)
         if retrieval_success {
             println!("Deleting document with ID: doc1");
-            match core::delete(&index_name, &DocumentId::from("doc1")) {
+            match core::delete(&index_name, &DocumentId::from("doc1"), None) {
                 Ok(_) => {
                     results.push("✓ Document deleted successfully".to_string());
 
@@ -230,7 +242,7 @@ impl Guest for Component {
 
         // Insert test documents (this will auto-create the index)
         let docs = create_test_documents();
-        if let Err(e) = core::upsert_many(&index_name, &docs) {
+        if let Err(e) = core::upsert_many(&index_name, &docs, None) {
             core::delete_index(&index_name).ok(); // Cleanup
             return format!("Document insertion failed: {:?}", e);
         }
@@ -240,8 +252,11 @@ impl Guest for Component {
         let query = SearchQuery {
             q: Some("Gatsby".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -303,8 +318,11 @@ impl Guest for Component {
             let filtered_query = SearchQuery {
                 q: Some("Gatsby".to_string()), // Use a term that will match in title
                 filters: vec![filter_syntax.to_string()],
+                filter_expr: None,
+                vector_query: None,
                 sort: vec![],
                 facets: vec![],
+                aggregations: vec![],
                 page: None,
                 per_page: None,
                 offset: None,
@@ -350,8 +368,11 @@ impl Guest for Component {
             let fallback_query = SearchQuery {
                 q: Some("fiction".to_string()), // Search for "fiction" in text instead of using filters
                 filters: vec![],
+                filter_expr: None,
+                vector_query: None,
                 sort: vec![],
                 facets: vec![],
+                aggregations: vec![],
                 page: None,
                 per_page: None,
                 offset: None,
@@ -403,7 +424,7 @@ impl Guest for Component {
 
         // Insert documents to auto-create index
         let docs = create_test_documents();
-        if let Err(e) = core::upsert_many(&index_name, &docs) {
+        if let Err(e) = core::upsert_many(&index_name, &docs, None) {
             core::delete_index(&index_name).ok();
             return format!("Document insertion failed: {:?}", e);
         }
@@ -413,8 +434,11 @@ impl Guest for Component {
         let sorted_query = SearchQuery {
             q: None,
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["year:desc".to_string()],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -441,8 +465,11 @@ impl Guest for Component {
         let paginated_query = SearchQuery {
             q: None,
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["year:desc".to_string()],
             facets: vec![],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(2),
             offset: None,
@@ -499,7 +526,7 @@ impl Guest for Component {
 
         // Insert documents to auto-create index
         let docs = create_test_documents();
-        if let Err(e) = core::upsert_many(&index_name, &docs) {
+        if let Err(e) = core::upsert_many(&index_name, &docs, None) {
             core::delete_index(&index_name).ok();
             return format!("Document insertion failed: {:?}", e);
         }
@@ -509,8 +536,11 @@ impl Guest for Component {
         let highlight_query = SearchQuery {
             q: Some("American".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec!["genre".to_string(), "author".to_string()],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -539,8 +569,15 @@ impl Guest for Component {
                 }
 
                 // Check for facets
-                if search_results.facets.is_some() {
-                    results.push("  ✓ Facet data returned".to_string());
+                if !search_results.facets.is_empty() {
+                    results.push(format!(
+                        "  ✓ Facet data returned for {:?}",
+                        search_results
+                            .facets
+                            .iter()
+                            .map(|facet| facet.name.as_str())
+                            .collect::<Vec<_>>()
+                    ));
                 } else {
                     results.push("  ⚠ No facet data returned (may not be supported)".to_string());
                 }
@@ -589,7 +626,7 @@ impl Guest for Component {
                 );
                 // Insert a test document to auto-create the index
                 let test_docs = vec![create_test_documents().into_iter().next().unwrap()];
-                if let Err(e) = core::upsert_many(&index_name, &test_docs) {
+                if let Err(e) = core::upsert_many(&index_name, &test_docs, None) {
                     return format!("Document insertion failed: {:?}", e);
                 }
             }
@@ -600,7 +637,7 @@ impl Guest for Component {
                 ));
 
                 let test_docs = vec![create_test_documents().into_iter().next().unwrap()];
-                if let Err(e) = core::upsert_many(&index_name, &test_docs) {
+                if let Err(e) = core::upsert_many(&index_name, &test_docs, None) {
                     return format!("Document insertion failed: {:?}", e);
                 }
             }
@@ -640,6 +677,7 @@ impl Guest for Component {
             facet: false,
             sort: false,
             index: true,
+            vector: None,
         });
 
         match core::update_schema(&index_name, &updated_schema) {
@@ -685,10 +723,11 @@ impl Guest for Component {
             docs.push(Doc {
                 id: format!("doc{}", i),
                 content: format!(r#"{{"title": "Book {}", "author": "Author {}", "year": {}, "genre": "test", "description": "A test book for streaming search"}}"#, i, i, 1900 + i),
+                embeddings: vec![],
             });
         }
 
-        if let Err(e) = core::upsert_many(&index_name, &docs) {
+        if let Err(e) = core::upsert_many(&index_name, &docs, None) {
             core::delete_index(&index_name).ok();
             return format!("Document insertion failed: {:?}", e);
         }
@@ -697,8 +736,11 @@ impl Guest for Component {
         let stream_query = SearchQuery {
             q: Some("book".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["year:asc".to_string()],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: Some(5), // Small page size to encourage streaming
             offset: None,
@@ -801,8 +843,11 @@ impl Guest for Component {
         let advanced_query = SearchQuery {
             q: Some("test".to_string()),
             filters: vec!["complex_filter:value AND nested.field:value".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["complex_sort:desc".to_string()],
             facets: vec!["facet1".to_string(), "facet2".to_string()],
+            aggregations: vec![],
             page: Some(1),
             per_page: Some(10),
             offset: Some(0),
@@ -845,10 +890,11 @@ impl Guest for Component {
         let invalid_doc = Doc {
             id: "invalid-json".to_string(),
             content: r#"{"invalid": json, "malformed": true"#.to_string(), // Missing closing brace, invalid syntax
+            embeddings: vec![],
         };
 
-        match core::upsert(&test_index, &invalid_doc) {
-            Ok(()) => results.push("⚠ Invalid JSON was accepted (lenient validation)".to_string()),
+        match core::upsert(&test_index, &invalid_doc, None) {
+            Ok(_) => results.push("⚠ Invalid JSON was accepted (lenient validation)".to_string()),
             Err(SearchError::InvalidQuery(msg)) => {
                 results.push(format!("✓ Invalid JSON rejected: {}", msg))
             }
@@ -859,8 +905,11 @@ impl Guest for Component {
         let invalid_query = SearchQuery {
             q: Some("((unclosed parenthesis AND malformed:".to_string()),
             filters: vec!["invalid_filter_syntax:::".to_string()],
+            filter_expr: None,
+            vector_query: None,
             sort: vec!["invalid_sort_field:invalid_direction".to_string()],
             facets: vec![],
+            aggregations: vec![],
             page: Some(0),     // Invalid page number
             per_page: Some(0), // Invalid page size
             offset: None,
@@ -897,8 +946,12 @@ impl Guest for Component {
         }
 
         // Test deleting non-existent document
-        match core::delete(&nonexistent_index, &DocumentId::from("non-existent-doc")) {
-            Ok(()) => {
+        match core::delete(
+            &nonexistent_index,
+            &DocumentId::from("non-existent-doc"),
+            None,
+        ) {
+            Ok(_) => {
                 results.push("✓ Deleting non-existent document succeeds (idempotent)".to_string())
             }
             Err(SearchError::IndexNotFound) => {
@@ -926,10 +979,11 @@ impl Guest for Component {
         let empty_doc = Doc {
             id: "empty-doc".to_string(),
             content: "{}".to_string(),
+            embeddings: vec![],
         };
 
-        match core::upsert(&test_index, &empty_doc) {
-            Ok(()) => results.push("✓ Empty document accepted".to_string()),
+        match core::upsert(&test_index, &empty_doc, None) {
+            Ok(_) => results.push("✓ Empty document accepted".to_string()),
             Err(e) => results.push(format!("✓ Empty document  handled: {:?}", e)),
         }
 
@@ -937,10 +991,11 @@ impl Guest for Component {
         let long_id_doc = Doc {
             id: "a".repeat(1000), // Very long ID
             content: r#"{"test": "value"}"#.to_string(),
+            embeddings: vec![],
         };
 
-        match core::upsert(&test_index, &long_id_doc) {
-            Ok(()) => results.push("✓ Long document ID accepted".to_string()),
+        match core::upsert(&test_index, &long_id_doc, None) {
+            Ok(_) => results.push("✓ Long document ID accepted".to_string()),
             Err(SearchError::InvalidQuery(msg)) => {
                 results.push(format!("✓ Long ID rejected: {}", msg))
             }
@@ -951,8 +1006,11 @@ impl Guest for Component {
         let empty_query = SearchQuery {
             q: Some("".to_string()),
             filters: vec![],
+            filter_expr: None,
+            vector_query: None,
             sort: vec![],
             facets: vec![],
+            aggregations: vec![],
             page: None,
             per_page: None,
             offset: None,
@@ -1006,10 +1064,11 @@ impl Guest for Component {
             let doc = Doc {
                 id: format!("stress-doc-{}", i),
                 content: format!(r#"{{"value": {}, "test": "stress"}}"#, i),
+                embeddings: vec![],
             };
 
-            match core::upsert(&stress_index, &doc) {
-                Ok(()) => stress_results.push(true),
+            match core::upsert(&stress_index, &doc, None) {
+                Ok(_) => stress_results.push(true),
                 Err(_) => stress_results.push(false),
             }
         }
@@ -1029,6 +1088,1031 @@ impl Guest for Component {
         results.push("\n=== Error Handling Test Complete ===".to_string());
         results.join("\n")
     }
+
+    /// test8 demonstrates typed filter expressions
+    fn test8() -> String {
+        let index_name = format!("{}-test8", TEST_INDEX);
+        let mut results = Vec::new();
+
+        // The filtered fields have to be facets for the providers which only filter on facets
+        let mut schema = create_test_schema();
+        for field in schema.fields.iter_mut() {
+            if field.name == "year" {
+                field.facet = true;
+            }
+        }
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&schema)) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        } else {
+            println!("Setting up index: {}", index_name);
+        }
+
+        match core::update_schema(&index_name, &schema) {
+            Ok(_) => {}
+            Err(SearchError::Unsupported) => {}
+            Err(_) => {} // Continue anyway
+        }
+
+        if let Err(e) = core::upsert_many(&index_name, &create_test_documents(), None) {
+            core::delete_index(&index_name).ok();
+            return format!("Document insertion failed: {:?}", e);
+        }
+
+        // genre = "fiction" AND year >= 1950 AND NOT author = "J.D. Salinger"
+        println!("Searching with a filter expression");
+        let filter_query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: Some(FilterExpr {
+                nodes: vec![
+                    FilterNode::Eq(FieldFilter {
+                        field: "genre".to_string(),
+                        value: FilterValue::Text("fiction".to_string()),
+                    }),
+                    FilterNode::Range(RangeFilter {
+                        field: "year".to_string(),
+                        gt: None,
+                        gte: Some(FilterValue::Integer(1950)),
+                        lt: None,
+                        lte: None,
+                    }),
+                    FilterNode::Eq(FieldFilter {
+                        field: "author".to_string(),
+                        value: FilterValue::Text("J.D. Salinger".to_string()),
+                    }),
+                    FilterNode::Not(2),
+                    FilterNode::And(vec![0, 1, 3]),
+                ],
+            }),
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        match core::search(&index_name, &filter_query) {
+            Ok(search_results) => {
+                let ids: Vec<String> = search_results.hits.into_iter().map(|hit| hit.id).collect();
+                results.push(format!("✓ Filtered search returned {:?}", ids));
+                if ids == vec!["doc2".to_string()] {
+                    results.push("  ✓ Only the expected document matched".to_string());
+                } else {
+                    results.push("  ⚠ Expected only doc2 to match".to_string());
+                }
+            }
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Filter expression not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Filtered search failed: {:?}", e)),
+        }
+
+        // A node referring to itself is rejected before reaching the provider
+        let invalid_query = SearchQuery {
+            filter_expr: Some(FilterExpr {
+                nodes: vec![FilterNode::Not(0)],
+            }),
+            ..filter_query
+        };
+
+        match core::search(&index_name, &invalid_query) {
+            Ok(_) => results.push("✗ Invalid filter expression was accepted".to_string()),
+            Err(SearchError::InvalidQuery(msg)) => {
+                results.push(format!("✓ Invalid filter expression rejected: {}", msg))
+            }
+            Err(e) => results.push(format!("⚠ Invalid filter expression failed with: {:?}", e)),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    /// test9 demonstrates vector and hybrid search over document embeddings
+    fn test9() -> String {
+        let index_name = format!("{}-test9", TEST_INDEX);
+        let mut results = Vec::new();
+
+        let mut schema = create_test_schema();
+        schema.fields.push(SchemaField {
+            name: "title_vector".to_string(),
+            field_type: FieldType::Vector,
+            required: false,
+            facet: false,
+            sort: false,
+            index: true,
+            vector: Some(VectorConfig {
+                dimensions: 3,
+                similarity: VectorSimilarity::Cosine,
+            }),
+        });
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&schema)) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(SearchError::Unsupported) => {
+                    return "⚠ Vector fields not supported by this provider".to_string()
+                }
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        } else {
+            println!("Setting up index: {}", index_name);
+        }
+
+        match core::update_schema(&index_name, &schema) {
+            Ok(_) => {}
+            Err(SearchError::Unsupported) => {}
+            Err(_) => {} // Continue anyway
+        }
+
+        // Toy embeddings, the first dimension stands for fiction and the second for dystopia
+        let embeddings = [
+            vec![1.0, 0.0, 0.1],
+            vec![0.9, 0.1, 0.0],
+            vec![0.1, 1.0, 0.0],
+            vec![0.8, 0.0, 0.3],
+            vec![0.9, 0.2, 0.1],
+        ];
+        let docs: Vec<Doc> = create_test_documents()
+            .into_iter()
+            .zip(embeddings)
+            .map(|(doc, values)| Doc {
+                embeddings: vec![Embedding {
+                    field: "title_vector".to_string(),
+                    values,
+                }],
+                ..doc
+            })
+            .collect();
+
+        match core::upsert_many(&index_name, &docs, None) {
+            Ok(bulk_result)
+                if bulk_result
+                    .items
+                    .iter()
+                    .any(|item| matches!(item.error, Some(SearchError::Unsupported))) =>
+            {
+                core::delete_index(&index_name).ok();
+                return "⚠ Document embeddings not supported by this provider".to_string();
+            }
+            Ok(_) => results.push("✓ Documents with embeddings inserted".to_string()),
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("Document insertion failed: {:?}", e);
+            }
+        }
+
+        println!("Searching the nearest neighbours of a dystopian embedding");
+        let vector_query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![0.0, 1.0, 0.0],
+                k: 2,
+                hybrid_weight: None,
+            }),
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(2),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        match core::search(&index_name, &vector_query) {
+            Ok(search_results) => {
+                let ids: Vec<String> = search_results.hits.into_iter().map(|hit| hit.id).collect();
+                results.push(format!("✓ Vector search returned {:?}", ids));
+                if ids.first().map(String::as_str) == Some("doc3") {
+                    results.push("  ✓ Nearest neighbour is doc3".to_string());
+                } else {
+                    results.push("  ⚠ Expected doc3 as the nearest neighbour".to_string());
+                }
+            }
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Vector search not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Vector search failed: {:?}", e)),
+        }
+
+        println!("Searching with a hybrid keyword and vector query");
+        let hybrid_query = SearchQuery {
+            q: Some("novel".to_string()),
+            vector_query: Some(VectorQuery {
+                field: "title_vector".to_string(),
+                embedding: vec![1.0, 0.0, 0.0],
+                k: 3,
+                hybrid_weight: Some(0.5),
+            }),
+            ..vector_query
+        };
+
+        match core::search(&index_name, &hybrid_query) {
+            Ok(search_results) => results.push(format!(
+                "✓ Hybrid search returned {} hits",
+                search_results.hits.len()
+            )),
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Hybrid search not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Hybrid search failed: {:?}", e)),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    /// test10 demonstrates per-document results of bulk writes split into chunks
+    fn test10() -> String {
+        let index_name = format!("{}-test10", TEST_INDEX);
+        let mut results = Vec::new();
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&create_test_schema())) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        } else {
+            println!("Setting up index: {}", index_name);
+        }
+
+        match core::update_schema(&index_name, &create_test_schema()) {
+            Ok(_) => {}
+            Err(SearchError::Unsupported) => {}
+            Err(_) => {} // Continue anyway
+        }
+
+        // Two documents the providers reject, between valid ones
+        let mut docs = create_test_documents();
+        docs.insert(
+            2,
+            Doc {
+                id: "invalid-json".to_string(),
+                content: "{not json".to_string(),
+                embeddings: vec![],
+            },
+        );
+        docs.push(Doc {
+            id: "invalid-year".to_string(),
+            content: r#"{"title": "Untitled", "author": "Anonymous", "year": "unknown", "genre": "fiction", "description": "A year that is not a number"}"#.to_string(),
+            embeddings: vec![],
+        });
+        let options = BulkOptions {
+            chunk_size: Some(2),
+            consistency: None,
+        };
+
+        println!("Inserting {} documents in chunks of 2", docs.len());
+        match core::upsert_many(&index_name, &docs, Some(&options)) {
+            Ok(bulk_result) => {
+                let failed = bulk_result
+                    .items
+                    .iter()
+                    .filter(|item| item.error.is_some())
+                    .count();
+                results.push(format!(
+                    "✓ Bulk upsert reported {} documents, {} failed",
+                    bulk_result.items.len(),
+                    failed
+                ));
+                for item in &bulk_result.items {
+                    match &item.error {
+                        None => results.push(format!("  ✓ {}", item.id)),
+                        Some(e) => results.push(format!("  ✗ {}: {:?}", item.id, e)),
+                    }
+                }
+                if bulk_result.items.len() != docs.len() {
+                    results.push("  ✗ Expected one result per document".to_string());
+                }
+            }
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("✗ Bulk upsert failed: {:?}", e);
+            }
+        }
+
+        println!("Rejecting a chunk size of 0");
+        let empty_chunks = BulkOptions {
+            chunk_size: Some(0),
+            consistency: None,
+        };
+        match core::upsert_many(&index_name, &docs, Some(&empty_chunks)) {
+            Err(SearchError::InvalidQuery(_)) => {
+                results.push("✓ Chunk size of 0 rejected".to_string())
+            }
+            other => results.push(format!(
+                "✗ Expected invalid-query for a chunk size of 0, got {:?}",
+                other
+            )),
+        }
+
+        println!("Deleting {} documents in chunks of 2", docs.len());
+        let ids: Vec<DocumentId> = docs.iter().map(|doc| doc.id.clone()).collect();
+        match core::delete_many(&index_name, &ids, Some(&options)) {
+            Ok(bulk_result) => {
+                let failed = bulk_result
+                    .items
+                    .iter()
+                    .filter(|item| item.error.is_some())
+                    .count();
+                results.push(format!(
+                    "✓ Bulk delete reported {} documents, {} failed",
+                    bulk_result.items.len(),
+                    failed
+                ));
+            }
+            Err(e) => results.push(format!("✗ Bulk delete failed: {:?}", e)),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    /// test11 demonstrates reindexing into a new index and switching an alias over to it
+    fn test11() -> String {
+        let source = format!("{}-test11-v1", TEST_INDEX);
+        let target = format!("{}-test11-v2", TEST_INDEX);
+        let alias = format!("{}-test11", TEST_INDEX);
+        let mut results = Vec::new();
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            for index_name in [&source, &target] {
+                println!("Setting   index: {}", index_name);
+                if let Err(e) = core::create_index(index_name, Some(&create_test_schema())) {
+                    core::delete_index(&source).ok();
+                    return format!("✗ Index creation failed: {:?}", e);
+                }
+            }
+            results.push("✓ Indexes created successfully".to_string());
+        }
+
+        match core::upsert_many(&source, &create_test_documents(), None) {
+            Ok(_) => results.push("✓ Documents inserted into the source index".to_string()),
+            Err(e) => {
+                core::delete_index(&source).ok();
+                core::delete_index(&target).ok();
+                return format!("✗ Document insertion failed: {:?}", e);
+            }
+        }
+
+        // Wait for indexing
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+
+        println!("Reindexing {} into {}", source, target);
+        let transform = ReindexTransform {
+            filter: Some(FilterExpr {
+                nodes: vec![FilterNode::Range(RangeFilter {
+                    field: "year".to_string(),
+                    gt: None,
+                    gte: Some(FilterValue::Integer(1900)),
+                    lt: None,
+                    lte: None,
+                })],
+            }),
+            fields: vec![
+                "title".to_string(),
+                "author".to_string(),
+                "year".to_string(),
+                "genre".to_string(),
+            ],
+        };
+        let reindexed = match core::reindex(&source, &target, Some(&transform)) {
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Reindex transforms unsupported, copying everything".to_string());
+                core::reindex(&source, &target, None)
+            }
+            other => other,
+        };
+        match reindexed {
+            Ok(result) => results.push(format!(
+                "✓ Reindex finished, copied documents: {:?}",
+                result.copied
+            )),
+            Err(e) => results.push(format!("✗ Reindex failed: {:?}", e)),
+        }
+
+        println!("Pointing alias {} to {}", alias, source);
+        match core::create_alias(&alias, &source) {
+            Ok(_) => {
+                results.push("✓ Alias created".to_string());
+                match core::create_alias(&alias, &source) {
+                    Err(SearchError::InvalidQuery(_)) => {
+                        results.push("✓ Existing alias rejected".to_string())
+                    }
+                    other => results.push(format!(
+                        "✗ Expected invalid-query for an existing alias, got {:?}",
+                        other
+                    )),
+                }
+            }
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Alias creation not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Alias creation failed: {:?}", e)),
+        }
+
+        println!("Swapping alias {} to {}", alias, target);
+        match core::swap_alias(&alias, &target) {
+            Ok(_) => results.push("✓ Alias swapped to the new index".to_string()),
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Alias swap not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Alias swap failed: {:?}", e)),
+        }
+
+        match core::list_aliases() {
+            Ok(aliases) => {
+                if aliases
+                    .iter()
+                    .any(|a| a.alias == alias && a.index == target)
+                {
+                    results.push("✓ Alias listed with the new index".to_string());
+                } else {
+                    results.push(format!("✗ Alias missing from {:?}", aliases));
+                }
+            }
+            Err(SearchError::Unsupported) => {
+                results.push("⚠ Listing aliases not supported by this provider".to_string())
+            }
+            Err(e) => results.push(format!("✗ Listing aliases failed: {:?}", e)),
+        }
+
+        let query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        match core::search(&alias, &query) {
+            Ok(search_results) => results.push(format!(
+                "✓ Search through the alias found {} documents",
+                search_results.hits.len()
+            )),
+            Err(e) => results.push(format!("✗ Search through the alias failed: {:?}", e)),
+        }
+
+        // Cleanup, providers without aliases keep the alias as an index
+        core::delete_index(&alias).ok();
+        core::delete_index(&source).ok();
+        core::delete_index(&target).ok();
+        results.join("\n")
+    }
+
+    /// test12 demonstrates updating synonyms, stop words and ranking rules of an index
+    fn test12() -> String {
+        let index_name = format!("{}-test12", TEST_INDEX);
+        let mut results = Vec::new();
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&create_test_schema())) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        }
+
+        match core::upsert_many(&index_name, &create_test_documents(), None) {
+            Ok(_) => results.push("✓ Documents inserted".to_string()),
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("✗ Document insertion failed: {:?}", e);
+            }
+        }
+
+        let synonyms_only = RelevanceSettings {
+            synonyms: vec![SynonymSet {
+                synonyms: vec!["book".to_string(), "novel".to_string()],
+                root: None,
+            }],
+            stop_words: vec![],
+            ranking_rules: vec![],
+            provider_settings: None,
+        };
+        let settings = RelevanceSettings {
+            stop_words: vec!["the".to_string(), "a".to_string()],
+            ranking_rules: vec!["year:desc".to_string()],
+            ..synonyms_only.clone()
+        };
+
+        println!("Updating relevance settings of {}", index_name);
+        let updated = match core::update_relevance_settings(&index_name, &settings) {
+            Err(SearchError::Unsupported) => {
+                results.push(
+                    "⚠ Stop words or ranking rules unsupported, updating synonyms only".to_string(),
+                );
+                core::update_relevance_settings(&index_name, &synonyms_only)
+            }
+            other => other,
+        };
+        match updated {
+            Ok(_) => results.push("✓ Relevance settings updated".to_string()),
+            Err(e) => results.push(format!("✗ Relevance settings update failed: {:?}", e)),
+        }
+
+        println!("Rejecting a synonym set with a single term");
+        let invalid = RelevanceSettings {
+            synonyms: vec![SynonymSet {
+                synonyms: vec!["book".to_string()],
+                root: None,
+            }],
+            ..synonyms_only.clone()
+        };
+        match core::update_relevance_settings(&index_name, &invalid) {
+            Err(SearchError::InvalidQuery(_)) => {
+                results.push("✓ Single term synonym set rejected".to_string())
+            }
+            other => results.push(format!(
+                "✗ Expected invalid-query for a single term synonym set, got {:?}",
+                other
+            )),
+        }
+
+        match core::get_relevance_settings(&index_name) {
+            Ok(current) => {
+                results.push(format!(
+                    "✓ Relevance settings read: {} synonym sets, stop words {:?}, ranking rules {:?}",
+                    current.synonyms.len(),
+                    current.stop_words,
+                    current.ranking_rules
+                ));
+                if !current
+                    .synonyms
+                    .iter()
+                    .any(|set| set.synonyms.iter().any(|synonym| synonym == "novel"))
+                {
+                    results.push("  ✗ Synonyms missing from the settings".to_string());
+                }
+            }
+            Err(e) => results.push(format!("✗ Reading relevance settings failed: {:?}", e)),
+        }
+
+        // Wait for indexing
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+
+        let query = SearchQuery {
+            q: Some("book".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        match core::search(&index_name, &query) {
+            Ok(search_results) => {
+                let ids: Vec<&str> = search_results
+                    .hits
+                    .iter()
+                    .map(|hit| hit.id.as_str())
+                    .collect();
+                results.push(format!(
+                    "✓ Searching 'book' found the novels through the synonym: {:?}",
+                    ids
+                ));
+            }
+            Err(e) => results.push(format!("✗ Search failed: {:?}", e)),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    /// test13 demonstrates typed facet results with stats and range aggregations
+    fn test13() -> String {
+        let index_name = format!("{}-test13", TEST_INDEX);
+        let mut results = Vec::new();
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&create_test_schema())) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        }
+
+        match core::upsert_many(&index_name, &create_test_documents(), None) {
+            Ok(_) => results.push("✓ Documents inserted".to_string()),
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("✗ Document insertion failed: {:?}", e);
+            }
+        }
+
+        // Wait for indexing
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+
+        let year_stats = Aggregation {
+            name: "year_stats".to_string(),
+            field: "year".to_string(),
+            kind: AggregationKind::Stats,
+        };
+        let eras = Aggregation {
+            name: "eras".to_string(),
+            field: "year".to_string(),
+            kind: AggregationKind::Range(vec![
+                RangeBucket {
+                    key: Some("classic".to_string()),
+                    from: Some(1800.0),
+                    to: Some(1950.0),
+                },
+                RangeBucket {
+                    key: Some("modern".to_string()),
+                    from: Some(1950.0),
+                    to: Some(2000.0),
+                },
+            ]),
+        };
+        let mut query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec!["genre".to_string()],
+            aggregations: vec![year_stats.clone(), eras],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+
+        println!("Searching {} with facets and aggregations", index_name);
+        let mut search_results = core::search(&index_name, &query);
+        if let Err(SearchError::Unsupported) = search_results {
+            results.push("⚠ Range aggregations unsupported, requesting stats only".to_string());
+            query.aggregations = vec![year_stats];
+            search_results = core::search(&index_name, &query);
+        }
+        match search_results {
+            Ok(search_results) => {
+                results.push(format!(
+                    "✓ Search returned {} facet results",
+                    search_results.facets.len()
+                ));
+                for facet in &search_results.facets {
+                    let buckets: Vec<String> = facet
+                        .buckets
+                        .iter()
+                        .map(|bucket| format!("{}={}", bucket.value, bucket.count))
+                        .collect();
+                    results.push(format!("  {}: {:?}", facet.name, buckets));
+                    if let Some(stats) = &facet.stats {
+                        results.push(format!(
+                            "    min {:?}, max {:?}, avg {:?}, sum {:?}",
+                            stats.min, stats.max, stats.avg, stats.sum
+                        ));
+                    }
+                }
+                if !search_results
+                    .facets
+                    .iter()
+                    .any(|facet| facet.name == "year_stats" && facet.stats.is_some())
+                {
+                    results.push("  ✗ Stats of the year missing".to_string());
+                }
+            }
+            Err(e) => results.push(format!("✗ Search with aggregations failed: {:?}", e)),
+        }
+
+        println!("Rejecting an aggregation named like a facet");
+        query.aggregations = vec![Aggregation {
+            name: "genre".to_string(),
+            field: "year".to_string(),
+            kind: AggregationKind::Stats,
+        }];
+        match core::search(&index_name, &query) {
+            Err(SearchError::InvalidQuery(_)) => {
+                results.push("✓ Duplicate facet name rejected".to_string())
+            }
+            other => results.push(format!(
+                "✗ Expected invalid-query for a duplicate facet name, got {:?}",
+                other.map(|search_results| search_results.facets)
+            )),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    /// test14 demonstrates write consistency options and the status of write tasks
+    fn test14() -> String {
+        let index_name = format!("{}-test14", TEST_INDEX);
+        let mut results = Vec::new();
+
+        if TEST_INDEX == "test-elasticsearch-index"
+            || TEST_INDEX == "test-typesense-index"
+            || TEST_INDEX == "test-opensearch-index"
+        {
+            println!("Setting   index: {}", index_name);
+            match core::create_index(&index_name, Some(&create_test_schema())) {
+                Ok(_) => results.push("✓ Index created successfully".to_string()),
+                Err(e) => return format!("✗ Index creation failed: {:?}", e),
+            }
+        }
+
+        let mut docs = create_test_documents();
+        let async_doc = docs.remove(0);
+        let async_options = WriteOptions {
+            consistency: Some(WriteConsistency::Async),
+        };
+
+        println!("Upserting {} without waiting", async_doc.id);
+        match core::upsert(&index_name, &async_doc, Some(&async_options)) {
+            Ok(Some(task_id)) => {
+                results.push(format!("✓ Asynchronous upsert returned task {}", task_id));
+                let mut state = None;
+                for _ in 0..20 {
+                    match core::get_task_status(&task_id) {
+                        Ok(status) => {
+                            let finished =
+                                matches!(status.state, TaskState::Succeeded | TaskState::Failed);
+                            state = Some(status);
+                            if finished {
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            results.push(format!("✗ Getting the task status failed: {:?}", e));
+                            break;
+                        }
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(250));
+                }
+                match state {
+                    Some(status) if status.state == TaskState::Succeeded => {
+                        results.push("✓ Task succeeded".to_string())
+                    }
+                    Some(status) => results.push(format!(
+                        "✗ Task ended as {:?}: {:?}",
+                        status.state, status.error
+                    )),
+                    None => {}
+                }
+            }
+            Ok(None) => results.push("✓ Upsert is synchronous, no task to track".to_string()),
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("✗ Asynchronous upsert failed: {:?}", e);
+            }
+        }
+
+        println!("Upserting the other documents, waiting until they are searchable");
+        let bulk_options = BulkOptions {
+            chunk_size: None,
+            consistency: Some(WriteConsistency::WaitForIndexing),
+        };
+        match core::upsert_many(&index_name, &docs, Some(&bulk_options)) {
+            Ok(bulk_result) => results.push(format!(
+                "✓ {} documents written by {} tasks",
+                bulk_result.items.len(),
+                bulk_result.tasks.len()
+            )),
+            Err(e) => {
+                core::delete_index(&index_name).ok();
+                return format!("✗ Document insertion failed: {:?}", e);
+            }
+        }
+
+        // No sleep, the written documents have to be visible right away
+        let query = SearchQuery {
+            q: None,
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        match core::search(&index_name, &query) {
+            Ok(search_results) if search_results.hits.len() >= docs.len() => results.push(format!(
+                "✓ Search sees {} documents",
+                search_results.hits.len()
+            )),
+            Ok(search_results) => results.push(format!(
+                "✗ Search sees only {} of the {} waited for documents",
+                search_results.hits.len(),
+                docs.len()
+            )),
+            Err(e) => results.push(format!("✗ Search failed: {:?}", e)),
+        }
+
+        println!("Deleting {} with a refresh", docs[0].id);
+        let refresh_options = WriteOptions {
+            consistency: Some(WriteConsistency::Refresh),
+        };
+        match core::delete(&index_name, &docs[0].id, Some(&refresh_options)) {
+            Ok(_) => match core::get(&index_name, &docs[0].id) {
+                Ok(None) => results.push("✓ Deleted document is gone".to_string()),
+                Ok(Some(_)) => results.push("✗ Deleted document is still found".to_string()),
+                Err(e) => results.push(format!("✗ Get failed: {:?}", e)),
+            },
+            Err(e) => results.push(format!("✗ Delete failed: {:?}", e)),
+        }
+
+        println!("Getting the status of an unknown task");
+        match core::get_task_status(&"unknown-task".to_string()) {
+            Err(SearchError::Unsupported) => {
+                results.push("✓ Write tasks unsupported by the provider".to_string())
+            }
+            Err(SearchError::InvalidQuery(_)) | Err(SearchError::IndexNotFound) => {
+                results.push("✓ Unknown task rejected".to_string())
+            }
+            other => results.push(format!(
+                "✗ Unexpected status of an unknown task: {:?}",
+                other
+            )),
+        }
+
+        // Cleanup
+        core::delete_index(&index_name).ok();
+        results.join("\n")
+    }
+
+    fn test15() -> String {
+        let index_names = [
+            format!("{}-test15-classics", TEST_INDEX),
+            format!("{}-test15-modern", TEST_INDEX),
+        ];
+        let mut results = Vec::new();
+
+        let (classics, modern): (Vec<Doc>, Vec<Doc>) = create_test_documents()
+            .into_iter()
+            .partition(|doc| doc.id == "doc1" || doc.id == "doc4");
+        for (index_name, docs) in index_names.iter().zip([classics, modern]) {
+            if TEST_INDEX == "test-elasticsearch-index"
+                || TEST_INDEX == "test-typesense-index"
+                || TEST_INDEX == "test-opensearch-index"
+            {
+                println!("Setting   index: {}", index_name);
+                if let Err(e) = core::create_index(index_name, Some(&create_test_schema())) {
+                    return format!("✗ Index creation failed: {:?}", e);
+                }
+            }
+            if let Err(e) = core::upsert_many(index_name, &docs, None) {
+                for index_name in &index_names {
+                    core::delete_index(index_name).ok();
+                }
+                return format!("✗ Document insertion failed: {:?}", e);
+            }
+        }
+        results.push("✓ Documents inserted into two indexes".to_string());
+
+        // Wait for indexing
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+
+        let query = SearchQuery {
+            q: Some("novel".to_string()),
+            filters: vec![],
+            filter_expr: None,
+            vector_query: None,
+            sort: vec![],
+            facets: vec![],
+            aggregations: vec![],
+            page: None,
+            per_page: Some(10),
+            offset: None,
+            highlight: None,
+            config: None,
+        };
+        let queries = index_names
+            .iter()
+            .map(|index_name| (index_name.clone(), query.clone()))
+            .collect::<Vec<_>>();
+
+        println!("Searching both indexes, a result per index");
+        match core::multi_search(&queries, None) {
+            Ok(MultiSearchResults::PerIndex(per_index)) => {
+                for (index_name, search_results) in per_index {
+                    results.push(format!(
+                        "✓ {} returned {} hits",
+                        index_name,
+                        search_results.hits.len()
+                    ));
+                }
+            }
+            Ok(MultiSearchResults::Merged(_)) => {
+                results.push("✗ Merged hits returned without federation".to_string())
+            }
+            Err(e) => results.push(format!("✗ Multi-search failed: {:?}", e)),
+        }
+
+        println!("Searching both indexes, merging the hits");
+        let federation = FederationOptions {
+            weights: vec![1.0, 2.0],
+            offset: None,
+            limit: Some(3),
+        };
+        match core::multi_search(&queries, Some(&federation)) {
+            Ok(MultiSearchResults::Merged(merged)) => {
+                results.push(format!(
+                    "✓ Merged search returned {} hits",
+                    merged.hits.len()
+                ));
+                if merged.hits.len() > 3 {
+                    results.push("✗ Merged hits exceed the federation limit".to_string());
+                }
+                for federated_hit in &merged.hits {
+                    results.push(format!(
+                        "  - {} from {} (query {}, score {:?})",
+                        federated_hit.hit.id,
+                        federated_hit.index,
+                        federated_hit.query_position,
+                        federated_hit.hit.score
+                    ));
+                }
+                let sorted = merged.hits.windows(2).all(|pair| {
+                    pair[0].hit.score.unwrap_or(0.0) >= pair[1].hit.score.unwrap_or(0.0)
+                });
+                if !sorted {
+                    results.push("✗ Merged hits are not ranked by score".to_string());
+                }
+            }
+            Ok(MultiSearchResults::PerIndex(_)) => {
+                results.push("✗ Results per index returned with federation".to_string())
+            }
+            Err(e) => results.push(format!("✗ Federated multi-search failed: {:?}", e)),
+        }
+
+        println!("Merging faceted queries");
+        let faceted_queries = vec![(
+            index_names[0].clone(),
+            SearchQuery {
+                facets: vec!["genre".to_string()],
+                ..query
+            },
+        )];
+        match core::multi_search(&faceted_queries, Some(&federation)) {
+            Err(SearchError::InvalidQuery(_)) => {
+                results.push("✓ Faceted queries cannot be merged".to_string())
+            }
+            other => results.push(format!(
+                "✗ Unexpected result of merging faceted queries: {:?}",
+                other
+            )),
+        }
+
+        // Cleanup
+        for index_name in &index_names {
+            core::delete_index(index_name).ok();
+        }
+        results.join("\n")
+    }
 }
 
 bindings::export!(Component with_types_in bindings);
@@ -10,6 +10,13 @@ use crate::bindings::golem::web_search::types::{
     SafeSearchLevel,
     TimeRange,
     SearchError,
+    Kv,
+    ContentFormat,
+    ExtractOptions,
+    ExtractResult,
+    SearchType,
+    QueryOperators,
+    CacheStatus,
 };
 
 struct Component;
@@ -22,6 +29,12 @@ const PROVIDER: &'static str = "brave";
 const PROVIDER: &'static str = "tavily";
 #[cfg(feature = "serper")]
 const PROVIDER: &'static str = "serper";
+#[cfg(feature = "searxng")]
+const PROVIDER: &'static str = "searxng";
+#[cfg(feature = "exa")]
+const PROVIDER: &'static str = "exa";
+#[cfg(feature = "multi")]
+const PROVIDER: &'static str = "multi";
 
 impl Guest for Component {
     /// test1 demonstrates a simple, one-shot web search query
@@ -38,6 +51,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Sending search request using {} provider...", PROVIDER);
@@ -128,6 +144,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Starting search session using {} provider...", PROVIDER);
@@ -258,6 +277,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Searching for recent AI news using {} provider...", PROVIDER);
@@ -323,6 +345,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Searching academic sources for climate research using {} provider...", PROVIDER);
@@ -392,6 +417,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Searching hiking gear reviews (excluding e-commerce) using {} provider...", PROVIDER);
@@ -465,6 +493,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Searching Slovenian recipes in Slovenian language using {} provider...", PROVIDER);
@@ -536,6 +567,9 @@ impl Guest for Component {
             include_images: None,
             include_html: None,
             advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
         };
 
         println!("Searching child safety resources with high safe search using {} provider...", PROVIDER);
@@ -597,6 +631,437 @@ impl Guest for Component {
             }
         }
     }
+
+    /// test8 demonstrates requesting a direct answer to the query with advanced-answer
+    fn test8() -> String {
+        let params = SearchParams {
+            query: "What is the capital of Australia?".to_string(),
+            safe_search: None,
+            language: Some("en".to_string()),
+            region: None,
+            max_results: Some(5),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: Some(true),
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
+        };
+
+        println!("Searching for a direct answer using {} provider...", PROVIDER);
+        let response = web_search::search_once(&params);
+
+        match response {
+            Ok((results, metadata)) => {
+                let mut output = String::new();
+
+                match metadata.and_then(|meta| meta.answer) {
+                    Some(answer) => {
+                        output.push_str(&format!("Answer: {}\n", answer.text));
+                        for url in &answer.source_urls {
+                            output.push_str(&format!("  Source: {}\n", url));
+                        }
+                    }
+                    None => {
+                        output.push_str("No answer returned\n");
+                    }
+                }
+
+                output.push_str(&format!("\n{} results:\n", results.len()));
+                for (i, result) in results.iter().enumerate() {
+                    output.push_str(&format!("{}. {} ({})\n", i + 1, result.title, result.url));
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test9 demonstrates finding pages similar to a given URL, with their page contents, using
+    /// provider specific options (only supported by Exa, ignored by the other providers)
+    fn test9() -> String {
+        let params = SearchParams {
+            query: "durable execution".to_string(),
+            safe_search: None,
+            language: None,
+            region: None,
+            max_results: Some(5),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: Some(vec![
+                Kv {
+                    key: "find_similar_url".to_string(),
+                    value: "https://www.golem.cloud".to_string(),
+                },
+                Kv {
+                    key: "max_characters".to_string(),
+                    value: "1000".to_string(),
+                },
+            ]),
+        };
+
+        println!("Searching for similar pages using {} provider...", PROVIDER);
+        let response = web_search::search_once(&params);
+
+        match response {
+            Ok((results, _)) => {
+                let mut output = String::new();
+
+                output.push_str(&format!("Found {} results:\n", results.len()));
+                for (i, result) in results.iter().enumerate() {
+                    output.push_str(&format!("{}. {} ({})\n", i + 1, result.title, result.url));
+                    for chunk in result.content_chunks.iter().flatten() {
+                        let preview: String = chunk.chars().take(200).collect();
+                        output.push_str(&format!("   Chunk: {}\n", preview));
+                    }
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test10 demonstrates extracting the main content of web pages as markdown, with a failure
+    /// reported per page instead of failing the whole call
+    fn test10() -> String {
+        let urls = vec![
+            "https://learn.golem.cloud".to_string(),
+            "https://www.rust-lang.org".to_string(),
+        ];
+        let options = ExtractOptions {
+            format: Some(ContentFormat::Markdown),
+            include_images: Some(false),
+            max_characters: Some(1000),
+            provider_options: None,
+        };
+
+        println!("Extracting {} pages using {} provider...", urls.len(), PROVIDER);
+        let response = web_search::extract(&urls, &options);
+
+        match response {
+            Ok(results) => {
+                let mut output = String::new();
+
+                for result in results {
+                    match result {
+                        ExtractResult::Success(page) => {
+                            output.push_str(&format!(
+                                "{} ({}):\n",
+                                page.title.unwrap_or_default(),
+                                page.url
+                            ));
+                            let preview: String = page.content.chars().take(300).collect();
+                            output.push_str(&format!("{}\n\n", preview));
+                        }
+                        ExtractResult::Failure(failure) => {
+                            output.push_str(&format!(
+                                "Failed to extract {}: {}\n\n",
+                                failure.url, failure.message
+                            ));
+                        }
+                    }
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test11 demonstrates searching the news vertical, returning the publishing outlet and the
+    /// thumbnail of each article where the provider reports them
+    fn test11() -> String {
+        let params = SearchParams {
+            query: "WebAssembly".to_string(),
+            safe_search: None,
+            language: Some("en".to_string()),
+            region: None,
+            max_results: Some(10),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: None,
+            search_type: Some(SearchType::News),
+            query_operators: None,
+            provider_options: None,
+        };
+
+        println!("Searching for news using {} provider...", PROVIDER);
+        let response = web_search::search_once(&params);
+
+        match response {
+            Ok((results, _)) => {
+                let mut output = String::new();
+
+                output.push_str(&format!("Found {} news results:\n", results.len()));
+                for (i, result) in results.iter().enumerate() {
+                    output.push_str(&format!("{}. {}\n", i + 1, result.title));
+                    output.push_str(&format!("   URL: {}\n", result.url));
+                    if let Some(outlet) = &result.source_outlet {
+                        output.push_str(&format!("   Outlet: {}\n", outlet));
+                    }
+                    if let Some(date) = &result.date_published {
+                        output.push_str(&format!("   Published: {}\n", date));
+                    }
+                    if let Some(thumbnail) = &result.thumbnail {
+                        output.push_str(&format!("   Thumbnail: {}\n", thumbnail));
+                    }
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test12 demonstrates handing over a half-consumed search to a new session using the cursor
+    /// returned by get-cursor
+    fn test12() -> String {
+        let params = SearchParams {
+            query: "Rust programming language tutorials".to_string(),
+            safe_search: Some(SafeSearchLevel::Off),
+            language: Some("en".to_string()),
+            region: Some("US".to_string()),
+            max_results: Some(3),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
+        };
+
+        println!("Starting search session using {} provider...", PROVIDER);
+
+        let mut output = String::new();
+
+        let cursor = {
+            let session = match web_search::start_search(&params) {
+                Ok(session) => session,
+                Err(error) => {
+                    let error_msg = format_search_error(error);
+                    println!("{}", error_msg);
+                    return error_msg;
+                }
+            };
+
+            match session.next_page() {
+                Ok(results) => {
+                    output.push_str(&format!("First page - {} results:\n", results.len()));
+                    for (i, result) in results.iter().enumerate() {
+                        output.push_str(&format!("{}. {}\n   {}\n", i + 1, result.title, result.url));
+                    }
+                    output.push_str("\n");
+                }
+                Err(error) => {
+                    let error_msg = format_search_error(error);
+                    println!("{}", error_msg);
+                    return error_msg;
+                }
+            }
+
+            match session.get_cursor() {
+                Ok(cursor) => cursor,
+                Err(error) => {
+                    let error_msg = format_search_error(error);
+                    println!("{}", error_msg);
+                    return error_msg;
+                }
+            }
+        };
+
+        output.push_str(&format!("Cursor after the first page: {}\n\n", cursor));
+
+        println!("Resuming search from the cursor...");
+        let session = match web_search::resume_search(&params, &cursor) {
+            Ok(session) => session,
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                output.push_str(&format!("{}\n", error_msg));
+                return output;
+            }
+        };
+
+        match session.next_page() {
+            Ok(results) => {
+                if results.is_empty() {
+                    output.push_str("No more results available (end of pagination)\n");
+                } else {
+                    output.push_str(&format!("Resumed page - {} results:\n", results.len()));
+                    for (i, result) in results.iter().enumerate() {
+                        output.push_str(&format!("{}. {}\n   {}\n", i + 1, result.title, result.url));
+                    }
+                }
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                output.push_str(&format!("{}\n", error_msg));
+            }
+        }
+
+        if let Some(metadata) = session.get_metadata() {
+            output.push_str(&format!("\nCurrent Page: {}\n", metadata.current_page));
+        }
+
+        output
+    }
+
+    /// test13 demonstrates structured query operators, which are compiled into the query syntax
+    /// of the provider
+    fn test13() -> String {
+        let params = SearchParams {
+            query: "ownership".to_string(),
+            safe_search: None,
+            language: Some("en".to_string()),
+            region: None,
+            max_results: Some(5),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: None,
+            search_type: None,
+            query_operators: Some(QueryOperators {
+                exact_phrases: Some(vec!["borrow checker".to_string()]),
+                must_terms: None,
+                must_not_terms: None,
+                filetype: None,
+                sites: Some(vec!["doc.rust-lang.org".to_string()]),
+            }),
+            provider_options: None,
+        };
+
+        println!("Searching with query operators using {} provider...", PROVIDER);
+        let response = web_search::search_once(&params);
+
+        match response {
+            Ok((results, _)) => {
+                let mut output = String::new();
+
+                output.push_str(&format!("Found {} results:\n", results.len()));
+                for (i, result) in results.iter().enumerate() {
+                    output.push_str(&format!("{}. {}\n", i + 1, result.title));
+                    output.push_str(&format!("   URL: {}\n", result.url));
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test14 demonstrates query suggestions for the beginning of a query
+    fn test14() -> String {
+        println!("Requesting suggestions using {} provider...", PROVIDER);
+        let response = web_search::suggest("how to lea", Some("en"), Some("us"));
+
+        match response {
+            Ok(suggestions) => {
+                let mut output = String::new();
+
+                output.push_str(&format!("Found {} suggestions:\n", suggestions.len()));
+                for (i, suggestion) in suggestions.iter().enumerate() {
+                    output.push_str(&format!("{}. {}\n", i + 1, suggestion));
+                }
+
+                output
+            }
+            Err(error) => {
+                let error_msg = format_search_error(error);
+                println!("{}", error_msg);
+                error_msg
+            }
+        }
+    }
+
+    /// test15 demonstrates the result cache by repeating the same query, which is only sent to
+    /// the provider once if GOLEM_WEB_SEARCH_CACHE_TTL_SECONDS is set
+    fn test15() -> String {
+        let params = SearchParams {
+            query: "rust async runtime".to_string(),
+            safe_search: None,
+            language: Some("en".to_string()),
+            region: None,
+            max_results: Some(5),
+            time_range: None,
+            include_domains: None,
+            exclude_domains: None,
+            include_images: None,
+            include_html: None,
+            advanced_answer: None,
+            search_type: None,
+            query_operators: None,
+            provider_options: None,
+        };
+
+        let mut output = String::new();
+        for attempt in 1..=2 {
+            println!("Sending search request #{} using {} provider...", attempt, PROVIDER);
+            match web_search::search_once(&params) {
+                Ok((results, metadata)) => {
+                    let cache_status = match metadata.and_then(|meta| meta.cache_status) {
+                        Some(CacheStatus::Hit) => "hit",
+                        Some(CacheStatus::Miss) => "miss",
+                        None => "disabled",
+                    };
+                    output.push_str(
+                        &format!(
+                            "Request #{}: {} results, cache {}\n",
+                            attempt,
+                            results.len(),
+                            cache_status
+                        )
+                    );
+                }
+                Err(error) => {
+                    let error_msg = format_search_error(error);
+                    println!("{}", error_msg);
+                    output.push_str(&format!("Request #{}: {}\n", attempt, error_msg));
+                }
+            }
+        }
+
+        output
+    }
 }
 
 fn format_search_error(error: SearchError) -> String {
@@ -0,0 +1,301 @@
+use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::{RateLimitInfo, SearchType};
+use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
+use log::trace;
+use reqwest::Method;
+use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+const BASE_URL: &str = "https://api.search.brave.com/res/v1";
+const SUMMARIZER_URL: &str = "https://api.search.brave.com/res/v1/summarizer/search";
+
+/// The Brave Search API client for web search.
+pub struct BraveSearchApi {
+    client: Client,
+    pub api_key: String,
+}
+
+impl BraveSearchApi {
+    pub fn new(api_key: String) -> Self {
+        let client = Client::builder()
+            .user_agent("Golem-Web-Search/1.0")
+            .build()
+            .expect("Failed to initialize HTTP client");
+
+        Self { client, api_key }
+    }
+
+    pub fn search(&self, request: SearchRequest) -> Result<SearchResponse, SearchError> {
+        trace!("Sending request to Brave Search API: {request:?}");
+
+        let endpoint = match request.search_type {
+            SearchType::Web => "web/search",
+            SearchType::News => "news/search",
+            SearchType::Images => "images/search",
+            SearchType::Videos => "videos/search",
+            SearchType::Places => {
+                return Err(SearchError::UnsupportedFeature(
+                    "places search not supported".to_string(),
+                ))
+            }
+        };
+
+        let mut query = vec![
+            ("q", request.query.clone()),
+            ("count", request.count.unwrap_or(10).to_string()),
+        ];
+        // Image search has no pagination
+        if request.search_type != SearchType::Images {
+            query.push(("offset", request.offset.unwrap_or(0).to_string()));
+        }
+        if request.summary {
+            query.push(("summary", "1".to_string()));
+        }
+
+        let response = self
+            .client
+            .request(Method::GET, format!("{BASE_URL}/{endpoint}"))
+            .header("X-Subscription-Token", &self.api_key)
+            .header("Accept", "application/json")
+            .query(&query)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body: SearchResponse = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
+    }
+
+    /// Fetches the summary for a search made with `summary` enabled, using the summarizer key
+    /// returned in the search response.
+    pub fn summarize(&self, key: &str) -> Result<SummarizerResponse, SearchError> {
+        trace!("Sending request to Brave Summarizer API: {key}");
+
+        let response = self
+            .client
+            .request(Method::GET, SUMMARIZER_URL)
+            .header("X-Subscription-Token", &self.api_key)
+            .header("Accept", "application/json")
+            .query(&[("key", key), ("entity_info", "1")])
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    /// Returns query suggestions, requires a subscription plan with autosuggest access
+    pub fn suggest(&self, request: SuggestRequest) -> Result<SuggestResponse, SearchError> {
+        trace!("Sending request to Brave Suggest API: {request:?}");
+
+        let mut query = vec![
+            ("q", request.query.clone()),
+            ("count", request.count.to_string()),
+        ];
+        if let Some(country) = &request.country {
+            query.push(("country", country.clone()));
+        }
+        if let Some(lang) = &request.lang {
+            query.push(("lang", lang.clone()));
+        }
+
+        let response = self
+            .client
+            .request(Method::GET, format!("{BASE_URL}/suggest/search"))
+            .header("X-Subscription-Token", &self.api_key)
+            .header("Accept", "application/json")
+            .query(&query)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    pub fn api_key(&self) -> &String {
+        &self.api_key
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchRequest {
+    pub search_type: SearchType,
+    pub query: String,
+    pub count: Option<u32>,
+    pub offset: Option<u32>,
+    pub summary: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuggestRequest {
+    pub query: String,
+    pub country: Option<String>,
+    pub lang: Option<String>,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestResponse {
+    #[serde(default)]
+    pub results: Vec<SuggestResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestResult {
+    pub query: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub query: QueryInfo,
+    pub web: Option<WebResults>,
+    /// Results of the news, image and video searches
+    #[serde(default)]
+    pub results: Vec<VerticalResult>,
+    pub summarizer: Option<SummarizerKey>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummarizerKey {
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummarizerResponse {
+    pub status: Option<String>,
+    pub title: Option<String>,
+    pub summary: Option<Vec<SummaryMessage>>,
+    pub enrichments: Option<SummaryEnrichments>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryMessage {
+    #[serde(rename = "type")]
+    pub message_type: String,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryEnrichments {
+    pub raw: Option<String>,
+    pub context: Option<Vec<SummaryContext>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryContext {
+    pub title: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryInfo {
+    pub original: String,
+    #[serde(default)]
+    pub more_results_available: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebResults {
+    pub results: Vec<WebResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebResult {
+    pub title: String,
+    pub url: String,
+    pub description: String,
+    pub date: Option<String>,
+}
+
+/// A news, image or video result, the fields not returned by the searched vertical are `None`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerticalResult {
+    pub title: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub age: Option<String>,
+    pub page_age: Option<String>,
+    /// Domain of the page an image was found on
+    pub source: Option<String>,
+    pub meta_url: Option<MetaUrl>,
+    pub thumbnail: Option<Thumbnail>,
+    pub properties: Option<ImageProperties>,
+    pub video: Option<VideoData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaUrl {
+    pub hostname: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thumbnail {
+    pub src: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageProperties {
+    /// URL of the full size image
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoData {
+    pub duration: Option<String>,
+    pub creator: Option<String>,
+    pub publisher: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: String,
+}
+
+fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, SearchError> {
+    let status = response.status();
+    if status.is_success() {
+        let body = response
+            .json::<T>()
+            .map_err(|err| from_reqwest_error("Failed to decode response body", err))?;
+
+        trace!("Received response from Brave Search API: {body:?}");
+        Ok(body)
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!(
+            "Received {status} response from Brave Search API, retry after {retry_after} seconds"
+        );
+        Err(SearchError::RateLimited(retry_after))
+    } else {
+        // Try to parse error response
+        match response.json::<ErrorResponse>() {
+            Ok(error_body) => {
+                trace!("Received {status} response from Brave Search API: {error_body:?}");
+
+                let search_error = match status.as_u16() {
+                    400 => SearchError::InvalidQuery,
+                    401 => SearchError::BackendError("Invalid API key".to_string()),
+                    403 => SearchError::BackendError("API key quota exceeded".to_string()),
+                    _ => SearchError::BackendError(format!(
+                        "Request failed with {}: {}",
+                        status, error_body.message
+                    )),
+                };
+
+                Err(search_error)
+            }
+            Err(_) => {
+                // Fallback for non-JSON error responses
+                Err(SearchError::BackendError(format!(
+                    "Request failed with status {status}"
+                )))
+            }
+        }
+    }
+}
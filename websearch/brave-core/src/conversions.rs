@@ -0,0 +1,474 @@
+use crate::client::{
+    SearchRequest, SearchResponse, SuggestRequest, SuggestResponse, SummarizerResponse,
+    VerticalResult, WebResult,
+};
+use golem_web_search::golem::web_search::types::{Answer, ImageResult, SearchType};
+use golem_web_search::golem::web_search::web_search::{
+    SearchError, SearchMetadata, SearchParams, SearchResult,
+};
+use golem_web_search::query::compile_query;
+
+pub fn params_to_request(params: &SearchParams, offset: u32) -> Result<SearchRequest, SearchError> {
+    // Validate query
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    // Brave understands the common search operators, including the domain filters
+    let query = compile_query(params);
+
+    let search_type = params.search_type.unwrap_or(SearchType::Web);
+
+    Ok(SearchRequest {
+        search_type,
+        query,
+        count: Some(params.max_results.unwrap_or(10)),
+        offset: Some(offset),
+        // The summary is only requested for the first page
+        summary: params.advanced_answer == Some(true)
+            && offset == 0
+            && search_type == SearchType::Web,
+    })
+}
+
+/// Maximum number of suggestions returned by the Brave Suggest API
+const MAX_SUGGESTIONS: u32 = 20;
+
+pub fn suggest_request(
+    prefix: &str,
+    language: Option<String>,
+    region: Option<String>,
+) -> Result<SuggestRequest, SearchError> {
+    if prefix.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    Ok(SuggestRequest {
+        query: prefix.to_string(),
+        country: region.map(|region| region.to_lowercase()),
+        lang: language.map(|language| language.to_lowercase()),
+        count: MAX_SUGGESTIONS,
+    })
+}
+
+pub fn suggest_response_to_suggestions(response: SuggestResponse) -> Vec<String> {
+    let mut suggestions: Vec<String> = Vec::new();
+    for suggestion in response.results {
+        let query = suggestion.query.trim();
+        if !query.is_empty() && !suggestions.iter().any(|existing| existing == query) {
+            suggestions.push(query.to_string());
+        }
+    }
+    suggestions
+}
+
+pub fn response_to_results(
+    response: &SearchResponse,
+    original_params: &SearchParams,
+    current_offset: u32,
+) -> (Vec<SearchResult>, SearchMetadata) {
+    let mut results = Vec::new();
+
+    // Process web results
+    if let Some(ref web_results) = response.web {
+        for (index, item) in web_results.results.iter().enumerate() {
+            results.push(web_result_to_search_result(item, index));
+        }
+    }
+
+    // Process news, image and video results
+    let search_type = original_params.search_type.unwrap_or(SearchType::Web);
+    for (index, item) in response.results.iter().enumerate() {
+        results.push(vertical_result_to_search_result(item, index, search_type));
+    }
+
+    let metadata = create_search_metadata(response, original_params, current_offset);
+    (results, metadata)
+}
+
+fn web_result_to_search_result(item: &WebResult, index: usize) -> SearchResult {
+    let mut content_chunks = None;
+
+    // Create content chunks from description
+    let mut chunks = Vec::new();
+    if !item.description.is_empty() {
+        chunks.push(item.description.clone());
+    }
+
+    if !chunks.is_empty() {
+        content_chunks = Some(chunks);
+    }
+
+    // Simple position-based scoring
+    let score = 1.0 - (index as f32) * 0.05;
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.url.clone(),
+        snippet: item.description.clone(),
+        display_url: extract_domain(&item.url),
+        source: extract_domain(&item.url),
+        score: Some(score.clamp(0.0, 1.0) as f64),
+        html_snippet: None,
+        date_published: item.date.clone(),
+        images: None,
+        content_chunks,
+        thumbnail: None,
+        source_outlet: None,
+        duration: None,
+        location: None,
+    }
+}
+
+fn vertical_result_to_search_result(
+    item: &VerticalResult,
+    index: usize,
+    search_type: SearchType,
+) -> SearchResult {
+    let snippet = item.description.clone().unwrap_or_default();
+    let content_chunks = if snippet.is_empty() {
+        None
+    } else {
+        Some(vec![snippet.clone()])
+    };
+
+    // Simple position-based scoring
+    let score = 1.0 - (index as f32) * 0.05;
+
+    let thumbnail = item
+        .thumbnail
+        .as_ref()
+        .and_then(|thumbnail| thumbnail.src.clone());
+    let hostname = item
+        .meta_url
+        .as_ref()
+        .and_then(|meta_url| meta_url.hostname.as_deref())
+        .map(|host| host.strip_prefix("www.").unwrap_or(host).to_string());
+
+    let source_outlet = match search_type {
+        SearchType::Videos => item
+            .video
+            .as_ref()
+            .and_then(|video| video.publisher.clone().or(video.creator.clone()))
+            .or(hostname),
+        SearchType::Images => item.source.clone().or(hostname),
+        _ => hostname,
+    };
+
+    let images = if search_type == SearchType::Images {
+        item.properties
+            .as_ref()
+            .and_then(|properties| properties.url.clone())
+            .or(thumbnail.clone())
+            .map(|url| {
+                vec![ImageResult {
+                    url,
+                    description: Some(item.title.clone()),
+                }]
+            })
+    } else {
+        None
+    };
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.url.clone(),
+        snippet,
+        display_url: extract_domain(&item.url),
+        source: extract_domain(&item.url),
+        score: Some(score.clamp(0.0, 1.0) as f64),
+        html_snippet: None,
+        date_published: item.page_age.clone().or(item.age.clone()),
+        images,
+        content_chunks,
+        thumbnail,
+        source_outlet,
+        duration: item.video.as_ref().and_then(|video| video.duration.clone()),
+        location: None,
+    }
+}
+
+fn extract_domain(url: &str) -> Option<String> {
+    if let Ok(parsed_url) = url::Url::parse(url) {
+        parsed_url.host_str().map(|host| {
+            // Remove www. prefix if present
+            if let Some(stripped) = host.strip_prefix("www.") {
+                stripped.to_string()
+            } else {
+                host.to_string()
+            }
+        })
+    } else {
+        None
+    }
+}
+
+fn create_search_metadata(
+    response: &SearchResponse,
+    params: &SearchParams,
+    current_offset: u32,
+) -> SearchMetadata {
+    // Create next page token if more results are available
+    let next_page_token = if response.query.more_results_available {
+        Some((current_offset + 1).to_string())
+    } else {
+        None
+    };
+
+    // Simple total results estimation
+    let result_count = response
+        .web
+        .as_ref()
+        .map(|web_results| web_results.results.len())
+        .unwrap_or(response.results.len());
+    let total_results = if result_count >= (params.max_results.unwrap_or(10) as usize) {
+        Some(100000u64) // Conservative estimate
+    } else {
+        Some(result_count as u64)
+    };
+
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results,
+        search_time_ms: None,
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token,
+        rate_limits: response.rate_limits,
+        current_page: current_offset,
+        answer: None,
+        cache_status: None,
+        failed_providers: None,
+    }
+}
+
+/// Builds the answer from the summary tokens, citing the inline references and the context
+/// the summary was generated from.
+pub fn summarizer_response_to_answer(response: SummarizerResponse) -> Option<Answer> {
+    if response
+        .status
+        .as_deref()
+        .is_some_and(|status| status != "complete")
+    {
+        return None;
+    }
+
+    let mut text = String::new();
+    let mut source_urls: Vec<String> = Vec::new();
+    for message in response.summary.unwrap_or_default() {
+        match message.message_type.as_str() {
+            "token" => {
+                if let Some(token) = message.data.as_str() {
+                    text.push_str(token);
+                }
+            }
+            "inline_reference" => {
+                if let Some(url) = message.data.get("url").and_then(|url| url.as_str()) {
+                    if !source_urls.iter().any(|existing| existing == url) {
+                        source_urls.push(url.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(enrichments) = response.enrichments {
+        if text.trim().is_empty() {
+            text = enrichments.raw.unwrap_or_default();
+        }
+        for context in enrichments.context.unwrap_or_default() {
+            if !source_urls.contains(&context.url) {
+                source_urls.push(context.url);
+            }
+        }
+    }
+
+    if text.trim().is_empty() {
+        None
+    } else {
+        Some(Answer {
+            text: text.trim().to_string(),
+            source_urls,
+        })
+    }
+}
+
+pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError> {
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+    let search_type = params.search_type.unwrap_or(SearchType::Web);
+    if search_type == SearchType::Places {
+        return Err(SearchError::UnsupportedFeature(
+            "places search not supported".to_string(),
+        ));
+    }
+    let max_results_limit = match search_type {
+        SearchType::Web => 20,
+        SearchType::Images => 200,
+        _ => 50,
+    };
+    if let Some(max_results) = params.max_results {
+        if max_results > max_results_limit {
+            return Err(SearchError::UnsupportedFeature(format!(
+                "max_results cannot exceed {max_results_limit} for Brave Search"
+            )));
+        }
+    }
+    if params.include_images == Some(true) && search_type != SearchType::Images {
+        return Err(SearchError::UnsupportedFeature(
+            "include-images not supported".to_string(),
+        ));
+    }
+    if params.include_html == Some(true) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-html not supported".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{SuggestResponse, SuggestResult, SummarizerResponse};
+    use crate::conversions::{
+        params_to_request, suggest_request, suggest_response_to_suggestions,
+        summarizer_response_to_answer,
+    };
+    use golem_web_search::test_util::{
+        query_operators, search_params, search_params_with_operators,
+    };
+    use serde_json::json;
+
+    fn summarizer_response(status: &str) -> SummarizerResponse {
+        serde_json::from_value(json!({
+            "type": "summarizer",
+            "status": status,
+            "title": "What is the second highest mountain?",
+            "summary": [
+                {"type": "token", "data": "The second highest mountain is "},
+                {"type": "token", "data": "K2, at 8,611 metres."},
+                {"type": "inline_reference", "data": {
+                    "type": "inline_reference",
+                    "url": "https://en.wikipedia.org/wiki/K2"
+                }},
+                {"type": "token", "data": " "},
+                {"type": "inline_reference", "data": {
+                    "type": "inline_reference",
+                    "url": "https://en.wikipedia.org/wiki/K2"
+                }}
+            ],
+            "enrichments": {
+                "raw": "The second highest mountain is K2, at 8,611 metres.",
+                "images": [],
+                "qa": null,
+                "entities": [],
+                "context": [
+                    {
+                        "title": "K2 - Wikipedia",
+                        "url": "https://en.wikipedia.org/wiki/K2",
+                        "meta_url": {"scheme": "https", "netloc": "en.wikipedia.org"}
+                    },
+                    {
+                        "title": "K2 | Britannica",
+                        "url": "https://www.britannica.com/place/K2",
+                        "meta_url": {"scheme": "https", "netloc": "britannica.com"}
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn converts_summary_to_answer() {
+        let answer = summarizer_response_to_answer(summarizer_response("complete")).unwrap();
+        assert_eq!(
+            answer.text,
+            "The second highest mountain is K2, at 8,611 metres."
+        );
+        assert_eq!(
+            answer.source_urls,
+            vec![
+                "https://en.wikipedia.org/wiki/K2",
+                "https://www.britannica.com/place/K2"
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_raw_summary() {
+        let response: SummarizerResponse = serde_json::from_value(json!({
+            "type": "summarizer",
+            "status": "complete",
+            "summary": [],
+            "enrichments": {"raw": "K2 is 8,611 metres high.", "context": []}
+        }))
+        .unwrap();
+        let answer = summarizer_response_to_answer(response).unwrap();
+        assert_eq!(answer.text, "K2 is 8,611 metres high.");
+        assert!(answer.source_urls.is_empty());
+    }
+
+    #[test]
+    fn returns_no_answer_without_complete_summary() {
+        assert_eq!(
+            summarizer_response_to_answer(summarizer_response("failed")),
+            None
+        );
+
+        let response: SummarizerResponse = serde_json::from_value(json!({
+            "type": "summarizer",
+            "status": "complete",
+            "summary": []
+        }))
+        .unwrap();
+        assert_eq!(summarizer_response_to_answer(response), None);
+    }
+
+    #[test]
+    fn compiles_query_operators_into_query() {
+        let mut params = search_params_with_operators("rust", query_operators());
+        params.exclude_domains = Some(vec!["spam.com".to_string()]);
+        let request = params_to_request(&params, 0).unwrap();
+        assert_eq!(
+            request.query,
+            "(site:rust-lang.org OR site:docs.rs) rust \"borrow checker\" -java filetype:pdf -site:spam.com"
+        );
+    }
+
+    #[test]
+    fn restricts_query_to_included_domains() {
+        let mut params = search_params("rust");
+        params.include_domains = Some(vec!["docs.rs".to_string()]);
+        let request = params_to_request(&params, 0).unwrap();
+        assert_eq!(request.query, "site:docs.rs rust");
+    }
+
+    #[test]
+    fn rejects_empty_suggest_prefix() {
+        assert!(suggest_request(" ", None, None).is_err());
+        let request =
+            suggest_request("rus", Some("EN".to_string()), Some("US".to_string())).unwrap();
+        assert_eq!(request.lang, Some("en".to_string()));
+        assert_eq!(request.country, Some("us".to_string()));
+    }
+
+    #[test]
+    fn keeps_order_of_suggestions_without_duplicates() {
+        let response = SuggestResponse {
+            results: ["rust", "rust lang", " ", "rust"]
+                .iter()
+                .map(|query| SuggestResult {
+                    query: query.to_string(),
+                })
+                .collect(),
+        };
+        assert_eq!(
+            suggest_response_to_suggestions(response),
+            vec!["rust", "rust lang"]
+        );
+    }
+}
@@ -0,0 +1,255 @@
+mod client;
+mod conversions;
+
+use log::warn;
+use std::cell::RefCell;
+
+use crate::client::{BraveSearchApi, SearchResponse};
+use crate::conversions::{
+    params_to_request, response_to_results, suggest_request, suggest_response_to_suggestions,
+    summarizer_response_to_answer, validate_search_params,
+};
+use golem_web_search::cursor::{decode_cursor, encode_cursor};
+use golem_web_search::durability::ExtendedwebsearchGuest;
+use golem_web_search::extract::{extract_with_http, validate_extract_urls};
+use golem_web_search::golem::web_search::types::Answer;
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, Guest, GuestSearchSession, SearchError, SearchMetadata,
+    SearchParams, SearchResult, SearchSession,
+};
+
+/// Identifies the cursors of this provider
+const CURSOR_PROVIDER: &str = "brave";
+
+// Define a custom ReplayState struct
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    golem_rust::FromValueAndType,
+    golem_rust::IntoValue,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct BraveReplayState {
+    /// Not part of the cursors, the resuming worker uses its own configuration
+    #[serde(skip)]
+    pub api_key: String,
+    pub current_offset: u32,
+    pub metadata: Option<SearchMetadata>,
+    pub finished: bool,
+}
+
+struct BraveSearch {
+    client: BraveSearchApi,
+    params: SearchParams,
+    metadata: Option<SearchMetadata>,
+    current_offset: u32,
+    finished: bool,
+}
+
+impl BraveSearch {
+    fn new(client: BraveSearchApi, params: SearchParams) -> Self {
+        Self {
+            client,
+            params,
+            metadata: None,
+            current_offset: 0,
+            finished: false,
+        }
+    }
+
+    fn next_page(&mut self) -> Result<Vec<SearchResult>, SearchError> {
+        if self.finished {
+            return Ok(Vec::new());
+        }
+
+        // Update request with current offset
+        let request = crate::conversions::params_to_request(&self.params, self.current_offset)?;
+
+        let response = self.client.search(request)?;
+        let (results, mut metadata) =
+            response_to_results(&response, &self.params, self.current_offset);
+        metadata.answer = fetch_answer(&self.client, &response);
+
+        self.finished = !response.query.more_results_available;
+        self.current_offset += 1;
+        self.metadata = Some(metadata);
+
+        Ok(results)
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        self.metadata.clone()
+    }
+}
+
+/// Fetches the summary if one was requested for the search. A failing summarizer request does
+/// not fail the search, the results are returned without an answer instead.
+fn fetch_answer(client: &BraveSearchApi, response: &SearchResponse) -> Option<Answer> {
+    let summarizer = response.summarizer.as_ref()?;
+    match client.summarize(&summarizer.key) {
+        Ok(summary) => summarizer_response_to_answer(summary),
+        Err(err) => {
+            warn!("Failed to fetch summary from Brave Summarizer API: {err:?}");
+            None
+        }
+    }
+}
+
+// Create a wrapper that implements GuestSearchSession properly
+pub struct BraveSearchSession(RefCell<BraveSearch>);
+
+impl BraveSearchSession {
+    fn new(search: BraveSearch) -> Self {
+        Self(RefCell::new(search))
+    }
+}
+
+impl GuestSearchSession for BraveSearchSession {
+    fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
+        let mut search = self.0.borrow_mut();
+        search.next_page()
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        let search = self.0.borrow();
+        search.get_metadata()
+    }
+
+    fn get_cursor(&self) -> Result<String, SearchError> {
+        encode_cursor(
+            CURSOR_PROVIDER,
+            &BraveSearchComponent::session_to_state(self),
+        )
+    }
+}
+
+pub struct BraveSearchComponent;
+
+impl BraveSearchComponent {
+    const API_KEY_VAR: &'static str = "BRAVE_API_KEY";
+
+    fn create_client() -> Result<BraveSearchApi, SearchError> {
+        let api_key = Self::get_api_key()?;
+        Ok(BraveSearchApi::new(api_key))
+    }
+
+    fn get_api_key() -> Result<String, SearchError> {
+        std::env::var(Self::API_KEY_VAR).map_err(|_| {
+            SearchError::BackendError("BRAVE_API_KEY environment variable not set".to_string())
+        })
+    }
+
+    fn execute_search(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, SearchMetadata), SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let request = params_to_request(&params, 0)?;
+
+        let response = client.search(request)?;
+        let (results, mut metadata) = response_to_results(&response, &params, 0);
+        metadata.answer = fetch_answer(&client, &response);
+
+        Ok((results, metadata))
+    }
+
+    fn start_search_session(params: SearchParams) -> Result<BraveSearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let search = BraveSearch::new(client, params);
+        Ok(BraveSearchSession::new(search))
+    }
+
+    /// Restores a session from the state of a cursor, with the client configured for this worker
+    pub fn resume_session(
+        state: &BraveReplayState,
+        params: SearchParams,
+    ) -> Result<BraveSearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let session = Self::session_from_state(state, params)?;
+        session.0.borrow_mut().client = Self::create_client()?;
+        Ok(session)
+    }
+}
+
+impl Guest for BraveSearchComponent {
+    type SearchSession = BraveSearchSession;
+
+    fn start_search(params: SearchParams) -> Result<SearchSession, SearchError> {
+        Self::start_search_session(params).map(SearchSession::new)
+    }
+
+    fn resume_search(params: SearchParams, cursor: String) -> Result<SearchSession, SearchError> {
+        Self::unwrapped_resume_search(params, cursor).map(SearchSession::new)
+    }
+
+    fn search_once(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        let (results, metadata) = Self::execute_search(params)?;
+        Ok((results, Some(metadata)))
+    }
+
+    fn suggest(
+        prefix: String,
+        language: Option<String>,
+        region: Option<String>,
+    ) -> Result<Vec<String>, SearchError> {
+        let request = suggest_request(&prefix, language, region)?;
+        let client = Self::create_client()?;
+        let response = client.suggest(request)?;
+        Ok(suggest_response_to_suggestions(response))
+    }
+
+    fn extract(
+        urls: Vec<String>,
+        options: ExtractOptions,
+    ) -> Result<Vec<ExtractResult>, SearchError> {
+        validate_extract_urls(&urls)?;
+        extract_with_http(&urls, &options)
+    }
+}
+
+// ExtendedwebsearchGuest implementation
+impl ExtendedwebsearchGuest for BraveSearchComponent {
+    type ReplayState = BraveReplayState;
+
+    fn unwrapped_search_session(params: SearchParams) -> Result<Self::SearchSession, SearchError> {
+        Self::start_search_session(params)
+    }
+
+    fn unwrapped_resume_search(
+        params: SearchParams,
+        cursor: String,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let state: BraveReplayState = decode_cursor(CURSOR_PROVIDER, &cursor)?;
+        Self::resume_session(&state, params)
+    }
+
+    fn session_to_state(session: &Self::SearchSession) -> Self::ReplayState {
+        let search = session.0.borrow();
+        BraveReplayState {
+            api_key: search.client.api_key().clone(),
+            current_offset: search.current_offset,
+            metadata: search.metadata.clone(),
+            finished: search.finished,
+        }
+    }
+
+    fn session_from_state(
+        state: &Self::ReplayState,
+        params: SearchParams,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let client = BraveSearchApi::new(state.api_key.clone());
+        let mut search = BraveSearch::new(client, params);
+        search.current_offset = state.current_offset;
+        search.metadata = state.metadata.clone();
+        search.finished = state.finished;
+        Ok(BraveSearchSession::new(search))
+    }
+}
@@ -0,0 +1,270 @@
+use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::RateLimitInfo;
+use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
+use log::trace;
+use reqwest::{Client, Method, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+const BASE_URL: &str = "https://api.exa.ai";
+
+/// The Exa API client for neural web search with page contents.
+pub struct ExaSearchApi {
+    client: Client,
+    pub api_key: String,
+}
+
+impl ExaSearchApi {
+    pub fn new(api_key: String) -> Self {
+        let client = Client::builder()
+            .user_agent("Golem-Web-Search/1.0")
+            .build()
+            .expect("Failed to initialize HTTP client");
+
+        Self { client, api_key }
+    }
+
+    /// Runs the search using `/search`, or `/findSimilar` if the request has a source URL
+    /// instead of a query
+    pub fn search(&self, request: SearchRequest) -> Result<SearchResponse, SearchError> {
+        let endpoint = if request.url.is_some() {
+            "findSimilar"
+        } else {
+            "search"
+        };
+        trace!("Sending request to Exa API /{endpoint}: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/{endpoint}"))
+            .header("x-api-key", &self.api_key)
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body: SearchResponse = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
+    }
+
+    /// Generates a direct answer to the query, with citations, using `/answer`
+    pub fn answer(&self, query: &str) -> Result<AnswerResponse, SearchError> {
+        trace!("Sending request to Exa API /answer: {query}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/answer"))
+            .header("x-api-key", &self.api_key)
+            .json(&AnswerRequest {
+                query: query.to_string(),
+                text: false,
+            })
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    /// Fetches the contents of the given pages using `/contents`
+    pub fn contents(&self, request: ContentsRequest) -> Result<ContentsResponse, SearchError> {
+        trace!("Sending request to Exa API /contents: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/contents"))
+            .header("x-api-key", &self.api_key)
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    pub fn api_key(&self) -> &String {
+        &self.api_key
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRequest {
+    /// Query for `/search`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Source page for `/findSimilar`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub search_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_results: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_domains: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_domains: Option<Vec<String>>,
+    /// Text the pages have to contain, a single phrase of up to 5 words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_text: Option<Vec<String>>,
+    /// Text the pages must not contain, a single phrase of up to 5 words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_published_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<ContentsOptions>,
+}
+
+/// Page contents to return with each result, the same options as accepted by `/contents`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights: Option<HighlightsOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_characters: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_sentences: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights_per_url: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    pub request_id: Option<String>,
+    pub resolved_search_type: Option<String>,
+    pub results: Vec<SearchResult>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub id: String,
+    pub url: String,
+    pub title: Option<String>,
+    pub published_date: Option<String>,
+    pub author: Option<String>,
+    pub score: Option<f64>,
+    pub text: Option<String>,
+    pub highlights: Option<Vec<String>>,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentsRequest {
+    pub urls: Vec<String>,
+    pub text: TextOptions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub livecrawl: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentsResponse {
+    pub results: Vec<SearchResult>,
+    #[serde(default)]
+    pub statuses: Vec<ContentStatus>,
+}
+
+/// Status of fetching a single page, reported for both successful and failed pages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentStatus {
+    pub id: String,
+    pub status: String,
+    pub error: Option<ContentError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentError {
+    pub tag: Option<String>,
+    pub http_status_code: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AnswerRequest {
+    query: String,
+    text: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerResponse {
+    pub answer: String,
+    #[serde(default)]
+    pub citations: Vec<Citation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Citation {
+    pub url: String,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, SearchError> {
+    let status = response.status();
+    if status.is_success() {
+        let body = response
+            .json::<T>()
+            .map_err(|err| from_reqwest_error("Failed to decode response body", err))?;
+
+        trace!("Received response from Exa API: {body:?}");
+        Ok(body)
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!("Received {status} response from Exa API, retry after {retry_after} seconds");
+        Err(SearchError::RateLimited(retry_after))
+    } else {
+        // Try to parse error response
+        match response.json::<ErrorResponse>() {
+            Ok(error_body) => {
+                trace!("Received {status} response from Exa API: {error_body:?}");
+
+                let search_error = match status.as_u16() {
+                    400 => SearchError::InvalidQuery,
+                    401 => SearchError::BackendError("Invalid API key".to_string()),
+                    402 | 403 => SearchError::BackendError("API key quota exceeded".to_string()),
+                    _ => SearchError::BackendError(format!(
+                        "Request failed with {}: {}",
+                        status, error_body.error
+                    )),
+                };
+
+                Err(search_error)
+            }
+            Err(_) => {
+                // Fallback for non-JSON error responses
+                Err(SearchError::BackendError(format!(
+                    "Request failed with status {status}"
+                )))
+            }
+        }
+    }
+}
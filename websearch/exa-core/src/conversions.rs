@@ -0,0 +1,671 @@
+use crate::client::{
+    AnswerResponse, ContentsOptions, ContentsRequest, ContentsResponse, HighlightsOptions,
+    SearchRequest, SearchResponse, SearchResult as ExaSearchResult, TextOptions,
+};
+use golem_web_search::extract::{empty_metadata, truncate_content};
+use golem_web_search::golem::web_search::types::{
+    Answer, ContentFormat, ExtractFailure, ExtractedPage, ImageResult, PageMetadata,
+    QueryOperators, SafeSearchLevel, SearchType, TimeRange,
+};
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, SearchError, SearchMetadata, SearchParams, SearchResult,
+};
+use golem_web_search::query::{excluded_sites, excluded_terms, included_sites, required_phrases};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default number of results, Exa does not support pagination so a session returns a single page
+const DEFAULT_NUM_RESULTS: u32 = 10;
+
+/// Default limit for the page text returned in the content chunks
+const DEFAULT_MAX_CHARACTERS: u32 = 4000;
+
+/// Maximum number of words of the `includeText` and `excludeText` phrases
+const TEXT_FILTER_MAX_WORDS: usize = 5;
+
+/// Maximum length of the snippet when it is taken from the page text
+const SNIPPET_MAX_CHARACTERS: usize = 300;
+
+/// Exa specific options, passed in the `provider-options` of the search parameters
+#[derive(Debug, Clone, Default)]
+pub struct ExaOptions {
+    /// `auto`, `neural`, `keyword` or `fast`
+    pub search_type: Option<String>,
+    /// Data category to focus on, for example `research paper`, `news` or `company`
+    pub category: Option<String>,
+    /// Finds pages similar to this URL with `/findSimilar` instead of searching for the query
+    pub find_similar_url: Option<String>,
+    /// Whether to include the page text in the content chunks, `true` by default
+    pub include_text: bool,
+    /// Limit for the page text
+    pub max_characters: u32,
+    /// Number of sentences per highlight
+    pub num_sentences: Option<u32>,
+    /// Number of highlights per result
+    pub highlights_per_url: Option<u32>,
+}
+
+impl ExaOptions {
+    pub fn from_params(params: &SearchParams) -> Result<Self, SearchError> {
+        let mut options = ExaOptions {
+            include_text: true,
+            max_characters: DEFAULT_MAX_CHARACTERS,
+            ..Default::default()
+        };
+
+        for kv in params.provider_options.iter().flatten() {
+            match kv.key.as_str() {
+                "type" => options.search_type = Some(kv.value.clone()),
+                "category" => options.category = Some(kv.value.clone()),
+                "find_similar_url" => options.find_similar_url = Some(kv.value.clone()),
+                "include_text" => options.include_text = parse_option(&kv.key, &kv.value)?,
+                "max_characters" => options.max_characters = parse_option(&kv.key, &kv.value)?,
+                "num_sentences" => options.num_sentences = Some(parse_option(&kv.key, &kv.value)?),
+                "highlights_per_url" => {
+                    options.highlights_per_url = Some(parse_option(&kv.key, &kv.value)?)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_option<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, SearchError> {
+    value.parse().map_err(|_| {
+        SearchError::UnsupportedFeature(format!("Invalid value for provider option {key}: {value}"))
+    })
+}
+
+pub fn params_to_request(params: &SearchParams) -> Result<SearchRequest, SearchError> {
+    let options = ExaOptions::from_params(params)?;
+
+    // The query is optional when searching for similar pages, but it still focuses the highlights
+    let query = if params.query.trim().is_empty() {
+        None
+    } else {
+        Some(params.query.clone())
+    };
+    if query.is_none() && options.find_similar_url.is_none() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    let start_published_date = params
+        .time_range
+        .map(|range| time_range_to_start_date(range, now_seconds()));
+
+    let moderation = params.safe_search.and_then(|safe| match safe {
+        SafeSearchLevel::Off => None,
+        SafeSearchLevel::Medium | SafeSearchLevel::High => Some(true),
+    });
+
+    let contents = ContentsOptions {
+        text: options.include_text.then_some(TextOptions {
+            max_characters: Some(options.max_characters),
+        }),
+        highlights: Some(HighlightsOptions {
+            query: query.clone(),
+            num_sentences: options.num_sentences,
+            highlights_per_url: options.highlights_per_url,
+        }),
+    };
+
+    let (query, url) = match options.find_similar_url {
+        Some(url) => (None, Some(url)),
+        None => (query, None),
+    };
+
+    Ok(SearchRequest {
+        query,
+        url,
+        search_type: options.search_type,
+        // An explicit category option takes precedence over the news search type
+        category: options.category.or(match params.search_type {
+            Some(SearchType::News) => Some("news".to_string()),
+            _ => None,
+        }),
+        num_results: Some(params.max_results.unwrap_or(DEFAULT_NUM_RESULTS)),
+        include_domains: Some(included_sites(params)).filter(|d| !d.is_empty()),
+        exclude_domains: Some(excluded_sites(params)).filter(|d| !d.is_empty()),
+        include_text: params
+            .query_operators
+            .as_ref()
+            .map(required_phrases)
+            .filter(|text| !text.is_empty()),
+        exclude_text: params
+            .query_operators
+            .as_ref()
+            .map(excluded_terms)
+            .filter(|text| !text.is_empty()),
+        start_published_date,
+        user_location: params.region.clone(),
+        moderation,
+        contents: Some(contents),
+    })
+}
+
+pub fn response_to_results(
+    response: SearchResponse,
+    original_params: &SearchParams,
+) -> (Vec<SearchResult>, SearchMetadata) {
+    let include_images = original_params.include_images == Some(true);
+    let is_news = original_params.search_type == Some(SearchType::News);
+    let metadata = create_search_metadata(&response, original_params);
+
+    let results = response
+        .results
+        .into_iter()
+        .map(|item| exa_result_to_search_result(item, include_images, is_news))
+        .collect();
+
+    (results, metadata)
+}
+
+fn exa_result_to_search_result(
+    item: ExaSearchResult,
+    include_images: bool,
+    is_news: bool,
+) -> SearchResult {
+    let highlights = item
+        .highlights
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|highlight| !highlight.trim().is_empty())
+        .collect::<Vec<_>>();
+    let text = item.text.filter(|text| !text.trim().is_empty());
+
+    let snippet = highlights
+        .first()
+        .cloned()
+        .or_else(|| text.as_deref().map(truncate_snippet))
+        .unwrap_or_default();
+
+    // Highlights first, followed by the full page text
+    let mut chunks = highlights;
+    if let Some(text) = text {
+        chunks.push(text);
+    }
+    let content_chunks = if chunks.is_empty() {
+        None
+    } else {
+        Some(chunks)
+    };
+
+    let images = if include_images {
+        item.image.map(|url| {
+            vec![ImageResult {
+                url,
+                description: None,
+            }]
+        })
+    } else {
+        None
+    };
+
+    let source_outlet = if is_news {
+        extract_domain(&item.url)
+    } else {
+        None
+    };
+
+    SearchResult {
+        title: item.title.unwrap_or_default(),
+        display_url: extract_domain(&item.url),
+        url: item.url,
+        snippet,
+        source: item.author,
+        score: item.score.map(|score| score.clamp(0.0, 1.0)),
+        html_snippet: None,
+        date_published: item.published_date,
+        images,
+        content_chunks,
+        thumbnail: None,
+        source_outlet,
+        duration: None,
+        location: None,
+    }
+}
+
+fn truncate_snippet(text: &str) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(SNIPPET_MAX_CHARACTERS) {
+        Some((idx, _)) => format!("{}...", &text[..idx]),
+        None => text.to_string(),
+    }
+}
+
+fn extract_domain(url: &str) -> Option<String> {
+    if let Ok(parsed_url) = url::Url::parse(url) {
+        parsed_url.host_str().map(|host| {
+            // Remove www. prefix if present
+            if let Some(stripped) = host.strip_prefix("www.") {
+                stripped.to_string()
+            } else {
+                host.to_string()
+            }
+        })
+    } else {
+        None
+    }
+}
+
+fn create_search_metadata(response: &SearchResponse, params: &SearchParams) -> SearchMetadata {
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results: Some(response.results.len() as u64),
+        search_time_ms: None,
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token: None,
+        rate_limits: response.rate_limits,
+        current_page: 0,
+        answer: None,
+        cache_status: None,
+        failed_providers: None,
+    }
+}
+
+pub fn answer_response_to_answer(response: AnswerResponse) -> Option<Answer> {
+    if response.answer.trim().is_empty() {
+        return None;
+    }
+
+    let mut source_urls: Vec<String> = Vec::new();
+    for citation in response.citations {
+        if !source_urls.contains(&citation.url) {
+            source_urls.push(citation.url);
+        }
+    }
+
+    Some(Answer {
+        text: response.answer,
+        source_urls,
+    })
+}
+
+pub fn extract_options_to_request(urls: Vec<String>, options: &ExtractOptions) -> ContentsRequest {
+    let livecrawl = options
+        .provider_options
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == "livecrawl")
+        .map(|kv| kv.value.clone());
+
+    ContentsRequest {
+        urls,
+        text: TextOptions {
+            max_characters: options.max_characters,
+        },
+        livecrawl,
+    }
+}
+
+/// Converts the page contents in the order of the requested URLs. Exa returns the cleaned up
+/// page text, so the format is always text.
+pub fn contents_response_to_results(
+    urls: &[String],
+    response: ContentsResponse,
+    options: &ExtractOptions,
+) -> Vec<ExtractResult> {
+    let include_images = options.include_images == Some(true);
+
+    urls.iter()
+        .map(|url| {
+            let result = response
+                .results
+                .iter()
+                .find(|result| &result.url == url || &result.id == url);
+            match result {
+                Some(result) => ExtractResult::Success(ExtractedPage {
+                    url: url.clone(),
+                    title: result.title.clone(),
+                    content: truncate_content(
+                        result.text.as_deref().unwrap_or_default(),
+                        options.max_characters,
+                    ),
+                    format: ContentFormat::Text,
+                    metadata: PageMetadata {
+                        author: result.author.clone(),
+                        date_published: result.published_date.clone(),
+                        ..empty_metadata()
+                    },
+                    images: if include_images {
+                        Some(
+                            result
+                                .image
+                                .iter()
+                                .map(|image| ImageResult {
+                                    url: image.clone(),
+                                    description: None,
+                                })
+                                .collect(),
+                        )
+                    } else {
+                        None
+                    },
+                }),
+                None => {
+                    let error = response
+                        .statuses
+                        .iter()
+                        .find(|status| &status.id == url)
+                        .and_then(|status| status.error.as_ref());
+                    let message = match error {
+                        Some(error) => format!(
+                            "Failed to fetch page: {}{}",
+                            error.tag.as_deref().unwrap_or("unknown error"),
+                            error
+                                .http_status_code
+                                .map(|code| format!(" (HTTP {code})"))
+                                .unwrap_or_default()
+                        ),
+                        None => "No content returned by Exa".to_string(),
+                    };
+                    ExtractResult::Failure(ExtractFailure {
+                        url: url.clone(),
+                        message,
+                    })
+                }
+            }
+        })
+        .collect()
+}
+
+/// Converts the time range to the ISO 8601 `startPublishedDate` filter
+fn time_range_to_start_date(range: TimeRange, now_seconds: u64) -> String {
+    let days = match range {
+        TimeRange::Day => 1,
+        TimeRange::Week => 7,
+        TimeRange::Month => 30,
+        TimeRange::Year => 365,
+    };
+    let start = now_seconds.saturating_sub(days * 24 * 60 * 60);
+
+    let (year, month, day) = civil_from_days((start / 86_400) as i64);
+    let seconds_of_day = start % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.000Z",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Converts days since the unix epoch to a (year, month, day) date in the proleptic Gregorian
+/// calendar, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn now_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError> {
+    let options = ExaOptions::from_params(params)?;
+    if params.query.trim().is_empty() && options.find_similar_url.is_none() {
+        return Err(SearchError::InvalidQuery);
+    }
+    if let Some(max_results) = params.max_results {
+        if max_results > 100 {
+            return Err(SearchError::UnsupportedFeature(
+                "max_results cannot exceed 100 for Exa Search".to_string(),
+            ));
+        }
+    }
+    if matches!(
+        params.search_type,
+        Some(SearchType::Images | SearchType::Videos | SearchType::Places)
+    ) {
+        return Err(SearchError::UnsupportedFeature(
+            "only web and news search types are supported".to_string(),
+        ));
+    }
+    if params.include_html == Some(true) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-html not supported".to_string(),
+        ));
+    }
+    if let Some(operators) = &params.query_operators {
+        validate_query_operators(operators)?;
+    }
+    Ok(())
+}
+
+/// Exa filters the page text with a single phrase to include and a single phrase to exclude,
+/// and has no file type filter
+fn validate_query_operators(operators: &QueryOperators) -> Result<(), SearchError> {
+    let required = required_phrases(operators);
+    let excluded = excluded_terms(operators);
+    if required.len() > 1 || excluded.len() > 1 {
+        return Err(SearchError::UnsupportedFeature(
+            "only one required and one excluded phrase are supported".to_string(),
+        ));
+    }
+    if required
+        .iter()
+        .chain(excluded.iter())
+        .any(|phrase| phrase.split_whitespace().count() > TEXT_FILTER_MAX_WORDS)
+    {
+        return Err(SearchError::UnsupportedFeature(format!(
+            "phrases cannot be longer than {TEXT_FILTER_MAX_WORDS} words"
+        )));
+    }
+    if operators.filetype.is_some() {
+        return Err(SearchError::UnsupportedFeature(
+            "filetype not supported".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::SearchResult as ExaSearchResult;
+    use crate::conversions::{
+        exa_result_to_search_result, params_to_request, time_range_to_start_date,
+        validate_search_params,
+    };
+    use golem_web_search::golem::web_search::types::{Kv, TimeRange};
+    use golem_web_search::golem::web_search::web_search::{SearchError, SearchParams};
+    use golem_web_search::test_util::{
+        query_operators, search_params, search_params_with_operators,
+    };
+    use serde_json::json;
+
+    fn find_similar_params(query: &str) -> SearchParams {
+        let mut params = search_params(query);
+        params.provider_options = Some(vec![Kv {
+            key: "find_similar_url".to_string(),
+            value: "https://www.rust-lang.org/".to_string(),
+        }]);
+        params
+    }
+
+    #[test]
+    fn finds_similar_pages_instead_of_searching() {
+        let request = params_to_request(&find_similar_params("memory safety")).unwrap();
+        assert_eq!(request.url.as_deref(), Some("https://www.rust-lang.org/"));
+        assert_eq!(request.query, None);
+        // The query still focuses the highlights of the similar pages
+        let highlights = request.contents.unwrap().highlights.unwrap();
+        assert_eq!(highlights.query.as_deref(), Some("memory safety"));
+
+        let request = params_to_request(&find_similar_params("")).unwrap();
+        assert_eq!(request.url.as_deref(), Some("https://www.rust-lang.org/"));
+        assert!(validate_search_params(&find_similar_params("")).is_ok());
+
+        assert!(matches!(
+            validate_search_params(&search_params(" ")),
+            Err(SearchError::InvalidQuery)
+        ));
+        let request = params_to_request(&search_params("rust")).unwrap();
+        assert_eq!(request.query.as_deref(), Some("rust"));
+        assert_eq!(request.url, None);
+    }
+
+    #[test]
+    fn converts_time_range_to_published_date() {
+        // 2023-11-14T22:13:20Z
+        let now = 1_700_000_000;
+        assert_eq!(
+            time_range_to_start_date(TimeRange::Day, now),
+            "2023-11-13T22:13:20.000Z"
+        );
+        assert_eq!(
+            time_range_to_start_date(TimeRange::Week, now),
+            "2023-11-07T22:13:20.000Z"
+        );
+        assert_eq!(
+            time_range_to_start_date(TimeRange::Month, now),
+            "2023-10-15T22:13:20.000Z"
+        );
+        assert_eq!(
+            time_range_to_start_date(TimeRange::Year, now),
+            "2022-11-14T22:13:20.000Z"
+        );
+
+        let mut params = search_params("rust");
+        assert_eq!(
+            params_to_request(&params).unwrap().start_published_date,
+            None
+        );
+        params.time_range = Some(TimeRange::Week);
+        let start = params_to_request(&params)
+            .unwrap()
+            .start_published_date
+            .unwrap();
+        assert!(start.ends_with(".000Z"));
+    }
+
+    #[test]
+    fn maps_include_and_exclude_domains() {
+        let mut params = search_params("rust");
+        params.include_domains = Some(vec!["rust-lang.org".to_string(), " ".to_string()]);
+        params.exclude_domains = Some(vec!["reddit.com".to_string()]);
+        let request = params_to_request(&params).unwrap();
+        assert_eq!(
+            request.include_domains,
+            Some(vec!["rust-lang.org".to_string()])
+        );
+        assert_eq!(
+            request.exclude_domains,
+            Some(vec!["reddit.com".to_string()])
+        );
+
+        let request = params_to_request(&search_params("rust")).unwrap();
+        assert_eq!(request.include_domains, None);
+        assert_eq!(request.exclude_domains, None);
+    }
+
+    #[test]
+    fn maps_highlights_and_text_to_content_chunks() {
+        let item: ExaSearchResult = serde_json::from_value(json!({
+            "id": "https://www.rust-lang.org/",
+            "url": "https://www.rust-lang.org/",
+            "title": "Rust Programming Language",
+            "publishedDate": "2024-01-01T00:00:00.000Z",
+            "author": "The Rust Team",
+            "score": 0.42,
+            "text": "A language empowering everyone to build reliable and efficient software.",
+            "highlights": [
+                "Rust is blazingly fast and memory-efficient.",
+                " ",
+                "Rust's rich type system and ownership model guarantee memory-safety."
+            ],
+            "image": "https://www.rust-lang.org/static/images/rust-social.jpg"
+        }))
+        .unwrap();
+        let result = exa_result_to_search_result(item.clone(), true, false);
+
+        assert_eq!(result.title, "Rust Programming Language");
+        assert_eq!(result.display_url.as_deref(), Some("rust-lang.org"));
+        assert_eq!(
+            result.snippet,
+            "Rust is blazingly fast and memory-efficient."
+        );
+        assert_eq!(result.source.as_deref(), Some("The Rust Team"));
+        assert_eq!(result.score, Some(0.42));
+        assert_eq!(
+            result.date_published.as_deref(),
+            Some("2024-01-01T00:00:00.000Z")
+        );
+        assert_eq!(
+            result.content_chunks,
+            Some(vec![
+                "Rust is blazingly fast and memory-efficient.".to_string(),
+                "Rust's rich type system and ownership model guarantee memory-safety.".to_string(),
+                "A language empowering everyone to build reliable and efficient software."
+                    .to_string(),
+            ])
+        );
+        assert_eq!(result.images.map(|images| images.len()), Some(1));
+
+        let without_highlights = ExaSearchResult {
+            highlights: None,
+            ..item.clone()
+        };
+        let result = exa_result_to_search_result(without_highlights, false, false);
+        assert_eq!(
+            result.snippet,
+            "A language empowering everyone to build reliable and efficient software."
+        );
+        assert_eq!(result.content_chunks.map(|chunks| chunks.len()), Some(1));
+        assert_eq!(result.images, None);
+
+        let without_content = ExaSearchResult {
+            highlights: Some(vec![]),
+            text: None,
+            ..item
+        };
+        let result = exa_result_to_search_result(without_content, false, true);
+        assert_eq!(result.snippet, "");
+        assert_eq!(result.content_chunks, None);
+        assert_eq!(result.source_outlet.as_deref(), Some("rust-lang.org"));
+    }
+
+    #[test]
+    fn maps_operators_to_text_and_domain_filters() {
+        let mut operators = query_operators();
+        operators.filetype = None;
+        let request = params_to_request(&search_params_with_operators("rust", operators)).unwrap();
+        assert_eq!(
+            request.include_text,
+            Some(vec!["borrow checker".to_string()])
+        );
+        assert_eq!(request.exclude_text, Some(vec!["java".to_string()]));
+        assert_eq!(
+            request.include_domains,
+            Some(vec!["rust-lang.org".to_string(), "docs.rs".to_string()])
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_operators() {
+        assert!(matches!(
+            validate_search_params(&search_params_with_operators("rust", query_operators())),
+            Err(SearchError::UnsupportedFeature(_))
+        ));
+
+        let mut operators = query_operators();
+        operators.filetype = None;
+        operators.must_terms = Some(vec!["lifetimes".to_string()]);
+        assert!(matches!(
+            validate_search_params(&search_params_with_operators("rust", operators)),
+            Err(SearchError::UnsupportedFeature(_))
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+use golem_web_search::durability::Durablewebsearch;
+use golem_web_search_exa_core::ExaSearchComponent;
+
+type DurableExaComponent = Durablewebsearch<ExaSearchComponent>;
+golem_web_search::export_websearch!(DurableExaComponent with_types_in golem_web_search);
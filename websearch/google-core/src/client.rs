@@ -1,8 +1,10 @@
 use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::RateLimitInfo;
 use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
 use log::trace;
 use reqwest::Url;
-use reqwest::{Client, Method, Response};
+use reqwest::{Client, Method, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 
 const BASE_URL: &str = "https://www.googleapis.com/customsearch/v1";
@@ -61,7 +63,7 @@ impl GoogleSearchApi {
             if let Some(site_search_filter) = &request.site_search_filter {
                 query_pairs.append_pair("siteSearchFilter", site_search_filter);
             }
-            if request.img_type.is_some() || request.img_size.is_some() {
+            if request.image_search || request.img_type.is_some() || request.img_size.is_some() {
                 query_pairs.append_pair("searchType", "image");
                 if let Some(img_type) = &request.img_type {
                     query_pairs.append_pair("imgType", img_type);
@@ -77,7 +79,10 @@ impl GoogleSearchApi {
             .send()
             .map_err(|err| from_reqwest_error("Failed to send request", err))?;
 
-        parse_response(response)
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
     }
 
     pub fn api_key(&self) -> &String {
@@ -100,6 +105,7 @@ pub struct SearchRequest {
     pub date_restrict: Option<String>,
     pub site_search: Option<String>,
     pub site_search_filter: Option<String>,
+    pub image_search: bool,
     pub img_type: Option<String>,
     pub img_size: Option<String>,
 }
@@ -112,6 +118,8 @@ pub struct SearchResponse {
     pub results: Vec<SearchResult>,
     pub next_page: Option<NextPage>,
     pub previous_page: Option<PreviousPage>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -120,6 +128,16 @@ pub struct SearchResult {
     pub url: String,
     pub content: String,
     pub published_date: Option<String>,
+    /// Only returned by image search, the `url` is the image itself in that case
+    pub image: Option<ImageDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDetails {
+    /// Page the image was found on
+    pub context_link: Option<String>,
+    pub thumbnail_link: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -161,7 +179,9 @@ struct GoogleSearchInformation {
 struct GoogleSearchItem {
     pub title: String,
     pub link: String,
+    #[serde(default)]
     pub snippet: String,
+    pub image: Option<ImageDetails>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -234,6 +254,7 @@ fn parse_response(response: Response) -> Result<SearchResponse, SearchError> {
                 url: item.link,
                 content: item.snippet,
                 published_date: None, // Google doesn't provide this in basic search
+                image: item.image,
             })
             .collect();
 
@@ -244,7 +265,12 @@ fn parse_response(response: Response) -> Result<SearchResponse, SearchError> {
             results,
             next_page,
             previous_page,
+            rate_limits: None,
         })
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!("Received {status} response from Google Custom Search API, retry after {retry_after} seconds");
+        Err(SearchError::RateLimited(retry_after))
     } else {
         // Try to parse error response
         match response.json::<ErrorResponse>() {
@@ -255,7 +281,6 @@ fn parse_response(response: Response) -> Result<SearchResponse, SearchError> {
                     400 => SearchError::InvalidQuery,
                     401 => SearchError::BackendError("Invalid API key".to_string()),
                     403 => SearchError::BackendError("API key quota exceeded".to_string()),
-                    429 => SearchError::RateLimited(60), // Default to 60 seconds
                     _ => SearchError::BackendError(format!(
                         "Request failed with {}: {}",
                         status, error_body.error.message
@@ -1,8 +1,9 @@
 use crate::client::{SearchRequest, SearchResponse, SearchResult as ClientSearchResult};
-use golem_web_search::golem::web_search::types::SafeSearchLevel;
+use golem_web_search::golem::web_search::types::{ImageResult, SafeSearchLevel, SearchType};
 use golem_web_search::golem::web_search::web_search::{
     SearchError, SearchMetadata, SearchParams, SearchResult,
 };
+use golem_web_search::query::compile_query;
 
 pub fn params_to_request(params: &SearchParams, start: u32) -> Result<SearchRequest, SearchError> {
     // Validate query
@@ -10,27 +11,8 @@ pub fn params_to_request(params: &SearchParams, start: u32) -> Result<SearchRequ
         return Err(SearchError::InvalidQuery);
     }
 
-    // Handle domain filtering in query
-    let mut query = params.query.clone();
-
-    // Add included domains
-    if let Some(include_domains) = &params.include_domains {
-        if !include_domains.is_empty() {
-            let site_filter = include_domains
-                .iter()
-                .map(|domain| format!("site:{domain}"))
-                .collect::<Vec<_>>()
-                .join(" OR ");
-            query = format!("({site_filter}) {query}");
-        }
-    }
-
-    // Add excluded domains
-    if let Some(exclude_domains) = &params.exclude_domains {
-        for domain in exclude_domains {
-            query.push_str(&format!(" -site:{domain}"));
-        }
-    }
+    // The query operators and domain filters are written in Google's query syntax
+    let query = compile_query(params);
 
     Ok(SearchRequest {
         query,
@@ -46,6 +28,7 @@ pub fn params_to_request(params: &SearchParams, start: u32) -> Result<SearchRequ
         date_restrict: None,
         site_search: None,
         site_search_filter: None,
+        image_search: params.search_type == Some(SearchType::Images),
         img_type: None,
         img_size: None,
     })
@@ -83,17 +66,37 @@ fn web_result_to_search_result(item: &ClientSearchResult, index: usize) -> Searc
     // Simple position-based scoring
     let score = 1.0 - (index as f32) * 0.05;
 
+    // Image results link to the image, the result points to the page it was found on instead
+    let (url, images, thumbnail) = match &item.image {
+        Some(image) => (
+            image
+                .context_link
+                .clone()
+                .unwrap_or_else(|| item.url.clone()),
+            Some(vec![ImageResult {
+                url: item.url.clone(),
+                description: Some(item.title.clone()),
+            }]),
+            image.thumbnail_link.clone(),
+        ),
+        None => (item.url.clone(), None, None),
+    };
+
     SearchResult {
         title: item.title.clone(),
-        url: item.url.clone(),
         snippet: item.content.clone(),
-        display_url: extract_domain(&item.url),
-        source: extract_domain(&item.url),
+        display_url: extract_domain(&url),
+        source: extract_domain(&url),
+        url,
         score: Some(score.clamp(0.0, 1.0) as f64),
         html_snippet: None,
         date_published: item.published_date.clone(),
-        images: None,
+        images,
         content_chunks,
+        thumbnail,
+        source_outlet: None,
+        duration: None,
+        location: None,
     }
 }
 
@@ -140,8 +143,11 @@ fn create_search_metadata(
         language: params.language.clone(),
         region: params.region.clone(),
         next_page_token,
-        rate_limits: None,
+        rate_limits: response.rate_limits,
         current_page,
+        answer: None,
+        cache_status: None,
+        failed_providers: None,
     }
 }
 
@@ -156,6 +162,14 @@ pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError>
             ));
         }
     }
+    if matches!(
+        params.search_type,
+        Some(SearchType::News | SearchType::Videos | SearchType::Places)
+    ) {
+        return Err(SearchError::UnsupportedFeature(
+            "only web and images search types are supported".to_string(),
+        ));
+    }
     if params.time_range.is_some() {
         return Err(SearchError::UnsupportedFeature(
             "time-range not supported".to_string(),
@@ -173,3 +187,20 @@ pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError>
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::conversions::params_to_request;
+    use golem_web_search::test_util::{query_operators, search_params_with_operators};
+
+    #[test]
+    fn compiles_query_operators_into_query() {
+        let mut params = search_params_with_operators("rust", query_operators());
+        params.include_domains = Some(vec!["docs.rs".to_string()]);
+        let request = params_to_request(&params, 1).unwrap();
+        assert_eq!(
+            request.query,
+            "(site:docs.rs OR site:rust-lang.org) rust \"borrow checker\" -java filetype:pdf"
+        );
+    }
+}
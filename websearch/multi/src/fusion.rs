@@ -0,0 +1,386 @@
+use golem_web_search::golem::web_search::web_search::{SearchMetadata, SearchParams, SearchResult};
+use std::collections::{HashMap, HashSet};
+
+/// Constant of the reciprocal rank fusion, dampens the advantage of the top ranked results
+const RRF_K: f64 = 60.0;
+
+/// Query parameters which only track the visitor and do not change the page
+const TRACKING_PARAMS: [&str; 5] = ["gclid", "fbclid", "msclkid", "ref", "ref_src"];
+
+/// Normalizes the URL for deduplication, so the same page returned by different providers with
+/// a different scheme, `www.` prefix, trailing slash, fragment or tracking parameters is only
+/// returned once.
+pub fn normalize_url(url: &str) -> String {
+    let Ok(parsed) = url::Url::parse(url.trim()) else {
+        return url.trim().to_lowercase();
+    };
+
+    let host = parsed.host_str().unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let path = parsed.path().trim_end_matches('/');
+    let query = parsed
+        .query_pairs()
+        .filter(|(key, _)| !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_ref()))
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut normalized = format!("{host}{path}");
+    if !query.is_empty() {
+        normalized.push('?');
+        normalized.push_str(&query);
+    }
+    normalized
+}
+
+struct FusedResult {
+    result: SearchResult,
+    score: f64,
+    providers: Vec<String>,
+}
+
+/// Fused results of a search session which were not returned yet, and the normalized URLs of
+/// all results fused so far.
+///
+/// A page of the providers usually fuses into more results than fit on a page of the session.
+/// The rest is buffered and returned before the providers are queried for their next page.
+#[derive(Default)]
+pub struct FusedPages {
+    pub seen_urls: HashSet<String>,
+    pub pending: Vec<SearchResult>,
+}
+
+impl FusedPages {
+    /// Whether the buffered results do not fill a page of `max_results`
+    pub fn needs_more(&self, max_results: usize) -> bool {
+        self.pending.len() < max_results
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Fuses the next pages of the providers, buffering the results after the ones not returned yet
+    pub fn push(&mut self, provider_results: Vec<(String, Vec<SearchResult>)>) {
+        let results = fuse_results(provider_results, &mut self.seen_urls);
+        self.pending.extend(results);
+    }
+
+    /// Returns the next `max_results` buffered results
+    pub fn next_page(&mut self, max_results: usize) -> Vec<SearchResult> {
+        let count = max_results.min(self.pending.len());
+        self.pending.drain(..count).collect()
+    }
+}
+
+/// Fuses the ranked result lists of the providers with reciprocal rank fusion.
+///
+/// Results with the same normalized URL are merged, keeping the first provider's result and
+/// filling its missing fields from the others. The providers which returned a result are listed
+/// in its `source`. Results already fused from a previous page, tracked in `seen_urls`, are
+/// skipped, and the URLs of the returned results are added to it.
+pub fn fuse_results(
+    provider_results: Vec<(String, Vec<SearchResult>)>,
+    seen_urls: &mut HashSet<String>,
+) -> Vec<SearchResult> {
+    let provider_count = provider_results.len().max(1);
+    let mut fused: Vec<FusedResult> = Vec::new();
+    let mut index_by_url: HashMap<String, usize> = HashMap::new();
+
+    for (provider, results) in provider_results {
+        for (rank, result) in results.into_iter().enumerate() {
+            let key = normalize_url(&result.url);
+            if seen_urls.contains(&key) {
+                continue;
+            }
+
+            let score = 1.0 / (RRF_K + (rank + 1) as f64);
+            match index_by_url.get(&key) {
+                Some(&index) => {
+                    let existing = &mut fused[index];
+                    // A provider returning the same page twice only counts once
+                    if !existing.providers.contains(&provider) {
+                        existing.score += score;
+                        existing.providers.push(provider.clone());
+                    }
+                    merge_result(&mut existing.result, result);
+                }
+                None => {
+                    index_by_url.insert(key, fused.len());
+                    fused.push(FusedResult {
+                        result,
+                        score,
+                        providers: vec![provider.clone()],
+                    });
+                }
+            }
+        }
+    }
+
+    // Stable sort, so equally scored results keep the order of the configured providers
+    fused.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    // The best possible score is being ranked first by every provider
+    let max_score = provider_count as f64 / (RRF_K + 1.0);
+
+    fused
+        .into_iter()
+        .map(|fused| {
+            seen_urls.insert(normalize_url(&fused.result.url));
+            SearchResult {
+                source: Some(fused.providers.join(", ")),
+                score: Some((fused.score / max_score).clamp(0.0, 1.0)),
+                ..fused.result
+            }
+        })
+        .collect()
+}
+
+fn merge_result(target: &mut SearchResult, other: SearchResult) {
+    if target.snippet.is_empty() {
+        target.snippet = other.snippet;
+    }
+    target.display_url = target.display_url.take().or(other.display_url);
+    target.html_snippet = target.html_snippet.take().or(other.html_snippet);
+    target.date_published = target.date_published.take().or(other.date_published);
+    target.images = target.images.take().or(other.images);
+    target.content_chunks = target.content_chunks.take().or(other.content_chunks);
+    target.thumbnail = target.thumbnail.take().or(other.thumbnail);
+    target.source_outlet = target.source_outlet.take().or(other.source_outlet);
+    target.duration = target.duration.take().or(other.duration);
+    target.location = target.location.take().or(other.location);
+}
+
+/// Merges the metadata of the providers which returned a page. The answer is taken from the first
+/// provider returning one, the rate limit from the provider closest to its limit.
+pub fn merge_metadata(
+    params: &SearchParams,
+    provider_metadata: Vec<SearchMetadata>,
+    current_page: u32,
+    has_more: bool,
+    failed_providers: &[String],
+) -> SearchMetadata {
+    let total_results = provider_metadata
+        .iter()
+        .filter_map(|metadata| metadata.total_results)
+        .max();
+    let search_time_ms = provider_metadata
+        .iter()
+        .filter_map(|metadata| metadata.search_time_ms)
+        .reduce(f64::max);
+    let rate_limits = provider_metadata
+        .iter()
+        .filter_map(|metadata| metadata.rate_limits)
+        .min_by_key(|rate_limits| rate_limits.remaining);
+    let answer = provider_metadata
+        .into_iter()
+        .find_map(|metadata| metadata.answer);
+
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results,
+        search_time_ms,
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token: next_page_token(current_page, has_more),
+        rate_limits,
+        current_page,
+        answer,
+        cache_status: None,
+        failed_providers: reported_failures(failed_providers),
+    }
+}
+
+/// Lists the failed providers in the metadata, or leaves the field unset if none failed
+pub fn reported_failures(failed_providers: &[String]) -> Option<Vec<String>> {
+    if failed_providers.is_empty() {
+        None
+    } else {
+        Some(failed_providers.to_vec())
+    }
+}
+
+pub fn next_page_token(current_page: u32, has_more: bool) -> Option<String> {
+    if has_more {
+        Some((current_page + 1).to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuse_results, merge_metadata, normalize_url, FusedPages};
+    use golem_web_search::golem::web_search::web_search::{SearchMetadata, SearchResult};
+    use golem_web_search::test_util::search_params;
+    use std::collections::HashSet;
+
+    fn result(url: &str) -> SearchResult {
+        SearchResult {
+            title: url.to_string(),
+            url: url.to_string(),
+            snippet: String::new(),
+            display_url: None,
+            source: None,
+            score: None,
+            html_snippet: None,
+            date_published: None,
+            images: None,
+            content_chunks: None,
+            thumbnail: None,
+            source_outlet: None,
+            duration: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn normalize_url_ignores_presentation_differences() {
+        assert_eq!(
+            normalize_url("https://www.example.com/page/?utm_source=x&id=1#top"),
+            normalize_url("http://example.com/page?id=1")
+        );
+        assert_ne!(
+            normalize_url("https://example.com/page?id=1"),
+            normalize_url("https://example.com/page?id=2")
+        );
+    }
+
+    #[test]
+    fn fuse_results_ranks_shared_results_first() {
+        let mut seen = HashSet::new();
+        let fused = fuse_results(
+            vec![
+                (
+                    "brave".to_string(),
+                    vec![result("https://a.com"), result("https://b.com")],
+                ),
+                (
+                    "serper".to_string(),
+                    vec![result("https://c.com"), result("https://www.b.com/")],
+                ),
+            ],
+            &mut seen,
+        );
+
+        let urls = fused.iter().map(|r| r.url.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec!["https://b.com", "https://a.com", "https://c.com"]
+        );
+        assert_eq!(fused[0].source.as_deref(), Some("brave, serper"));
+        assert_eq!(fused[1].source.as_deref(), Some("brave"));
+    }
+
+    #[test]
+    fn fuse_results_skips_already_returned_results() {
+        let mut seen = HashSet::new();
+        let first = fuse_results(
+            vec![("brave".to_string(), vec![result("https://a.com")])],
+            &mut seen,
+        );
+        let second = fuse_results(
+            vec![(
+                "brave".to_string(),
+                vec![result("https://a.com/"), result("https://b.com")],
+            )],
+            &mut seen,
+        );
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].url, "https://b.com");
+    }
+
+    #[test]
+    fn fused_pages_buffer_results_beyond_the_page() {
+        let mut pages = FusedPages::default();
+        pages.push(vec![
+            (
+                "brave".to_string(),
+                vec![
+                    result("https://a.com"),
+                    result("https://b.com"),
+                    result("https://c.com"),
+                ],
+            ),
+            (
+                "serper".to_string(),
+                vec![
+                    result("https://c.com"),
+                    result("https://d.com"),
+                    result("https://e.com"),
+                ],
+            ),
+        ]);
+
+        let first = pages.next_page(2);
+        assert!(!pages.needs_more(2));
+        let second = pages.next_page(2);
+        assert!(pages.needs_more(2));
+
+        pages.push(vec![
+            ("brave".to_string(), vec![result("https://f.com")]),
+            (
+                "serper".to_string(),
+                vec![result("https://a.com/"), result("https://g.com")],
+            ),
+        ]);
+        let third = pages.next_page(2);
+        let fourth = pages.next_page(2);
+        assert!(!pages.has_pending());
+
+        let urls = [first, second, third, fourth]
+            .concat()
+            .into_iter()
+            .map(|r| r.url)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec![
+                "https://c.com",
+                "https://a.com",
+                "https://b.com",
+                "https://d.com",
+                "https://e.com",
+                "https://f.com",
+                "https://g.com",
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_metadata_reports_failed_providers() {
+        let params = search_params("rust");
+        let provider_metadata = SearchMetadata {
+            query: "rust".to_string(),
+            total_results: Some(100),
+            search_time_ms: None,
+            safe_search: None,
+            language: None,
+            region: None,
+            next_page_token: Some("1".to_string()),
+            rate_limits: None,
+            current_page: 0,
+            answer: None,
+            cache_status: None,
+            failed_providers: None,
+        };
+
+        let metadata = merge_metadata(
+            &params,
+            vec![provider_metadata.clone()],
+            0,
+            true,
+            &["tavily".to_string()],
+        );
+        assert_eq!(metadata.total_results, Some(100));
+        assert_eq!(metadata.next_page_token.as_deref(), Some("1"));
+        assert_eq!(metadata.failed_providers, Some(vec!["tavily".to_string()]));
+
+        let metadata = merge_metadata(&params, vec![provider_metadata], 0, false, &[]);
+        assert_eq!(metadata.next_page_token, None);
+        assert_eq!(metadata.failed_providers, None);
+    }
+}
@@ -0,0 +1,473 @@
+mod fusion;
+mod providers;
+
+use crate::fusion::{fuse_results, merge_metadata, next_page_token, reported_failures, FusedPages};
+use crate::providers::{Provider, ProviderSession, ProviderStates};
+use golem_web_search::cursor::{decode_cursor, encode_cursor};
+use golem_web_search::durability::Durablewebsearch;
+use golem_web_search::durability::ExtendedwebsearchGuest;
+use golem_web_search::extract::validate_extract_urls;
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, Guest, GuestSearchSession, SearchError, SearchMetadata,
+    SearchParams, SearchResult, SearchSession,
+};
+use log::warn;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+/// Default number of fused results on a page
+const DEFAULT_MAX_RESULTS: u32 = 10;
+
+/// Identifies the cursors of this component
+const CURSOR_PROVIDER: &str = "multi";
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    golem_rust::FromValueAndType,
+    golem_rust::IntoValue,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct MultiReplayState {
+    /// Providers of the session in the configured order
+    pub providers: Vec<String>,
+    /// Providers which returned all their results or failed
+    pub finished: Vec<String>,
+    /// Providers which failed and were left out of the session
+    pub failed: Vec<String>,
+    pub states: ProviderStates,
+    /// Normalized URLs of the results fused on the previous pages
+    pub seen_urls: Vec<String>,
+    /// Fused results which did not fit on the previous pages
+    pub pending: Vec<SearchResult>,
+    pub current_page: u32,
+    pub metadata: Option<SearchMetadata>,
+}
+
+struct ProviderEntry {
+    provider: Provider,
+    session: ProviderSession,
+    finished: bool,
+}
+
+struct MultiSearch {
+    params: SearchParams,
+    providers: Vec<ProviderEntry>,
+    failed: Vec<String>,
+    pages: FusedPages,
+    current_page: u32,
+    metadata: Option<SearchMetadata>,
+}
+
+impl MultiSearch {
+    fn new(params: SearchParams, providers: Vec<ProviderEntry>, failed: Vec<String>) -> Self {
+        Self {
+            params,
+            providers,
+            failed,
+            pages: FusedPages::default(),
+            current_page: 0,
+            metadata: None,
+        }
+    }
+
+    fn next_page(&mut self) -> Result<Vec<SearchResult>, SearchError> {
+        let max_results = max_results(&self.params);
+        let mut provider_metadata = Vec::new();
+
+        // The providers are only queried once the results buffered from their previous pages
+        // do not fill the page anymore
+        if self.pages.needs_more(max_results) && self.has_unfinished_providers() {
+            let (provider_results, first_error) = self.query_providers(&mut provider_metadata);
+            if provider_results.is_empty() && !self.pages.has_pending() {
+                return match first_error {
+                    Some(error) => Err(error),
+                    None => Ok(Vec::new()),
+                };
+            }
+            self.pages.push(provider_results);
+        }
+
+        let results = self.pages.next_page(max_results);
+        let has_more = self.pages.has_pending() || self.has_unfinished_providers();
+        self.metadata = Some(match self.metadata.take() {
+            // The page was served from the buffer, so only the page changes
+            Some(previous) if provider_metadata.is_empty() => SearchMetadata {
+                current_page: self.current_page,
+                next_page_token: next_page_token(self.current_page, has_more),
+                failed_providers: reported_failures(&self.failed),
+                ..previous
+            },
+            _ => merge_metadata(
+                &self.params,
+                provider_metadata,
+                self.current_page,
+                has_more,
+                &self.failed,
+            ),
+        });
+        self.current_page += 1;
+        Ok(results)
+    }
+
+    /// Fetches the next page of each unfinished provider. Components are single threaded, so
+    /// the providers are queried one after the other. A failing provider is dropped from the
+    /// session instead of failing the whole page, it is reported in the metadata and its error is
+    /// returned with the results.
+    fn query_providers(
+        &mut self,
+        provider_metadata: &mut Vec<SearchMetadata>,
+    ) -> (Vec<(String, Vec<SearchResult>)>, Option<SearchError>) {
+        let mut provider_results = Vec::new();
+        let mut first_error = None;
+
+        for entry in self.providers.iter_mut().filter(|entry| !entry.finished) {
+            match entry.session.next_page() {
+                Ok(results) => {
+                    let metadata = entry.session.get_metadata();
+                    entry.finished = results.is_empty()
+                        || metadata
+                            .as_ref()
+                            .is_none_or(|metadata| metadata.next_page_token.is_none());
+                    provider_metadata.extend(metadata);
+                    provider_results.push((entry.provider.name().to_string(), results));
+                }
+                Err(error) => {
+                    warn!(
+                        "Web search provider {} failed, continuing without it: {error:?}",
+                        entry.provider.name()
+                    );
+                    entry.finished = true;
+                    self.failed.push(entry.provider.name().to_string());
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        (provider_results, first_error)
+    }
+
+    fn has_unfinished_providers(&self) -> bool {
+        self.providers.iter().any(|entry| !entry.finished)
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        self.metadata.clone()
+    }
+}
+
+fn max_results(params: &SearchParams) -> usize {
+    params.max_results.unwrap_or(DEFAULT_MAX_RESULTS) as usize
+}
+
+pub struct MultiSearchSession(RefCell<MultiSearch>);
+
+impl MultiSearchSession {
+    fn new(search: MultiSearch) -> Self {
+        Self(RefCell::new(search))
+    }
+}
+
+impl GuestSearchSession for MultiSearchSession {
+    fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
+        let mut search = self.0.borrow_mut();
+        search.next_page()
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        let search = self.0.borrow();
+        search.get_metadata()
+    }
+
+    fn get_cursor(&self) -> Result<String, SearchError> {
+        encode_cursor(
+            CURSOR_PROVIDER,
+            &MultiSearchComponent::session_to_state(self),
+        )
+    }
+}
+
+struct MultiSearchComponent;
+
+impl MultiSearchComponent {
+    const PROVIDERS_VAR: &'static str = "WEB_SEARCH_PROVIDERS";
+
+    /// Reads the comma separated list of providers to fuse, for example `brave,serper`
+    fn configured_providers() -> Result<Vec<Provider>, SearchError> {
+        let value = std::env::var(Self::PROVIDERS_VAR).map_err(|_| {
+            SearchError::BackendError(
+                "WEB_SEARCH_PROVIDERS environment variable not set".to_string(),
+            )
+        })?;
+
+        let mut providers = Vec::new();
+        for name in value.split(',').filter(|name| !name.trim().is_empty()) {
+            let provider = Provider::from_name(name).ok_or_else(|| {
+                SearchError::BackendError(format!("Unknown web search provider {}", name.trim()))
+            })?;
+            if !providers.contains(&provider) {
+                providers.push(provider);
+            }
+        }
+
+        if providers.is_empty() {
+            return Err(SearchError::BackendError(
+                "WEB_SEARCH_PROVIDERS environment variable does not list any providers".to_string(),
+            ));
+        }
+        Ok(providers)
+    }
+
+    fn execute_search(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, SearchMetadata), SearchError> {
+        let mut provider_results = Vec::new();
+        let mut provider_metadata = Vec::new();
+        let mut failed = Vec::new();
+        let mut first_error = None;
+
+        for provider in Self::configured_providers()? {
+            match provider.search_once(params.clone()) {
+                Ok((results, metadata)) => {
+                    provider_metadata.extend(metadata);
+                    provider_results.push((provider.name().to_string(), results));
+                }
+                Err(error) => {
+                    warn!(
+                        "Web search provider {} failed, continuing without it: {error:?}",
+                        provider.name()
+                    );
+                    failed.push(provider.name().to_string());
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        if provider_results.is_empty() {
+            return Err(first_error.unwrap_or_else(|| {
+                SearchError::BackendError("No web search provider returned results".to_string())
+            }));
+        }
+
+        let mut results = fuse_results(provider_results, &mut HashSet::new());
+        results.truncate(max_results(&params));
+        let metadata = merge_metadata(&params, provider_metadata, 0, false, &failed);
+        Ok((results, metadata))
+    }
+
+    fn start_search_session(params: SearchParams) -> Result<MultiSearchSession, SearchError> {
+        let mut providers = Vec::new();
+        let mut failed = Vec::new();
+        let mut first_error = None;
+
+        for provider in Self::configured_providers()? {
+            match provider.start_search(params.clone()) {
+                Ok(session) => providers.push(ProviderEntry {
+                    provider,
+                    session,
+                    finished: false,
+                }),
+                Err(error) => {
+                    warn!(
+                        "Failed to start search with web search provider {}, continuing without it: {error:?}",
+                        provider.name()
+                    );
+                    failed.push(provider.name().to_string());
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        if providers.is_empty() {
+            return Err(first_error.unwrap_or_else(|| {
+                SearchError::BackendError("No web search provider could be started".to_string())
+            }));
+        }
+
+        Ok(MultiSearchSession::new(MultiSearch::new(
+            params, providers, failed,
+        )))
+    }
+
+    /// Recreates the session from its state, restoring each provider's session with `restore`.
+    /// Providers which cannot be restored are left out of the session and reported as failed.
+    fn session_from_replay_state(
+        state: &MultiReplayState,
+        params: SearchParams,
+        restore: impl Fn(
+            Provider,
+            &ProviderStates,
+            SearchParams,
+        ) -> Option<Result<ProviderSession, SearchError>>,
+    ) -> MultiSearchSession {
+        let mut providers = Vec::new();
+        let mut failed = state.failed.clone();
+        for name in &state.providers {
+            let Some(provider) = Provider::from_name(name) else {
+                continue;
+            };
+            match restore(provider, &state.states, params.clone()) {
+                Some(Ok(session)) => providers.push(ProviderEntry {
+                    provider,
+                    session,
+                    finished: state.finished.contains(name),
+                }),
+                Some(Err(error)) => {
+                    warn!("Failed to restore web search provider {name}, continuing without it: {error:?}");
+                    failed.push(name.clone());
+                }
+                None => {}
+            }
+        }
+
+        let mut search = MultiSearch::new(params, providers, failed);
+        search.pages = FusedPages {
+            seen_urls: state.seen_urls.iter().cloned().collect(),
+            pending: state.pending.clone(),
+        };
+        search.current_page = state.current_page;
+        search.metadata = state.metadata.clone();
+        MultiSearchSession::new(search)
+    }
+}
+
+impl Guest for MultiSearchComponent {
+    type SearchSession = MultiSearchSession;
+
+    fn start_search(params: SearchParams) -> Result<SearchSession, SearchError> {
+        match Self::start_search_session(params) {
+            Ok(session) => Ok(SearchSession::new(session)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn resume_search(params: SearchParams, cursor: String) -> Result<SearchSession, SearchError> {
+        Self::unwrapped_resume_search(params, cursor).map(SearchSession::new)
+    }
+
+    fn search_once(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        let (results, metadata) = Self::execute_search(params)?;
+        Ok((results, Some(metadata)))
+    }
+
+    /// Returns the suggestions of the first configured provider which supports them
+    fn suggest(
+        prefix: String,
+        language: Option<String>,
+        region: Option<String>,
+    ) -> Result<Vec<String>, SearchError> {
+        let mut first_error = None;
+        for provider in Self::configured_providers()? {
+            match provider.suggest(prefix.clone(), language.clone(), region.clone()) {
+                Ok(suggestions) => return Ok(suggestions),
+                Err(SearchError::UnsupportedFeature(_)) => {}
+                Err(error) => {
+                    warn!(
+                        "Web search provider {} failed to suggest queries, trying the next one: {error:?}",
+                        provider.name()
+                    );
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            SearchError::UnsupportedFeature(
+                "suggest not supported by the configured providers".to_string(),
+            )
+        }))
+    }
+
+    /// Extracts the pages with the first configured provider which succeeds
+    fn extract(
+        urls: Vec<String>,
+        options: ExtractOptions,
+    ) -> Result<Vec<ExtractResult>, SearchError> {
+        validate_extract_urls(&urls)?;
+
+        let mut first_error = None;
+        for provider in Self::configured_providers()? {
+            match provider.extract(urls.clone(), options.clone()) {
+                Ok(results) => return Ok(results),
+                Err(error) => {
+                    warn!(
+                        "Web search provider {} failed to extract pages, trying the next one: {error:?}",
+                        provider.name()
+                    );
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            SearchError::BackendError("No web search provider could extract the pages".to_string())
+        }))
+    }
+}
+
+impl ExtendedwebsearchGuest for MultiSearchComponent {
+    type ReplayState = MultiReplayState;
+
+    fn unwrapped_search_session(params: SearchParams) -> Result<Self::SearchSession, SearchError> {
+        Self::start_search_session(params)
+    }
+
+    fn unwrapped_resume_search(
+        params: SearchParams,
+        cursor: String,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let state: MultiReplayState = decode_cursor(CURSOR_PROVIDER, &cursor)?;
+        Ok(Self::session_from_replay_state(
+            &state,
+            params,
+            ProviderSession::resume,
+        ))
+    }
+
+    fn session_to_state(session: &Self::SearchSession) -> Self::ReplayState {
+        let search = session.0.borrow_mut();
+
+        let mut states = ProviderStates::default();
+        for entry in &search.providers {
+            entry.session.save_state(&mut states);
+        }
+
+        MultiReplayState {
+            providers: search
+                .providers
+                .iter()
+                .map(|entry| entry.provider.name().to_string())
+                .collect(),
+            finished: search
+                .providers
+                .iter()
+                .filter(|entry| entry.finished)
+                .map(|entry| entry.provider.name().to_string())
+                .collect(),
+            failed: search.failed.clone(),
+            states,
+            seen_urls: search.pages.seen_urls.iter().cloned().collect(),
+            pending: search.pages.pending.clone(),
+            current_page: search.current_page,
+            metadata: search.metadata.clone(),
+        }
+    }
+
+    fn session_from_state(
+        state: &Self::ReplayState,
+        params: SearchParams,
+    ) -> Result<Self::SearchSession, SearchError> {
+        Ok(Self::session_from_replay_state(
+            state,
+            params,
+            ProviderSession::restore,
+        ))
+    }
+}
+
+type DurableMultiComponent = Durablewebsearch<MultiSearchComponent>;
+golem_web_search::export_websearch!(DurableMultiComponent with_types_in golem_web_search);
@@ -0,0 +1,260 @@
+use golem_web_search::durability::ExtendedwebsearchGuest;
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, Guest, GuestSearchSession, SearchError, SearchMetadata,
+    SearchParams, SearchResult,
+};
+use golem_web_search_brave_core::{BraveReplayState, BraveSearchComponent, BraveSearchSession};
+use golem_web_search_exa_core::{ExaReplayState, ExaSearchComponent, ExaSearchSession};
+use golem_web_search_google_core::{
+    GoogleCustomSearchComponent, GoogleReplayState, GoogleSearchSession,
+};
+use golem_web_search_searxng_core::{
+    SearxngReplayState, SearxngSearchComponent, SearxngSearchSession,
+};
+use golem_web_search_serper_core::{SerperReplayState, SerperSearchComponent, SerperSearchSession};
+use golem_web_search_tavily_core::{TavilyReplayState, TavilySearchComponent, TavilySearchSession};
+
+/// The web search providers the results can be fused from. Each provider reads its own
+/// configuration, such as its API key, from the same environment variables as its standalone
+/// component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provider {
+    Brave,
+    Exa,
+    Google,
+    Searxng,
+    Serper,
+    Tavily,
+}
+
+impl Provider {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "brave" => Some(Provider::Brave),
+            "exa" => Some(Provider::Exa),
+            "google" => Some(Provider::Google),
+            "searxng" => Some(Provider::Searxng),
+            "serper" => Some(Provider::Serper),
+            "tavily" => Some(Provider::Tavily),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::Brave => "brave",
+            Provider::Exa => "exa",
+            Provider::Google => "google",
+            Provider::Searxng => "searxng",
+            Provider::Serper => "serper",
+            Provider::Tavily => "tavily",
+        }
+    }
+
+    pub fn search_once(
+        &self,
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        match self {
+            Provider::Brave => BraveSearchComponent::search_once(params),
+            Provider::Exa => ExaSearchComponent::search_once(params),
+            Provider::Google => GoogleCustomSearchComponent::search_once(params),
+            Provider::Searxng => SearxngSearchComponent::search_once(params),
+            Provider::Serper => SerperSearchComponent::search_once(params),
+            Provider::Tavily => TavilySearchComponent::search_once(params),
+        }
+    }
+
+    pub fn start_search(&self, params: SearchParams) -> Result<ProviderSession, SearchError> {
+        match self {
+            Provider::Brave => {
+                BraveSearchComponent::unwrapped_search_session(params).map(ProviderSession::Brave)
+            }
+            Provider::Exa => {
+                ExaSearchComponent::unwrapped_search_session(params).map(ProviderSession::Exa)
+            }
+            Provider::Google => GoogleCustomSearchComponent::unwrapped_search_session(params)
+                .map(ProviderSession::Google),
+            Provider::Searxng => SearxngSearchComponent::unwrapped_search_session(params)
+                .map(ProviderSession::Searxng),
+            Provider::Serper => {
+                SerperSearchComponent::unwrapped_search_session(params).map(ProviderSession::Serper)
+            }
+            Provider::Tavily => {
+                TavilySearchComponent::unwrapped_search_session(params).map(ProviderSession::Tavily)
+            }
+        }
+    }
+
+    pub fn suggest(
+        &self,
+        prefix: String,
+        language: Option<String>,
+        region: Option<String>,
+    ) -> Result<Vec<String>, SearchError> {
+        match self {
+            Provider::Brave => BraveSearchComponent::suggest(prefix, language, region),
+            Provider::Exa => ExaSearchComponent::suggest(prefix, language, region),
+            Provider::Google => GoogleCustomSearchComponent::suggest(prefix, language, region),
+            Provider::Searxng => SearxngSearchComponent::suggest(prefix, language, region),
+            Provider::Serper => SerperSearchComponent::suggest(prefix, language, region),
+            Provider::Tavily => TavilySearchComponent::suggest(prefix, language, region),
+        }
+    }
+
+    pub fn extract(
+        &self,
+        urls: Vec<String>,
+        options: ExtractOptions,
+    ) -> Result<Vec<ExtractResult>, SearchError> {
+        match self {
+            Provider::Brave => BraveSearchComponent::extract(urls, options),
+            Provider::Exa => ExaSearchComponent::extract(urls, options),
+            Provider::Google => GoogleCustomSearchComponent::extract(urls, options),
+            Provider::Searxng => SearxngSearchComponent::extract(urls, options),
+            Provider::Serper => SerperSearchComponent::extract(urls, options),
+            Provider::Tavily => TavilySearchComponent::extract(urls, options),
+        }
+    }
+}
+
+/// A search session of one of the fused providers
+pub enum ProviderSession {
+    Brave(BraveSearchSession),
+    Exa(ExaSearchSession),
+    Google(GoogleSearchSession),
+    Searxng(SearxngSearchSession),
+    Serper(SerperSearchSession),
+    Tavily(TavilySearchSession),
+}
+
+impl ProviderSession {
+    pub fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
+        match self {
+            ProviderSession::Brave(session) => session.next_page(),
+            ProviderSession::Exa(session) => session.next_page(),
+            ProviderSession::Google(session) => session.next_page(),
+            ProviderSession::Searxng(session) => session.next_page(),
+            ProviderSession::Serper(session) => session.next_page(),
+            ProviderSession::Tavily(session) => session.next_page(),
+        }
+    }
+
+    pub fn get_metadata(&self) -> Option<SearchMetadata> {
+        match self {
+            ProviderSession::Brave(session) => session.get_metadata(),
+            ProviderSession::Exa(session) => session.get_metadata(),
+            ProviderSession::Google(session) => session.get_metadata(),
+            ProviderSession::Searxng(session) => session.get_metadata(),
+            ProviderSession::Serper(session) => session.get_metadata(),
+            ProviderSession::Tavily(session) => session.get_metadata(),
+        }
+    }
+
+    /// Stores the replay state of the session in the slot of its provider
+    pub fn save_state(&self, states: &mut ProviderStates) {
+        match self {
+            ProviderSession::Brave(session) => {
+                states.brave = Some(BraveSearchComponent::session_to_state(session))
+            }
+            ProviderSession::Exa(session) => {
+                states.exa = Some(ExaSearchComponent::session_to_state(session))
+            }
+            ProviderSession::Google(session) => {
+                states.google = Some(GoogleCustomSearchComponent::session_to_state(session))
+            }
+            ProviderSession::Searxng(session) => {
+                states.searxng = Some(SearxngSearchComponent::session_to_state(session))
+            }
+            ProviderSession::Serper(session) => {
+                states.serper = Some(SerperSearchComponent::session_to_state(session))
+            }
+            ProviderSession::Tavily(session) => {
+                states.tavily = Some(TavilySearchComponent::session_to_state(session))
+            }
+        }
+    }
+
+    /// Restores the session of the provider from its replay state, `None` if there is no state
+    /// saved for the provider
+    pub fn restore(
+        provider: Provider,
+        states: &ProviderStates,
+        params: SearchParams,
+    ) -> Option<Result<Self, SearchError>> {
+        match provider {
+            Provider::Brave => states.brave.as_ref().map(|state| {
+                BraveSearchComponent::session_from_state(state, params).map(ProviderSession::Brave)
+            }),
+            Provider::Exa => states.exa.as_ref().map(|state| {
+                ExaSearchComponent::session_from_state(state, params).map(ProviderSession::Exa)
+            }),
+            Provider::Google => states.google.as_ref().map(|state| {
+                GoogleCustomSearchComponent::session_from_state(state, params)
+                    .map(ProviderSession::Google)
+            }),
+            Provider::Searxng => states.searxng.as_ref().map(|state| {
+                SearxngSearchComponent::session_from_state(state, params)
+                    .map(ProviderSession::Searxng)
+            }),
+            Provider::Serper => states.serper.as_ref().map(|state| {
+                SerperSearchComponent::session_from_state(state, params)
+                    .map(ProviderSession::Serper)
+            }),
+            Provider::Tavily => states.tavily.as_ref().map(|state| {
+                TavilySearchComponent::session_from_state(state, params)
+                    .map(ProviderSession::Tavily)
+            }),
+        }
+    }
+
+    /// Restores the session of the provider from the state of a cursor, with the client configured
+    /// for this worker. `None` if the cursor has no state for the provider.
+    pub fn resume(
+        provider: Provider,
+        states: &ProviderStates,
+        params: SearchParams,
+    ) -> Option<Result<Self, SearchError>> {
+        match provider {
+            Provider::Brave => states.brave.as_ref().map(|state| {
+                BraveSearchComponent::resume_session(state, params).map(ProviderSession::Brave)
+            }),
+            Provider::Exa => states.exa.as_ref().map(|state| {
+                ExaSearchComponent::resume_session(state, params).map(ProviderSession::Exa)
+            }),
+            Provider::Google => states.google.as_ref().map(|state| {
+                GoogleCustomSearchComponent::resume_session(state, params)
+                    .map(ProviderSession::Google)
+            }),
+            Provider::Searxng => states.searxng.as_ref().map(|state| {
+                SearxngSearchComponent::resume_session(state, params).map(ProviderSession::Searxng)
+            }),
+            Provider::Serper => states.serper.as_ref().map(|state| {
+                SerperSearchComponent::resume_session(state, params).map(ProviderSession::Serper)
+            }),
+            Provider::Tavily => states.tavily.as_ref().map(|state| {
+                TavilySearchComponent::resume_session(state, params).map(ProviderSession::Tavily)
+            }),
+        }
+    }
+}
+
+/// Replay states of the provider sessions, one slot for each provider
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    golem_rust::FromValueAndType,
+    golem_rust::IntoValue,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ProviderStates {
+    pub brave: Option<BraveReplayState>,
+    pub exa: Option<ExaReplayState>,
+    pub google: Option<GoogleReplayState>,
+    pub searxng: Option<SearxngReplayState>,
+    pub serper: Option<SerperReplayState>,
+    pub tavily: Option<TavilyReplayState>,
+}
@@ -0,0 +1,188 @@
+use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::RateLimitInfo;
+use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
+use log::trace;
+use reqwest::Url;
+use reqwest::{Client, Method, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+
+/// SearXNG JSON API client for web search.
+///
+/// The instance must have the `json` format enabled in the `search.formats` section of its
+/// `settings.yml`, otherwise it rejects the requests with `403 Forbidden`.
+pub struct SearxngSearchApi {
+    client: Client,
+    pub base_url: String,
+}
+
+impl SearxngSearchApi {
+    pub fn new(base_url: String) -> Self {
+        let client = Client::builder()
+            .user_agent("Golem-Web-Search/1.0")
+            .build()
+            .expect("Failed to initialize HTTP client");
+
+        Self { client, base_url }
+    }
+
+    pub fn search(&self, request: SearchRequest) -> Result<SearchResponse, SearchError> {
+        trace!("Sending request to SearXNG API: {request:?}");
+
+        let mut url = self.search_url()?;
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.append_pair("q", &request.query);
+            query_pairs.append_pair("format", "json");
+            query_pairs.append_pair("pageno", &request.pageno.to_string());
+            if let Some(categories) = &request.categories {
+                query_pairs.append_pair("categories", categories);
+            }
+            if let Some(language) = &request.language {
+                query_pairs.append_pair("language", language);
+            }
+            if let Some(safesearch) = request.safesearch {
+                query_pairs.append_pair("safesearch", &safesearch.to_string());
+            }
+            if let Some(time_range) = &request.time_range {
+                query_pairs.append_pair("time_range", time_range);
+            }
+        }
+
+        let response = self
+            .client
+            .request(Method::GET, url.as_str())
+            .send()
+            .map_err(|err| from_reqwest_error("Failed to send request", err))?;
+
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
+    }
+
+    pub fn base_url(&self) -> &String {
+        &self.base_url
+    }
+
+    fn search_url(&self) -> Result<Url, SearchError> {
+        let base_url = self.base_url.trim_end_matches('/');
+        Url::parse(&format!("{base_url}/search")).map_err(|err| {
+            SearchError::BackendError(format!("Invalid SearXNG base URL {base_url}: {err}"))
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchRequest {
+    pub query: String,
+    /// 1-based page number
+    pub pageno: u32,
+    pub categories: Option<String>,
+    pub language: Option<String>,
+    /// 0 = off, 1 = moderate, 2 = strict
+    pub safesearch: Option<u8>,
+    pub time_range: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub query: String,
+    /// Estimated total number of results, 0 if none of the engines report it
+    #[serde(default)]
+    pub number_of_results: u64,
+    #[serde(default)]
+    pub results: Vec<SearchResult>,
+    #[serde(default)]
+    pub answers: Vec<AnswerItem>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub engine: Option<String>,
+    #[serde(default)]
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(rename = "publishedDate", default)]
+    pub published_date: Option<String>,
+    #[serde(default)]
+    pub img_src: Option<String>,
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    #[serde(default)]
+    pub thumbnail_src: Option<String>,
+    /// Author or channel of a video result
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Duration of a video result, a string or a number of seconds depending on the engine
+    #[serde(default)]
+    pub length: Option<serde_json::Value>,
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+    #[serde(default)]
+    pub address: Option<Address>,
+}
+
+/// Address of a map result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Address {
+    pub name: Option<String>,
+    pub road: Option<String>,
+    pub house_number: Option<String>,
+    pub locality: Option<String>,
+    pub postcode: Option<String>,
+    pub country: Option<String>,
+}
+
+/// Older SearXNG versions return the answers as plain strings, newer versions as objects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerItem {
+    Text(String),
+    Structured {
+        answer: String,
+        #[serde(default)]
+        url: Option<String>,
+    },
+}
+
+fn parse_response(response: Response) -> Result<SearchResponse, SearchError> {
+    let status = response.status();
+    if status.is_success() {
+        let body = response
+            .json::<SearchResponse>()
+            .map_err(|err| from_reqwest_error("Failed to decode response body", err))?;
+
+        trace!("Received response from SearXNG API: {body:?}");
+        Ok(body)
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!("Received {status} response from SearXNG API, retry after {retry_after} seconds");
+        Err(SearchError::RateLimited(retry_after))
+    } else {
+        // SearXNG returns HTML or plain text error pages
+        let body = response.text().unwrap_or_default();
+        trace!("Received {status} response from SearXNG API: {body}");
+
+        let search_error = match status.as_u16() {
+            400 => SearchError::InvalidQuery,
+            403 => SearchError::BackendError(
+                "The SearXNG instance does not allow the json output format".to_string(),
+            ),
+            _ => SearchError::BackendError(format!("Request failed with status {status}")),
+        };
+
+        Err(search_error)
+    }
+}
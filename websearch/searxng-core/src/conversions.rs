@@ -0,0 +1,471 @@
+use crate::client::{
+    Address, AnswerItem, SearchRequest, SearchResponse, SearchResult as ClientSearchResult,
+};
+use golem_web_search::golem::web_search::types::{
+    Answer, Coordinates, ImageResult, PlaceLocation, SafeSearchLevel, SearchType, TimeRange,
+};
+use golem_web_search::golem::web_search::web_search::{
+    SearchError, SearchMetadata, SearchParams, SearchResult,
+};
+use golem_web_search::query::{compile_query, excluded_sites, included_sites};
+
+pub fn params_to_request(params: &SearchParams, pageno: u32) -> Result<SearchRequest, SearchError> {
+    // Validate query
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    // Handle the query operators and domain filtering in query, the results are also filtered by
+    // domain afterward, as not all engines aggregated by SearXNG understand the site: operator
+    let query = compile_query(params);
+
+    // SearXNG expects a locale like `en-US` and has no separate region parameter
+    let language = match (&params.language, &params.region) {
+        (Some(language), Some(region)) => Some(format!("{language}-{}", region.to_uppercase())),
+        (Some(language), None) => Some(language.clone()),
+        (None, _) => None,
+    };
+
+    Ok(SearchRequest {
+        query,
+        pageno,
+        categories: Some(
+            match params.search_type.unwrap_or(SearchType::Web) {
+                SearchType::Web => "general",
+                SearchType::News => "news",
+                SearchType::Images => "images",
+                SearchType::Videos => "videos",
+                SearchType::Places => "map",
+            }
+            .to_string(),
+        ),
+        language,
+        safesearch: params.safe_search.map(|safe| match safe {
+            SafeSearchLevel::Off => 0,
+            SafeSearchLevel::Medium => 1,
+            SafeSearchLevel::High => 2,
+        }),
+        time_range: params.time_range.map(|range| {
+            match range {
+                TimeRange::Day => "day",
+                TimeRange::Week => "week",
+                TimeRange::Month => "month",
+                TimeRange::Year => "year",
+            }
+            .to_string()
+        }),
+    })
+}
+
+pub fn response_to_results(
+    response: &SearchResponse,
+    original_params: &SearchParams,
+    current_page: u32,
+) -> (Vec<SearchResult>, SearchMetadata) {
+    let max_score = response
+        .results
+        .iter()
+        .filter_map(|item| item.score)
+        .fold(0.0, f64::max);
+
+    // SearXNG has no page size parameter. The whole page is returned, as the session continues
+    // with the next page and results left out here would never be returned.
+    let results = response
+        .results
+        .iter()
+        .filter(|item| matches_domain_filters(&item.url, original_params))
+        .map(|item| web_result_to_search_result(item, original_params, max_score))
+        .collect::<Vec<_>>();
+
+    let metadata = create_search_metadata(response, original_params, current_page);
+    (results, metadata)
+}
+
+fn web_result_to_search_result(
+    item: &ClientSearchResult,
+    params: &SearchParams,
+    max_score: f64,
+) -> SearchResult {
+    let snippet = item.content.clone().unwrap_or_default();
+    let content_chunks = if snippet.is_empty() {
+        None
+    } else {
+        Some(vec![snippet.clone()])
+    };
+
+    // SearXNG scores are not bounded, so they are normalized to the best result of the page
+    let score = match item.score {
+        Some(score) if max_score > 0.0 => Some((score / max_score).clamp(0.0, 1.0)),
+        _ => None,
+    };
+
+    let search_type = params.search_type.unwrap_or(SearchType::Web);
+    let images = if params.include_images == Some(true) || search_type == SearchType::Images {
+        item.img_src
+            .as_ref()
+            .or(item.thumbnail.as_ref())
+            .filter(|url| !url.is_empty())
+            .map(|url| {
+                vec![ImageResult {
+                    url: url.clone(),
+                    description: Some(item.title.clone()),
+                }]
+            })
+    } else {
+        None
+    };
+
+    let thumbnail = match search_type {
+        SearchType::Web => None,
+        _ => item
+            .thumbnail_src
+            .clone()
+            .or(item.thumbnail.clone())
+            .filter(|url| !url.is_empty()),
+    };
+    let source_outlet = match search_type {
+        SearchType::News => extract_domain(&item.url),
+        SearchType::Videos => item.author.clone().or_else(|| extract_domain(&item.url)),
+        _ => None,
+    };
+    let duration = item.length.as_ref().and_then(|length| match length {
+        serde_json::Value::String(length) if !length.is_empty() => Some(length.clone()),
+        serde_json::Value::Number(seconds) => seconds.as_u64().map(format_duration),
+        _ => None,
+    });
+    let location = if search_type == SearchType::Places {
+        Some(PlaceLocation {
+            address: item.address.as_ref().and_then(format_address),
+            coordinates: match (item.latitude, item.longitude) {
+                (Some(latitude), Some(longitude)) => Some(Coordinates {
+                    latitude,
+                    longitude,
+                }),
+                _ => None,
+            },
+        })
+    } else {
+        None
+    };
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.url.clone(),
+        snippet,
+        display_url: extract_domain(&item.url),
+        source: item.engine.clone(),
+        score,
+        html_snippet: None,
+        date_published: item.published_date.clone(),
+        images,
+        content_chunks,
+        thumbnail,
+        source_outlet,
+        duration,
+        location,
+    }
+}
+
+/// Formats a duration in seconds as `h:mm:ss`, or `m:ss` if it is shorter than an hour
+fn format_duration(seconds: u64) -> String {
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+fn format_address(address: &Address) -> Option<String> {
+    let street = match (&address.road, &address.house_number) {
+        (Some(road), Some(house_number)) => Some(format!("{road} {house_number}")),
+        (road, _) => road.clone(),
+    };
+    let city = match (&address.postcode, &address.locality) {
+        (Some(postcode), Some(locality)) => Some(format!("{postcode} {locality}")),
+        (postcode, locality) => locality.clone().or(postcode.clone()),
+    };
+
+    let parts = [address.name.clone(), street, city, address.country.clone()]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
+fn matches_domain_filters(url: &str, params: &SearchParams) -> bool {
+    let Some(domain) = extract_domain(url) else {
+        return true;
+    };
+    let matches = |filter: &String| domain == *filter || domain.ends_with(&format!(".{filter}"));
+
+    let include_domains = included_sites(params);
+    let included = include_domains.is_empty() || include_domains.iter().any(matches);
+    let excluded = excluded_sites(params).iter().any(matches);
+
+    included && !excluded
+}
+
+fn extract_domain(url: &str) -> Option<String> {
+    if let Ok(parsed_url) = url::Url::parse(url) {
+        parsed_url.host_str().map(|host| {
+            // Remove www. prefix if present
+            if let Some(stripped) = host.strip_prefix("www.") {
+                stripped.to_string()
+            } else {
+                host.to_string()
+            }
+        })
+    } else {
+        None
+    }
+}
+
+fn create_search_metadata(
+    response: &SearchResponse,
+    params: &SearchParams,
+    current_page: u32,
+) -> SearchMetadata {
+    // SearXNG does not report whether there are more pages, so an empty page marks the end
+    let next_page_token = if response.results.is_empty() {
+        None
+    } else {
+        Some((current_page + 2).to_string())
+    };
+
+    let total_results = if response.number_of_results > 0 {
+        Some(response.number_of_results)
+    } else {
+        None
+    };
+
+    let answer = if params.advanced_answer == Some(true) {
+        response_to_answer(response)
+    } else {
+        None
+    };
+
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results,
+        search_time_ms: None,
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token,
+        rate_limits: response.rate_limits,
+        current_page,
+        answer,
+        cache_status: None,
+        failed_providers: None,
+    }
+}
+
+fn response_to_answer(response: &SearchResponse) -> Option<Answer> {
+    let answer = response.answers.first()?;
+    let (text, url) = match answer {
+        AnswerItem::Text(text) => (text.clone(), None),
+        AnswerItem::Structured { answer, url } => (answer.clone(), url.clone()),
+    };
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    Some(Answer {
+        text,
+        source_urls: url.into_iter().collect(),
+    })
+}
+
+pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError> {
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+    if params.region.is_some() && params.language.is_none() {
+        return Err(SearchError::UnsupportedFeature(
+            "region is only supported together with language".to_string(),
+        ));
+    }
+    if params.include_html == Some(true) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-html not supported".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::SearchResponse;
+    use crate::conversions::{
+        matches_domain_filters, params_to_request, response_to_answer, response_to_results,
+    };
+    use golem_web_search::golem::web_search::types::{SafeSearchLevel, TimeRange};
+    use golem_web_search::test_util::{
+        query_operators, search_params, search_params_with_operators,
+    };
+    use serde_json::json;
+
+    fn response(body: serde_json::Value) -> SearchResponse {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn maps_safe_search_levels() {
+        for (level, expected) in [
+            (SafeSearchLevel::Off, 0),
+            (SafeSearchLevel::Medium, 1),
+            (SafeSearchLevel::High, 2),
+        ] {
+            let mut params = search_params("rust");
+            params.safe_search = Some(level);
+            assert_eq!(
+                params_to_request(&params, 1).unwrap().safesearch,
+                Some(expected)
+            );
+        }
+        assert_eq!(
+            params_to_request(&search_params("rust"), 1)
+                .unwrap()
+                .safesearch,
+            None
+        );
+    }
+
+    #[test]
+    fn combines_language_and_region_into_locale() {
+        let mut params = search_params("rust");
+        params.language = Some("en".to_string());
+        params.region = Some("us".to_string());
+        assert_eq!(
+            params_to_request(&params, 1).unwrap().language.as_deref(),
+            Some("en-US")
+        );
+
+        params.region = None;
+        assert_eq!(
+            params_to_request(&params, 1).unwrap().language.as_deref(),
+            Some("en")
+        );
+    }
+
+    #[test]
+    fn maps_time_range() {
+        for (range, expected) in [
+            (TimeRange::Day, "day"),
+            (TimeRange::Week, "week"),
+            (TimeRange::Month, "month"),
+            (TimeRange::Year, "year"),
+        ] {
+            let mut params = search_params("rust");
+            params.time_range = Some(range);
+            assert_eq!(
+                params_to_request(&params, 1).unwrap().time_range.as_deref(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn pages_through_the_results() {
+        let mut params = search_params("rust");
+        params.max_results = Some(1);
+        assert_eq!(params_to_request(&params, 3).unwrap().pageno, 3);
+
+        let page = response(json!({
+            "query": "rust",
+            "number_of_results": 0,
+            "results": [
+                {"url": "https://www.rust-lang.org/", "title": "Rust", "engine": "duckduckgo", "score": 2.0},
+                {"url": "https://doc.rust-lang.org/book/", "title": "The Book", "engine": "google", "score": 1.0},
+                {"url": "https://docs.rs/", "title": "Docs.rs", "engine": "google", "score": 0.5}
+            ],
+            "answers": []
+        }));
+        let (results, metadata) = response_to_results(&page, &params, 1);
+
+        // A fetched page is returned whole, the session continues with the next one
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].score, Some(1.0));
+        assert_eq!(results[0].display_url.as_deref(), Some("rust-lang.org"));
+        assert_eq!(metadata.current_page, 1);
+        assert_eq!(metadata.next_page_token.as_deref(), Some("3"));
+        assert_eq!(metadata.total_results, None);
+
+        let last_page = response(json!({"query": "rust", "results": []}));
+        let (results, metadata) = response_to_results(&last_page, &params, 2);
+        assert!(results.is_empty());
+        assert_eq!(metadata.next_page_token, None);
+    }
+
+    #[test]
+    fn converts_text_and_structured_answers() {
+        let text = response(json!({"query": "2+2", "answers": ["4"]}));
+        let answer = response_to_answer(&text).unwrap();
+        assert_eq!(answer.text, "4");
+        assert!(answer.source_urls.is_empty());
+
+        let structured = response(json!({
+            "query": "rust",
+            "answers": [{
+                "answer": "Rust is a general-purpose programming language",
+                "url": "https://www.wikidata.org/wiki/Q575650"
+            }]
+        }));
+        let answer = response_to_answer(&structured).unwrap();
+        assert_eq!(
+            answer.text,
+            "Rust is a general-purpose programming language"
+        );
+        assert_eq!(
+            answer.source_urls,
+            vec!["https://www.wikidata.org/wiki/Q575650"]
+        );
+
+        let mut params = search_params("rust");
+        assert_eq!(response_to_results(&structured, &params, 0).1.answer, None);
+        params.advanced_answer = Some(true);
+        assert!(response_to_results(&structured, &params, 0)
+            .1
+            .answer
+            .is_some());
+    }
+
+    #[test]
+    fn returns_no_answer_without_answers() {
+        assert_eq!(
+            response_to_answer(&response(json!({"query": "rust"}))),
+            None
+        );
+        assert_eq!(
+            response_to_answer(&response(json!({"query": "rust", "answers": [" "]}))),
+            None
+        );
+    }
+
+    #[test]
+    fn compiles_query_operators_into_query() {
+        let request =
+            params_to_request(&search_params_with_operators("rust", query_operators()), 1).unwrap();
+        assert_eq!(
+            request.query,
+            "(site:rust-lang.org OR site:docs.rs) rust \"borrow checker\" -java filetype:pdf"
+        );
+    }
+
+    #[test]
+    fn filters_results_by_sites_operator() {
+        let params = search_params_with_operators("rust", query_operators());
+        assert!(matches_domain_filters(
+            "https://www.rust-lang.org/learn",
+            &params
+        ));
+        assert!(matches_domain_filters("https://docs.rs/serde", &params));
+        assert!(!matches_domain_filters("https://example.com/rust", &params));
+    }
+}
@@ -0,0 +1,233 @@
+mod client;
+mod conversions;
+
+use crate::client::SearxngSearchApi;
+use crate::conversions::{params_to_request, response_to_results, validate_search_params};
+use golem_web_search::cursor::{decode_cursor, encode_cursor};
+use golem_web_search::durability::ExtendedwebsearchGuest;
+use golem_web_search::extract::{extract_with_http, validate_extract_urls};
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, Guest, GuestSearchSession, SearchError, SearchMetadata,
+    SearchParams, SearchResult, SearchSession,
+};
+use std::cell::RefCell;
+
+/// SearXNG page numbers are 1-index based
+const INITIAL_PAGE_NUMBER: u32 = 1;
+
+/// Identifies the cursors of this provider
+const CURSOR_PROVIDER: &str = "searxng";
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    golem_rust::FromValueAndType,
+    golem_rust::IntoValue,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct SearxngReplayState {
+    /// Not part of the cursors, the resuming worker uses its own configuration
+    #[serde(skip)]
+    pub base_url: String,
+    pub current_page: u32,
+    pub metadata: Option<SearchMetadata>,
+    pub finished: bool,
+}
+
+struct SearxngSearch {
+    client: SearxngSearchApi,
+    params: SearchParams,
+    metadata: Option<SearchMetadata>,
+    current_page: u32,
+    finished: bool,
+}
+
+impl SearxngSearch {
+    fn new(client: SearxngSearchApi, params: SearchParams) -> Self {
+        Self {
+            client,
+            params,
+            metadata: None,
+            current_page: 0,
+            finished: false,
+        }
+    }
+
+    fn next_page(&mut self) -> Result<Vec<SearchResult>, SearchError> {
+        if self.finished {
+            return Ok(Vec::new());
+        }
+
+        let request = params_to_request(&self.params, INITIAL_PAGE_NUMBER + self.current_page)?;
+        let response = self.client.search(request)?;
+
+        let (results, metadata) = response_to_results(&response, &self.params, self.current_page);
+
+        self.finished = metadata.next_page_token.is_none();
+        self.current_page += 1;
+        self.metadata = Some(metadata);
+        Ok(results)
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        self.metadata.clone()
+    }
+}
+
+pub struct SearxngSearchSession(RefCell<SearxngSearch>);
+
+impl SearxngSearchSession {
+    fn new(search: SearxngSearch) -> Self {
+        Self(RefCell::new(search))
+    }
+}
+
+impl GuestSearchSession for SearxngSearchSession {
+    fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
+        let mut search = self.0.borrow_mut();
+        search.next_page()
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        let search = self.0.borrow();
+        search.get_metadata()
+    }
+
+    fn get_cursor(&self) -> Result<String, SearchError> {
+        encode_cursor(
+            CURSOR_PROVIDER,
+            &SearxngSearchComponent::session_to_state(self),
+        )
+    }
+}
+
+pub struct SearxngSearchComponent;
+
+impl SearxngSearchComponent {
+    const BASE_URL_VAR: &'static str = "SEARXNG_BASE_URL";
+
+    fn create_client() -> Result<SearxngSearchApi, SearchError> {
+        let base_url = std::env::var(Self::BASE_URL_VAR).map_err(|_| {
+            SearchError::BackendError("SEARXNG_BASE_URL environment variable not set".to_string())
+        })?;
+
+        Ok(SearxngSearchApi::new(base_url))
+    }
+
+    fn execute_search(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let request = params_to_request(&params, INITIAL_PAGE_NUMBER)?;
+
+        let response = client.search(request)?;
+        let (mut results, metadata) = response_to_results(&response, &params, 0);
+        if let Some(max_results) = params.max_results {
+            results.truncate(max_results as usize);
+        }
+
+        Ok((results, Some(metadata)))
+    }
+
+    fn start_search_session(params: SearchParams) -> Result<SearxngSearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let search = SearxngSearch::new(client, params);
+        Ok(SearxngSearchSession::new(search))
+    }
+
+    /// Restores a session from the state of a cursor, with the client configured for this worker
+    pub fn resume_session(
+        state: &SearxngReplayState,
+        params: SearchParams,
+    ) -> Result<SearxngSearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let session = Self::session_from_state(state, params)?;
+        session.0.borrow_mut().client = Self::create_client()?;
+        Ok(session)
+    }
+}
+
+impl Guest for SearxngSearchComponent {
+    type SearchSession = SearxngSearchSession;
+
+    fn start_search(params: SearchParams) -> Result<SearchSession, SearchError> {
+        match Self::start_search_session(params) {
+            Ok(session) => Ok(SearchSession::new(session)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn resume_search(params: SearchParams, cursor: String) -> Result<SearchSession, SearchError> {
+        Self::unwrapped_resume_search(params, cursor).map(SearchSession::new)
+    }
+
+    fn search_once(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        Self::execute_search(params)
+    }
+
+    fn suggest(
+        _prefix: String,
+        _language: Option<String>,
+        _region: Option<String>,
+    ) -> Result<Vec<String>, SearchError> {
+        Err(SearchError::UnsupportedFeature(
+            "suggest not supported".to_string(),
+        ))
+    }
+
+    fn extract(
+        urls: Vec<String>,
+        options: ExtractOptions,
+    ) -> Result<Vec<ExtractResult>, SearchError> {
+        validate_extract_urls(&urls)?;
+        extract_with_http(&urls, &options)
+    }
+}
+
+impl ExtendedwebsearchGuest for SearxngSearchComponent {
+    type ReplayState = SearxngReplayState;
+
+    fn unwrapped_search_session(params: SearchParams) -> Result<Self::SearchSession, SearchError> {
+        Self::start_search_session(params)
+    }
+
+    fn unwrapped_resume_search(
+        params: SearchParams,
+        cursor: String,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let state: SearxngReplayState = decode_cursor(CURSOR_PROVIDER, &cursor)?;
+        Self::resume_session(&state, params)
+    }
+
+    fn session_to_state(session: &Self::SearchSession) -> Self::ReplayState {
+        let search = session.0.borrow_mut();
+        SearxngReplayState {
+            base_url: search.client.base_url().to_string(),
+            current_page: search.current_page,
+            metadata: search.metadata.clone(),
+            finished: search.finished,
+        }
+    }
+
+    fn session_from_state(
+        state: &Self::ReplayState,
+        params: SearchParams,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let client = SearxngSearchApi::new(state.base_url.clone());
+        let mut search = SearxngSearch::new(client, params);
+        search.current_page = state.current_page;
+        search.metadata = state.metadata.clone();
+        search.finished = state.finished;
+
+        Ok(SearxngSearchSession::new(search))
+    }
+}
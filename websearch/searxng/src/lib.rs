@@ -0,0 +1,5 @@
+use golem_web_search::durability::Durablewebsearch;
+use golem_web_search_searxng_core::SearxngSearchComponent;
+
+type DurableSearxngComponent = Durablewebsearch<SearxngSearchComponent>;
+golem_web_search::export_websearch!(DurableSearxngComponent with_types_in golem_web_search);
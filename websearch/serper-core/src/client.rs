@@ -0,0 +1,264 @@
+use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::{RateLimitInfo, SearchType};
+use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
+use log::trace;
+use reqwest::Method;
+use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+const BASE_URL: &str = "https://google.serper.dev";
+
+/// The Serper Search API client for Google-powered web search.
+pub struct SerperSearchApi {
+    pub api_key: String,
+    client: Client,
+}
+
+impl SerperSearchApi {
+    pub fn new(api_key: String) -> Self {
+        let client = Client::builder()
+            .user_agent("Golem-Web-Search/1.0")
+            .build()
+            .expect("Failed to initialize HTTP client");
+
+        Self { api_key, client }
+    }
+
+    pub fn search(&self, request: SearchRequest) -> Result<SearchResponse, SearchError> {
+        trace!("Sending request to Serper Search API: {request:?}");
+
+        let endpoint = match request.search_type.unwrap_or(SearchType::Web) {
+            SearchType::Web => "search",
+            SearchType::News => "news",
+            SearchType::Images => "images",
+            SearchType::Videos => "videos",
+            SearchType::Places => "places",
+        };
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/{endpoint}"))
+            .header("X-API-KEY", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body: SearchResponse = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
+    }
+
+    pub fn autocomplete(
+        &self,
+        request: AutocompleteRequest,
+    ) -> Result<AutocompleteResponse, SearchError> {
+        trace!("Sending request to Serper Autocomplete API: {request:?}");
+
+        let response = self
+            .client
+            .request(Method::POST, format!("{BASE_URL}/autocomplete"))
+            .header("X-API-KEY", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
+        parse_response(response)
+    }
+
+    pub fn api_key(&self) -> &String {
+        &self.api_key
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchRequest {
+    /// Selects the endpoint, not part of the request body
+    #[serde(skip)]
+    pub search_type: Option<SearchType>,
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutocompleteRequest {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hl: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutocompleteResponse {
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResponse {
+    #[serde(default)]
+    pub organic: Vec<SearchResult>,
+    #[serde(default)]
+    pub news: Vec<NewsResult>,
+    #[serde(default)]
+    pub images: Vec<ImageResult>,
+    #[serde(default)]
+    pub videos: Vec<VideoResult>,
+    #[serde(default)]
+    pub places: Vec<PlaceResult>,
+    #[serde(rename = "searchParameters")]
+    pub search_parameters: SearchParameters,
+    #[serde(rename = "answerBox")]
+    pub answer_box: Option<AnswerBox>,
+    #[serde(rename = "knowledgeGraph")]
+    pub knowledge_graph: Option<KnowledgeGraph>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerBox {
+    pub title: Option<String>,
+    pub answer: Option<String>,
+    pub snippet: Option<String>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeGraph {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "descriptionLink")]
+    pub description_link: Option<String>,
+    pub website: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub title: String,
+    pub link: String,
+    pub snippet: String,
+    pub position: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewsResult {
+    pub title: String,
+    pub link: String,
+    pub snippet: Option<String>,
+    pub date: Option<String>,
+    pub source: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageResult {
+    pub title: String,
+    /// Page the image was found on
+    pub link: String,
+    pub image_url: String,
+    pub thumbnail_url: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoResult {
+    pub title: String,
+    pub link: String,
+    pub snippet: Option<String>,
+    pub date: Option<String>,
+    pub source: Option<String>,
+    pub channel: Option<String>,
+    pub duration: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceResult {
+    pub title: String,
+    pub address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub category: Option<String>,
+    pub website: Option<String>,
+    pub cid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchParameters {
+    pub q: String,
+    #[serde(rename = "type")]
+    pub search_type: String,
+    pub engine: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub message: String,
+    pub error: Option<String>,
+}
+
+fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T, SearchError> {
+    let status = response.status();
+    if status.is_success() {
+        let body = response
+            .json::<T>()
+            .map_err(|err| from_reqwest_error("Failed to decode response body", err))?;
+
+        trace!("Received response from Serper Search API: {body:?}");
+        Ok(body)
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!(
+            "Received {status} response from Serper Search API, retry after {retry_after} seconds"
+        );
+        Err(SearchError::RateLimited(retry_after))
+    } else {
+        // Try to parse error response
+        match response.json::<ErrorResponse>() {
+            Ok(error_body) => {
+                trace!("Received {status} response from Serper Search API: {error_body:?}");
+
+                let search_error = match status.as_u16() {
+                    400 => SearchError::InvalidQuery,
+                    401 => SearchError::BackendError("Invalid API key".to_string()),
+                    403 => SearchError::BackendError("API access forbidden".to_string()),
+                    _ => SearchError::BackendError(format!(
+                        "Request failed with {}: {}",
+                        status, error_body.message
+                    )),
+                };
+
+                Err(search_error)
+            }
+            Err(_) => {
+                // Fallback for non-JSON error responses
+                Err(SearchError::BackendError(format!(
+                    "Request failed with status {status}"
+                )))
+            }
+        }
+    }
+}
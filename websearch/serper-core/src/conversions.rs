@@ -0,0 +1,499 @@
+use crate::client::{
+    AutocompleteRequest, AutocompleteResponse, ImageResult as SerperImageResult, NewsResult,
+    PlaceResult, SearchRequest, SearchResponse, SearchResult as SerperSearchResult, VideoResult,
+};
+use golem_web_search::golem::web_search::types::{
+    Answer, Coordinates, ImageResult, PlaceLocation, SearchType,
+};
+use golem_web_search::golem::web_search::web_search::{
+    SearchError, SearchMetadata, SearchParams, SearchResult,
+};
+use golem_web_search::query::compile_query;
+
+pub fn params_to_request(params: SearchParams, page: u32) -> Result<SearchRequest, SearchError> {
+    // Validate query
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    // Serper passes the query to Google, so the query operators and domain filters are written in
+    // Google's query syntax
+    let q = compile_query(&params);
+
+    Ok(SearchRequest {
+        search_type: params.search_type,
+        q,
+        gl: params.region.map(to_country_code),
+        hl: params.language.map(to_language_code),
+        num: params.max_results,
+        page: Some(page), // 1-based
+    })
+}
+
+pub fn autocomplete_request(
+    prefix: String,
+    language: Option<String>,
+    region: Option<String>,
+) -> Result<AutocompleteRequest, SearchError> {
+    if prefix.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    Ok(AutocompleteRequest {
+        q: prefix,
+        gl: region.map(to_country_code),
+        hl: language.map(to_language_code),
+    })
+}
+
+pub fn autocomplete_response_to_suggestions(response: AutocompleteResponse) -> Vec<String> {
+    let mut suggestions: Vec<String> = Vec::new();
+    for suggestion in response.suggestions {
+        let value = suggestion.value.trim();
+        if !value.is_empty() && !suggestions.iter().any(|existing| existing == value) {
+            suggestions.push(value.to_string());
+        }
+    }
+    suggestions
+}
+
+/// Converts a region to a Google country code
+fn to_country_code(region: String) -> String {
+    match region.to_lowercase().as_str() {
+        "us" | "usa" | "united states" => "us".to_string(),
+        "uk" | "gb" | "united kingdom" => "uk".to_string(),
+        "in" | "india" => "in".to_string(),
+        _ => region,
+    }
+}
+
+/// Converts a language to a Google language code
+fn to_language_code(lang: String) -> String {
+    match lang.to_lowercase().as_str() {
+        "english" | "en" => "en".to_string(),
+        "spanish" | "es" => "es".to_string(),
+        "french" | "fr" => "fr".to_string(),
+        _ => lang,
+    }
+}
+
+pub fn response_to_results(
+    response: SearchResponse,
+    original_params: &SearchParams,
+    current_page: u32,
+) -> (Vec<SearchResult>, SearchMetadata) {
+    let mut results = Vec::new();
+
+    // Process organic search results
+    for (index, item) in response.organic.iter().enumerate() {
+        results.push(serper_result_to_search_result(item, index));
+    }
+
+    // Process vertical search results, only the list of the searched vertical is returned
+    let score = |index: usize| Some((1.0 - (index as f32) * 0.01) as f64);
+    for (index, item) in response.news.iter().enumerate() {
+        results.push(news_result_to_search_result(item, score(index)));
+    }
+    for (index, item) in response.images.iter().enumerate() {
+        results.push(image_result_to_search_result(item, score(index)));
+    }
+    for (index, item) in response.videos.iter().enumerate() {
+        results.push(video_result_to_search_result(item, score(index)));
+    }
+    for (index, item) in response.places.iter().enumerate() {
+        results.push(place_result_to_search_result(item, score(index)));
+    }
+
+    let metadata = create_search_metadata(&response, original_params, current_page);
+    (results, metadata)
+}
+
+fn serper_result_to_search_result(item: &SerperSearchResult, index: usize) -> SearchResult {
+    // Calculate score based on position
+    let score = 1.0 - (index as f32) * 0.01;
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.link.clone(),
+        snippet: item.snippet.clone(),
+        display_url: extract_domain(&item.link),
+        source: extract_domain(&item.link),
+        score: Some(score as f64),
+        html_snippet: None,
+        date_published: None,
+        images: None,
+        content_chunks: Some(vec![item.snippet.clone()]),
+        thumbnail: None,
+        source_outlet: None,
+        duration: None,
+        location: None,
+    }
+}
+
+fn news_result_to_search_result(item: &NewsResult, score: Option<f64>) -> SearchResult {
+    let snippet = item.snippet.clone().unwrap_or_default();
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.link.clone(),
+        snippet: snippet.clone(),
+        display_url: extract_domain(&item.link),
+        source: extract_domain(&item.link),
+        score,
+        html_snippet: None,
+        date_published: item.date.clone(),
+        images: None,
+        content_chunks: Some(vec![snippet]),
+        thumbnail: item.image_url.clone(),
+        source_outlet: item.source.clone(),
+        duration: None,
+        location: None,
+    }
+}
+
+fn image_result_to_search_result(item: &SerperImageResult, score: Option<f64>) -> SearchResult {
+    SearchResult {
+        title: item.title.clone(),
+        url: item.link.clone(),
+        snippet: String::new(),
+        display_url: extract_domain(&item.link),
+        source: extract_domain(&item.link),
+        score,
+        html_snippet: None,
+        date_published: None,
+        images: Some(vec![ImageResult {
+            url: item.image_url.clone(),
+            description: Some(item.title.clone()),
+        }]),
+        content_chunks: None,
+        thumbnail: item.thumbnail_url.clone(),
+        source_outlet: item.source.clone(),
+        duration: None,
+        location: None,
+    }
+}
+
+fn video_result_to_search_result(item: &VideoResult, score: Option<f64>) -> SearchResult {
+    let snippet = item.snippet.clone().unwrap_or_default();
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.link.clone(),
+        snippet: snippet.clone(),
+        display_url: extract_domain(&item.link),
+        source: extract_domain(&item.link),
+        score,
+        html_snippet: None,
+        date_published: item.date.clone(),
+        images: None,
+        content_chunks: Some(vec![snippet]),
+        thumbnail: item.image_url.clone(),
+        source_outlet: item.channel.clone().or(item.source.clone()),
+        duration: item.duration.clone(),
+        location: None,
+    }
+}
+
+fn place_result_to_search_result(item: &PlaceResult, score: Option<f64>) -> SearchResult {
+    // Places without a website link to their Google Maps entry
+    let url = item.website.clone().unwrap_or_else(|| match &item.cid {
+        Some(cid) => format!("https://maps.google.com/?cid={cid}"),
+        None => String::new(),
+    });
+    let coordinates = match (item.latitude, item.longitude) {
+        (Some(latitude), Some(longitude)) => Some(Coordinates {
+            latitude,
+            longitude,
+        }),
+        _ => None,
+    };
+
+    SearchResult {
+        title: item.title.clone(),
+        snippet: item.category.clone().unwrap_or_default(),
+        display_url: extract_domain(&url),
+        source: extract_domain(&url),
+        url,
+        score,
+        html_snippet: None,
+        date_published: None,
+        images: None,
+        content_chunks: None,
+        thumbnail: None,
+        source_outlet: None,
+        duration: None,
+        location: Some(PlaceLocation {
+            address: item.address.clone(),
+            coordinates,
+        }),
+    }
+}
+
+/// Number of results returned in the response, for whichever vertical was searched
+fn result_count(response: &SearchResponse) -> usize {
+    response.organic.len()
+        + response.news.len()
+        + response.images.len()
+        + response.videos.len()
+        + response.places.len()
+}
+
+fn extract_domain(url: &str) -> Option<String> {
+    if let Ok(parsed_url) = url::Url::parse(url) {
+        parsed_url.host_str().map(|host| host.to_string())
+    } else {
+        None
+    }
+}
+
+fn create_search_metadata(
+    response: &SearchResponse,
+    params: &SearchParams,
+    current_page: u32,
+) -> SearchMetadata {
+    // Check if we got the full count requested
+    let has_more_results = {
+        let requested_count = params.max_results.unwrap_or(10);
+        result_count(response) == (requested_count as usize)
+    };
+
+    // Create next page token if more results are available
+    let next_page_token = if has_more_results {
+        let next_page = current_page + 1;
+        Some(next_page.to_string())
+    } else {
+        None
+    };
+
+    // Estimate total results
+    let total_results = if (result_count(response) as u32) >= params.max_results.unwrap_or(10) {
+        Some(100000u64) // Conservative estimate
+    } else {
+        Some(result_count(response) as u64)
+    };
+
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results,
+        search_time_ms: None,
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token,
+        rate_limits: response.rate_limits,
+        current_page: current_page - 1, // 1-based
+        answer: if params.advanced_answer == Some(true) {
+            response_to_answer(response)
+        } else {
+            None
+        },
+        cache_status: None,
+        failed_providers: None,
+    }
+}
+
+/// Uses the answer box if Google returned one for the query, and falls back to the
+/// description of the knowledge graph entity otherwise.
+fn response_to_answer(response: &SearchResponse) -> Option<Answer> {
+    let from_answer_box = response.answer_box.as_ref().and_then(|answer_box| {
+        answer_box
+            .answer
+            .clone()
+            .or_else(|| answer_box.snippet.clone())
+            .map(|text| Answer {
+                text,
+                source_urls: answer_box.link.iter().cloned().collect(),
+            })
+    });
+
+    from_answer_box.or_else(|| {
+        response
+            .knowledge_graph
+            .as_ref()
+            .and_then(|knowledge_graph| {
+                knowledge_graph.description.clone().map(|text| Answer {
+                    text,
+                    source_urls: knowledge_graph
+                        .description_link
+                        .iter()
+                        .chain(knowledge_graph.website.iter())
+                        .cloned()
+                        .collect(),
+                })
+            })
+    })
+}
+
+pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError> {
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    if let Some(max_results) = params.max_results {
+        if max_results > 100 {
+            return Err(SearchError::UnsupportedFeature(
+                "max_results cannot exceed 100 for Serper Search".to_string(),
+            ));
+        }
+    }
+    if params.safe_search.is_some() {
+        return Err(SearchError::UnsupportedFeature(
+            "safe_search not supported".to_string(),
+        ));
+    }
+    if params.include_html == Some(true) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-html not supported".to_string(),
+        ));
+    }
+    if params.time_range.is_some() {
+        return Err(SearchError::UnsupportedFeature(
+            "time-range not supported".to_string(),
+        ));
+    }
+    if params.include_images == Some(true) && params.search_type != Some(SearchType::Images) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-images not supported".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{AutocompleteResponse, SearchResponse, Suggestion};
+    use crate::conversions::{
+        autocomplete_request, autocomplete_response_to_suggestions, params_to_request,
+        response_to_answer,
+    };
+    use golem_web_search::test_util::{query_operators, search_params_with_operators};
+    use serde_json::{json, Value};
+
+    fn search_response(extra: Value) -> SearchResponse {
+        let mut body = json!({
+            "searchParameters": {
+                "q": "eiffel tower height",
+                "type": "search",
+                "engine": "google"
+            },
+            "organic": [{
+                "title": "Eiffel Tower - Wikipedia",
+                "link": "https://en.wikipedia.org/wiki/Eiffel_Tower",
+                "snippet": "The Eiffel Tower is a wrought-iron lattice tower in Paris.",
+                "position": 1
+            }]
+        });
+        body.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn converts_answer_box_to_answer() {
+        let response = search_response(json!({
+            "answerBox": {
+                "title": "Eiffel Tower / Height",
+                "answer": "330 m",
+                "link": "https://www.toureiffel.paris/en/the-monument/key-figures"
+            },
+            "knowledgeGraph": {
+                "title": "Eiffel Tower",
+                "description": "The Eiffel Tower is a wrought-iron lattice tower on the Champ de Mars in Paris.",
+                "descriptionLink": "https://en.wikipedia.org/wiki/Eiffel_Tower"
+            }
+        }));
+        let answer = response_to_answer(&response).unwrap();
+        assert_eq!(answer.text, "330 m");
+        assert_eq!(
+            answer.source_urls,
+            vec!["https://www.toureiffel.paris/en/the-monument/key-figures"]
+        );
+
+        let response = search_response(json!({
+            "answerBox": {
+                "title": "How tall is the Eiffel Tower?",
+                "snippet": "The Eiffel Tower is 330 metres tall.",
+                "link": "https://www.toureiffel.paris/en"
+            }
+        }));
+        let answer = response_to_answer(&response).unwrap();
+        assert_eq!(answer.text, "The Eiffel Tower is 330 metres tall.");
+    }
+
+    #[test]
+    fn falls_back_to_knowledge_graph_description() {
+        let response = search_response(json!({
+            "knowledgeGraph": {
+                "title": "Eiffel Tower",
+                "type": "Tower",
+                "website": "https://www.toureiffel.paris/",
+                "description": "The Eiffel Tower is a wrought-iron lattice tower on the Champ de Mars in Paris.",
+                "descriptionSource": "Wikipedia",
+                "descriptionLink": "https://en.wikipedia.org/wiki/Eiffel_Tower",
+                "attributes": {"Height": "330 m"}
+            }
+        }));
+        let answer = response_to_answer(&response).unwrap();
+        assert_eq!(
+            answer.text,
+            "The Eiffel Tower is a wrought-iron lattice tower on the Champ de Mars in Paris."
+        );
+        assert_eq!(
+            answer.source_urls,
+            vec![
+                "https://en.wikipedia.org/wiki/Eiffel_Tower",
+                "https://www.toureiffel.paris/"
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_no_answer_without_answer_box_or_knowledge_graph() {
+        assert_eq!(response_to_answer(&search_response(json!({}))), None);
+
+        let response = search_response(json!({
+            "knowledgeGraph": {"title": "Eiffel Tower", "type": "Tower"}
+        }));
+        assert_eq!(response_to_answer(&response), None);
+    }
+
+    #[test]
+    fn compiles_query_operators_into_query() {
+        let request =
+            params_to_request(search_params_with_operators("rust", query_operators()), 1).unwrap();
+        assert_eq!(
+            request.q,
+            "(site:rust-lang.org OR site:docs.rs) rust \"borrow checker\" -java filetype:pdf"
+        );
+    }
+
+    #[test]
+    fn maps_autocomplete_region_and_language() {
+        assert!(autocomplete_request(" ".to_string(), None, None).is_err());
+        let request = autocomplete_request(
+            "rus".to_string(),
+            Some("English".to_string()),
+            Some("United Kingdom".to_string()),
+        )
+        .unwrap();
+        assert_eq!(request.hl, Some("en".to_string()));
+        assert_eq!(request.gl, Some("uk".to_string()));
+    }
+
+    #[test]
+    fn drops_empty_and_duplicate_suggestions() {
+        let response = AutocompleteResponse {
+            suggestions: ["rust", "", "rust", "rust book"]
+                .iter()
+                .map(|value| Suggestion {
+                    value: value.to_string(),
+                })
+                .collect(),
+        };
+        assert_eq!(
+            autocomplete_response_to_suggestions(response),
+            vec!["rust", "rust book"]
+        );
+    }
+}
@@ -1,13 +1,16 @@
 use golem_web_search::error::from_reqwest_error;
+use golem_web_search::golem::web_search::types::RateLimitInfo;
 use golem_web_search::golem::web_search::web_search::SearchError;
+use golem_web_search::rate_limit::{rate_limit_from_headers, retry_after_from_headers};
 use log::trace;
 use reqwest::Method;
-use reqwest::Response;
+use reqwest::{Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 const BASE_URL: &str = "https://api.tavily.com/search";
+const EXTRACT_URL: &str = "https://api.tavily.com/extract";
 
 /// The Tavily Search API client for web search with deep document indexing.
 pub struct TavilySearchApi {
@@ -32,6 +35,23 @@ impl TavilySearchApi {
             .send()
             .map_err(|err| from_reqwest_error("Request failed", err))?;
 
+        let rate_limits = rate_limit_from_headers(response.headers());
+        let mut body: SearchResponse = parse_response(response)?;
+        body.rate_limits = rate_limits;
+        Ok(body)
+    }
+
+    pub fn extract(&self, request: ExtractRequest) -> Result<ExtractResponse, SearchError> {
+        trace!("Sending request to Tavily Extract API: {request:?}");
+        let response = self
+            .client
+            .request(Method::POST, EXTRACT_URL)
+            .header("Content-Type", "application/json")
+            .bearer_auth(&self.api_key)
+            .json(&request)
+            .send()
+            .map_err(|err| from_reqwest_error("Request failed", err))?;
+
         parse_response(response)
     }
 
@@ -44,6 +64,8 @@ impl TavilySearchApi {
 pub struct SearchRequest {
     pub query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search_depth: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_images: Option<bool>,
@@ -71,6 +93,8 @@ pub struct SearchResponse {
     pub images: Option<Vec<String>>,
     pub results: Vec<SearchResult>,
     pub follow_up_questions: Option<Vec<String>>,
+    #[serde(skip)]
+    pub rate_limits: Option<RateLimitInfo>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +107,38 @@ pub struct SearchResult {
     pub published_date: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractRequest {
+    pub urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_images: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extract_depth: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractResponse {
+    pub results: Vec<ExtractedResult>,
+    #[serde(default)]
+    pub failed_results: Vec<FailedResult>,
+    pub response_time: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedResult {
+    pub url: String,
+    pub raw_content: Option<String>,
+    pub images: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedResult {
+    pub url: String,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
@@ -98,6 +154,12 @@ fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T,
 
         trace!("Received response from Tavily Search API: {body:?}");
         Ok(body)
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after_from_headers(response.headers());
+        trace!(
+            "Received {status} response from Tavily Search API, retry after {retry_after} seconds"
+        );
+        Err(SearchError::RateLimited(retry_after))
     } else {
         // Try to parse error response
         match response.json::<ErrorResponse>() {
@@ -108,7 +170,6 @@ fn parse_response<T: DeserializeOwned + Debug>(response: Response) -> Result<T,
                     400 => SearchError::InvalidQuery,
                     401 => SearchError::BackendError("Invalid API key".to_string()),
                     403 => SearchError::BackendError("API key quota exceeded".to_string()),
-                    429 => SearchError::RateLimited(60), // Default to 60 seconds
                     _ => SearchError::BackendError(format!(
                         "Request failed with {}: {}",
                         status, error_body.error
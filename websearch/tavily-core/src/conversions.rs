@@ -0,0 +1,419 @@
+use crate::client::{
+    ExtractRequest, ExtractResponse, SearchRequest, SearchResponse,
+    SearchResult as TavilySearchResult,
+};
+use golem_web_search::extract::{empty_metadata, truncate_content};
+use golem_web_search::golem::web_search::types::{
+    Answer, ContentFormat, ExtractFailure, ExtractedPage, ImageResult, SearchType, TimeRange,
+};
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, SearchError, SearchMetadata, SearchParams, SearchResult,
+};
+use golem_web_search::query::{excluded_sites, included_sites, quote, required_phrases};
+
+pub fn params_to_request(params: &SearchParams) -> Result<SearchRequest, SearchError> {
+    // Validate query
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+
+    // Determine search depth based on parameters
+    let search_depth = determine_search_depth(params);
+
+    // Convert time range to days
+    let days = params.time_range.map(|range| match range {
+        TimeRange::Day => 1,
+        TimeRange::Week => 7,
+        TimeRange::Month => 30,
+        TimeRange::Year => 365,
+    });
+
+    // Tavily has no search operators, the required phrases are added to the query in quotes
+    let mut query = params.query.trim().to_string();
+    if let Some(operators) = &params.query_operators {
+        for phrase in required_phrases(operators) {
+            query.push(' ');
+            query.push_str(&quote(&phrase));
+        }
+    }
+
+    // The domains are filtered with the include_domains and exclude_domains parameters
+    // rather than modifying the query directly
+    let include_domains = Some(included_sites(params)).filter(|d| !d.is_empty());
+    let exclude_domains = Some(excluded_sites(params)).filter(|d| !d.is_empty());
+
+    // Note: Tavily's SearchRequest doesn't have pagination fields (page/start/offset)
+    // This is a limitation of the current API structure
+    // News search uses the news topic, which also returns the publication dates
+    let topic = match params.search_type {
+        Some(SearchType::News) => Some("news".to_string()),
+        _ => None,
+    };
+
+    Ok(SearchRequest {
+        query,
+        topic,
+        search_depth: Some(search_depth),
+        include_images: params.include_images,
+        include_answer: Some(params.advanced_answer == Some(true)),
+        include_raw_content: Some(true), // Include raw content for better content chunks
+        max_results: params.max_results,
+        include_domains,
+        exclude_domains,
+        format: Some("json".to_string()),
+        days,
+    })
+}
+
+fn determine_search_depth(params: &SearchParams) -> String {
+    // Use "advanced" search depth if we need comprehensive results
+    // Use "basic" for faster, simpler searches
+    if params.max_results.unwrap_or(10) > 10 || params.include_images == Some(true) {
+        "advanced".to_string()
+    } else {
+        "basic".to_string()
+    }
+}
+
+pub fn response_to_results(
+    response: SearchResponse,
+    original_params: &SearchParams,
+) -> (Vec<SearchResult>, SearchMetadata) {
+    let mut results = Vec::new();
+
+    // Process main search results
+    for (index, item) in response.results.iter().enumerate() {
+        results.push(tavily_result_to_search_result(
+            item,
+            index,
+            original_params.include_images.unwrap_or(false),
+            &response.images,
+            original_params.search_type == Some(SearchType::News),
+        ));
+    }
+
+    let metadata = create_search_metadata(&response, original_params);
+    (results, metadata)
+}
+
+fn tavily_result_to_search_result(
+    item: &TavilySearchResult,
+    index: usize,
+    include_images: bool,
+    response_images: &Option<Vec<String>>,
+    is_news: bool,
+) -> SearchResult {
+    let mut images = None;
+    let mut content_chunks = None;
+
+    // Extract images if requested and available
+    if include_images {
+        if let Some(img_urls) = response_images {
+            if !img_urls.is_empty() {
+                images = Some(
+                    img_urls
+                        .iter()
+                        .map(|url| ImageResult {
+                            url: url.clone(),
+                            description: Some(format!("Image related to: {}", item.title)),
+                        })
+                        .collect(),
+                );
+            }
+        }
+    }
+
+    // Create content chunks from both content and raw_content
+    let mut chunks = Vec::new();
+
+    // Add main content
+    if !item.content.is_empty() {
+        chunks.push(item.content.clone());
+    }
+
+    // Add raw content if available and different from main content
+    if let Some(raw_content) = &item.raw_content {
+        if !raw_content.is_empty() && raw_content != &item.content {
+            chunks.push(raw_content.clone());
+        }
+    }
+
+    if !chunks.is_empty() {
+        content_chunks = Some(chunks);
+    }
+
+    // Use Tavily's score directly, but adjust for position bias
+    let adjusted_score = item.score * (1.0 - (index as f32) * 0.01);
+
+    SearchResult {
+        title: item.title.clone(),
+        url: item.url.clone(),
+        snippet: item.content.clone(),
+        display_url: extract_domain(&item.url),
+        source: extract_domain(&item.url),
+        score: Some(adjusted_score as f64),
+        html_snippet: None,
+        date_published: item.published_date.clone(),
+        images,
+        content_chunks,
+        thumbnail: None,
+        source_outlet: if is_news {
+            extract_domain(&item.url)
+        } else {
+            None
+        },
+        duration: None,
+        location: None,
+    }
+}
+
+fn extract_domain(url: &str) -> Option<String> {
+    if let Ok(parsed_url) = url::Url::parse(url) {
+        parsed_url.host_str().map(|host| host.to_string())
+    } else {
+        None
+    }
+}
+
+fn create_search_metadata(response: &SearchResponse, params: &SearchParams) -> SearchMetadata {
+    let total_results = Some(response.results.len() as u64);
+
+    SearchMetadata {
+        query: params.query.clone(),
+        total_results,
+        search_time_ms: Some(response.response_time as f64),
+        safe_search: params.safe_search,
+        language: params.language.clone(),
+        region: params.region.clone(),
+        next_page_token: None,
+        rate_limits: response.rate_limits,
+        current_page: 0,
+        answer: response_to_answer(response),
+        cache_status: None,
+        failed_providers: None,
+    }
+}
+
+/// Tavily's answer is generated from the returned results, so those are used as its sources
+fn response_to_answer(response: &SearchResponse) -> Option<Answer> {
+    response
+        .answer
+        .as_ref()
+        .filter(|answer| !answer.trim().is_empty())
+        .map(|answer| Answer {
+            text: answer.clone(),
+            source_urls: response
+                .results
+                .iter()
+                .map(|result| result.url.clone())
+                .collect(),
+        })
+}
+
+pub fn extract_options_to_request(urls: Vec<String>, options: &ExtractOptions) -> ExtractRequest {
+    let extract_depth = options
+        .provider_options
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == "extract_depth")
+        .map(|kv| kv.value.clone());
+
+    ExtractRequest {
+        urls,
+        include_images: options.include_images,
+        extract_depth,
+        format: Some(
+            match options.format.unwrap_or(ContentFormat::Markdown) {
+                ContentFormat::Markdown => "markdown",
+                ContentFormat::Text => "text",
+            }
+            .to_string(),
+        ),
+    }
+}
+
+/// Converts the extracted pages in the order of the requested URLs
+pub fn extract_response_to_results(
+    urls: &[String],
+    response: ExtractResponse,
+    options: &ExtractOptions,
+) -> Vec<ExtractResult> {
+    let format = options.format.unwrap_or(ContentFormat::Markdown);
+
+    urls.iter()
+        .map(|url| {
+            if let Some(result) = response.results.iter().find(|result| &result.url == url) {
+                ExtractResult::Success(ExtractedPage {
+                    url: url.clone(),
+                    title: None,
+                    content: truncate_content(
+                        result.raw_content.as_deref().unwrap_or_default(),
+                        options.max_characters,
+                    ),
+                    format,
+                    metadata: empty_metadata(),
+                    images: result.images.as_ref().map(|images| {
+                        images
+                            .iter()
+                            .map(|image| ImageResult {
+                                url: image.clone(),
+                                description: None,
+                            })
+                            .collect()
+                    }),
+                })
+            } else {
+                let message = response
+                    .failed_results
+                    .iter()
+                    .find(|failed| &failed.url == url)
+                    .and_then(|failed| failed.error.clone())
+                    .unwrap_or_else(|| "No content returned by Tavily".to_string());
+                ExtractResult::Failure(ExtractFailure {
+                    url: url.clone(),
+                    message,
+                })
+            }
+        })
+        .collect()
+}
+
+pub fn validate_search_params(params: &SearchParams) -> Result<(), SearchError> {
+    if params.query.trim().is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+    if let Some(max_results) = params.max_results {
+        if max_results > 500 {
+            return Err(SearchError::UnsupportedFeature(
+                "max_results cannot exceed 500 for Tavily Search".to_string(),
+            ));
+        }
+    }
+    if matches!(
+        params.search_type,
+        Some(SearchType::Images | SearchType::Videos | SearchType::Places)
+    ) {
+        return Err(SearchError::UnsupportedFeature(
+            "only web and news search types are supported".to_string(),
+        ));
+    }
+    if params.safe_search.is_some() {
+        return Err(SearchError::UnsupportedFeature(
+            "safe_search not supported".to_string(),
+        ));
+    }
+    if params.include_html == Some(true) {
+        return Err(SearchError::UnsupportedFeature(
+            "include-html not supported".to_string(),
+        ));
+    }
+    if let Some(operators) = &params.query_operators {
+        if operators
+            .must_not_terms
+            .as_ref()
+            .is_some_and(|terms| !terms.is_empty())
+        {
+            return Err(SearchError::UnsupportedFeature(
+                "must-not-terms not supported".to_string(),
+            ));
+        }
+        if operators.filetype.is_some() {
+            return Err(SearchError::UnsupportedFeature(
+                "filetype not supported".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::SearchResponse;
+    use crate::conversions::{params_to_request, response_to_answer, validate_search_params};
+    use golem_web_search::golem::web_search::web_search::SearchError;
+    use golem_web_search::test_util::{query_operators, search_params_with_operators};
+    use serde_json::{json, Value};
+
+    fn search_response(answer: Value) -> SearchResponse {
+        serde_json::from_value(json!({
+            "query": "Who is Leo Messi?",
+            "follow_up_questions": null,
+            "answer": answer,
+            "images": [],
+            "results": [
+                {
+                    "title": "Lionel Messi - Wikipedia",
+                    "url": "https://en.wikipedia.org/wiki/Lionel_Messi",
+                    "content": "Lionel Andrés Messi is an Argentine professional footballer.",
+                    "score": 0.81,
+                    "raw_content": null
+                },
+                {
+                    "title": "Lionel Messi | Biography & Facts | Britannica",
+                    "url": "https://www.britannica.com/biography/Lionel-Messi",
+                    "content": "Lionel Messi, Argentine-born football player.",
+                    "score": 0.77,
+                    "raw_content": null
+                }
+            ],
+            "response_time": 1.67
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn converts_answer_citing_the_results() {
+        let response = search_response(json!(
+            "Lionel Messi is an Argentine footballer who plays for Inter Miami."
+        ));
+        let answer = response_to_answer(&response).unwrap();
+        assert_eq!(
+            answer.text,
+            "Lionel Messi is an Argentine footballer who plays for Inter Miami."
+        );
+        assert_eq!(
+            answer.source_urls,
+            vec![
+                "https://en.wikipedia.org/wiki/Lionel_Messi",
+                "https://www.britannica.com/biography/Lionel-Messi"
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_no_answer_without_answer() {
+        assert_eq!(response_to_answer(&search_response(Value::Null)), None);
+        assert_eq!(response_to_answer(&search_response(json!(""))), None);
+    }
+
+    #[test]
+    fn adds_required_phrases_to_query_and_sites_to_domains() {
+        let mut operators = query_operators();
+        operators.must_terms = Some(vec!["lifetimes".to_string()]);
+        operators.must_not_terms = None;
+        operators.filetype = None;
+        let request = params_to_request(&search_params_with_operators("rust", operators)).unwrap();
+        assert_eq!(request.query, "rust \"borrow checker\" \"lifetimes\"");
+        assert_eq!(
+            request.include_domains,
+            Some(vec!["rust-lang.org".to_string(), "docs.rs".to_string()])
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_operators() {
+        let mut operators = query_operators();
+        operators.filetype = None;
+        assert!(matches!(
+            validate_search_params(&search_params_with_operators("rust", operators.clone())),
+            Err(SearchError::UnsupportedFeature(_))
+        ));
+
+        operators.must_not_terms = None;
+        operators.filetype = Some("pdf".to_string());
+        assert!(matches!(
+            validate_search_params(&search_params_with_operators("rust", operators)),
+            Err(SearchError::UnsupportedFeature(_))
+        ));
+    }
+}
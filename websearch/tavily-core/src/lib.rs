@@ -0,0 +1,231 @@
+mod client;
+mod conversions;
+
+use crate::client::TavilySearchApi;
+use crate::conversions::{
+    extract_options_to_request, extract_response_to_results, params_to_request,
+    response_to_results, validate_search_params,
+};
+use golem_web_search::cursor::{decode_cursor, encode_cursor};
+use golem_web_search::durability::ExtendedwebsearchGuest;
+use golem_web_search::extract::validate_extract_urls;
+use golem_web_search::golem::web_search::web_search::{
+    ExtractOptions, ExtractResult, Guest, GuestSearchSession, SearchError, SearchMetadata,
+    SearchParams, SearchResult, SearchSession,
+};
+use std::cell::RefCell;
+
+/// Identifies the cursors of this provider
+const CURSOR_PROVIDER: &str = "tavily";
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    golem_rust::FromValueAndType,
+    golem_rust::IntoValue,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct TavilyReplayState {
+    /// Not part of the cursors, the resuming worker uses its own configuration
+    #[serde(skip)]
+    pub api_key: String,
+    pub metadata: Option<SearchMetadata>,
+    pub finished: bool,
+}
+
+struct TavilySearch {
+    client: TavilySearchApi,
+    params: SearchParams,
+    metadata: Option<SearchMetadata>,
+    finished: bool,
+}
+
+impl TavilySearch {
+    fn new(client: TavilySearchApi, params: SearchParams) -> Self {
+        Self {
+            client,
+            params,
+            metadata: None,
+            finished: false,
+        }
+    }
+
+    fn next_page(&mut self) -> Result<Vec<SearchResult>, SearchError> {
+        if self.finished {
+            return Ok(Vec::new());
+        }
+
+        let request = crate::conversions::params_to_request(&self.params)?;
+        let response = self.client.search(request)?;
+        let (results, metadata) = response_to_results(response, &self.params);
+
+        self.finished = true;
+        self.metadata = Some(metadata);
+        Ok(results)
+    }
+
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        self.metadata.clone()
+    }
+}
+
+// Create a wrapper that implements GuestSearchSession properly
+pub struct TavilySearchSession(RefCell<TavilySearch>);
+
+impl TavilySearchSession {
+    fn new(search: TavilySearch) -> Self {
+        Self(RefCell::new(search))
+    }
+}
+
+impl GuestSearchSession for TavilySearchSession {
+    fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
+        let mut search = self.0.borrow_mut();
+        search.next_page()
+    }
+    fn get_metadata(&self) -> Option<SearchMetadata> {
+        let search = self.0.borrow();
+        search.get_metadata()
+    }
+
+    fn get_cursor(&self) -> Result<String, SearchError> {
+        encode_cursor(
+            CURSOR_PROVIDER,
+            &TavilySearchComponent::session_to_state(self),
+        )
+    }
+}
+
+pub struct TavilySearchComponent;
+
+impl TavilySearchComponent {
+    const API_KEY_VAR: &'static str = "TAVILY_API_KEY";
+
+    fn create_client() -> Result<TavilySearchApi, SearchError> {
+        let api_key = Self::get_api_key()?;
+        Ok(TavilySearchApi::new(api_key))
+    }
+
+    fn get_api_key() -> Result<String, SearchError> {
+        std::env::var(Self::API_KEY_VAR).map_err(|_| {
+            SearchError::BackendError("TAVILY_API_KEY environment variable not set".to_string())
+        })
+    }
+
+    fn execute_search(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, SearchMetadata), SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let request = params_to_request(&params)?;
+
+        let response = client.search(request)?;
+        let (results, metadata) = response_to_results(response, &params);
+
+        // Unwrap the metadata Option since we know it should be Some
+        Ok((results, metadata))
+    }
+
+    fn start_search_session(params: SearchParams) -> Result<TavilySearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let client = Self::create_client()?;
+        let search = TavilySearch::new(client, params);
+        Ok(TavilySearchSession::new(search))
+    }
+
+    /// Restores a session from the state of a cursor, with the client configured for this worker
+    pub fn resume_session(
+        state: &TavilyReplayState,
+        params: SearchParams,
+    ) -> Result<TavilySearchSession, SearchError> {
+        validate_search_params(&params)?;
+
+        let session = Self::session_from_state(state, params)?;
+        session.0.borrow_mut().client = Self::create_client()?;
+        Ok(session)
+    }
+}
+
+impl Guest for TavilySearchComponent {
+    type SearchSession = TavilySearchSession;
+
+    fn start_search(params: SearchParams) -> Result<SearchSession, SearchError> {
+        match Self::start_search_session(params) {
+            Ok(session) => Ok(SearchSession::new(session)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn resume_search(params: SearchParams, cursor: String) -> Result<SearchSession, SearchError> {
+        Self::unwrapped_resume_search(params, cursor).map(SearchSession::new)
+    }
+
+    fn search_once(
+        params: SearchParams,
+    ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
+        let (results, metadata) = Self::execute_search(params)?;
+        Ok((results, Some(metadata)))
+    }
+
+    fn suggest(
+        _prefix: String,
+        _language: Option<String>,
+        _region: Option<String>,
+    ) -> Result<Vec<String>, SearchError> {
+        Err(SearchError::UnsupportedFeature(
+            "suggest not supported".to_string(),
+        ))
+    }
+
+    fn extract(
+        urls: Vec<String>,
+        options: ExtractOptions,
+    ) -> Result<Vec<ExtractResult>, SearchError> {
+        validate_extract_urls(&urls)?;
+
+        let client = Self::create_client()?;
+        let request = extract_options_to_request(urls.clone(), &options);
+        let response = client.extract(request)?;
+
+        Ok(extract_response_to_results(&urls, response, &options))
+    }
+}
+
+impl ExtendedwebsearchGuest for TavilySearchComponent {
+    type ReplayState = TavilyReplayState;
+
+    fn unwrapped_search_session(params: SearchParams) -> Result<Self::SearchSession, SearchError> {
+        Self::start_search_session(params)
+    }
+
+    fn unwrapped_resume_search(
+        params: SearchParams,
+        cursor: String,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let state: TavilyReplayState = decode_cursor(CURSOR_PROVIDER, &cursor)?;
+        Self::resume_session(&state, params)
+    }
+
+    fn session_to_state(session: &Self::SearchSession) -> Self::ReplayState {
+        let search = session.0.borrow_mut();
+        TavilyReplayState {
+            api_key: search.client.api_key().to_string(),
+            metadata: search.metadata.clone(),
+            finished: search.finished,
+        }
+    }
+    fn session_from_state(
+        state: &Self::ReplayState,
+        params: SearchParams,
+    ) -> Result<Self::SearchSession, SearchError> {
+        let client = TavilySearchApi::new(state.api_key.clone());
+        let mut search = TavilySearch::new(client, params);
+        search.metadata = state.metadata.clone();
+        search.finished = state.finished;
+        Ok(TavilySearchSession::new(search))
+    }
+}
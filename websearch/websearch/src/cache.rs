@@ -0,0 +1,356 @@
+use crate::golem::web_search::types::{CacheStatus, QueryOperators};
+use crate::golem::web_search::web_search::{SearchMetadata, SearchParams, SearchResult};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Enables the cache with the number of seconds a cached page stays valid
+const TTL_VAR: &str = "GOLEM_WEB_SEARCH_CACHE_TTL_SECONDS";
+const MAX_ENTRIES_VAR: &str = "GOLEM_WEB_SEARCH_CACHE_MAX_ENTRIES";
+const DEFAULT_MAX_ENTRIES: usize = 100;
+
+/// The cached operations, a `search-once` response is not reused as the first page of a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum CacheKind {
+    SearchOnce,
+    FirstPage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CacheConfig {
+    ttl_millis: u64,
+    max_entries: usize,
+}
+
+impl CacheConfig {
+    /// Reads the configuration from the environment, the cache is disabled unless a TTL is set
+    fn from_env() -> Option<Self> {
+        let ttl_seconds = std::env::var(TTL_VAR)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|ttl_seconds| *ttl_seconds > 0)?;
+        let max_entries = std::env::var(MAX_ENTRIES_VAR)
+            .ok()
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .unwrap_or(DEFAULT_MAX_ENTRIES);
+        if max_entries == 0 {
+            return None;
+        }
+
+        Some(Self {
+            ttl_millis: ttl_seconds.saturating_mul(1000),
+            max_entries,
+        })
+    }
+}
+
+struct CacheEntry {
+    inserted_at: u64,
+    value: Box<dyn Any>,
+}
+
+/// In-memory cache of the pages, keyed by the hash of the normalized search parameters
+#[derive(Default)]
+struct ResultCache {
+    entries: HashMap<(CacheKind, u64), CacheEntry>,
+}
+
+impl ResultCache {
+    fn get<T: Clone + 'static>(
+        &mut self,
+        key: (CacheKind, u64),
+        config: &CacheConfig,
+        now: u64,
+    ) -> Option<T> {
+        let entry = self.entries.get(&key)?;
+        if now.saturating_sub(entry.inserted_at) >= config.ttl_millis {
+            self.entries.remove(&key);
+            return None;
+        }
+        entry.value.downcast_ref::<T>().cloned()
+    }
+
+    /// Inserts a value, evicting the expired entries and then the oldest ones when the cache is full
+    fn insert<T: 'static>(
+        &mut self,
+        key: (CacheKind, u64),
+        value: T,
+        config: &CacheConfig,
+        now: u64,
+    ) {
+        self.entries.remove(&key);
+        if self.entries.len() >= config.max_entries {
+            self.entries
+                .retain(|_, entry| now.saturating_sub(entry.inserted_at) < config.ttl_millis);
+        }
+        while self.entries.len() >= config.max_entries {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(key, entry)| (entry.inserted_at, **key))
+                .map(|(key, _)| *key)
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+
+        self.entries.insert(
+            key,
+            CacheEntry {
+                inserted_at: now,
+                value: Box::new(value),
+            },
+        );
+    }
+}
+
+thread_local! {
+    static RESULT_CACHE: RefCell<ResultCache> = RefCell::new(ResultCache::default());
+}
+
+/// Opt-in cache of the `search-once` responses and the first pages of search sessions, enabled
+/// by setting `GOLEM_WEB_SEARCH_CACHE_TTL_SECONDS`.
+///
+/// The cache lives in the memory of the worker. To keep it consistent across durable replay,
+/// `open` reads the current time outside of the durable operations, so the time is recorded in the
+/// oplog, and the replayed misses are stored again with their original timestamps. The replayed
+/// hits are returned from the oplog and do not touch the cache.
+pub struct SearchCache {
+    config: CacheConfig,
+    now: u64,
+}
+
+impl SearchCache {
+    /// Returns the cache if it is enabled. Must not be called with `PersistNothing`, the current
+    /// time has to be persisted for the replay.
+    pub fn open() -> Option<Self> {
+        let config = CacheConfig::from_env()?;
+        Some(Self {
+            config,
+            now: now_millis(),
+        })
+    }
+
+    /// Returns the cached response of `search-once` for the parameters, marked as a cache hit
+    pub fn search_once(
+        &self,
+        params: &SearchParams,
+    ) -> Option<(Vec<SearchResult>, Option<SearchMetadata>)> {
+        let (results, metadata) =
+            self.get::<(Vec<SearchResult>, Option<SearchMetadata>)>(CacheKind::SearchOnce, params)?;
+        Some((results, with_cache_status(metadata, CacheStatus::Hit)))
+    }
+
+    /// Stores a response of `search-once` which was not served from the cache, marking it as a
+    /// cache miss. Responses without metadata are not cached, as they cannot report the status.
+    pub fn remember_search_once(
+        &self,
+        params: &SearchParams,
+        response: &mut (Vec<SearchResult>, Option<SearchMetadata>),
+    ) {
+        let Some(metadata) = &mut response.1 else {
+            return;
+        };
+        if metadata.cache_status == Some(CacheStatus::Hit) {
+            return;
+        }
+        metadata.cache_status = Some(CacheStatus::Miss);
+        self.put(CacheKind::SearchOnce, params, response.clone());
+    }
+
+    /// Returns the cached first page of a search session and the session state after it
+    pub fn first_page<S: Clone + 'static>(
+        &self,
+        params: &SearchParams,
+    ) -> Option<(Vec<SearchResult>, S)> {
+        self.get(CacheKind::FirstPage, params)
+    }
+
+    /// Stores the first page of a search session and the session state after it
+    pub fn remember_first_page<S: Clone + 'static>(
+        &self,
+        params: &SearchParams,
+        results: &[SearchResult],
+        state: &S,
+    ) {
+        self.put(
+            CacheKind::FirstPage,
+            params,
+            (results.to_vec(), state.clone()),
+        );
+    }
+
+    fn get<T: Clone + 'static>(&self, kind: CacheKind, params: &SearchParams) -> Option<T> {
+        let key = (kind, params_hash(params));
+        RESULT_CACHE.with_borrow_mut(|cache| cache.get(key, &self.config, self.now))
+    }
+
+    fn put<T: 'static>(&self, kind: CacheKind, params: &SearchParams, value: T) {
+        let key = (kind, params_hash(params));
+        RESULT_CACHE.with_borrow_mut(|cache| cache.insert(key, value, &self.config, self.now));
+    }
+}
+
+fn with_cache_status(
+    metadata: Option<SearchMetadata>,
+    cache_status: CacheStatus,
+) -> Option<SearchMetadata> {
+    metadata.map(|metadata| SearchMetadata {
+        cache_status: Some(cache_status),
+        ..metadata
+    })
+}
+
+/// Hashes the search parameters after normalizing the parts which do not change the results,
+/// like the case and whitespace of the query and the order of the domains
+fn params_hash(params: &SearchParams) -> u64 {
+    let normalized = normalize_params(params);
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(&normalized)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+fn normalize_params(params: &SearchParams) -> SearchParams {
+    let mut provider_options = params.provider_options.clone().unwrap_or_default();
+    provider_options.sort_by(|a, b| (&a.key, &a.value).cmp(&(&b.key, &b.value)));
+
+    SearchParams {
+        query: normalize_text(&params.query),
+        safe_search: params.safe_search,
+        language: params.language.as_deref().map(normalize_text),
+        region: params.region.as_deref().map(normalize_text),
+        max_results: params.max_results,
+        time_range: params.time_range,
+        include_domains: normalize_terms(&params.include_domains),
+        exclude_domains: normalize_terms(&params.exclude_domains),
+        include_images: params.include_images,
+        include_html: params.include_html,
+        advanced_answer: params.advanced_answer,
+        search_type: params.search_type,
+        query_operators: params
+            .query_operators
+            .as_ref()
+            .map(|operators| QueryOperators {
+                exact_phrases: normalize_terms(&operators.exact_phrases),
+                must_terms: normalize_terms(&operators.must_terms),
+                must_not_terms: normalize_terms(&operators.must_not_terms),
+                filetype: operators.filetype.as_deref().map(normalize_text),
+                sites: normalize_terms(&operators.sites),
+            }),
+        provider_options: Some(provider_options).filter(|options| !options.is_empty()),
+    }
+}
+
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn normalize_terms(terms: &Option<Vec<String>>) -> Option<Vec<String>> {
+    let mut terms = terms
+        .iter()
+        .flatten()
+        .map(|term| normalize_text(term))
+        .filter(|term| !term.is_empty())
+        .collect::<Vec<_>>();
+    terms.sort();
+    terms.dedup();
+    Some(terms).filter(|terms| !terms.is_empty())
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{params_hash, CacheConfig, CacheKind, ResultCache};
+    use crate::golem::web_search::types::Kv;
+    use crate::test_util::search_params;
+
+    const CONFIG: CacheConfig = CacheConfig {
+        ttl_millis: 1000,
+        max_entries: 2,
+    };
+
+    #[test]
+    fn normalizes_equivalent_params() {
+        let mut a = search_params("Rust  ownership ");
+        a.include_domains = Some(vec!["docs.rs".to_string(), "Rust-Lang.org".to_string()]);
+        a.exclude_domains = Some(vec![]);
+        a.provider_options = Some(vec![
+            Kv {
+                key: "b".to_string(),
+                value: "2".to_string(),
+            },
+            Kv {
+                key: "a".to_string(),
+                value: "1".to_string(),
+            },
+        ]);
+
+        let mut b = search_params("rust ownership");
+        b.include_domains = Some(vec!["rust-lang.org".to_string(), "docs.rs".to_string()]);
+        b.provider_options = Some(vec![
+            Kv {
+                key: "a".to_string(),
+                value: "1".to_string(),
+            },
+            Kv {
+                key: "b".to_string(),
+                value: "2".to_string(),
+            },
+        ]);
+
+        assert_eq!(params_hash(&a), params_hash(&b));
+
+        b.max_results = Some(5);
+        assert_ne!(params_hash(&a), params_hash(&b));
+    }
+
+    #[test]
+    fn expires_entries_after_ttl() {
+        let mut cache = ResultCache::default();
+        let key = (CacheKind::SearchOnce, 1);
+        cache.insert(key, "page".to_string(), &CONFIG, 0);
+
+        assert_eq!(
+            cache.get::<String>(key, &CONFIG, 999),
+            Some("page".to_string())
+        );
+        assert_eq!(cache.get::<String>(key, &CONFIG, 1000), None);
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn evicts_oldest_entry_when_full() {
+        let mut cache = ResultCache::default();
+        cache.insert((CacheKind::SearchOnce, 1), 1u32, &CONFIG, 0);
+        cache.insert((CacheKind::FirstPage, 1), 2u32, &CONFIG, 10);
+        cache.insert((CacheKind::SearchOnce, 2), 3u32, &CONFIG, 20);
+
+        assert_eq!(
+            cache.get::<u32>((CacheKind::SearchOnce, 1), &CONFIG, 30),
+            None
+        );
+        assert_eq!(
+            cache.get::<u32>((CacheKind::FirstPage, 1), &CONFIG, 30),
+            Some(2)
+        );
+        assert_eq!(
+            cache.get::<u32>((CacheKind::SearchOnce, 2), &CONFIG, 30),
+            Some(3)
+        );
+    }
+}
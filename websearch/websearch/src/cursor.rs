@@ -0,0 +1,103 @@
+use crate::golem::web_search::web_search::SearchError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+struct CursorRef<'a, T> {
+    provider: &'a str,
+    state: &'a T,
+}
+
+#[derive(Deserialize)]
+struct Cursor<T> {
+    provider: String,
+    state: T,
+}
+
+/// Encodes the replay state of a search session as an opaque, URL safe cursor string.
+///
+/// The cursor is tagged with the provider, so it cannot be resumed by a different provider.
+/// Credentials have to be excluded from the state with `#[serde(skip)]`, the resuming worker
+/// uses its own configuration.
+pub fn encode_cursor<T: Serialize>(provider: &str, state: &T) -> Result<String, SearchError> {
+    let json = serde_json::to_vec(&CursorRef { provider, state }).map_err(|err| {
+        SearchError::BackendError(format!("Failed to encode search cursor: {err}"))
+    })?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Decodes the replay state from a cursor created by `encode_cursor` for the same provider
+pub fn decode_cursor<T: DeserializeOwned>(provider: &str, cursor: &str) -> Result<T, SearchError> {
+    let json = URL_SAFE_NO_PAD
+        .decode(cursor.trim())
+        .map_err(|err| SearchError::BackendError(format!("Invalid search cursor: {err}")))?;
+    let cursor: Cursor<T> = serde_json::from_slice(&json)
+        .map_err(|err| SearchError::BackendError(format!("Invalid search cursor: {err}")))?;
+
+    if cursor.provider != provider {
+        return Err(SearchError::BackendError(format!(
+            "The search cursor was created by the {} provider and cannot be resumed by {provider}",
+            cursor.provider
+        )));
+    }
+    Ok(cursor.state)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cursor::{decode_cursor, encode_cursor};
+    use crate::golem::web_search::web_search::SearchError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct State {
+        #[serde(skip)]
+        api_key: String,
+        current_page: u32,
+    }
+
+    #[test]
+    fn roundtrip_without_credentials() {
+        let state = State {
+            api_key: "secret".to_string(),
+            current_page: 3,
+        };
+        let cursor = encode_cursor("brave", &state).unwrap();
+        assert!(cursor
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        let decoded: State = decode_cursor("brave", &cursor).unwrap();
+        assert_eq!(
+            decoded,
+            State {
+                api_key: String::new(),
+                current_page: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_cursor_of_other_provider() {
+        let cursor = encode_cursor(
+            "brave",
+            &State {
+                api_key: String::new(),
+                current_page: 1,
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            decode_cursor::<State>("google", &cursor),
+            Err(SearchError::BackendError(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_cursor() {
+        assert!(decode_cursor::<State>("brave", "not a cursor").is_err());
+        assert!(decode_cursor::<State>("brave", "e30").is_err());
+    }
+}
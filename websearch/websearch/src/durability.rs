@@ -10,11 +10,18 @@ pub struct Durablewebsearch<Impl> {
 
 /// Trait to be implemented in addition to the websearch `Guest` trait when wrapping it with `Durablewebsearch`.
 pub trait ExtendedwebsearchGuest: Guest + 'static {
-    type ReplayState: std::fmt::Debug + Clone + IntoValueTrait + FromValueAndType;
+    type ReplayState: std::fmt::Debug + Clone + IntoValueTrait + FromValueAndType + 'static;
 
     /// Creates an instance of the websearch specific `SearchSession` without wrapping it in a `Resource`
     fn unwrapped_search_session(params: SearchParams) -> Result<Self::SearchSession, SearchError>;
 
+    /// Creates an instance of the websearch specific `SearchSession` from a cursor returned by
+    /// `get-cursor`, using the credentials configured for the current worker
+    fn unwrapped_resume_search(
+        params: SearchParams,
+        cursor: String,
+    ) -> Result<Self::SearchSession, SearchError>;
+
     /// Used at the end of replay to go from replay to live mode
     fn session_to_state(session: &Self::SearchSession) -> Self::ReplayState;
     fn session_from_state(
@@ -26,11 +33,12 @@ pub trait ExtendedwebsearchGuest: Guest + 'static {
 /// When the durability feature flag is off, wrapping with `Durablewebsearch` is just a passthrough
 #[cfg(not(feature = "durability"))]
 mod passthrough_impl {
+    use crate::cache::SearchCache;
     use crate::durability::{Durablewebsearch, ExtendedwebsearchGuest};
-    use crate::golem::web_search::web_search::{Guest, SearchSession};
     use crate::golem::web_search::web_search::{
-        SearchError, SearchMetadata, SearchParams, SearchResult,
+        ExtractOptions, ExtractResult, SearchError, SearchMetadata, SearchParams, SearchResult,
     };
+    use crate::golem::web_search::web_search::{Guest, SearchSession};
     use crate::init_logging;
 
     impl<Impl: ExtendedwebsearchGuest> Guest for Durablewebsearch<Impl> {
@@ -41,11 +49,45 @@ mod passthrough_impl {
             Impl::start_search(params)
         }
 
+        fn resume_search(
+            params: SearchParams,
+            cursor: String,
+        ) -> Result<SearchSession, SearchError> {
+            init_logging();
+            Impl::resume_search(params, cursor)
+        }
+
         fn search_once(
             params: SearchParams,
         ) -> Result<(Vec<SearchResult>, Option<SearchMetadata>), SearchError> {
             init_logging();
-            Impl::search_once(params)
+
+            let cache = SearchCache::open();
+            if let Some(response) = cache.as_ref().and_then(|cache| cache.search_once(&params)) {
+                return Ok(response);
+            }
+            let mut result = Impl::search_once(params.clone());
+            if let (Some(cache), Ok(response)) = (&cache, &mut result) {
+                cache.remember_search_once(&params, response);
+            }
+            result
+        }
+
+        fn suggest(
+            prefix: String,
+            language: Option<String>,
+            region: Option<String>,
+        ) -> Result<Vec<String>, SearchError> {
+            init_logging();
+            Impl::suggest(prefix, language, region)
+        }
+
+        fn extract(
+            urls: Vec<String>,
+            options: ExtractOptions,
+        ) -> Result<Vec<ExtractResult>, SearchError> {
+            init_logging();
+            Impl::extract(urls, options)
         }
     }
 }
@@ -60,20 +102,41 @@ mod passthrough_impl {
 /// which is implemented using the type classes and builder in the `golem-rust` library.
 #[cfg(feature = "durability")]
 mod durable_impl {
+    use crate::cache::SearchCache;
     use crate::durability::{Durablewebsearch, ExtendedwebsearchGuest};
-    use crate::exports::golem::web_search::web_search::{Guest, GuestSearchSession, SearchSession};
+    use crate::exports::golem::web_search::types::CacheStatus;
     use crate::exports::golem::web_search::web_search::{
-        SearchError, SearchMetadata, SearchParams, SearchResult,
+        ExtractOptions, ExtractResult, SearchError, SearchMetadata, SearchParams, SearchResult,
     };
+    use crate::exports::golem::web_search::web_search::{Guest, GuestSearchSession, SearchSession};
     use crate::init_logging;
     use golem_rust::bindings::golem::durability::durability::DurableFunctionType;
     use golem_rust::durability::Durability;
     use golem_rust::{with_persistence_level, PersistenceLevel};
-    use std::cell::RefCell;
+    use std::cell::{Cell, RefCell};
 
     #[derive(Debug, golem_rust::IntoValue)]
     struct NoInput;
 
+    #[derive(Debug, golem_rust::IntoValue)]
+    struct ResumeSearchInput {
+        params: SearchParams,
+        cursor: String,
+    }
+
+    #[derive(Debug, golem_rust::IntoValue)]
+    struct SuggestInput {
+        prefix: String,
+        language: Option<String>,
+        region: Option<String>,
+    }
+
+    #[derive(Debug, golem_rust::IntoValue)]
+    struct ExtractInput {
+        urls: Vec<String>,
+        options: ExtractOptions,
+    }
+
     // Add the From implementation for SearchError to satisfy the Durability trait bounds
     impl From<&SearchError> for SearchError {
         fn from(error: &SearchError) -> Self {
@@ -102,12 +165,61 @@ mod durable_impl {
                     Ok(session) => {
                         let replay_state = Impl::session_to_state(&session);
                         let _ = durability.persist(params.clone(), Ok(replay_state));
+                        Ok(SearchSession::new(
+                            DurableSearchSession::<Impl>::live(session, params).with_cache(),
+                        ))
+                    }
+                    Err(error) => {
+                        let _ = durability.persist(params.clone(), Err(error.clone()));
+                        Err(error)
+                    }
+                }
+            } else {
+                let replay_state = durability.replay::<Impl::ReplayState, SearchError>()?;
+                let session = DurableSearchSession::<Impl>::replay(replay_state, params)?;
+                Ok(SearchSession::new(session.with_cache()))
+            }
+        }
+
+        fn resume_search(
+            params: SearchParams,
+            cursor: String,
+        ) -> Result<SearchSession, SearchError> {
+            init_logging();
+
+            let durability = Durability::<Impl::ReplayState, SearchError>::new(
+                "golem_websearch",
+                "resume_search",
+                DurableFunctionType::WriteRemote,
+            );
+
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::unwrapped_resume_search(params.clone(), cursor.clone())
+                });
+
+                match result {
+                    Ok(session) => {
+                        let replay_state = Impl::session_to_state(&session);
+                        let _ = durability.persist(
+                            ResumeSearchInput {
+                                params: params.clone(),
+                                cursor,
+                            },
+                            Ok(replay_state),
+                        );
                         Ok(SearchSession::new(DurableSearchSession::<Impl>::live(
                             session, params,
                         )))
                     }
                     Err(error) => {
-                        let _ = durability.persist(params.clone(), Err(error.clone()));
+                        let _ = durability.persist(
+                            ResumeSearchInput {
+                                params: params.clone(),
+                                cursor,
+                            },
+                            Err(error.clone()),
+                        );
                         Err(error)
                     }
                 }
@@ -130,11 +242,80 @@ mod durable_impl {
                     DurableFunctionType::WriteRemote,
                 );
 
+            // Opened before the durable operation, so the current time is persisted
+            let cache = SearchCache::open();
+
             if durability.is_live() {
                 let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
-                    Impl::search_once(params.clone())
+                    if let Some(response) =
+                        cache.as_ref().and_then(|cache| cache.search_once(&params))
+                    {
+                        return Ok(response);
+                    }
+                    let mut result = Impl::search_once(params.clone());
+                    if let (Some(cache), Ok(response)) = (&cache, &mut result) {
+                        cache.remember_search_once(&params, response);
+                    }
+                    result
                 });
                 durability.persist(params, result)
+            } else {
+                let mut result = durability.replay();
+                // Restores the misses in the cache, so the next live calls see the same entries
+                if let (Some(cache), Ok(response)) = (&cache, &mut result) {
+                    cache.remember_search_once(&params, response);
+                }
+                result
+            }
+        }
+
+        fn suggest(
+            prefix: String,
+            language: Option<String>,
+            region: Option<String>,
+        ) -> Result<Vec<String>, SearchError> {
+            init_logging();
+
+            let durability = Durability::<Vec<String>, SearchError>::new(
+                "golem_websearch",
+                "suggest",
+                DurableFunctionType::WriteRemote,
+            );
+
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::suggest(prefix.clone(), language.clone(), region.clone())
+                });
+                durability.persist(
+                    SuggestInput {
+                        prefix,
+                        language,
+                        region,
+                    },
+                    result,
+                )
+            } else {
+                durability.replay()
+            }
+        }
+
+        fn extract(
+            urls: Vec<String>,
+            options: ExtractOptions,
+        ) -> Result<Vec<ExtractResult>, SearchError> {
+            init_logging();
+
+            let durability = Durability::<Vec<ExtractResult>, SearchError>::new(
+                "golem_websearch",
+                "extract",
+                DurableFunctionType::WriteRemote,
+            );
+
+            if durability.is_live() {
+                let result = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    Impl::extract(urls.clone(), options.clone())
+                });
+                durability.persist(ExtractInput { urls, options }, result)
             } else {
                 durability.replay()
             }
@@ -160,6 +341,10 @@ mod durable_impl {
     pub struct DurableSearchSession<Impl: ExtendedwebsearchGuest> {
         state: RefCell<Option<DurableSearchSessionState<Impl>>>,
         params: SearchParams,
+        /// Whether the next page is the first page of a new search, which can be served from the cache
+        cacheable: Cell<bool>,
+        /// Cache status of the last returned page
+        cache_status: Cell<Option<CacheStatus>>,
     }
 
     impl<Impl: ExtendedwebsearchGuest> DurableSearchSession<Impl> {
@@ -167,6 +352,8 @@ mod durable_impl {
             Self {
                 state: RefCell::new(Some(DurableSearchSessionState::Live { session })),
                 params,
+                cacheable: Cell::new(false),
+                cache_status: Cell::new(None),
             }
         }
 
@@ -177,8 +364,28 @@ mod durable_impl {
             Ok(Self {
                 state: RefCell::new(Some(DurableSearchSessionState::Replay { replay_state })),
                 params,
+                cacheable: Cell::new(false),
+                cache_status: Cell::new(None),
             })
         }
+
+        /// Allows serving the first page from the result cache, only used for new searches
+        fn with_cache(self) -> Self {
+            self.cacheable.set(true);
+            self
+        }
+
+        /// Stores a first page which was not served from the cache, returning the status to persist
+        fn remember_first_page(
+            &self,
+            cache: Option<&SearchCache>,
+            results: &[SearchResult],
+            replay_state: &Impl::ReplayState,
+        ) -> Option<CacheStatus> {
+            let cache = cache?;
+            cache.remember_first_page(&self.params, results, replay_state);
+            Some(CacheStatus::Miss)
+        }
     }
 
     impl<Impl: ExtendedwebsearchGuest> Drop for DurableSearchSession<Impl> {
@@ -199,13 +406,44 @@ mod durable_impl {
 
     impl<Impl: ExtendedwebsearchGuest> GuestSearchSession for DurableSearchSession<Impl> {
         fn next_page(&self) -> Result<Vec<SearchResult>, SearchError> {
-            let durability = Durability::<(Vec<SearchResult>, Impl::ReplayState), SearchError>::new(
+            let durability = Durability::<
+                (Vec<SearchResult>, Impl::ReplayState, Option<CacheStatus>),
+                SearchError,
+            >::new(
                 "golem_websearch",
                 "next_page",
                 DurableFunctionType::ReadRemote,
             );
 
+            // Opened before the durable operation, so the current time is persisted
+            let cache = if self.cacheable.replace(false) {
+                SearchCache::open()
+            } else {
+                None
+            };
+
             if durability.is_live() {
+                let cached = with_persistence_level(PersistenceLevel::PersistNothing, || {
+                    cache
+                        .as_ref()
+                        .and_then(|cache| cache.first_page::<Impl::ReplayState>(&self.params))
+                });
+                if let Some((results, replay_state)) = cached {
+                    let persisted_result = durability.persist(
+                        NoInput,
+                        Ok((results, replay_state.clone(), Some(CacheStatus::Hit))),
+                    )?;
+                    // The next page continues from the cached state
+                    let previous = self
+                        .state
+                        .replace(Some(DurableSearchSessionState::Replay { replay_state }));
+                    with_persistence_level(PersistenceLevel::PersistNothing, move || {
+                        drop(previous);
+                    });
+                    self.cache_status.set(Some(CacheStatus::Hit));
+                    return Ok(persisted_result.0);
+                }
+
                 let mut state = self.state.borrow_mut();
                 match &mut *state {
                     Some(DurableSearchSessionState::Live { session }) => {
@@ -217,16 +455,23 @@ mod durable_impl {
                         match result {
                             Ok(value) => {
                                 let replay_state = Impl::session_to_state(session);
-                                let persisted_result = durability
-                                    .persist(NoInput, Ok((value.clone(), replay_state)))?;
+                                let cache_status =
+                                    self.remember_first_page(cache.as_ref(), &value, &replay_state);
+                                let persisted_result = durability.persist(
+                                    NoInput,
+                                    Ok((value.clone(), replay_state, cache_status)),
+                                )?;
+                                self.cache_status.set(cache_status);
                                 Ok(persisted_result.0)
                             }
                             Err(error) => {
-                                let _ = durability.persist::<
-                                    _,
-                                    (Vec<SearchResult>, Impl::ReplayState),
-                                    SearchError
-                                >(NoInput, Err(error.clone()));
+                                let _ = durability.persist::<_, (
+                                    Vec<SearchResult>,
+                                    Impl::ReplayState,
+                                    Option<CacheStatus>,
+                                ), SearchError>(
+                                    NoInput, Err(error.clone())
+                                );
                                 Err(error)
                             }
                         }
@@ -241,17 +486,27 @@ mod durable_impl {
                         match result {
                             Ok(value) => {
                                 let new_replay_state = Impl::session_to_state(&session);
-                                let persisted_result = durability
-                                    .persist(NoInput, Ok((value.clone(), new_replay_state)))?;
+                                let cache_status = self.remember_first_page(
+                                    cache.as_ref(),
+                                    &value,
+                                    &new_replay_state,
+                                );
+                                let persisted_result = durability.persist(
+                                    NoInput,
+                                    Ok((value.clone(), new_replay_state, cache_status)),
+                                )?;
                                 *state = Some(DurableSearchSessionState::Live { session });
+                                self.cache_status.set(cache_status);
                                 Ok(persisted_result.0)
                             }
                             Err(error) => {
-                                let _ = durability.persist::<
-                                    _,
-                                    (Vec<SearchResult>, Impl::ReplayState),
-                                    SearchError
-                                >(NoInput, Err(error.clone()));
+                                let _ = durability.persist::<_, (
+                                    Vec<SearchResult>,
+                                    Impl::ReplayState,
+                                    Option<CacheStatus>,
+                                ), SearchError>(
+                                    NoInput, Err(error.clone())
+                                );
                                 Err(error)
                             }
                         }
@@ -259,8 +514,19 @@ mod durable_impl {
                     None => unreachable!(),
                 }
             } else {
-                let (result, next_replay_state) =
-                    durability.replay::<(Vec<SearchResult>, Impl::ReplayState), SearchError>()?;
+                let (result, next_replay_state, cache_status) = durability.replay::<(
+                    Vec<SearchResult>,
+                    Impl::ReplayState,
+                    Option<CacheStatus>,
+                ), SearchError>(
+                )?;
+                // Restores the misses in the cache, so the next live calls see the same entries
+                if cache_status == Some(CacheStatus::Miss) {
+                    if let Some(cache) = &cache {
+                        cache.remember_first_page(&self.params, &result, &next_replay_state);
+                    }
+                }
+                self.cache_status.set(cache_status);
                 let mut state = self.state.borrow_mut();
 
                 match &mut *state {
@@ -282,7 +548,7 @@ mod durable_impl {
 
         fn get_metadata(&self) -> Option<SearchMetadata> {
             let state = self.state.borrow();
-            match &*state {
+            let metadata = match &*state {
                 Some(DurableSearchSessionState::Live { session }) => {
                     with_persistence_level(PersistenceLevel::PersistNothing, || {
                         session.get_metadata()
@@ -296,6 +562,28 @@ mod durable_impl {
                 None => {
                     unreachable!()
                 }
+            };
+            metadata.map(|metadata| SearchMetadata {
+                cache_status: self.cache_status.get(),
+                ..metadata
+            })
+        }
+
+        fn get_cursor(&self) -> Result<String, SearchError> {
+            let state = self.state.borrow();
+            match &*state {
+                Some(DurableSearchSessionState::Live { session }) => {
+                    with_persistence_level(PersistenceLevel::PersistNothing, || {
+                        session.get_cursor()
+                    })
+                }
+                Some(DurableSearchSessionState::Replay { replay_state }) => {
+                    let session = Impl::session_from_state(replay_state, self.params.clone())?;
+                    session.get_cursor()
+                }
+                None => {
+                    unreachable!()
+                }
             }
         }
     }
@@ -303,10 +591,12 @@ mod durable_impl {
     #[cfg(test)]
     mod tests {
         use crate::golem::web_search::types::{
-            ImageResult, RateLimitInfo, SafeSearchLevel, TimeRange,
+            Answer, CacheStatus, ContentFormat, Coordinates, ExtractFailure, ExtractedPage,
+            ImageResult, Kv, PageMetadata, PlaceLocation, QueryOperators, RateLimitInfo,
+            SafeSearchLevel, SearchType, TimeRange,
         };
         use crate::golem::web_search::web_search::{
-            SearchError, SearchMetadata, SearchParams, SearchResult,
+            ExtractOptions, ExtractResult, SearchError, SearchMetadata, SearchParams, SearchResult,
         };
         use golem_rust::value_and_type::{FromValueAndType, IntoValueAndType};
         use golem_rust::wasm_rpc::WitTypeNode;
@@ -335,6 +625,15 @@ mod durable_impl {
             roundtrip_test(TimeRange::Year);
         }
 
+        #[test]
+        fn search_type_roundtrip() {
+            roundtrip_test(SearchType::Web);
+            roundtrip_test(SearchType::News);
+            roundtrip_test(SearchType::Images);
+            roundtrip_test(SearchType::Videos);
+            roundtrip_test(SearchType::Places);
+        }
+
         #[test]
         fn search_error_roundtrip() {
             roundtrip_test(SearchError::InvalidQuery);
@@ -385,6 +684,32 @@ mod durable_impl {
                     "First chunk of content".to_string(),
                     "Second chunk of content".to_string(),
                 ]),
+                thumbnail: None,
+                source_outlet: None,
+                duration: None,
+                location: None,
+            });
+            roundtrip_test(SearchResult {
+                title: "Sample Place".to_string(),
+                url: "https://example.com/place".to_string(),
+                snippet: "Restaurant".to_string(),
+                display_url: None,
+                source: None,
+                score: None,
+                html_snippet: None,
+                date_published: None,
+                images: None,
+                content_chunks: None,
+                thumbnail: Some("https://example.com/thumb.jpg".to_string()),
+                source_outlet: Some("Example News".to_string()),
+                duration: Some("12:34".to_string()),
+                location: Some(PlaceLocation {
+                    address: Some("1 Example Street, Springfield".to_string()),
+                    coordinates: Some(Coordinates {
+                        latitude: 47.4979,
+                        longitude: 19.0402,
+                    }),
+                }),
             });
         }
 
@@ -404,6 +729,12 @@ mod durable_impl {
                     reset_timestamp: 1698761200,
                 }),
                 current_page: 0,
+                answer: Some(Answer {
+                    text: "A sample answer".to_string(),
+                    source_urls: vec!["https://example.com/page".to_string()],
+                }),
+                cache_status: Some(CacheStatus::Hit),
+                failed_providers: Some(vec!["serper".to_string()]),
             });
         }
 
@@ -424,9 +755,59 @@ mod durable_impl {
                 include_images: Some(true),
                 include_html: Some(false),
                 advanced_answer: Some(true),
+                search_type: Some(SearchType::News),
+                query_operators: Some(QueryOperators {
+                    exact_phrases: Some(vec!["zero cost abstractions".to_string()]),
+                    must_terms: Some(vec!["ownership".to_string()]),
+                    must_not_terms: Some(vec!["java".to_string()]),
+                    filetype: Some("pdf".to_string()),
+                    sites: None,
+                }),
+                provider_options: Some(vec![Kv {
+                    key: "type".to_string(),
+                    value: "neural".to_string(),
+                }]),
             });
         }
 
+        #[test]
+        fn extract_options_roundtrip() {
+            roundtrip_test(ExtractOptions {
+                format: Some(ContentFormat::Text),
+                include_images: Some(true),
+                max_characters: Some(5000),
+                provider_options: Some(vec![Kv {
+                    key: "extract_depth".to_string(),
+                    value: "advanced".to_string(),
+                }]),
+            });
+        }
+
+        #[test]
+        fn extract_result_roundtrip() {
+            roundtrip_test(ExtractResult::Success(ExtractedPage {
+                url: "https://example.com/article".to_string(),
+                title: Some("An article".to_string()),
+                content: "# An article\n\nSome content".to_string(),
+                format: ContentFormat::Markdown,
+                metadata: PageMetadata {
+                    description: Some("Description of the article".to_string()),
+                    author: Some("Jane Doe".to_string()),
+                    date_published: Some("2024-01-01".to_string()),
+                    language: Some("en".to_string()),
+                    site_name: None,
+                },
+                images: Some(vec![ImageResult {
+                    url: "https://example.com/image.png".to_string(),
+                    description: None,
+                }]),
+            }));
+            roundtrip_test(ExtractResult::Failure(ExtractFailure {
+                url: "https://example.com/missing".to_string(),
+                message: "Request failed with status 404 Not Found".to_string(),
+            }));
+        }
+
         #[test]
         fn start_search_input_encoding() {
             let input = SearchParams {
@@ -444,6 +825,9 @@ mod durable_impl {
                 include_images: Some(true),
                 include_html: Some(true),
                 advanced_answer: Some(false),
+                search_type: None,
+                query_operators: None,
+                provider_options: None,
             };
 
             let encoded = input.into_value_and_type();
@@ -470,6 +854,9 @@ mod durable_impl {
                 include_images: Some(false),
                 include_html: Some(true),
                 advanced_answer: Some(true),
+                search_type: None,
+                query_operators: None,
+                provider_options: None,
             };
 
             let encoded = input.into_value_and_type();
@@ -0,0 +1,1231 @@
+use crate::golem::web_search::types::{
+    ContentFormat, ExtractFailure, ExtractOptions, ExtractResult, ExtractedPage, ImageResult,
+    PageMetadata,
+};
+use crate::golem::web_search::web_search::SearchError;
+use log::trace;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, Method, Url};
+
+/// Maximum number of URLs accepted by a single `extract` call
+pub const MAX_EXTRACT_URLS: usize = 20;
+
+/// Elements whose content is never part of the extracted text
+const RAW_TEXT_ELEMENTS: [&str; 7] = [
+    "script", "style", "noscript", "template", "svg", "iframe", "canvas",
+];
+
+/// Elements which are usually navigation or page chrome rather than content
+const SKIPPED_ELEMENTS: [&str; 8] = [
+    "head", "nav", "footer", "aside", "form", "button", "select", "dialog",
+];
+
+const BLOCK_ELEMENTS: [&str; 16] = [
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "header",
+    "figure",
+    "figcaption",
+    "table",
+    "blockquote",
+    "dl",
+    "dt",
+    "dd",
+    "address",
+    "details",
+    "summary",
+];
+
+/// Checks the URLs passed to `extract`
+pub fn validate_extract_urls(urls: &[String]) -> Result<(), SearchError> {
+    if urls.is_empty() {
+        return Err(SearchError::InvalidQuery);
+    }
+    if urls.len() > MAX_EXTRACT_URLS {
+        return Err(SearchError::UnsupportedFeature(format!(
+            "cannot extract more than {MAX_EXTRACT_URLS} URLs at once"
+        )));
+    }
+    for url in urls {
+        match Url::parse(url) {
+            Ok(parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => {}
+            _ => return Err(SearchError::InvalidQuery),
+        }
+    }
+    Ok(())
+}
+
+/// Extracts the pages by fetching them directly and converting their HTML to markdown or text.
+///
+/// Used by the providers which do not have a content extraction API of their own.
+pub fn extract_with_http(
+    urls: &[String],
+    options: &ExtractOptions,
+) -> Result<Vec<ExtractResult>, SearchError> {
+    let client = Client::builder()
+        .user_agent("Golem-Web-Search/1.0")
+        .build()
+        .map_err(|err| {
+            SearchError::BackendError(format!("Failed to initialize HTTP client: {err}"))
+        })?;
+
+    Ok(urls
+        .iter()
+        .map(|url| match fetch_page(&client, url, options) {
+            Ok(page) => ExtractResult::Success(page),
+            Err(message) => ExtractResult::Failure(ExtractFailure {
+                url: url.clone(),
+                message,
+            }),
+        })
+        .collect())
+}
+
+fn fetch_page(
+    client: &Client,
+    url: &str,
+    options: &ExtractOptions,
+) -> Result<ExtractedPage, String> {
+    trace!("Fetching page for extraction: {url}");
+
+    let response = client
+        .request(Method::GET, url)
+        .header(
+            "Accept",
+            "text/html,application/xhtml+xml,text/plain;q=0.9,*/*;q=0.8",
+        )
+        .send()
+        .map_err(|err| format!("Failed to fetch page: {err}"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Request failed with status {status}"));
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("text/html")
+        .to_lowercase();
+    let body = response
+        .text()
+        .map_err(|err| format!("Failed to read page: {err}"))?;
+
+    if content_type.contains("html") {
+        Ok(html_to_page(url, &body, options))
+    } else if content_type.starts_with("text/") {
+        Ok(ExtractedPage {
+            url: url.to_string(),
+            title: None,
+            content: truncate_content(body.trim(), options.max_characters),
+            format: options.format.unwrap_or(ContentFormat::Markdown),
+            metadata: empty_metadata(),
+            images: None,
+        })
+    } else {
+        Err(format!("Unsupported content type {content_type}"))
+    }
+}
+
+/// Converts an HTML document to an extracted page, dropping scripts, styles, navigation and
+/// other page chrome.
+pub fn html_to_page(url: &str, html: &str, options: &ExtractOptions) -> ExtractedPage {
+    let format = options.format.unwrap_or(ContentFormat::Markdown);
+    let mut converter = HtmlConverter::new(Url::parse(url).ok(), format == ContentFormat::Markdown);
+    converter.convert(html);
+
+    let images = if options.include_images == Some(true) {
+        Some(converter.images)
+    } else {
+        None
+    };
+
+    ExtractedPage {
+        url: url.to_string(),
+        title: converter.title,
+        content: truncate_content(&normalize_output(&converter.out), options.max_characters),
+        format,
+        metadata: converter.metadata,
+        images,
+    }
+}
+
+/// Truncates the content to at most `max_characters` characters
+pub fn truncate_content(content: &str, max_characters: Option<u32>) -> String {
+    match max_characters.and_then(|max| content.char_indices().nth(max as usize)) {
+        Some((idx, _)) => content[..idx].to_string(),
+        None => content.to_string(),
+    }
+}
+
+pub fn empty_metadata() -> PageMetadata {
+    PageMetadata {
+        description: None,
+        author: None,
+        date_published: None,
+        language: None,
+        site_name: None,
+    }
+}
+
+struct InlineElement {
+    tag: String,
+    start: usize,
+    suffix: String,
+}
+
+struct HtmlConverter {
+    base_url: Option<Url>,
+    markdown: bool,
+    out: String,
+    title: Option<String>,
+    metadata: PageMetadata,
+    images: Vec<ImageResult>,
+    skip_depth: usize,
+    pre_depth: usize,
+    /// `None` for unordered lists, the next item number for ordered lists
+    lists: Vec<Option<u32>>,
+    inline: Vec<InlineElement>,
+}
+
+impl HtmlConverter {
+    fn new(base_url: Option<Url>, markdown: bool) -> Self {
+        Self {
+            base_url,
+            markdown,
+            out: String::new(),
+            title: None,
+            metadata: empty_metadata(),
+            images: Vec::new(),
+            skip_depth: 0,
+            pre_depth: 0,
+            lists: Vec::new(),
+            inline: Vec::new(),
+        }
+    }
+
+    fn convert(&mut self, html: &str) {
+        let mut pos = 0;
+        while pos < html.len() {
+            let rest = &html[pos..];
+            let Some(lt) = rest.find('<') else {
+                self.text(rest);
+                break;
+            };
+            if lt > 0 {
+                self.text(&rest[..lt]);
+                pos += lt;
+                continue;
+            }
+
+            if rest.starts_with("<!--") {
+                pos += rest.find("-->").map(|end| end + 3).unwrap_or(rest.len());
+                continue;
+            }
+
+            let next = rest[1..].chars().next();
+            let is_tag = next.is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+            if !is_tag {
+                self.text("<");
+                pos += 1;
+                continue;
+            }
+
+            let Some(end) = find_tag_end(rest) else {
+                self.text(rest);
+                break;
+            };
+            let tag = parse_tag(&rest[1..end - 1]);
+            pos += end;
+
+            let Some(tag) = tag else {
+                // Doctype or other declarations
+                continue;
+            };
+
+            let is_raw_text = RAW_TEXT_ELEMENTS.contains(&tag.name.as_str()) && !tag.self_closing
+                || tag.name == "title";
+            if !tag.closing && is_raw_text {
+                let (content, consumed) = raw_text(&html[pos..], &tag.name);
+                if tag.name == "title" && self.title.is_none() {
+                    let title = collapse_whitespace(&decode_entities(content));
+                    if !title.trim().is_empty() {
+                        self.title = Some(title.trim().to_string());
+                    }
+                }
+                pos += consumed;
+                continue;
+            }
+
+            if tag.closing {
+                self.end_tag(&tag.name);
+            } else {
+                self.start_tag(&tag);
+            }
+        }
+        self.trim_trailing_spaces();
+        self.close_inline();
+    }
+
+    fn start_tag(&mut self, tag: &Tag) {
+        match tag.name.as_str() {
+            "html" => {
+                if let Some(lang) = tag.attr("lang") {
+                    self.metadata.language = Some(lang.to_string());
+                }
+            }
+            "meta" => self.meta(tag),
+            _ => {}
+        }
+
+        if SKIPPED_ELEMENTS.contains(&tag.name.as_str()) {
+            if !tag.self_closing {
+                self.skip_depth += 1;
+            }
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        match tag.name.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.blank_line();
+                if self.markdown {
+                    let level = tag.name[1..].parse::<usize>().unwrap_or(1);
+                    self.out.push_str(&"#".repeat(level));
+                    self.out.push(' ');
+                }
+            }
+            "br" => self.out.push('\n'),
+            "hr" => {
+                self.blank_line();
+                if self.markdown {
+                    self.out.push_str("---");
+                }
+                self.blank_line();
+            }
+            "ul" => {
+                self.list_boundary();
+                self.lists.push(None);
+            }
+            "ol" => {
+                self.list_boundary();
+                let start = tag
+                    .attr("start")
+                    .and_then(|start| start.parse().ok())
+                    .unwrap_or(1);
+                self.lists.push(Some(start));
+            }
+            "li" => {
+                self.newline();
+                let depth = self.lists.len().saturating_sub(1);
+                self.out.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        self.out.push_str(&format!("{number}. "));
+                        *number += 1;
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            "pre" => {
+                self.blank_line();
+                if self.markdown {
+                    self.out.push_str("```\n");
+                }
+                self.pre_depth += 1;
+            }
+            "code" if self.pre_depth == 0 && self.markdown => self.start_inline(tag, "`", "`"),
+            "strong" | "b" if self.markdown => self.start_inline(tag, "**", "**"),
+            "em" | "i" if self.markdown => self.start_inline(tag, "_", "_"),
+            "a" if self.markdown => {
+                match tag.attr("href").and_then(|href| self.resolve_url(href)) {
+                    Some(href) => self.start_inline(tag, "[", &format!("]({href})")),
+                    None => self.start_inline(tag, "", ""),
+                }
+            }
+            "img" => {
+                if let Some(src) = tag.attr("src").and_then(|src| self.resolve_url(src)) {
+                    if !self.images.iter().any(|image| image.url == src) {
+                        self.images.push(ImageResult {
+                            url: src,
+                            description: tag
+                                .attr("alt")
+                                .map(|alt| alt.trim().to_string())
+                                .filter(|alt| !alt.is_empty()),
+                        });
+                    }
+                }
+            }
+            "tr" => self.newline(),
+            "td" | "th" if !self.out.is_empty() && !self.out.ends_with('\n') => {
+                self.out.push_str(" | ");
+            }
+            "blockquote" => {
+                self.blank_line();
+                if self.markdown {
+                    self.out.push_str("> ");
+                }
+            }
+            name if BLOCK_ELEMENTS.contains(&name) => self.blank_line(),
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, name: &str) {
+        if SKIPPED_ELEMENTS.contains(&name) {
+            self.skip_depth = self.skip_depth.saturating_sub(1);
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.blank_line(),
+            "ul" | "ol" => {
+                self.lists.pop();
+                self.list_boundary();
+            }
+            "li" | "tr" => self.newline(),
+            "pre" => {
+                self.pre_depth = self.pre_depth.saturating_sub(1);
+                self.newline();
+                if self.markdown {
+                    self.out.push_str("```");
+                }
+                self.blank_line();
+            }
+            "code" | "strong" | "b" | "em" | "i" | "a" => self.end_inline(name),
+            name if BLOCK_ELEMENTS.contains(&name) => self.blank_line(),
+            _ => {}
+        }
+    }
+
+    fn meta(&mut self, tag: &Tag) {
+        let Some(content) = tag.attr("content").map(|c| c.trim().to_string()) else {
+            return;
+        };
+        if content.is_empty() {
+            return;
+        }
+        let key = tag
+            .attr("name")
+            .or(tag.attr("property"))
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let field = match key.as_str() {
+            "description" | "og:description" => &mut self.metadata.description,
+            "author" | "article:author" => &mut self.metadata.author,
+            "article:published_time" | "date" | "datepublished" => {
+                &mut self.metadata.date_published
+            }
+            "og:site_name" | "application-name" => &mut self.metadata.site_name,
+            "og:title" => &mut self.title,
+            _ => return,
+        };
+        if field.is_none() {
+            *field = Some(content);
+        }
+    }
+
+    fn start_inline(&mut self, tag: &Tag, prefix: &str, suffix: &str) {
+        self.inline.push(InlineElement {
+            tag: tag.name.clone(),
+            start: self.out.len(),
+            suffix: suffix.to_string(),
+        });
+        self.out.push_str(prefix);
+    }
+
+    fn end_inline(&mut self, name: &str) {
+        let Some(idx) = self.inline.iter().rposition(|element| element.tag == name) else {
+            return;
+        };
+        let element = self.inline.remove(idx);
+        let content = &self.out[element.start..];
+        let is_empty = content
+            .trim_start_matches(['[', '*', '_', '`'])
+            .trim()
+            .is_empty();
+        if is_empty {
+            // Drops the markup of elements without text, like links around images
+            self.out.truncate(element.start);
+        } else {
+            self.out.push_str(&element.suffix);
+        }
+    }
+
+    /// Closes the inline elements left open by malformed markup, as markdown emphasis and links
+    /// cannot span paragraphs
+    fn close_inline(&mut self) {
+        while let Some(element) = self.inline.last() {
+            let tag = element.tag.clone();
+            self.end_inline(&tag);
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip_depth > 0 {
+            return;
+        }
+        let decoded = decode_entities(text);
+        if self.pre_depth > 0 {
+            self.out.push_str(&decoded);
+            return;
+        }
+
+        let collapsed = collapse_whitespace(&decoded);
+        let at_line_start = self.out.is_empty() || self.out.ends_with(['\n', ' ']);
+        let collapsed = if at_line_start {
+            collapsed.trim_start()
+        } else {
+            &collapsed
+        };
+        self.out.push_str(collapsed);
+    }
+
+    fn resolve_url(&self, href: &str) -> Option<String> {
+        let href = href.trim();
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            return None;
+        }
+        let url = match &self.base_url {
+            Some(base) => base.join(href).ok()?,
+            None => Url::parse(href).ok()?,
+        };
+        if url.scheme() == "http" || url.scheme() == "https" {
+            Some(url.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let trimmed = self.out.trim_end_matches([' ', '\t']).len();
+        self.out.truncate(trimmed);
+    }
+
+    fn newline(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn blank_line(&mut self) {
+        self.trim_trailing_spaces();
+        self.close_inline();
+        self.newline();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    /// Top level lists are separated from the surrounding paragraphs, nested lists are not
+    fn list_boundary(&mut self) {
+        if self.lists.is_empty() {
+            self.blank_line();
+        } else {
+            self.newline();
+        }
+    }
+}
+
+struct Tag {
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Finds the end of the tag starting at the beginning of `input`, skipping `>` characters in
+/// quoted attribute values. Returns the index after the closing `>`.
+fn find_tag_end(input: &str) -> Option<usize> {
+    let mut quote = None;
+    for (idx, c) in input.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(idx + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses the inside of a tag (without the angle brackets). Returns `None` for declarations
+/// like `<!DOCTYPE html>`.
+fn parse_tag(input: &str) -> Option<Tag> {
+    if input.starts_with('!') || input.starts_with('?') {
+        return None;
+    }
+    let (closing, input) = match input.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+    let (self_closing, input) = match input.trim_end().strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+
+    let name_end = input
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(input.len());
+    let name = input[..name_end].to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut attributes = Vec::new();
+    let mut rest = input[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (raw, remaining) = match after_eq.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let inner = &after_eq[1..];
+                    let end = inner.find(q).unwrap_or(inner.len());
+                    (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..end], &after_eq[end..])
+                }
+            };
+            value = decode_entities(raw);
+            rest = remaining;
+        } else if key.is_empty() {
+            // Stray characters like a `/` in the middle of the tag
+            rest = rest.get(1..).unwrap_or("");
+        }
+
+        if !key.is_empty() {
+            attributes.push((key, value));
+        }
+        rest = rest.trim_start();
+    }
+
+    Some(Tag {
+        name,
+        closing,
+        self_closing,
+        attributes,
+    })
+}
+
+/// Returns the raw content of an element up to its closing tag, and the number of bytes
+/// consumed including the closing tag
+fn raw_text<'a>(input: &'a str, name: &str) -> (&'a str, usize) {
+    let closing_tag = input.match_indices("</").find(|(start, _)| {
+        input.as_bytes()[start + 2..]
+            .get(..name.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(name.as_bytes()))
+    });
+    match closing_tag {
+        Some((start, _)) => {
+            let end = input[start..]
+                .find('>')
+                .map(|end| start + end + 1)
+                .unwrap_or(input.len());
+            (&input[..start], end)
+        }
+        None => (input, input.len()),
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                result.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            result.push(c);
+            in_whitespace = false;
+        }
+    }
+    result
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        // Entity names are short, so only the next few characters are checked for the `;`
+        let decoded = rest[1..]
+            .char_indices()
+            .take(12)
+            .find(|(_, c)| *c == ';')
+            .and_then(|(end, _)| {
+                let entity = &rest[1..end + 1];
+                decode_entity(entity).map(|c| (c, end + 2))
+            });
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse::<u32>().ok()?,
+        };
+        // Like browsers, NUL and invalid code points decode to the replacement character
+        return match code {
+            0 => Some(char::REPLACEMENT_CHARACTER),
+            code => Some(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)),
+        };
+    }
+
+    let c = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ensp" | "emsp" | "thinsp" => ' ',
+        "shy" => '\u{ad}',
+        "zwnj" => '\u{200c}',
+        "zwj" => '\u{200d}',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "euro" => '€',
+        "middot" => '·',
+        "bull" => '•',
+        "sbquo" => '‚',
+        "bdquo" => '„',
+        "lsaquo" => '‹',
+        "rsaquo" => '›',
+        "dagger" => '†',
+        "Dagger" => '‡',
+        "permil" => '‰',
+        "prime" => '′',
+        "Prime" => '″',
+        "cent" => '¢',
+        "pound" => '£',
+        "yen" => '¥',
+        "curren" => '¤',
+        "sect" => '§',
+        "para" => '¶',
+        "deg" => '°',
+        "plusmn" => '±',
+        "times" => '×',
+        "divide" => '÷',
+        "minus" => '−',
+        "frac14" => '¼',
+        "frac12" => '½',
+        "frac34" => '¾',
+        "sup1" => '¹',
+        "sup2" => '²',
+        "sup3" => '³',
+        "micro" => 'µ',
+        "iexcl" => '¡',
+        "iquest" => '¿',
+        "ordf" => 'ª',
+        "ordm" => 'º',
+        "not" => '¬',
+        "macr" => '¯',
+        "acute" => '´',
+        "cedil" => '¸',
+        "uml" => '¨',
+        "brvbar" => '¦',
+        "larr" => '←',
+        "uarr" => '↑',
+        "rarr" => '→',
+        "darr" => '↓',
+        "harr" => '↔',
+        "rArr" => '⇒',
+        "lArr" => '⇐',
+        "hArr" => '⇔',
+        "le" => '≤',
+        "ge" => '≥',
+        "ne" => '≠',
+        "asymp" => '≈',
+        "equiv" => '≡',
+        "infin" => '∞',
+        "sum" => '∑',
+        "prod" => '∏',
+        "radic" => '√',
+        "part" => '∂',
+        "nabla" => '∇',
+        "isin" => '∈',
+        "forall" => '∀',
+        "exist" => '∃',
+        "empty" => '∅',
+        "and" => '∧',
+        "or" => '∨',
+        "cap" => '∩',
+        "cup" => '∪',
+        "int" => '∫',
+        "there4" => '∴',
+        "sim" => '∼',
+        "loz" => '◊',
+        "spades" => '♠',
+        "clubs" => '♣',
+        "hearts" => '♥',
+        "diams" => '♦',
+        "Agrave" => 'À',
+        "Aacute" => 'Á',
+        "Acirc" => 'Â',
+        "Atilde" => 'Ã',
+        "Auml" => 'Ä',
+        "Aring" => 'Å',
+        "AElig" => 'Æ',
+        "Ccedil" => 'Ç',
+        "Egrave" => 'È',
+        "Eacute" => 'É',
+        "Ecirc" => 'Ê',
+        "Euml" => 'Ë',
+        "Igrave" => 'Ì',
+        "Iacute" => 'Í',
+        "Icirc" => 'Î',
+        "Iuml" => 'Ï',
+        "ETH" => 'Ð',
+        "Ntilde" => 'Ñ',
+        "Ograve" => 'Ò',
+        "Oacute" => 'Ó',
+        "Ocirc" => 'Ô',
+        "Otilde" => 'Õ',
+        "Ouml" => 'Ö',
+        "Oslash" => 'Ø',
+        "Ugrave" => 'Ù',
+        "Uacute" => 'Ú',
+        "Ucirc" => 'Û',
+        "Uuml" => 'Ü',
+        "Yacute" => 'Ý',
+        "THORN" => 'Þ',
+        "szlig" => 'ß',
+        "agrave" => 'à',
+        "aacute" => 'á',
+        "acirc" => 'â',
+        "atilde" => 'ã',
+        "auml" => 'ä',
+        "aring" => 'å',
+        "aelig" => 'æ',
+        "ccedil" => 'ç',
+        "egrave" => 'è',
+        "eacute" => 'é',
+        "ecirc" => 'ê',
+        "euml" => 'ë',
+        "igrave" => 'ì',
+        "iacute" => 'í',
+        "icirc" => 'î',
+        "iuml" => 'ï',
+        "eth" => 'ð',
+        "ntilde" => 'ñ',
+        "ograve" => 'ò',
+        "oacute" => 'ó',
+        "ocirc" => 'ô',
+        "otilde" => 'õ',
+        "ouml" => 'ö',
+        "oslash" => 'ø',
+        "ugrave" => 'ù',
+        "uacute" => 'ú',
+        "ucirc" => 'û',
+        "uuml" => 'ü',
+        "yacute" => 'ý',
+        "thorn" => 'þ',
+        "yuml" => 'ÿ',
+        "OElig" => 'Œ',
+        "oelig" => 'œ',
+        "Scaron" => 'Š',
+        "scaron" => 'š',
+        "Yuml" => 'Ÿ',
+        "fnof" => 'ƒ',
+        "circ" => 'ˆ',
+        "tilde" => '˜',
+        "Alpha" => 'Α',
+        "Beta" => 'Β',
+        "Gamma" => 'Γ',
+        "Delta" => 'Δ',
+        "Theta" => 'Θ',
+        "Lambda" => 'Λ',
+        "Pi" => 'Π',
+        "Sigma" => 'Σ',
+        "Phi" => 'Φ',
+        "Psi" => 'Ψ',
+        "Omega" => 'Ω',
+        "alpha" => 'α',
+        "beta" => 'β',
+        "gamma" => 'γ',
+        "delta" => 'δ',
+        "epsilon" => 'ε',
+        "zeta" => 'ζ',
+        "eta" => 'η',
+        "theta" => 'θ',
+        "iota" => 'ι',
+        "kappa" => 'κ',
+        "lambda" => 'λ',
+        "mu" => 'μ',
+        "nu" => 'ν',
+        "xi" => 'ξ',
+        "omicron" => 'ο',
+        "pi" => 'π',
+        "rho" => 'ρ',
+        "sigmaf" => 'ς',
+        "sigma" => 'σ',
+        "tau" => 'τ',
+        "upsilon" => 'υ',
+        "phi" => 'φ',
+        "chi" => 'χ',
+        "psi" => 'ψ',
+        "omega" => 'ω',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// Trims the lines and collapses the empty lines of the converted content
+fn normalize_output(out: &str) -> String {
+    let mut result = String::with_capacity(out.len());
+    let mut empty_lines = 0;
+    for line in out.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+        if !result.is_empty() {
+            result.push_str(if empty_lines > 0 { "\n\n" } else { "\n" });
+        }
+        result.push_str(line);
+        empty_lines = 0;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extract::{html_to_page, truncate_content, validate_extract_urls};
+    use crate::golem::web_search::types::{ContentFormat, ExtractOptions};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <title>Durable &amp; reliable</title>
+  <meta name="description" content="An article about durability">
+  <meta property="og:site_name" content="Example">
+  <meta name="author" content="Jane Doe">
+  <style>body { color: red; }</style>
+</head>
+<body>
+  <nav><a href="/">Home</a> <a href="/about">About</a></nav>
+  <article>
+    <h1>Durable   execution</h1>
+    <p>Workers <strong>survive</strong> crashes, see <a href="/docs/oplog">the oplog</a>.</p>
+    <script>console.log("ignored")</script>
+    <ul>
+      <li>First</li>
+      <li>Second <em>item</em></li>
+    </ul>
+    <img src="/images/diagram.png" alt="Diagram">
+    <pre>let x = 1;
+let y = 2;</pre>
+  </article>
+  <footer>Copyright</footer>
+</body>
+</html>"#;
+
+    fn options(format: ContentFormat) -> ExtractOptions {
+        ExtractOptions {
+            format: Some(format),
+            include_images: Some(true),
+            max_characters: None,
+            provider_options: None,
+        }
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let page = html_to_page(
+            "https://example.com/blog/post",
+            PAGE,
+            &options(ContentFormat::Markdown),
+        );
+
+        assert_eq!(page.title, Some("Durable & reliable".to_string()));
+        assert_eq!(
+            page.content,
+            "# Durable execution\n\n\
+             Workers **survive** crashes, see [the oplog](https://example.com/docs/oplog).\n\n\
+             - First\n\
+             - Second _item_\n\n\
+             ```\nlet x = 1;\nlet y = 2;\n```"
+        );
+        assert_eq!(
+            page.metadata.description,
+            Some("An article about durability".to_string())
+        );
+        assert_eq!(page.metadata.author, Some("Jane Doe".to_string()));
+        assert_eq!(page.metadata.site_name, Some("Example".to_string()));
+        assert_eq!(page.metadata.language, Some("en".to_string()));
+
+        let images = page.images.unwrap();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].url, "https://example.com/images/diagram.png");
+        assert_eq!(images[0].description, Some("Diagram".to_string()));
+    }
+
+    #[test]
+    fn converts_html_to_text() {
+        let page = html_to_page(
+            "https://example.com/blog/post",
+            PAGE,
+            &options(ContentFormat::Text),
+        );
+
+        assert_eq!(
+            page.content,
+            "Durable execution\n\n\
+             Workers survive crashes, see the oplog.\n\n\
+             - First\n\
+             - Second item\n\n\
+             let x = 1;\nlet y = 2;"
+        );
+    }
+
+    fn markdown(url: &str, html: &str) -> String {
+        html_to_page(url, html, &options(ContentFormat::Markdown)).content
+    }
+
+    #[test]
+    fn converts_malformed_markup() {
+        let url = "https://example.com/";
+
+        assert_eq!(
+            markdown(url, "<p>Unclosed <strong>bold<p>Next"),
+            "Unclosed **bold**\n\nNext"
+        );
+        assert_eq!(markdown(url, "<p>1 < 2 and 3 <> 4</p>"), "1 < 2 and 3 <> 4");
+        assert_eq!(
+            markdown(url, "<p>Before</span></p></div>After"),
+            "Before\n\nAfter"
+        );
+        assert_eq!(markdown(url, "Text <!-- unterminated comment"), "Text");
+        assert_eq!(markdown(url, "Text <a href=\"/x\""), "Text <a href=\"/x\"");
+        assert_eq!(
+            markdown(url, "<a title=\"a > b\" href='/quoted'>Link</a>"),
+            "[Link](https://example.com/quoted)"
+        );
+        assert_eq!(
+            markdown(url, "<P CLASS=lead>Upper <EM>case</EM></P>"),
+            "Upper _case_"
+        );
+        assert_eq!(markdown(url, "<nav>Unclosed navigation"), "");
+    }
+
+    #[test]
+    fn skips_scripts_and_styles() {
+        let url = "https://example.com/";
+
+        assert_eq!(
+            markdown(
+                url,
+                "<p>Before</p><script>if (a < b) { document.write(\"<p>x</p>\"); }</script><p>After</p>"
+            ),
+            "Before\n\nAfter"
+        );
+        assert_eq!(
+            markdown(
+                url,
+                "<STYLE type=\"text/css\">p > a { color: red; }</STYLE><p>Styled</p>"
+            ),
+            "Styled"
+        );
+        assert_eq!(
+            markdown(
+                url,
+                "<p>Kept</p><noscript><p>Enable JavaScript</p></noscript>"
+            ),
+            "Kept"
+        );
+        assert_eq!(
+            markdown(
+                url,
+                "<svg><text>Icon</text></svg><template><p>Hidden</p></template>Visible"
+            ),
+            "Visible"
+        );
+        assert_eq!(markdown(url, "<p>Kept</p><script>never closed"), "Kept");
+    }
+
+    #[test]
+    fn resolves_relative_links_and_images() {
+        let html = r##"
+            <a href="../guide">Parent</a>
+            <a href="sibling">Sibling</a>
+            <a href="//cdn.example.org/file">Protocol relative</a>
+            <a href="?page=2">Query</a>
+            <a href="https://other.com/absolute">Absolute</a>
+            <a href="#section">Anchor</a>
+            <a href="javascript:void(0)">Script</a>
+            <a href="mailto:someone@example.com">Mail</a>
+            <a href="/"><img src="logo.png" alt=" Logo "></a>
+            <img src="/img/a.png"><img src="/img/a.png" alt="Duplicate">
+            <img src="data:image/png;base64,AAAA">
+        "##;
+        let page = html_to_page(
+            "https://example.com/docs/intro/page.html",
+            html,
+            &options(ContentFormat::Markdown),
+        );
+
+        assert_eq!(
+            page.content,
+            "[Parent](https://example.com/docs/guide) \
+             [Sibling](https://example.com/docs/intro/sibling) \
+             [Protocol relative](https://cdn.example.org/file) \
+             [Query](https://example.com/docs/intro/page.html?page=2) \
+             [Absolute](https://other.com/absolute) \
+             Anchor Script Mail"
+        );
+
+        let images = page.images.unwrap();
+        let urls = images
+            .iter()
+            .map(|image| image.url.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/docs/intro/logo.png",
+                "https://example.com/img/a.png"
+            ]
+        );
+        assert_eq!(images[0].description, Some("Logo".to_string()));
+        assert_eq!(images[1].description, None);
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let url = "https://example.com/";
+
+        assert_eq!(
+            markdown(
+                url,
+                "<p>&lt;tag&gt; &amp; &quot;quoted&quot; &apos;single&apos;</p>"
+            ),
+            "<tag> & \"quoted\" 'single'"
+        );
+        assert_eq!(
+            markdown(
+                url,
+                "<p>&#65;&#x42;&#X43; &#8364; &#x1F600; &#0; &#xD800;</p>"
+            ),
+            "ABC € 😀 \u{fffd} \u{fffd}"
+        );
+        assert_eq!(
+            markdown(
+                url,
+                "<p>&eacute;t&eacute; &szlig; &alpha;&beta; &times; &frac12; &rarr;</p>"
+            ),
+            "été ß αβ × ½ →"
+        );
+        assert_eq!(
+            markdown(url, "<p>AT&T &unknown; &amp &#xZZ; & done</p>"),
+            "AT&T &unknown; &amp &#xZZ; & done"
+        );
+        assert_eq!(
+            markdown(url, "<a href=\"/search?a=1&amp;b=2\">Search</a>"),
+            "[Search](https://example.com/search?a=1&b=2)"
+        );
+        assert_eq!(
+            html_to_page(
+                url,
+                "<title>Caf&eacute; &#8211; Menu</title>",
+                &options(ContentFormat::Text)
+            )
+            .title,
+            Some("Café – Menu".to_string())
+        );
+    }
+
+    #[test]
+    fn truncates_content() {
+        assert_eq!(truncate_content("Ünïcödé text", Some(7)), "Ünïcödé");
+        assert_eq!(truncate_content("short", Some(100)), "short");
+        assert_eq!(truncate_content("unlimited", None), "unlimited");
+    }
+
+    #[test]
+    fn truncates_multibyte_content_on_character_boundaries() {
+        assert_eq!(truncate_content("日本語のテキスト", Some(3)), "日本語");
+        assert_eq!(truncate_content("😀👍🎉 emoji", Some(2)), "😀👍");
+        assert_eq!(truncate_content("e\u{301}te\u{301}", Some(3)), "e\u{301}t");
+        assert_eq!(truncate_content("日本語", Some(3)), "日本語");
+        assert_eq!(truncate_content("日本語", Some(0)), "");
+
+        let page = html_to_page(
+            "https://example.com/",
+            "<p>Grüße aus Köln</p>",
+            &ExtractOptions {
+                max_characters: Some(9),
+                ..options(ContentFormat::Text)
+            },
+        );
+        assert_eq!(page.content, "Grüße aus");
+    }
+
+    #[test]
+    fn validates_urls() {
+        assert!(validate_extract_urls(&["https://example.com".to_string()]).is_ok());
+        assert!(validate_extract_urls(&[]).is_err());
+        assert!(validate_extract_urls(&["ftp://example.com/file".to_string()]).is_err());
+        assert!(validate_extract_urls(&["not a url".to_string()]).is_err());
+    }
+}
@@ -1,6 +1,13 @@
+pub mod cache;
 pub mod config;
+pub mod cursor;
 pub mod durability;
 pub mod error;
+pub mod extract;
+pub mod query;
+pub mod rate_limit;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 pub mod types;
 
 wit_bindgen::generate!({
@@ -13,6 +20,8 @@ wit_bindgen::generate!({
         golem_rust::FromValueAndType,
         golem_rust::IntoValue,
         Clone,
+        serde::Serialize,
+        serde::Deserialize,
     ],
     pub_export_macro: true,
 });
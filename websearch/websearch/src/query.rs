@@ -0,0 +1,169 @@
+use crate::golem::web_search::types::QueryOperators;
+use crate::golem::web_search::web_search::SearchParams;
+
+/// Quotes a phrase for the query syntax of the providers, dropping the quotes inside it
+pub fn quote(phrase: &str) -> String {
+    format!("\"{}\"", phrase.replace('"', "").trim())
+}
+
+/// Sites the results have to come from, both from `include-domains` and the `sites` operator
+pub fn included_sites(params: &SearchParams) -> Vec<String> {
+    let sites = params
+        .query_operators
+        .as_ref()
+        .and_then(|operators| operators.sites.as_ref());
+    non_empty_terms(params.include_domains.iter().chain(sites).flatten())
+}
+
+/// Sites the results must not come from
+pub fn excluded_sites(params: &SearchParams) -> Vec<String> {
+    non_empty_terms(params.exclude_domains.iter().flatten())
+}
+
+/// Phrases the results have to contain verbatim, the required terms included
+pub fn required_phrases(operators: &QueryOperators) -> Vec<String> {
+    non_empty_terms(
+        operators
+            .exact_phrases
+            .iter()
+            .chain(operators.must_terms.iter())
+            .flatten(),
+    )
+}
+
+/// Terms the results must not contain
+pub fn excluded_terms(operators: &QueryOperators) -> Vec<String> {
+    non_empty_terms(operators.must_not_terms.iter().flatten())
+}
+
+/// Compiles the query operators and the domain filters into the search operator syntax shared
+/// by Google, Brave and most engines aggregated by SearXNG: quoted phrases and required terms,
+/// `-term` for excluded terms, `filetype:` and `site:` filters with the included sites joined
+/// by `OR`.
+pub fn compile_query(params: &SearchParams) -> String {
+    let mut parts = Vec::new();
+
+    let sites = included_sites(params);
+    match sites.as_slice() {
+        [] => {}
+        [site] => parts.push(format!("site:{site}")),
+        sites => parts.push(format!(
+            "({})",
+            sites
+                .iter()
+                .map(|site| format!("site:{site}"))
+                .collect::<Vec<_>>()
+                .join(" OR ")
+        )),
+    }
+
+    parts.push(params.query.trim().to_string());
+
+    if let Some(operators) = &params.query_operators {
+        parts.extend(
+            required_phrases(operators)
+                .iter()
+                .map(|phrase| quote(phrase)),
+        );
+        parts.extend(excluded_terms(operators).iter().map(|term| {
+            if term.contains(char::is_whitespace) {
+                format!("-{}", quote(term))
+            } else {
+                format!("-{term}")
+            }
+        }));
+        if let Some(filetype) = filetype(operators) {
+            parts.push(format!("filetype:{filetype}"));
+        }
+    }
+
+    parts.extend(
+        excluded_sites(params)
+            .iter()
+            .map(|site| format!("-site:{site}")),
+    );
+
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The requested file type without a leading dot, like `pdf`
+pub fn filetype(operators: &QueryOperators) -> Option<String> {
+    operators
+        .filetype
+        .as_ref()
+        .map(|filetype| filetype.trim().trim_start_matches('.').to_lowercase())
+        .filter(|filetype| !filetype.is_empty())
+}
+
+fn non_empty_terms<'a>(terms: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for term in terms
+        .map(|term| term.trim())
+        .filter(|term| !term.is_empty())
+    {
+        if !result.iter().any(|existing| existing == term) {
+            result.push(term.to_string());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golem::web_search::types::QueryOperators;
+    use crate::query::{compile_query, filetype, included_sites};
+    use crate::test_util::{search_params, search_params_with_operators};
+
+    fn operators() -> QueryOperators {
+        QueryOperators {
+            exact_phrases: Some(vec!["borrow checker".to_string()]),
+            must_terms: Some(vec!["lifetimes".to_string()]),
+            must_not_terms: Some(vec!["java".to_string(), "garbage collector".to_string()]),
+            filetype: Some(".PDF".to_string()),
+            sites: Some(vec!["rust-lang.org".to_string()]),
+        }
+    }
+
+    #[test]
+    fn compiles_all_operators() {
+        let mut params = search_params_with_operators("rust", operators());
+        params.exclude_domains = Some(vec!["spam.com".to_string()]);
+        assert_eq!(
+            compile_query(&params),
+            "site:rust-lang.org rust \"borrow checker\" \"lifetimes\" -java -\"garbage collector\" filetype:pdf -site:spam.com"
+        );
+    }
+
+    #[test]
+    fn joins_included_sites_with_or() {
+        let mut params = search_params_with_operators("rust", operators());
+        params.include_domains = Some(vec!["docs.rs".to_string(), "rust-lang.org".to_string()]);
+        assert_eq!(included_sites(&params), vec!["docs.rs", "rust-lang.org"]);
+        assert!(compile_query(&params).starts_with("(site:docs.rs OR site:rust-lang.org) rust "));
+    }
+
+    #[test]
+    fn keeps_plain_query_without_operators() {
+        assert_eq!(compile_query(&search_params(" rust ")), "rust");
+    }
+
+    #[test]
+    fn drops_quotes_and_empty_terms() {
+        let operators = QueryOperators {
+            exact_phrases: Some(vec!["\"zero cost\"".to_string(), " ".to_string()]),
+            must_terms: None,
+            must_not_terms: None,
+            filetype: Some(" ".to_string()),
+            sites: None,
+        };
+        assert_eq!(filetype(&operators), None);
+        assert_eq!(
+            compile_query(&search_params_with_operators("rust", operators)),
+            "rust \"zero cost\""
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::golem::web_search::types::RateLimitInfo;
+use reqwest::header::HeaderMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Header name prefixes used by the providers to report their rate limits, in order of preference
+const RATE_LIMIT_HEADER_PREFIXES: [&str; 2] = ["x-ratelimit", "ratelimit"];
+
+/// Reset values above this are treated as unix timestamps (in seconds) instead of
+/// the number of seconds until the reset
+const RESET_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+/// Parses the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers
+/// (or their `RateLimit-*` variants) of a provider response.
+///
+/// Some providers (like Brave) report multiple rate limit windows as comma separated lists,
+/// for example a per-second and a per-month limit. In this case the window with the fewest
+/// remaining requests is returned, as that is the one the caller has to respect.
+pub fn rate_limit_from_headers(headers: &HeaderMap) -> Option<RateLimitInfo> {
+    rate_limit_from_headers_at(headers, now_millis())
+}
+
+fn rate_limit_from_headers_at(headers: &HeaderMap, now_millis: u64) -> Option<RateLimitInfo> {
+    RATE_LIMIT_HEADER_PREFIXES.iter().find_map(|prefix| {
+        let limits = header_values(headers, &format!("{prefix}-limit"))?;
+        let remaining = header_values(headers, &format!("{prefix}-remaining"))?;
+        let resets = header_values(headers, &format!("{prefix}-reset")).unwrap_or_default();
+
+        let (index, remaining) = remaining
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, remaining)| **remaining)?;
+        let limit = limits.get(index).or(limits.first()).copied()?;
+        let reset_timestamp = resets
+            .get(index)
+            .map(|reset| reset_to_timestamp_millis(*reset, now_millis))
+            .unwrap_or(0);
+
+        Some(RateLimitInfo {
+            limit: clamp_to_u32(limit),
+            remaining: clamp_to_u32(*remaining),
+            reset_timestamp,
+        })
+    })
+}
+
+/// Determines how many seconds to wait after a `429 Too Many Requests` response.
+///
+/// Uses the `Retry-After` header if present, and otherwise the reset time of the exhausted
+/// rate limit window. Falls back to 60 seconds if the provider does not report either.
+pub fn retry_after_from_headers(headers: &HeaderMap) -> u32 {
+    retry_after_from_headers_at(headers, now_millis())
+}
+
+fn retry_after_from_headers_at(headers: &HeaderMap, now_millis: u64) -> u32 {
+    const DEFAULT_RETRY_AFTER_SECONDS: u32 = 60;
+
+    if let Some(retry_after) =
+        header_values(headers, "retry-after").and_then(|values| values.first().copied())
+    {
+        return clamp_to_u32(retry_after);
+    }
+
+    match rate_limit_from_headers_at(headers, now_millis) {
+        Some(rate_limit) if rate_limit.reset_timestamp > 0 => {
+            let millis = rate_limit.reset_timestamp.saturating_sub(now_millis);
+            clamp_to_u32(millis.div_ceil(1000))
+        }
+        _ => DEFAULT_RETRY_AFTER_SECONDS,
+    }
+}
+
+fn header_values(headers: &HeaderMap, name: &str) -> Option<Vec<u64>> {
+    let value = headers.get(name)?.to_str().ok()?;
+    let values = value
+        .split(',')
+        .map(|part| part.trim().parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+fn reset_to_timestamp_millis(reset: u64, now_millis: u64) -> u64 {
+    if reset > RESET_TIMESTAMP_THRESHOLD {
+        reset.saturating_mul(1000)
+    } else {
+        now_millis.saturating_add(reset.saturating_mul(1000))
+    }
+}
+
+fn clamp_to_u32(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golem::web_search::types::RateLimitInfo;
+    use crate::rate_limit::{rate_limit_from_headers_at, retry_after_from_headers_at};
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    const NOW: u64 = 1_700_000_000_000;
+
+    fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in values {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn picks_most_restrictive_window() {
+        let headers = headers(&[
+            ("x-ratelimit-limit", "1, 15000"),
+            ("x-ratelimit-remaining", "1, 0"),
+            ("x-ratelimit-reset", "1, 86400"),
+        ]);
+        assert_eq!(
+            rate_limit_from_headers_at(&headers, NOW),
+            Some(RateLimitInfo {
+                limit: 15000,
+                remaining: 0,
+                reset_timestamp: NOW + 86_400_000,
+            })
+        );
+        assert_eq!(retry_after_from_headers_at(&headers, NOW), 86400);
+    }
+
+    #[test]
+    fn supports_reset_timestamps() {
+        let headers = headers(&[
+            ("ratelimit-limit", "100"),
+            ("ratelimit-remaining", "42"),
+            ("ratelimit-reset", "1700000030"),
+        ]);
+        assert_eq!(
+            rate_limit_from_headers_at(&headers, NOW),
+            Some(RateLimitInfo {
+                limit: 100,
+                remaining: 42,
+                reset_timestamp: 1_700_000_030_000,
+            })
+        );
+    }
+
+    #[test]
+    fn prefers_retry_after() {
+        let headers = headers(&[
+            ("retry-after", "12"),
+            ("x-ratelimit-limit", "1"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1"),
+        ]);
+        assert_eq!(retry_after_from_headers_at(&headers, NOW), 12);
+    }
+
+    #[test]
+    fn missing_headers() {
+        let headers = HeaderMap::new();
+        assert_eq!(rate_limit_from_headers_at(&headers, NOW), None);
+        assert_eq!(retry_after_from_headers_at(&headers, NOW), 60);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::golem::web_search::types::QueryOperators;
+use crate::golem::web_search::web_search::SearchParams;
+
+/// Search parameters for the query, with all other parameters unset
+pub fn search_params(query: &str) -> SearchParams {
+    SearchParams {
+        query: query.to_string(),
+        safe_search: None,
+        language: None,
+        region: None,
+        max_results: None,
+        time_range: None,
+        include_domains: None,
+        exclude_domains: None,
+        include_images: None,
+        include_html: None,
+        advanced_answer: None,
+        search_type: None,
+        query_operators: None,
+        provider_options: None,
+    }
+}
+
+/// Search parameters for the query refined with the query operators
+pub fn search_params_with_operators(query: &str, operators: QueryOperators) -> SearchParams {
+    SearchParams {
+        query_operators: Some(operators),
+        ..search_params(query)
+    }
+}
+
+/// Query operators using every operator except `must-terms`, restricted to two sites
+pub fn query_operators() -> QueryOperators {
+    QueryOperators {
+        exact_phrases: Some(vec!["borrow checker".to_string()]),
+        must_terms: None,
+        must_not_terms: Some(vec!["java".to_string()]),
+        filetype: Some("pdf".to_string()),
+        sites: Some(vec!["rust-lang.org".to_string(), "docs.rs".to_string()]),
+    }
+}
@@ -68,6 +68,25 @@ pub struct SearchMetadata {
     /// Current page number
     #[serde(rename = "current-page")]
     pub current_page: u32,
+    /// Direct answer to the query (if requested and available)
+    #[serde(default)]
+    pub answer: Option<Answer>,
+    /// Whether the page was served from the result cache (if the cache is enabled)
+    #[serde(rename = "cache-status", default)]
+    pub cache_status: Option<CacheStatus>,
+    /// Providers of a meta-search which failed (if any)
+    #[serde(rename = "failed-providers", default)]
+    pub failed_providers: Option<Vec<String>>,
+}
+
+/// Direct answer to the query generated or extracted by the provider.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Answer {
+    /// Text of the answer
+    pub text: String,
+    /// URLs of the sources the answer is based on
+    #[serde(rename = "source-urls")]
+    pub source_urls: Vec<String>,
 }
 
 /// Level of safe search filtering.
@@ -79,6 +98,14 @@ pub enum SafeSearchLevel {
     High,
 }
 
+/// Result cache lookup outcome of a page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheStatus {
+    Hit,
+    Miss,
+}
+
 /// Metadata about the API's rate limits.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RateLimitInfo {